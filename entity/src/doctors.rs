@@ -20,6 +20,10 @@ pub struct Model {
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(has_many = "super::clinical_note_addenda::Entity")]
+    ClinicalNoteAddenda,
+    #[sea_orm(has_many = "super::clinical_notes::Entity")]
+    ClinicalNotes,
     #[sea_orm(has_many = "super::doctor_schedules::Entity")]
     DoctorSchedules,
     #[sea_orm(
@@ -30,6 +34,8 @@ pub enum Relation {
         on_delete = "Cascade"
     )]
     Employees,
+    #[sea_orm(has_many = "super::encounters::Entity")]
+    Encounters,
     #[sea_orm(
         belongs_to = "super::polyclinic::Entity",
         from = "Column::PolyclinicId",
@@ -38,6 +44,10 @@ pub enum Relation {
         on_delete = "Cascade"
     )]
     Polyclinic,
+    #[sea_orm(has_many = "super::prescriptions::Entity")]
+    Prescriptions,
+    #[sea_orm(has_many = "super::queue_ticket::Entity")]
+    QueueTicket,
     #[sea_orm(
         belongs_to = "super::rooms::Entity",
         from = "Column::RoomCode",
@@ -46,6 +56,22 @@ pub enum Relation {
         on_delete = "Cascade"
     )]
     Rooms,
+    #[sea_orm(has_many = "super::visit_diagnoses::Entity")]
+    VisitDiagnoses,
+    #[sea_orm(has_many = "super::visit_procedures::Entity")]
+    VisitProcedures,
+}
+
+impl Related<super::clinical_note_addenda::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ClinicalNoteAddenda.def()
+    }
+}
+
+impl Related<super::clinical_notes::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ClinicalNotes.def()
+    }
 }
 
 impl Related<super::doctor_schedules::Entity> for Entity {
@@ -60,16 +86,46 @@ impl Related<super::employees::Entity> for Entity {
     }
 }
 
+impl Related<super::encounters::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Encounters.def()
+    }
+}
+
 impl Related<super::polyclinic::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Polyclinic.def()
     }
 }
 
+impl Related<super::prescriptions::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Prescriptions.def()
+    }
+}
+
+impl Related<super::queue_ticket::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::QueueTicket.def()
+    }
+}
+
 impl Related<super::rooms::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Rooms.def()
     }
 }
 
+impl Related<super::visit_diagnoses::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::VisitDiagnoses.def()
+    }
+}
+
+impl Related<super::visit_procedures::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::VisitProcedures.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
@@ -2,18 +2,40 @@
 
 pub mod prelude;
 
+pub mod clinical_note_addenda;
+pub mod clinical_notes;
 pub mod departments;
 pub mod doctor_schedules;
 pub mod doctors;
+pub mod drug_batches;
+pub mod drugs;
 pub mod employee_position;
 pub mod employees;
+pub mod encounters;
+pub mod goods_receipts;
+pub mod icd10_codes;
+pub mod icd9cm_procedures;
+pub mod mrn_counters;
 pub mod nurses;
 pub mod nurses_polyclinic_assignments;
+pub mod patient_allergies;
+pub mod patient_history;
+pub mod patient_merges;
 pub mod patients;
 pub mod patients_visit_intent;
 pub mod polyclinic;
 pub mod position_titles;
+pub mod prescription_interaction_alerts;
+pub mod prescription_item_components;
+pub mod prescription_items;
+pub mod prescriptions;
+pub mod queue_counters;
 pub mod queue_ticket;
 pub mod referral_documents;
 pub mod rooms;
+pub mod stock_movements;
+pub mod storage_locations;
 pub mod user;
+pub mod visit_diagnoses;
+pub mod visit_procedures;
+pub mod vital_signs;
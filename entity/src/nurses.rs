@@ -36,6 +36,8 @@ pub enum Relation {
         on_delete = "Cascade"
     )]
     Polyclinic,
+    #[sea_orm(has_many = "super::vital_signs::Entity")]
+    VitalSigns,
 }
 
 impl Related<super::employees::Entity> for Entity {
@@ -56,4 +58,10 @@ impl Related<super::polyclinic::Entity> for Entity {
     }
 }
 
+impl Related<super::vital_signs::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::VitalSigns.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
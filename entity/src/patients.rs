@@ -10,27 +10,60 @@ pub struct Model {
     pub name: String,
     pub date_of_birth: Date,
     #[sea_orm(unique)]
-    pub national_id: String,
+    pub national_id: Option<String>,
     #[sea_orm(unique)]
     pub bpjs_number: Option<String>,
     pub gender: String,
-    pub emergency_contact_name: String,
-    pub emergency_contact_phone: String,
-    pub emergency_contact_relationship: String,
-    pub blood_type: String,
+    pub emergency_contact_name: Option<String>,
+    pub emergency_contact_phone: Option<String>,
+    pub emergency_contact_relationship: Option<String>,
+    pub blood_type: Option<String>,
     pub known_allergies: Option<String>,
+    pub merged_into_id: Option<i32>,
+    #[sea_orm(unique)]
+    pub medical_record_number: Option<String>,
+    pub mrn_scope: Option<String>,
+    pub mrn_sequence: Option<i32>,
+    pub is_unidentified: bool,
+    pub date_of_birth_estimated: bool,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub identification_notes: Option<String>,
     pub created_at: DateTime,
     pub updated_at: DateTime,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(
+        belongs_to = "Entity",
+        from = "Column::MergedIntoId",
+        to = "Column::Id",
+        on_update = "NoAction",
+        on_delete = "SetNull"
+    )]
+    SelfRef,
+    #[sea_orm(has_many = "super::patient_allergies::Entity")]
+    PatientAllergies,
+    #[sea_orm(has_many = "super::patient_history::Entity")]
+    PatientHistory,
     #[sea_orm(has_many = "super::patients_visit_intent::Entity")]
     PatientsVisitIntent,
     #[sea_orm(has_many = "super::referral_documents::Entity")]
     ReferralDocuments,
 }
 
+impl Related<super::patient_allergies::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::PatientAllergies.def()
+    }
+}
+
+impl Related<super::patient_history::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::PatientHistory.def()
+    }
+}
+
 impl Related<super::patients_visit_intent::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::PatientsVisitIntent.def()
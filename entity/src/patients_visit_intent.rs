@@ -9,14 +9,51 @@ pub struct Model {
     pub id: i32,
     pub patient_id: i32,
     pub visit_type: String,
-    pub status: String,
+    pub status: VisitStatus,
     pub created_at: DateTime,
     pub updated_at: DateTime,
     pub referral_document_id: Option<i32>,
+    pub priority: PriorityCategory,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum, DeriveDisplay)]
+#[sea_orm(rs_type = "String", db_type = "Text")]
+pub enum PriorityCategory {
+    #[sea_orm(string_value = "REGULAR")]
+    Regular,
+    #[sea_orm(string_value = "ELDERLY")]
+    Elderly,
+    #[sea_orm(string_value = "DISABLED")]
+    Disabled,
+    #[sea_orm(string_value = "PREGNANT")]
+    Pregnant,
+    #[sea_orm(string_value = "EMERGENCY")]
+    Emergency,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum, DeriveDisplay)]
+#[sea_orm(rs_type = "String", db_type = "Text")]
+pub enum VisitStatus {
+    #[sea_orm(string_value = "WAITING")]
+    Waiting,
+    #[sea_orm(string_value = "CALLED")]
+    Called,
+    #[sea_orm(string_value = "NO_SHOW")]
+    NoShow,
+    #[sea_orm(string_value = "DONE")]
+    Done,
+    #[sea_orm(string_value = "CANCELED")]
+    Canceled,
+    #[sea_orm(string_value = "EXPIRED")]
+    Expired,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(has_many = "super::clinical_notes::Entity")]
+    ClinicalNotes,
+    #[sea_orm(has_one = "super::encounters::Entity")]
+    Encounters,
     #[sea_orm(
         belongs_to = "super::patients::Entity",
         from = "Column::PatientId",
@@ -25,6 +62,8 @@ pub enum Relation {
         on_delete = "Cascade"
     )]
     Patients,
+    #[sea_orm(has_many = "super::prescriptions::Entity")]
+    Prescriptions,
     #[sea_orm(has_one = "super::queue_ticket::Entity")]
     QueueTicket,
     #[sea_orm(
@@ -35,6 +74,24 @@ pub enum Relation {
         on_delete = "Cascade"
     )]
     ReferralDocuments,
+    #[sea_orm(has_many = "super::visit_diagnoses::Entity")]
+    VisitDiagnoses,
+    #[sea_orm(has_many = "super::visit_procedures::Entity")]
+    VisitProcedures,
+    #[sea_orm(has_many = "super::vital_signs::Entity")]
+    VitalSigns,
+}
+
+impl Related<super::clinical_notes::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ClinicalNotes.def()
+    }
+}
+
+impl Related<super::encounters::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Encounters.def()
+    }
 }
 
 impl Related<super::patients::Entity> for Entity {
@@ -43,6 +100,12 @@ impl Related<super::patients::Entity> for Entity {
     }
 }
 
+impl Related<super::prescriptions::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Prescriptions.def()
+    }
+}
+
 impl Related<super::queue_ticket::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::QueueTicket.def()
@@ -55,4 +118,22 @@ impl Related<super::referral_documents::Entity> for Entity {
     }
 }
 
+impl Related<super::visit_diagnoses::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::VisitDiagnoses.def()
+    }
+}
+
+impl Related<super::visit_procedures::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::VisitProcedures.def()
+    }
+}
+
+impl Related<super::vital_signs::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::VitalSigns.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
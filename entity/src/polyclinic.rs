@@ -14,6 +14,8 @@ pub struct Model {
     pub description: Option<String>,
     pub department_code: String,
     pub created_at: DateTime,
+    #[sea_orm(unique)]
+    pub queue_prefix: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -30,10 +32,14 @@ pub enum Relation {
     DoctorSchedules,
     #[sea_orm(has_many = "super::doctors::Entity")]
     Doctors,
+    #[sea_orm(has_many = "super::encounters::Entity")]
+    Encounters,
     #[sea_orm(has_many = "super::nurses::Entity")]
     Nurses,
     #[sea_orm(has_many = "super::nurses_polyclinic_assignments::Entity")]
     NursesPolyclinicAssignments,
+    #[sea_orm(has_many = "super::queue_ticket::Entity")]
+    QueueTicket,
     #[sea_orm(
         belongs_to = "super::rooms::Entity",
         from = "Column::RoomCode",
@@ -42,6 +48,8 @@ pub enum Relation {
         on_delete = "Cascade"
     )]
     Rooms,
+    #[sea_orm(has_many = "super::visit_procedures::Entity")]
+    VisitProcedures,
 }
 
 impl Related<super::departments::Entity> for Entity {
@@ -62,6 +70,12 @@ impl Related<super::doctors::Entity> for Entity {
     }
 }
 
+impl Related<super::encounters::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Encounters.def()
+    }
+}
+
 impl Related<super::nurses::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Nurses.def()
@@ -74,10 +88,22 @@ impl Related<super::nurses_polyclinic_assignments::Entity> for Entity {
     }
 }
 
+impl Related<super::queue_ticket::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::QueueTicket.def()
+    }
+}
+
 impl Related<super::rooms::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Rooms.def()
     }
 }
 
+impl Related<super::visit_procedures::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::VisitProcedures.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
@@ -1,17 +1,39 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.11
 
+pub use super::clinical_note_addenda::Entity as ClinicalNoteAddenda;
+pub use super::clinical_notes::Entity as ClinicalNotes;
 pub use super::departments::Entity as Departments;
 pub use super::doctor_schedules::Entity as DoctorSchedules;
 pub use super::doctors::Entity as Doctors;
+pub use super::drug_batches::Entity as DrugBatches;
+pub use super::drugs::Entity as Drugs;
 pub use super::employee_position::Entity as EmployeePosition;
 pub use super::employees::Entity as Employees;
+pub use super::encounters::Entity as Encounters;
+pub use super::goods_receipts::Entity as GoodsReceipts;
+pub use super::icd10_codes::Entity as Icd10Codes;
+pub use super::icd9cm_procedures::Entity as Icd9cmProcedures;
+pub use super::mrn_counters::Entity as MrnCounters;
 pub use super::nurses::Entity as Nurses;
 pub use super::nurses_polyclinic_assignments::Entity as NursesPolyclinicAssignments;
+pub use super::patient_allergies::Entity as PatientAllergies;
+pub use super::patient_history::Entity as PatientHistory;
+pub use super::patient_merges::Entity as PatientMerges;
 pub use super::patients::Entity as Patients;
 pub use super::patients_visit_intent::Entity as PatientsVisitIntent;
 pub use super::polyclinic::Entity as Polyclinic;
 pub use super::position_titles::Entity as PositionTitles;
+pub use super::prescription_interaction_alerts::Entity as PrescriptionInteractionAlerts;
+pub use super::prescription_item_components::Entity as PrescriptionItemComponents;
+pub use super::prescription_items::Entity as PrescriptionItems;
+pub use super::prescriptions::Entity as Prescriptions;
+pub use super::queue_counters::Entity as QueueCounters;
 pub use super::queue_ticket::Entity as QueueTicket;
 pub use super::referral_documents::Entity as ReferralDocuments;
 pub use super::rooms::Entity as Rooms;
+pub use super::stock_movements::Entity as StockMovements;
+pub use super::storage_locations::Entity as StorageLocations;
 pub use super::user::Entity as User;
+pub use super::visit_diagnoses::Entity as VisitDiagnoses;
+pub use super::visit_procedures::Entity as VisitProcedures;
+pub use super::vital_signs::Entity as VitalSigns;
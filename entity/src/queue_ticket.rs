@@ -11,14 +11,56 @@ pub struct Model {
     pub visit_intent_id: i32,
     pub queue_number: i32,
     pub queue_type: String,
-    pub status: String,
+    pub status: QueueStatus,
     pub called_at: Option<DateTime>,
     pub done_at: Option<DateTime>,
     pub created_at: DateTime,
+    pub polyclinic_id: Option<i32>,
+    pub doctor_id: Option<i32>,
+    pub queue_code: String,
+    pub service_date: Date,
+    pub queue_key: String,
+    pub call_count: i32,
+    pub recalled_at: Option<DateTime>,
+    pub skipped_at: Option<DateTime>,
+    pub no_show_at: Option<DateTime>,
+    pub canceled_at: Option<DateTime>,
+    pub requeued_at: Option<DateTime>,
+    pub expired_at: Option<DateTime>,
+    pub sort_at: DateTime,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum, DeriveDisplay)]
+#[sea_orm(rs_type = "String", db_type = "Text")]
+pub enum QueueStatus {
+    #[sea_orm(string_value = "WAITING")]
+    Waiting,
+    #[sea_orm(string_value = "CALLED")]
+    Called,
+    #[sea_orm(string_value = "RECALLED")]
+    Recalled,
+    #[sea_orm(string_value = "SKIPPED")]
+    Skipped,
+    #[sea_orm(string_value = "NO_SHOW")]
+    NoShow,
+    #[sea_orm(string_value = "DONE")]
+    Done,
+    #[sea_orm(string_value = "CANCELED")]
+    Canceled,
+    #[sea_orm(string_value = "EXPIRED")]
+    Expired,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::doctors::Entity",
+        from = "Column::DoctorId",
+        to = "super::doctors::Column::Id",
+        on_update = "NoAction",
+        on_delete = "SetNull"
+    )]
+    Doctors,
     #[sea_orm(
         belongs_to = "super::patients_visit_intent::Entity",
         from = "Column::VisitIntentId",
@@ -27,6 +69,20 @@ pub enum Relation {
         on_delete = "Cascade"
     )]
     PatientsVisitIntent,
+    #[sea_orm(
+        belongs_to = "super::polyclinic::Entity",
+        from = "Column::PolyclinicId",
+        to = "super::polyclinic::Column::Id",
+        on_update = "NoAction",
+        on_delete = "SetNull"
+    )]
+    Polyclinic,
+}
+
+impl Related<super::doctors::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Doctors.def()
+    }
 }
 
 impl Related<super::patients_visit_intent::Entity> for Entity {
@@ -35,4 +91,10 @@ impl Related<super::patients_visit_intent::Entity> for Entity {
     }
 }
 
+impl Related<super::polyclinic::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Polyclinic.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
@@ -20,6 +20,8 @@ pub enum Relation {
     Doctors,
     #[sea_orm(has_many = "super::polyclinic::Entity")]
     Polyclinic,
+    #[sea_orm(has_many = "super::visit_procedures::Entity")]
+    VisitProcedures,
 }
 
 impl Related<super::doctors::Entity> for Entity {
@@ -34,4 +36,10 @@ impl Related<super::polyclinic::Entity> for Entity {
     }
 }
 
+impl Related<super::visit_procedures::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::VisitProcedures.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
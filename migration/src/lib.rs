@@ -1,13 +1,17 @@
 pub use sea_orm_migration::prelude::*;
 
+#[allow(clippy::upper_case_acronyms)]
 mod m20250508_094052_create_patient_visit_intent_table;
+#[allow(clippy::upper_case_acronyms)]
 mod m20250509_061644_create_queue_ticket_table;
 mod m20250509_070014_create_patient_table;
 mod m20250511_121632_alter_table_queue_ticket;
 mod m20250511_123421_alter_table_patients;
 mod m20250512_050855_alter_patients_table;
+#[allow(clippy::upper_case_acronyms)]
 mod m20250521_071832_create_referral_documents_table;
 mod m20250521_094041_alter_patients_visit_intent_table;
+#[allow(clippy::upper_case_acronyms, clippy::useless_conversion)]
 mod m20250527_112721_create_table_employees;
 mod m20250527_114154_create_user_table;
 mod m20250527_141001_create_table_departments;
@@ -19,8 +23,31 @@ mod m20250528_055359_create_table_position_title;
 mod m20250528_060315_create_table_employee_position;
 mod m20250528_061735_create_table_doctor_schedule;
 mod m20250528_062543_create_table_nurse_polyclinic_assignment;
+#[allow(clippy::needless_borrow)]
 mod m20250528_144437_alter_table_department;
 mod m20250529_023225_alter_table_department_head_id_null;
+mod m20250602_081512_rename_table_user_to_users;
+mod m20250603_021045_alter_table_queue_ticket_polyclinic;
+mod m20250604_030512_create_table_queue_counters;
+mod m20250605_014233_alter_table_queue_ticket_status_timestamps;
+mod m20250606_072318_alter_table_queue_ticket_requeue;
+mod m20250607_033720_alter_table_patients_visit_intent_priority;
+mod m20250608_024517_add_patients_name_trigram_index;
+mod m20250609_013204_create_table_patient_history;
+mod m20250610_021147_create_table_patient_merges;
+mod m20250611_040322_add_patients_medical_record_number;
+mod m20250612_015630_create_table_patient_allergies;
+mod m20250613_020914_add_unidentified_patients;
+mod m20250614_031208_create_table_encounters;
+mod m20250615_022451_create_table_vital_signs;
+mod m20250616_021735_create_table_clinical_notes;
+mod m20250617_023318_create_table_icd10_codes;
+mod m20250617_024105_create_table_visit_diagnoses;
+mod m20250618_020417_create_table_icd9cm_procedures;
+mod m20250618_021552_create_table_visit_procedures;
+mod m20250619_023840_create_table_prescriptions;
+mod m20250620_024512_create_table_pharmacy_stock;
+mod m20250621_030226_create_table_prescription_interaction_alerts;
 
 pub struct Migrator;
 
@@ -49,6 +76,28 @@ impl MigratorTrait for Migrator {
             Box::new(m20250528_062543_create_table_nurse_polyclinic_assignment::Migration),
             Box::new(m20250529_023225_alter_table_department_head_id_null::Migration),
             Box::new(m20250528_144437_alter_table_department::Migration),
+            Box::new(m20250602_081512_rename_table_user_to_users::Migration),
+            Box::new(m20250603_021045_alter_table_queue_ticket_polyclinic::Migration),
+            Box::new(m20250604_030512_create_table_queue_counters::Migration),
+            Box::new(m20250605_014233_alter_table_queue_ticket_status_timestamps::Migration),
+            Box::new(m20250606_072318_alter_table_queue_ticket_requeue::Migration),
+            Box::new(m20250607_033720_alter_table_patients_visit_intent_priority::Migration),
+            Box::new(m20250608_024517_add_patients_name_trigram_index::Migration),
+            Box::new(m20250609_013204_create_table_patient_history::Migration),
+            Box::new(m20250610_021147_create_table_patient_merges::Migration),
+            Box::new(m20250611_040322_add_patients_medical_record_number::Migration),
+            Box::new(m20250612_015630_create_table_patient_allergies::Migration),
+            Box::new(m20250613_020914_add_unidentified_patients::Migration),
+            Box::new(m20250614_031208_create_table_encounters::Migration),
+            Box::new(m20250615_022451_create_table_vital_signs::Migration),
+            Box::new(m20250616_021735_create_table_clinical_notes::Migration),
+            Box::new(m20250617_023318_create_table_icd10_codes::Migration),
+            Box::new(m20250617_024105_create_table_visit_diagnoses::Migration),
+            Box::new(m20250618_020417_create_table_icd9cm_procedures::Migration),
+            Box::new(m20250618_021552_create_table_visit_procedures::Migration),
+            Box::new(m20250619_023840_create_table_prescriptions::Migration),
+            Box::new(m20250620_024512_create_table_pharmacy_stock::Migration),
+            Box::new(m20250621_030226_create_table_prescription_interaction_alerts::Migration),
         ]
     }
 }
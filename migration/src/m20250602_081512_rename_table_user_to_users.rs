@@ -0,0 +1,38 @@
+use sea_orm_migration::prelude::*;
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20250602_081512_rename_table_user_to_users"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // entity::user maps to `users`, the original migration created `user`
+        if manager.has_table("user").await? && !manager.has_table("users").await? {
+            manager
+                .rename_table(Table::rename().table(User::Table, Users::Table).to_owned())
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .rename_table(Table::rename().table(Users::Table, User::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum User {
+    Table,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+}
@@ -0,0 +1,159 @@
+use sea_orm_migration::prelude::*;
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20250603_021045_alter_table_queue_ticket_polyclinic"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Polyclinic::Table)
+                    .add_column(ColumnDef::new(Polyclinic::QueuePrefix).string().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        // existing polyclinics get A, B, C, ... in id order, anything past Z keeps using its code
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"UPDATE polyclinic p
+                SET queue_prefix = s.prefix
+                FROM (
+                    SELECT id, chr(64 + (row_number() OVER (ORDER BY id))::int) AS prefix
+                    FROM polyclinic
+                ) s
+                WHERE p.id = s.id AND s.prefix BETWEEN 'A' AND 'Z'"#,
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-polyclinic_queue_prefix")
+                    .table(Polyclinic::Table)
+                    .col(Polyclinic::QueuePrefix)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(QueueTicket::Table)
+                    .add_column(ColumnDef::new(QueueTicket::PolyclinicId).integer().null())
+                    .add_column(ColumnDef::new(QueueTicket::DoctorId).integer().null())
+                    .add_column(
+                        ColumnDef::new(QueueTicket::QueueCode)
+                            .string()
+                            .not_null()
+                            .default(""),
+                    )
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk-queue_ticket-polyclinic_id")
+                            .from_tbl(QueueTicket::Table)
+                            .from_col(QueueTicket::PolyclinicId)
+                            .to_tbl(Polyclinic::Table)
+                            .to_col(Polyclinic::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk-queue_ticket-doctor_id")
+                            .from_tbl(QueueTicket::Table)
+                            .from_col(QueueTicket::DoctorId)
+                            .to_tbl(Doctors::Table)
+                            .to_col(Doctors::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"UPDATE queue_ticket
+                SET queue_code = queue_type || '-' || lpad(queue_number::text, 3, '0')
+                WHERE queue_code = ''"#,
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-queue_ticket_polyclinic_id")
+                    .table(QueueTicket::Table)
+                    .col(QueueTicket::PolyclinicId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx-queue_ticket_polyclinic_id")
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(QueueTicket::Table)
+                    .drop_foreign_key(Alias::new("fk-queue_ticket-polyclinic_id"))
+                    .drop_foreign_key(Alias::new("fk-queue_ticket-doctor_id"))
+                    .drop_column(QueueTicket::PolyclinicId)
+                    .drop_column(QueueTicket::DoctorId)
+                    .drop_column(QueueTicket::QueueCode)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_index(Index::drop().name("idx-polyclinic_queue_prefix").to_owned())
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Polyclinic::Table)
+                    .drop_column(Polyclinic::QueuePrefix)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Polyclinic {
+    Table,
+    Id,
+    QueuePrefix,
+}
+
+#[derive(DeriveIden)]
+enum QueueTicket {
+    Table,
+    PolyclinicId,
+    DoctorId,
+    QueueCode,
+}
+
+#[derive(DeriveIden)]
+enum Doctors {
+    Table,
+    Id,
+}
@@ -0,0 +1,165 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20250604_030512_create_table_queue_counters"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(QueueCounters::Table)
+                    .if_not_exists()
+                    .col(date(QueueCounters::ServiceDate))
+                    .col(string(QueueCounters::QueueKey))
+                    .col(integer(QueueCounters::LastNumber).default(0))
+                    .col(timestamp(QueueCounters::UpdatedAt).default(Expr::current_timestamp()))
+                    .primary_key(
+                        Index::create()
+                            .col(QueueCounters::ServiceDate)
+                            .col(QueueCounters::QueueKey),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(QueueTicket::Table)
+                    .add_column(
+                        ColumnDef::new(QueueTicket::ServiceDate)
+                            .date()
+                            .not_null()
+                            .default(Expr::current_date()),
+                    )
+                    .add_column(
+                        ColumnDef::new(QueueTicket::QueueKey)
+                            .string()
+                            .not_null()
+                            .default(""),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // created_at is stored in UTC, the service day is the hospital's (Asia/Jakarta) day
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"UPDATE queue_ticket
+                SET service_date = (created_at AT TIME ZONE 'UTC' AT TIME ZONE 'Asia/Jakarta')::date,
+                    queue_key = CASE
+                        WHEN polyclinic_id IS NULL THEN queue_type
+                        ELSE 'POLY-' || polyclinic_id
+                    END"#,
+            )
+            .await?;
+
+        // Numbers used to restart per visit type regardless of the day, so a day can hold the
+        // same number twice. Later duplicates move past the highest number of their day,
+        // in the order they were issued, before the numbers are made unique.
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"WITH ranked AS (
+                    SELECT id, service_date, queue_key, created_at,
+                        row_number() OVER (
+                            PARTITION BY service_date, queue_key, queue_number
+                            ORDER BY created_at, id
+                        ) AS occurrence,
+                        max(queue_number) OVER (PARTITION BY service_date, queue_key) AS day_max
+                    FROM queue_ticket
+                ),
+                moved AS (
+                    SELECT id, day_max + row_number() OVER (
+                        PARTITION BY service_date, queue_key
+                        ORDER BY created_at, id
+                    ) AS queue_number
+                    FROM ranked
+                    WHERE occurrence > 1
+                )
+                UPDATE queue_ticket t
+                SET queue_number = m.queue_number,
+                    queue_code = CASE
+                        WHEN t.queue_code = '' THEN t.queue_code
+                        ELSE regexp_replace(t.queue_code, '[0-9]+$', lpad(m.queue_number::text, 3, '0'))
+                    END
+                FROM moved m
+                WHERE t.id = m.id"#,
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"INSERT INTO queue_counters (service_date, queue_key, last_number)
+                SELECT service_date, queue_key, max(queue_number)
+                FROM queue_ticket
+                GROUP BY service_date, queue_key
+                ON CONFLICT (service_date, queue_key) DO NOTHING"#,
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-queue_ticket_service_date_queue_key_number")
+                    .table(QueueTicket::Table)
+                    .col(QueueTicket::ServiceDate)
+                    .col(QueueTicket::QueueKey)
+                    .col(QueueTicket::QueueNumber)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx-queue_ticket_service_date_queue_key_number")
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(QueueTicket::Table)
+                    .drop_column(QueueTicket::ServiceDate)
+                    .drop_column(QueueTicket::QueueKey)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_table(Table::drop().table(QueueCounters::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum QueueCounters {
+    Table,
+    ServiceDate,
+    QueueKey,
+    LastNumber,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum QueueTicket {
+    Table,
+    ServiceDate,
+    QueueKey,
+    QueueNumber,
+}
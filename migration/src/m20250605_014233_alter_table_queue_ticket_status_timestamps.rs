@@ -0,0 +1,81 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20250605_014233_alter_table_queue_ticket_status_timestamps"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(QueueTicket::Table)
+                    .add_column(integer(QueueTicket::CallCount).default(0))
+                    .add_column(timestamp_null(QueueTicket::RecalledAt))
+                    .add_column(timestamp_null(QueueTicket::SkippedAt))
+                    .add_column(timestamp_null(QueueTicket::NoShowAt))
+                    .add_column(timestamp_null(QueueTicket::CanceledAt))
+                    .to_owned(),
+            )
+            .await?;
+
+        // called_at and done_at defaulted to the insert time, so every ticket looked
+        // called and done from the moment it was created
+        let db = manager.get_connection();
+        db.execute_unprepared(
+            r#"ALTER TABLE queue_ticket
+                ALTER COLUMN called_at DROP DEFAULT,
+                ALTER COLUMN done_at DROP DEFAULT"#,
+        )
+        .await?;
+        db.execute_unprepared(
+            r#"UPDATE queue_ticket SET status = 'CANCELED' WHERE status = 'CANCELLED'"#,
+        )
+        .await?;
+        db.execute_unprepared(
+            r#"UPDATE queue_ticket SET
+                called_at = CASE WHEN status = 'WAITING' THEN NULL ELSE called_at END,
+                done_at = CASE WHEN status = 'DONE' THEN done_at ELSE NULL END,
+                call_count = CASE WHEN status = 'WAITING' THEN 0 ELSE 1 END"#,
+        )
+        .await?;
+        db.execute_unprepared(
+            r#"UPDATE patients_visit_intent AS intent SET status = 'CANCELED'
+                FROM queue_ticket AS ticket
+                WHERE ticket.visit_intent_id = intent.id AND ticket.status = 'CANCELED'"#,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(QueueTicket::Table)
+                    .drop_column(QueueTicket::CallCount)
+                    .drop_column(QueueTicket::RecalledAt)
+                    .drop_column(QueueTicket::SkippedAt)
+                    .drop_column(QueueTicket::NoShowAt)
+                    .drop_column(QueueTicket::CanceledAt)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum QueueTicket {
+    Table,
+    CallCount,
+    RecalledAt,
+    SkippedAt,
+    NoShowAt,
+    CanceledAt,
+}
@@ -0,0 +1,77 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20250606_072318_alter_table_queue_ticket_requeue"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(QueueTicket::Table)
+                    .add_column(timestamp_null(QueueTicket::RequeuedAt))
+                    .add_column(timestamp_null(QueueTicket::ExpiredAt))
+                    .add_column(timestamp(QueueTicket::SortAt).default(Expr::current_timestamp()))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared("UPDATE queue_ticket SET sort_at = created_at")
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-queue_ticket_queue_status_sort_at")
+                    .table(QueueTicket::Table)
+                    .col(QueueTicket::ServiceDate)
+                    .col(QueueTicket::QueueKey)
+                    .col(QueueTicket::Status)
+                    .col(QueueTicket::SortAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx-queue_ticket_queue_status_sort_at")
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(QueueTicket::Table)
+                    .drop_column(QueueTicket::RequeuedAt)
+                    .drop_column(QueueTicket::ExpiredAt)
+                    .drop_column(QueueTicket::SortAt)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum QueueTicket {
+    Table,
+    ServiceDate,
+    QueueKey,
+    Status,
+    RequeuedAt,
+    ExpiredAt,
+    SortAt,
+}
@@ -0,0 +1,60 @@
+use sea_orm_migration::prelude::*;
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20250608_024517_add_patients_name_trigram_index"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        db.execute_unprepared("CREATE EXTENSION IF NOT EXISTS pg_trgm")
+            .await?;
+
+        // Patient search compares lower(name) so the index has to be built on it as well
+        db.execute_unprepared(
+            r#"CREATE INDEX IF NOT EXISTS "idx-patients_name_trgm"
+            ON patients USING gin (lower(name) gin_trgm_ops)"#,
+        )
+        .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-patients_date_of_birth")
+                    .table(Patients::Table)
+                    .col(Patients::DateOfBirth)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx-patients_date_of_birth")
+                    .table(Patients::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(r#"DROP INDEX IF EXISTS "idx-patients_name_trgm""#)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Patients {
+    Table,
+    DateOfBirth,
+}
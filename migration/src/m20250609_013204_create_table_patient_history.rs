@@ -0,0 +1,85 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20250609_013204_create_table_patient_history"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(PatientHistory::Table)
+                    .if_not_exists()
+                    .col(pk_auto(PatientHistory::Id))
+                    .col(integer(PatientHistory::PatientId))
+                    .col(string(PatientHistory::Source))
+                    .col(integer_null(PatientHistory::ChangedBy))
+                    .col(json_binary(PatientHistory::Changes))
+                    .col(timestamp(PatientHistory::CreatedAt).default(Expr::current_timestamp()))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-patient_history-patient_id")
+                            .from(PatientHistory::Table, PatientHistory::PatientId)
+                            .to(Patients::Table, Patients::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-patient_history-changed_by")
+                            .from(PatientHistory::Table, PatientHistory::ChangedBy)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-patient_history_patient_id_created_at")
+                    .table(PatientHistory::Table)
+                    .col(PatientHistory::PatientId)
+                    .col(PatientHistory::CreatedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(PatientHistory::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum PatientHistory {
+    Table,
+    Id,
+    PatientId,
+    Source,
+    ChangedBy,
+    Changes,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Patients {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
@@ -0,0 +1,154 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20250610_021147_create_table_patient_merges"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // levenshtein() for spotting single-digit typos in NIK and BPJS numbers
+        manager
+            .get_connection()
+            .execute_unprepared("CREATE EXTENSION IF NOT EXISTS fuzzystrmatch")
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Patients::Table)
+                    .add_column(integer_null(Patients::MergedIntoId))
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk-patients-merged_into_id")
+                            .from_tbl(Patients::Table)
+                            .from_col(Patients::MergedIntoId)
+                            .to_tbl(Patients::Table)
+                            .to_col(Patients::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(PatientMerges::Table)
+                    .if_not_exists()
+                    .col(pk_auto(PatientMerges::Id))
+                    .col(integer(PatientMerges::SurvivorId))
+                    .col(integer(PatientMerges::MergedId))
+                    .col(json_binary(PatientMerges::VisitIntentIds))
+                    .col(json_binary(PatientMerges::ReferralDocumentIds))
+                    .col(text_null(PatientMerges::Reason))
+                    .col(integer_null(PatientMerges::MergedBy))
+                    .col(timestamp(PatientMerges::MergedAt).default(Expr::current_timestamp()))
+                    .col(integer_null(PatientMerges::UndoneBy))
+                    .col(timestamp_null(PatientMerges::UndoneAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-patient_merges-survivor_id")
+                            .from(PatientMerges::Table, PatientMerges::SurvivorId)
+                            .to(Patients::Table, Patients::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-patient_merges-merged_id")
+                            .from(PatientMerges::Table, PatientMerges::MergedId)
+                            .to(Patients::Table, Patients::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-patient_merges-merged_by")
+                            .from(PatientMerges::Table, PatientMerges::MergedBy)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-patient_merges-undone_by")
+                            .from(PatientMerges::Table, PatientMerges::UndoneBy)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-patient_merges_survivor_id")
+                    .table(PatientMerges::Table)
+                    .col(PatientMerges::SurvivorId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-patient_merges_merged_id")
+                    .table(PatientMerges::Table)
+                    .col(PatientMerges::MergedId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(PatientMerges::Table).to_owned())
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Patients::Table)
+                    .drop_foreign_key(Alias::new("fk-patients-merged_into_id"))
+                    .drop_column(Patients::MergedIntoId)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Patients {
+    Table,
+    Id,
+    MergedIntoId,
+}
+
+#[derive(DeriveIden)]
+enum PatientMerges {
+    Table,
+    Id,
+    SurvivorId,
+    MergedId,
+    VisitIntentIds,
+    ReferralDocumentIds,
+    Reason,
+    MergedBy,
+    MergedAt,
+    UndoneBy,
+    UndoneAt,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
@@ -0,0 +1,92 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20250611_040322_add_patients_medical_record_number"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(MrnCounters::Table)
+                    .if_not_exists()
+                    .col(string(MrnCounters::Scope).primary_key())
+                    .col(integer(MrnCounters::LastNumber).default(0))
+                    .col(timestamp(MrnCounters::UpdatedAt).default(Expr::current_timestamp()))
+                    .to_owned(),
+            )
+            .await?;
+
+        // Patients registered before record numbers existed get one at their next visit
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Patients::Table)
+                    .add_column(string_null(Patients::MedicalRecordNumber).unique_key())
+                    .add_column(string_null(Patients::MrnScope))
+                    .add_column(integer_null(Patients::MrnSequence))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-patients_mrn_scope_sequence")
+                    .table(Patients::Table)
+                    .col(Patients::MrnScope)
+                    .col(Patients::MrnSequence)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx-patients_mrn_scope_sequence")
+                    .table(Patients::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Patients::Table)
+                    .drop_column(Patients::MedicalRecordNumber)
+                    .drop_column(Patients::MrnScope)
+                    .drop_column(Patients::MrnSequence)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(MrnCounters::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum MrnCounters {
+    Table,
+    Scope,
+    LastNumber,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Patients {
+    Table,
+    MedicalRecordNumber,
+    MrnScope,
+    MrnSequence,
+}
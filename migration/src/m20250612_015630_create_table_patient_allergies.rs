@@ -0,0 +1,112 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20250612_015630_create_table_patient_allergies"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(PatientAllergies::Table)
+                    .if_not_exists()
+                    .col(pk_auto(PatientAllergies::Id))
+                    .col(integer(PatientAllergies::PatientId))
+                    .col(string(PatientAllergies::Substance))
+                    .col(string(PatientAllergies::Category))
+                    .col(text_null(PatientAllergies::Reaction))
+                    .col(string(PatientAllergies::Severity))
+                    .col(integer_null(PatientAllergies::RecordedBy))
+                    .col(integer_null(PatientAllergies::VerifiedBy))
+                    .col(timestamp_null(PatientAllergies::VerifiedAt))
+                    .col(timestamp(PatientAllergies::CreatedAt).default(Expr::current_timestamp()))
+                    .col(timestamp(PatientAllergies::UpdatedAt).default(Expr::current_timestamp()))
+                    .col(timestamp_null(PatientAllergies::DeletedAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-patient_allergies-patient_id")
+                            .from(PatientAllergies::Table, PatientAllergies::PatientId)
+                            .to(Patients::Table, Patients::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-patient_allergies-recorded_by")
+                            .from(PatientAllergies::Table, PatientAllergies::RecordedBy)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-patient_allergies-verified_by")
+                            .from(PatientAllergies::Table, PatientAllergies::VerifiedBy)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        let db = manager.get_connection();
+
+        // One active entry per substance; removed entries are kept for the record
+        db.execute_unprepared(
+            r#"CREATE UNIQUE INDEX IF NOT EXISTS "idx-patient_allergies_patient_id_substance"
+            ON patient_allergies (patient_id, lower(substance))
+            WHERE deleted_at IS NULL"#,
+        )
+        .await?;
+
+        // Triage stored an empty string when no allergies were given
+        db.execute_unprepared(
+            "UPDATE patients SET known_allergies = NULL WHERE btrim(known_allergies) = ''",
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(PatientAllergies::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum PatientAllergies {
+    Table,
+    Id,
+    PatientId,
+    Substance,
+    Category,
+    Reaction,
+    Severity,
+    RecordedBy,
+    VerifiedBy,
+    VerifiedAt,
+    CreatedAt,
+    UpdatedAt,
+    DeletedAt,
+}
+
+#[derive(DeriveIden)]
+enum Patients {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
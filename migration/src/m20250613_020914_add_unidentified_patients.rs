@@ -0,0 +1,127 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20250613_020914_add_unidentified_patients"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // An unconscious emergency patient has no NIK, next of kin or known blood type.
+        // NULL NIKs never collide, so the unique constraint stays in place for everyone else.
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Patients::Table)
+                    .modify_column(ColumnDef::new(Patients::NationalId).string().null())
+                    .modify_column(
+                        ColumnDef::new(Patients::EmergencyContactName)
+                            .string()
+                            .null(),
+                    )
+                    .modify_column(
+                        ColumnDef::new(Patients::EmergencyContactPhone)
+                            .string()
+                            .null(),
+                    )
+                    .modify_column(
+                        ColumnDef::new(Patients::EmergencyContactRelationship)
+                            .string()
+                            .null(),
+                    )
+                    .modify_column(ColumnDef::new(Patients::BloodType).string().null())
+                    .add_column(boolean(Patients::IsUnidentified).default(false))
+                    .add_column(boolean(Patients::DateOfBirthEstimated).default(false))
+                    .add_column(text_null(Patients::IdentificationNotes))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"ALTER TABLE patients ADD CONSTRAINT "chk-patients-national_id-identified"
+                CHECK (national_id IS NOT NULL OR is_unidentified)"#,
+            )
+            .await?;
+
+        // Allergies recorded on a temporary record follow it into the real patient
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(PatientMerges::Table)
+                    .add_column(json_binary(PatientMerges::AllergyIds).default(Expr::cust("'[]'")))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(PatientMerges::Table)
+                    .drop_column(PatientMerges::AllergyIds)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"ALTER TABLE patients DROP CONSTRAINT "chk-patients-national_id-identified""#,
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Patients::Table)
+                    .drop_column(Patients::IsUnidentified)
+                    .drop_column(Patients::DateOfBirthEstimated)
+                    .drop_column(Patients::IdentificationNotes)
+                    .modify_column(ColumnDef::new(Patients::NationalId).string().not_null())
+                    .modify_column(
+                        ColumnDef::new(Patients::EmergencyContactName)
+                            .string()
+                            .not_null(),
+                    )
+                    .modify_column(
+                        ColumnDef::new(Patients::EmergencyContactPhone)
+                            .string()
+                            .not_null(),
+                    )
+                    .modify_column(
+                        ColumnDef::new(Patients::EmergencyContactRelationship)
+                            .string()
+                            .not_null(),
+                    )
+                    .modify_column(ColumnDef::new(Patients::BloodType).string().not_null())
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Patients {
+    Table,
+    NationalId,
+    EmergencyContactName,
+    EmergencyContactPhone,
+    EmergencyContactRelationship,
+    BloodType,
+    IsUnidentified,
+    DateOfBirthEstimated,
+    IdentificationNotes,
+}
+
+#[derive(DeriveIden)]
+enum PatientMerges {
+    Table,
+    AllergyIds,
+}
@@ -0,0 +1,124 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20250614_031208_create_table_encounters"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Encounters::Table)
+                    .if_not_exists()
+                    .col(pk_auto(Encounters::Id))
+                    .col(integer_uniq(Encounters::VisitIntentId))
+                    .col(integer_null(Encounters::DoctorId))
+                    .col(integer(Encounters::PolyclinicId))
+                    .col(string_null(Encounters::RoomCode))
+                    .col(date(Encounters::ServiceDate))
+                    .col(string(Encounters::Status))
+                    .col(string_null(Encounters::Disposition))
+                    .col(text_null(Encounters::DispositionNotes))
+                    .col(timestamp_null(Encounters::StartedAt))
+                    .col(timestamp_null(Encounters::EndedAt))
+                    .col(timestamp(Encounters::CreatedAt).default(Expr::current_timestamp()))
+                    .col(timestamp(Encounters::UpdatedAt).default(Expr::current_timestamp()))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-encounters-visit_intent_id")
+                            .from(Encounters::Table, Encounters::VisitIntentId)
+                            .to(PatientsVisitIntent::Table, PatientsVisitIntent::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-encounters-doctor_id")
+                            .from(Encounters::Table, Encounters::DoctorId)
+                            .to(Doctors::Table, Doctors::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-encounters-polyclinic_id")
+                            .from(Encounters::Table, Encounters::PolyclinicId)
+                            .to(Polyclinic::Table, Polyclinic::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-encounters_doctor_id_service_date")
+                    .table(Encounters::Table)
+                    .col(Encounters::DoctorId)
+                    .col(Encounters::ServiceDate)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-encounters_polyclinic_id_service_date")
+                    .table(Encounters::Table)
+                    .col(Encounters::PolyclinicId)
+                    .col(Encounters::ServiceDate)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Encounters::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Encounters {
+    Table,
+    Id,
+    VisitIntentId,
+    DoctorId,
+    PolyclinicId,
+    RoomCode,
+    ServiceDate,
+    Status,
+    Disposition,
+    DispositionNotes,
+    StartedAt,
+    EndedAt,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum PatientsVisitIntent {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Doctors {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Polyclinic {
+    Table,
+    Id,
+}
@@ -0,0 +1,123 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20250615_022451_create_table_vital_signs"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Measurements are stored in one unit each: mmHg, beats and breaths per minute,
+        // degrees Celsius, percent, kilograms and centimetres
+        manager
+            .create_table(
+                Table::create()
+                    .table(VitalSigns::Table)
+                    .if_not_exists()
+                    .col(pk_auto(VitalSigns::Id))
+                    .col(integer(VitalSigns::VisitIntentId))
+                    .col(integer_null(VitalSigns::SystolicBp))
+                    .col(integer_null(VitalSigns::DiastolicBp))
+                    .col(integer_null(VitalSigns::PulseRate))
+                    .col(integer_null(VitalSigns::RespiratoryRate))
+                    .col(double_null(VitalSigns::TemperatureCelsius))
+                    .col(integer_null(VitalSigns::Spo2))
+                    .col(double_null(VitalSigns::WeightKg))
+                    .col(double_null(VitalSigns::HeightCm))
+                    .col(double_null(VitalSigns::Bmi))
+                    .col(integer_null(VitalSigns::PainScore))
+                    .col(text_null(VitalSigns::Notes))
+                    .col(integer_null(VitalSigns::NurseId))
+                    .col(integer_null(VitalSigns::RecordedBy))
+                    .col(timestamp(VitalSigns::MeasuredAt))
+                    .col(timestamp(VitalSigns::CreatedAt).default(Expr::current_timestamp()))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-vital_signs-visit_intent_id")
+                            .from(VitalSigns::Table, VitalSigns::VisitIntentId)
+                            .to(PatientsVisitIntent::Table, PatientsVisitIntent::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-vital_signs-nurse_id")
+                            .from(VitalSigns::Table, VitalSigns::NurseId)
+                            .to(Nurses::Table, Nurses::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-vital_signs-recorded_by")
+                            .from(VitalSigns::Table, VitalSigns::RecordedBy)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-vital_signs_visit_intent_id_measured_at")
+                    .table(VitalSigns::Table)
+                    .col(VitalSigns::VisitIntentId)
+                    .col(VitalSigns::MeasuredAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(VitalSigns::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum VitalSigns {
+    Table,
+    Id,
+    VisitIntentId,
+    SystolicBp,
+    DiastolicBp,
+    PulseRate,
+    RespiratoryRate,
+    TemperatureCelsius,
+    Spo2,
+    WeightKg,
+    HeightCm,
+    Bmi,
+    PainScore,
+    Notes,
+    NurseId,
+    RecordedBy,
+    MeasuredAt,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum PatientsVisitIntent {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Nurses {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
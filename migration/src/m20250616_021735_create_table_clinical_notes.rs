@@ -0,0 +1,207 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20250616_021735_create_table_clinical_notes"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ClinicalNotes::Table)
+                    .if_not_exists()
+                    .col(pk_auto(ClinicalNotes::Id))
+                    .col(integer(ClinicalNotes::VisitIntentId))
+                    .col(integer(ClinicalNotes::DoctorId))
+                    .col(string(ClinicalNotes::Status))
+                    .col(text_null(ClinicalNotes::Subjective))
+                    .col(text_null(ClinicalNotes::Objective))
+                    .col(text_null(ClinicalNotes::Assessment))
+                    .col(text_null(ClinicalNotes::Plan))
+                    .col(timestamp_null(ClinicalNotes::SignedAt))
+                    .col(timestamp(ClinicalNotes::CreatedAt).default(Expr::current_timestamp()))
+                    .col(timestamp(ClinicalNotes::UpdatedAt).default(Expr::current_timestamp()))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-clinical_notes-visit_intent_id")
+                            .from(ClinicalNotes::Table, ClinicalNotes::VisitIntentId)
+                            .to(PatientsVisitIntent::Table, PatientsVisitIntent::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-clinical_notes-doctor_id")
+                            .from(ClinicalNotes::Table, ClinicalNotes::DoctorId)
+                            .to(Doctors::Table, Doctors::Id)
+                            .on_delete(ForeignKeyAction::Restrict)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-clinical_notes_visit_intent_id")
+                    .table(ClinicalNotes::Table)
+                    .col(ClinicalNotes::VisitIntentId)
+                    .to_owned(),
+            )
+            .await?;
+
+        // A doctor keeps at most one draft per visit
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"CREATE UNIQUE INDEX IF NOT EXISTS "idx-clinical_notes_visit_doctor_draft"
+                ON clinical_notes (visit_intent_id, doctor_id)
+                WHERE status = 'DRAFT'"#,
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(ClinicalNoteAddenda::Table)
+                    .if_not_exists()
+                    .col(pk_auto(ClinicalNoteAddenda::Id))
+                    .col(integer(ClinicalNoteAddenda::NoteId))
+                    .col(integer(ClinicalNoteAddenda::DoctorId))
+                    .col(text(ClinicalNoteAddenda::Content))
+                    .col(
+                        timestamp(ClinicalNoteAddenda::CreatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-clinical_note_addenda-note_id")
+                            .from(ClinicalNoteAddenda::Table, ClinicalNoteAddenda::NoteId)
+                            .to(ClinicalNotes::Table, ClinicalNotes::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-clinical_note_addenda-doctor_id")
+                            .from(ClinicalNoteAddenda::Table, ClinicalNoteAddenda::DoctorId)
+                            .to(Doctors::Table, Doctors::Id)
+                            .on_delete(ForeignKeyAction::Restrict)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-clinical_note_addenda_note_id")
+                    .table(ClinicalNoteAddenda::Table)
+                    .col(ClinicalNoteAddenda::NoteId)
+                    .to_owned(),
+            )
+            .await?;
+
+        // Signed notes and their addenda are part of the medical record and are never
+        // rewritten, whichever client touches the table
+        let db = manager.get_connection();
+        db.execute_unprepared(
+            r#"CREATE OR REPLACE FUNCTION clinical_notes_reject_signed_update()
+            RETURNS trigger AS $$
+            BEGIN
+                IF OLD.status = 'SIGNED' THEN
+                    RAISE EXCEPTION 'clinical note % is signed and cannot be changed', OLD.id;
+                END IF;
+                RETURN NEW;
+            END;
+            $$ LANGUAGE plpgsql"#,
+        )
+        .await?;
+        db.execute_unprepared(
+            r#"CREATE TRIGGER "trg-clinical_notes_signed_immutable"
+            BEFORE UPDATE ON clinical_notes
+            FOR EACH ROW EXECUTE FUNCTION clinical_notes_reject_signed_update()"#,
+        )
+        .await?;
+        db.execute_unprepared(
+            r#"CREATE OR REPLACE FUNCTION clinical_note_addenda_reject_update()
+            RETURNS trigger AS $$
+            BEGIN
+                RAISE EXCEPTION 'addendum % cannot be changed', OLD.id;
+            END;
+            $$ LANGUAGE plpgsql"#,
+        )
+        .await?;
+        db.execute_unprepared(
+            r#"CREATE TRIGGER "trg-clinical_note_addenda_immutable"
+            BEFORE UPDATE ON clinical_note_addenda
+            FOR EACH ROW EXECUTE FUNCTION clinical_note_addenda_reject_update()"#,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ClinicalNoteAddenda::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(ClinicalNotes::Table).to_owned())
+            .await?;
+
+        let db = manager.get_connection();
+        db.execute_unprepared("DROP FUNCTION IF EXISTS clinical_note_addenda_reject_update()")
+            .await?;
+        db.execute_unprepared("DROP FUNCTION IF EXISTS clinical_notes_reject_signed_update()")
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum ClinicalNotes {
+    Table,
+    Id,
+    VisitIntentId,
+    DoctorId,
+    Status,
+    Subjective,
+    Objective,
+    Assessment,
+    Plan,
+    SignedAt,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum ClinicalNoteAddenda {
+    Table,
+    Id,
+    NoteId,
+    DoctorId,
+    Content,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum PatientsVisitIntent {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Doctors {
+    Table,
+    Id,
+}
@@ -0,0 +1,49 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20250617_023318_create_table_icd10_codes"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Codes are kept as published, with the dot, e.g. `J06.9`. The catalog is loaded
+        // by the seeder; a retired code is deactivated rather than deleted so diagnoses
+        // recorded with it stay readable
+        manager
+            .create_table(
+                Table::create()
+                    .table(Icd10Codes::Table)
+                    .if_not_exists()
+                    .col(pk_auto(Icd10Codes::Id))
+                    .col(string_uniq(Icd10Codes::Code))
+                    .col(text(Icd10Codes::DescriptionEn))
+                    .col(text(Icd10Codes::DescriptionId))
+                    .col(boolean(Icd10Codes::IsActive).default(true))
+                    .col(timestamp(Icd10Codes::CreatedAt).default(Expr::current_timestamp()))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Icd10Codes::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Icd10Codes {
+    Table,
+    Id,
+    Code,
+    DescriptionEn,
+    DescriptionId,
+    IsActive,
+    CreatedAt,
+}
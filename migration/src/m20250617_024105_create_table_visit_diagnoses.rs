@@ -0,0 +1,116 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20250617_024105_create_table_visit_diagnoses"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(VisitDiagnoses::Table)
+                    .if_not_exists()
+                    .col(pk_auto(VisitDiagnoses::Id))
+                    .col(integer(VisitDiagnoses::VisitIntentId))
+                    .col(string(VisitDiagnoses::Icd10Code))
+                    .col(integer(VisitDiagnoses::DoctorId))
+                    .col(string(VisitDiagnoses::DiagnosisType))
+                    .col(text_null(VisitDiagnoses::Notes))
+                    .col(timestamp(VisitDiagnoses::CreatedAt).default(Expr::current_timestamp()))
+                    .col(timestamp(VisitDiagnoses::UpdatedAt).default(Expr::current_timestamp()))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-visit_diagnoses-visit_intent_id")
+                            .from(VisitDiagnoses::Table, VisitDiagnoses::VisitIntentId)
+                            .to(PatientsVisitIntent::Table, PatientsVisitIntent::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-visit_diagnoses-icd10_code")
+                            .from(VisitDiagnoses::Table, VisitDiagnoses::Icd10Code)
+                            .to(Icd10Codes::Table, Icd10Codes::Code)
+                            .on_delete(ForeignKeyAction::Restrict)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-visit_diagnoses-doctor_id")
+                            .from(VisitDiagnoses::Table, VisitDiagnoses::DoctorId)
+                            .to(Doctors::Table, Doctors::Id)
+                            .on_delete(ForeignKeyAction::Restrict)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-visit_diagnoses_visit_intent_id_icd10_code")
+                    .table(VisitDiagnoses::Table)
+                    .col(VisitDiagnoses::VisitIntentId)
+                    .col(VisitDiagnoses::Icd10Code)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        // A visit has at most one primary diagnosis
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"CREATE UNIQUE INDEX IF NOT EXISTS "idx-visit_diagnoses_visit_primary"
+                ON visit_diagnoses (visit_intent_id)
+                WHERE diagnosis_type = 'PRIMARY'"#,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(VisitDiagnoses::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum VisitDiagnoses {
+    Table,
+    Id,
+    VisitIntentId,
+    Icd10Code,
+    DoctorId,
+    DiagnosisType,
+    Notes,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum PatientsVisitIntent {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Icd10Codes {
+    Table,
+    Code,
+}
+
+#[derive(DeriveIden)]
+enum Doctors {
+    Table,
+    Id,
+}
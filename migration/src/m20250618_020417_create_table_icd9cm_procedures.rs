@@ -0,0 +1,47 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20250618_020417_create_table_icd9cm_procedures"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Codes are kept as published, with the dot, e.g. `96.04`
+        manager
+            .create_table(
+                Table::create()
+                    .table(Icd9cmProcedures::Table)
+                    .if_not_exists()
+                    .col(pk_auto(Icd9cmProcedures::Id))
+                    .col(string_uniq(Icd9cmProcedures::Code))
+                    .col(text(Icd9cmProcedures::DescriptionEn))
+                    .col(text(Icd9cmProcedures::DescriptionId))
+                    .col(boolean(Icd9cmProcedures::IsActive).default(true))
+                    .col(timestamp(Icd9cmProcedures::CreatedAt).default(Expr::current_timestamp()))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Icd9cmProcedures::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Icd9cmProcedures {
+    Table,
+    Id,
+    Code,
+    DescriptionEn,
+    DescriptionId,
+    IsActive,
+    CreatedAt,
+}
@@ -0,0 +1,149 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20250618_021552_create_table_visit_procedures"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(VisitProcedures::Table)
+                    .if_not_exists()
+                    .col(pk_auto(VisitProcedures::Id))
+                    .col(integer(VisitProcedures::VisitIntentId))
+                    .col(string(VisitProcedures::ProcedureCode))
+                    .col(integer(VisitProcedures::DoctorId))
+                    .col(integer_null(VisitProcedures::PolyclinicId))
+                    .col(string(VisitProcedures::RoomCode))
+                    .col(timestamp(VisitProcedures::PerformedAt))
+                    .col(text_null(VisitProcedures::Notes))
+                    .col(integer_null(VisitProcedures::RecordedBy))
+                    .col(timestamp(VisitProcedures::CreatedAt).default(Expr::current_timestamp()))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-visit_procedures-visit_intent_id")
+                            .from(VisitProcedures::Table, VisitProcedures::VisitIntentId)
+                            .to(PatientsVisitIntent::Table, PatientsVisitIntent::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-visit_procedures-procedure_code")
+                            .from(VisitProcedures::Table, VisitProcedures::ProcedureCode)
+                            .to(Icd9cmProcedures::Table, Icd9cmProcedures::Code)
+                            .on_delete(ForeignKeyAction::Restrict)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-visit_procedures-doctor_id")
+                            .from(VisitProcedures::Table, VisitProcedures::DoctorId)
+                            .to(Doctors::Table, Doctors::Id)
+                            .on_delete(ForeignKeyAction::Restrict)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-visit_procedures-polyclinic_id")
+                            .from(VisitProcedures::Table, VisitProcedures::PolyclinicId)
+                            .to(Polyclinic::Table, Polyclinic::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-visit_procedures-room_code")
+                            .from(VisitProcedures::Table, VisitProcedures::RoomCode)
+                            .to(Rooms::Table, Rooms::Code)
+                            .on_delete(ForeignKeyAction::Restrict)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-visit_procedures-recorded_by")
+                            .from(VisitProcedures::Table, VisitProcedures::RecordedBy)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-visit_procedures_visit_intent_id_performed_at")
+                    .table(VisitProcedures::Table)
+                    .col(VisitProcedures::VisitIntentId)
+                    .col(VisitProcedures::PerformedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(VisitProcedures::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum VisitProcedures {
+    Table,
+    Id,
+    VisitIntentId,
+    ProcedureCode,
+    DoctorId,
+    PolyclinicId,
+    RoomCode,
+    PerformedAt,
+    Notes,
+    RecordedBy,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum PatientsVisitIntent {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Icd9cmProcedures {
+    Table,
+    Code,
+}
+
+#[derive(DeriveIden)]
+enum Doctors {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Polyclinic {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Rooms {
+    Table,
+    Code,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
@@ -0,0 +1,246 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20250619_023840_create_table_prescriptions"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Prescriptions::Table)
+                    .if_not_exists()
+                    .col(pk_auto(Prescriptions::Id))
+                    .col(integer(Prescriptions::VisitIntentId))
+                    .col(integer(Prescriptions::DoctorId))
+                    .col(string(Prescriptions::Status))
+                    .col(text_null(Prescriptions::Notes))
+                    .col(text_null(Prescriptions::AllergyOverrideReason))
+                    .col(timestamp_null(Prescriptions::SubmittedAt))
+                    .col(timestamp_null(Prescriptions::DispensedAt))
+                    .col(integer_null(Prescriptions::DispensedBy))
+                    .col(timestamp(Prescriptions::CreatedAt).default(Expr::current_timestamp()))
+                    .col(timestamp(Prescriptions::UpdatedAt).default(Expr::current_timestamp()))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-prescriptions-visit_intent_id")
+                            .from(Prescriptions::Table, Prescriptions::VisitIntentId)
+                            .to(PatientsVisitIntent::Table, PatientsVisitIntent::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-prescriptions-doctor_id")
+                            .from(Prescriptions::Table, Prescriptions::DoctorId)
+                            .to(Doctors::Table, Doctors::Id)
+                            .on_delete(ForeignKeyAction::Restrict)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-prescriptions-dispensed_by")
+                            .from(Prescriptions::Table, Prescriptions::DispensedBy)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-prescriptions_visit_intent_id")
+                    .table(Prescriptions::Table)
+                    .col(Prescriptions::VisitIntentId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-prescriptions_status_submitted_at")
+                    .table(Prescriptions::Table)
+                    .col(Prescriptions::Status)
+                    .col(Prescriptions::SubmittedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        // A doctor keeps at most one draft per visit
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"CREATE UNIQUE INDEX IF NOT EXISTS "idx-prescriptions_visit_doctor_draft"
+                ON prescriptions (visit_intent_id, doctor_id)
+                WHERE status = 'DRAFT'"#,
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(PrescriptionItems::Table)
+                    .if_not_exists()
+                    .col(pk_auto(PrescriptionItems::Id))
+                    .col(integer(PrescriptionItems::PrescriptionId))
+                    .col(integer(PrescriptionItems::LineNo))
+                    .col(string(PrescriptionItems::DrugName))
+                    .col(string(PrescriptionItems::Dose))
+                    .col(string(PrescriptionItems::Route))
+                    .col(string(PrescriptionItems::Frequency))
+                    .col(integer(PrescriptionItems::DurationDays))
+                    .col(integer(PrescriptionItems::Quantity))
+                    .col(string(PrescriptionItems::Unit))
+                    .col(text_null(PrescriptionItems::Instructions))
+                    .col(boolean(PrescriptionItems::IsCompounded).default(false))
+                    .col(timestamp(PrescriptionItems::CreatedAt).default(Expr::current_timestamp()))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-prescription_items-prescription_id")
+                            .from(PrescriptionItems::Table, PrescriptionItems::PrescriptionId)
+                            .to(Prescriptions::Table, Prescriptions::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-prescription_items_prescription_line")
+                    .table(PrescriptionItems::Table)
+                    .col(PrescriptionItems::PrescriptionId)
+                    .col(PrescriptionItems::LineNo)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(PrescriptionItemComponents::Table)
+                    .if_not_exists()
+                    .col(pk_auto(PrescriptionItemComponents::Id))
+                    .col(integer(PrescriptionItemComponents::ItemId))
+                    .col(string(PrescriptionItemComponents::DrugName))
+                    .col(string(PrescriptionItemComponents::Strength))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-prescription_item_components-item_id")
+                            .from(
+                                PrescriptionItemComponents::Table,
+                                PrescriptionItemComponents::ItemId,
+                            )
+                            .to(PrescriptionItems::Table, PrescriptionItems::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-prescription_item_components_item_id")
+                    .table(PrescriptionItemComponents::Table)
+                    .col(PrescriptionItemComponents::ItemId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(PrescriptionItemComponents::Table)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_table(Table::drop().table(PrescriptionItems::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Prescriptions::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Prescriptions {
+    Table,
+    Id,
+    VisitIntentId,
+    DoctorId,
+    Status,
+    Notes,
+    AllergyOverrideReason,
+    SubmittedAt,
+    DispensedAt,
+    DispensedBy,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum PrescriptionItems {
+    Table,
+    Id,
+    PrescriptionId,
+    LineNo,
+    DrugName,
+    Dose,
+    Route,
+    Frequency,
+    DurationDays,
+    Quantity,
+    Unit,
+    Instructions,
+    IsCompounded,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum PrescriptionItemComponents {
+    Table,
+    Id,
+    ItemId,
+    DrugName,
+    Strength,
+}
+
+#[derive(DeriveIden)]
+enum PatientsVisitIntent {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Doctors {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
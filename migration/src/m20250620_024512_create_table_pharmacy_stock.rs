@@ -0,0 +1,349 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20250620_024512_create_table_pharmacy_stock"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Drugs::Table)
+                    .if_not_exists()
+                    .col(pk_auto(Drugs::Id))
+                    .col(string(Drugs::Code).unique_key())
+                    .col(string(Drugs::Name))
+                    .col(string(Drugs::GenericName))
+                    .col(string(Drugs::DosageForm))
+                    .col(string(Drugs::Strength))
+                    .col(string(Drugs::Unit))
+                    .col(string_null(Drugs::TherapeuticClass))
+                    .col(integer(Drugs::ReorderLevel).default(0))
+                    .col(boolean(Drugs::IsActive).default(true))
+                    .col(timestamp(Drugs::CreatedAt).default(Expr::current_timestamp()))
+                    .col(timestamp(Drugs::UpdatedAt).default(Expr::current_timestamp()))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(StorageLocations::Table)
+                    .if_not_exists()
+                    .col(pk_auto(StorageLocations::Id))
+                    .col(string(StorageLocations::Code).unique_key())
+                    .col(string(StorageLocations::Name))
+                    .col(boolean(StorageLocations::IsActive).default(true))
+                    .col(timestamp(StorageLocations::CreatedAt).default(Expr::current_timestamp()))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(DrugBatches::Table)
+                    .if_not_exists()
+                    .col(pk_auto(DrugBatches::Id))
+                    .col(integer(DrugBatches::DrugId))
+                    .col(integer(DrugBatches::LocationId))
+                    .col(string(DrugBatches::BatchNo))
+                    .col(date(DrugBatches::ExpiryDate))
+                    .col(
+                        integer(DrugBatches::QuantityOnHand)
+                            .default(0)
+                            .check(Expr::col(DrugBatches::QuantityOnHand).gte(0)),
+                    )
+                    .col(timestamp(DrugBatches::CreatedAt).default(Expr::current_timestamp()))
+                    .col(timestamp(DrugBatches::UpdatedAt).default(Expr::current_timestamp()))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-drug_batches-drug_id")
+                            .from(DrugBatches::Table, DrugBatches::DrugId)
+                            .to(Drugs::Table, Drugs::Id)
+                            .on_delete(ForeignKeyAction::Restrict)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-drug_batches-location_id")
+                            .from(DrugBatches::Table, DrugBatches::LocationId)
+                            .to(StorageLocations::Table, StorageLocations::Id)
+                            .on_delete(ForeignKeyAction::Restrict)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // A batch number is stocked once per drug and location
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-drug_batches_drug_location_batch_no")
+                    .table(DrugBatches::Table)
+                    .col(DrugBatches::DrugId)
+                    .col(DrugBatches::LocationId)
+                    .col(DrugBatches::BatchNo)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-drug_batches_location_expiry_date")
+                    .table(DrugBatches::Table)
+                    .col(DrugBatches::LocationId)
+                    .col(DrugBatches::ExpiryDate)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(GoodsReceipts::Table)
+                    .if_not_exists()
+                    .col(pk_auto(GoodsReceipts::Id))
+                    .col(integer(GoodsReceipts::LocationId))
+                    .col(string(GoodsReceipts::Supplier))
+                    .col(string_null(GoodsReceipts::ReferenceNo))
+                    .col(timestamp(GoodsReceipts::ReceivedAt))
+                    .col(integer_null(GoodsReceipts::ReceivedBy))
+                    .col(text_null(GoodsReceipts::Notes))
+                    .col(timestamp(GoodsReceipts::CreatedAt).default(Expr::current_timestamp()))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-goods_receipts-location_id")
+                            .from(GoodsReceipts::Table, GoodsReceipts::LocationId)
+                            .to(StorageLocations::Table, StorageLocations::Id)
+                            .on_delete(ForeignKeyAction::Restrict)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-goods_receipts-received_by")
+                            .from(GoodsReceipts::Table, GoodsReceipts::ReceivedBy)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(StockMovements::Table)
+                    .if_not_exists()
+                    .col(pk_auto(StockMovements::Id))
+                    .col(integer(StockMovements::BatchId))
+                    .col(string(StockMovements::MovementType))
+                    .col(integer(StockMovements::Quantity))
+                    .col(integer(StockMovements::BalanceAfter))
+                    .col(string_null(StockMovements::Reason))
+                    .col(text_null(StockMovements::Notes))
+                    .col(integer_null(StockMovements::GoodsReceiptId))
+                    .col(integer_null(StockMovements::PrescriptionItemId))
+                    .col(integer_null(StockMovements::PerformedBy))
+                    .col(timestamp(StockMovements::CreatedAt).default(Expr::current_timestamp()))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-stock_movements-batch_id")
+                            .from(StockMovements::Table, StockMovements::BatchId)
+                            .to(DrugBatches::Table, DrugBatches::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-stock_movements-goods_receipt_id")
+                            .from(StockMovements::Table, StockMovements::GoodsReceiptId)
+                            .to(GoodsReceipts::Table, GoodsReceipts::Id)
+                            .on_delete(ForeignKeyAction::Restrict)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-stock_movements-prescription_item_id")
+                            .from(StockMovements::Table, StockMovements::PrescriptionItemId)
+                            .to(PrescriptionItems::Table, PrescriptionItems::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-stock_movements-performed_by")
+                            .from(StockMovements::Table, StockMovements::PerformedBy)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-stock_movements_batch_id")
+                    .table(StockMovements::Table)
+                    .col(StockMovements::BatchId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-stock_movements_goods_receipt_id")
+                    .table(StockMovements::Table)
+                    .col(StockMovements::GoodsReceiptId)
+                    .to_owned(),
+            )
+            .await?;
+
+        // Items written before the formulary existed name their drug in free text only
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(PrescriptionItems::Table)
+                    .add_column(integer_null(PrescriptionItems::DrugId))
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk-prescription_items-drug_id")
+                            .from_tbl(PrescriptionItems::Table)
+                            .from_col(PrescriptionItems::DrugId)
+                            .to_tbl(Drugs::Table)
+                            .to_col(Drugs::Id)
+                            .on_delete(ForeignKeyAction::Restrict)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(PrescriptionItems::Table)
+                    .drop_foreign_key(Alias::new("fk-prescription_items-drug_id"))
+                    .drop_column(PrescriptionItems::DrugId)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_table(Table::drop().table(StockMovements::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(GoodsReceipts::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(DrugBatches::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(StorageLocations::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Drugs::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Drugs {
+    Table,
+    Id,
+    Code,
+    Name,
+    GenericName,
+    DosageForm,
+    Strength,
+    Unit,
+    TherapeuticClass,
+    ReorderLevel,
+    IsActive,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum StorageLocations {
+    Table,
+    Id,
+    Code,
+    Name,
+    IsActive,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum DrugBatches {
+    Table,
+    Id,
+    DrugId,
+    LocationId,
+    BatchNo,
+    ExpiryDate,
+    QuantityOnHand,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum GoodsReceipts {
+    Table,
+    Id,
+    LocationId,
+    Supplier,
+    ReferenceNo,
+    ReceivedAt,
+    ReceivedBy,
+    Notes,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum StockMovements {
+    Table,
+    Id,
+    BatchId,
+    MovementType,
+    Quantity,
+    BalanceAfter,
+    Reason,
+    Notes,
+    GoodsReceiptId,
+    PrescriptionItemId,
+    PerformedBy,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum PrescriptionItems {
+    Table,
+    Id,
+    DrugId,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
@@ -0,0 +1,114 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20250621_030226_create_table_prescription_interaction_alerts"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Prescriptions::Table)
+                    .add_column(text_null(Prescriptions::InteractionOverrideReason))
+                    .to_owned(),
+            )
+            .await?;
+
+        // The warnings a prescriber acknowledged, as they were when the prescription was submitted
+        manager
+            .create_table(
+                Table::create()
+                    .table(PrescriptionInteractionAlerts::Table)
+                    .if_not_exists()
+                    .col(pk_auto(PrescriptionInteractionAlerts::Id))
+                    .col(integer(PrescriptionInteractionAlerts::PrescriptionId))
+                    .col(string(PrescriptionInteractionAlerts::CheckName))
+                    .col(string(PrescriptionInteractionAlerts::Severity))
+                    .col(integer(PrescriptionInteractionAlerts::LineNo))
+                    .col(string(PrescriptionInteractionAlerts::DrugName))
+                    .col(integer(PrescriptionInteractionAlerts::OtherPrescriptionId))
+                    .col(integer(PrescriptionInteractionAlerts::OtherLineNo))
+                    .col(string(PrescriptionInteractionAlerts::OtherDrugName))
+                    .col(text(PrescriptionInteractionAlerts::Description))
+                    .col(
+                        timestamp(PrescriptionInteractionAlerts::CreatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-prescription_interaction_alerts-prescription_id")
+                            .from(
+                                PrescriptionInteractionAlerts::Table,
+                                PrescriptionInteractionAlerts::PrescriptionId,
+                            )
+                            .to(Prescriptions::Table, Prescriptions::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-prescription_interaction_alerts_prescription_id")
+                    .table(PrescriptionInteractionAlerts::Table)
+                    .col(PrescriptionInteractionAlerts::PrescriptionId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(PrescriptionInteractionAlerts::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Prescriptions::Table)
+                    .drop_column(Prescriptions::InteractionOverrideReason)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Prescriptions {
+    Table,
+    Id,
+    InteractionOverrideReason,
+}
+
+#[derive(DeriveIden)]
+enum PrescriptionInteractionAlerts {
+    Table,
+    Id,
+    PrescriptionId,
+    CheckName,
+    Severity,
+    LineNo,
+    DrugName,
+    OtherPrescriptionId,
+    OtherLineNo,
+    OtherDrugName,
+    Description,
+    CreatedAt,
+}
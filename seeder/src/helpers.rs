@@ -8,8 +8,8 @@ use rand::prelude::*;
 use std::collections::HashMap;
 
 use entity::{
-    departments, doctor_schedules, doctors, employee_position, employees, nurses,
-    nurses_polyclinic_assignments, polyclinic, position_titles, rooms,
+    departments, doctor_schedules, doctors, employee_position, employees, icd10_codes,
+    icd9cm_procedures, nurses, nurses_polyclinic_assignments, polyclinic, position_titles, rooms,
     user::{self, Role},
 };
 use sea_orm::ActiveValue::Set;
@@ -65,7 +65,7 @@ pub fn generate_employee(n: usize) -> Vec<employees::ActiveModel> {
 
     let expand_dist = |dist: &Vec<(&str, usize)>| -> Vec<String> {
         dist.iter()
-            .flat_map(|(v, percent)| std::iter::repeat((*v).to_string()).take(percent * n / 100))
+            .flat_map(|(v, percent)| std::iter::repeat_n((*v).to_string(), percent * n / 100))
             .collect()
     };
 
@@ -189,12 +189,19 @@ pub fn generate_polyclinic(
         .map(|(idx, name)| {
             let room = rooms.choose(&mut rng).unwrap().clone();
             rooms.retain(|r| r != &room);
+            let code = format!("PC{:02}", idx + 1);
+            // One letter per polyclinic while the alphabet lasts, then the code itself
+            let queue_prefix = (b'A'..=b'Z')
+                .nth(idx)
+                .map(|letter| (letter as char).to_string())
+                .unwrap_or_else(|| code.clone());
             polyclinic::ActiveModel {
                 name: Set(name.to_string()),
                 description: Set(Some(format!("Polyclinic specializing in {}", name))),
                 department_code: Set("DPT05".to_string()), // Assuming all polyclinics belong to the Clinical Department
-                code: Set(format!("PC{:02}", idx + 1)),    // Random code for polyclinic
+                code: Set(code),                           // Random code for polyclinic
                 room_code: Set(room),                      // Random room code
+                queue_prefix: Set(Some(queue_prefix)),
                 ..Default::default()
             }
         })
@@ -220,7 +227,7 @@ pub fn generate_position_title(
     for (title, desc, dept_name) in positions {
         let code = dept_map
             .get(dept_name)
-            .expect(&format!("Department not found: {}", dept_name));
+            .unwrap_or_else(|| panic!("Department not found: {}", dept_name));
 
         models.push(position_titles::ActiveModel {
             title: Set(title.to_string()),
@@ -387,3 +394,48 @@ pub fn generate_users(employee_ids_and_dept: Vec<(i32, String)>) -> Vec<user::Ac
 
     users
 }
+
+/// Parses the bundled ICD-10 catalog: a tab separated file with a header row and the
+/// columns `code`, `description_en` and `description_id`.
+pub fn generate_icd10_codes(catalog: &str) -> Vec<icd10_codes::ActiveModel> {
+    catalog
+        .lines()
+        .skip(1)
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| {
+            let mut columns = line.split('\t').map(str::trim);
+            match (columns.next(), columns.next(), columns.next()) {
+                (Some(code), Some(description_en), Some(description_id)) if !code.is_empty() => {
+                    Some(icd10_codes::ActiveModel {
+                        code: Set(code.to_uppercase()),
+                        description_en: Set(description_en.to_string()),
+                        description_id: Set(description_id.to_string()),
+                        is_active: Set(true),
+                        ..Default::default()
+                    })
+                }
+                _ => {
+                    warn!("❌ Skipping malformed ICD-10 line: {}", line);
+                    None
+                }
+            }
+        })
+        .collect()
+}
+
+pub fn generate_icd9cm_procedures(
+    procedures: Vec<(&str, &str, &str)>,
+) -> Vec<icd9cm_procedures::ActiveModel> {
+    procedures
+        .into_iter()
+        .map(
+            |(code, description_en, description_id)| icd9cm_procedures::ActiveModel {
+                code: Set(code.to_string()),
+                description_en: Set(description_en.to_string()),
+                description_id: Set(description_id.to_string()),
+                is_active: Set(true),
+                ..Default::default()
+            },
+        )
+        .collect()
+}
@@ -21,6 +21,8 @@ pub async fn seed_all(db: &DatabaseConnection) -> Result<(), DbErr> {
     seeds::seeds_nurses::seeds_nurses(&txn).await?;
     seeds::seeds_nurse_polyclinic_assignments::seeds_nurse_polyclinic_assignments(&txn).await?;
     seeds::seeds_users::seeds_users(&txn).await?;
+    seeds::seeds_icd10_codes::seeds_icd10_codes(&txn).await?;
+    seeds::seeds_icd9cm_procedures::seeds_icd9cm_procedures(&txn).await?;
     txn.commit().await?;
     info!("✅ All seeds have been successfully applied.");
     Ok(())
@@ -3,6 +3,8 @@ pub mod seeds_doctor_schedules;
 pub mod seeds_doctors;
 pub mod seeds_employees;
 pub mod seeds_employees_position;
+pub mod seeds_icd10_codes;
+pub mod seeds_icd9cm_procedures;
 pub mod seeds_nurse_polyclinic_assignments;
 pub mod seeds_nurses;
 pub mod seeds_polyclinic;
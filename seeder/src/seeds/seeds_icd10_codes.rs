@@ -0,0 +1,31 @@
+use entity::icd10_codes;
+use log::info;
+use sea_orm::{sea_query::OnConflict, DatabaseTransaction, DbErr, EntityTrait};
+
+use crate::helpers::generate_icd10_codes;
+
+pub const ICD10_CATALOG: &str = include_str!("../../data/icd10.tsv");
+
+/// Loads the bundled catalog. Unlike the other seeds it runs every time: codes added to
+/// the file are inserted and existing ones take the file's descriptions.
+pub async fn seeds_icd10_codes(txn: &DatabaseTransaction) -> Result<(), DbErr> {
+    info!("🚀 Seeding ICD-10 codes...");
+    let codes = generate_icd10_codes(ICD10_CATALOG);
+
+    for chunk in codes.chunks(1000) {
+        icd10_codes::Entity::insert_many(chunk.to_vec())
+            .on_conflict(
+                OnConflict::column(icd10_codes::Column::Code)
+                    .update_columns([
+                        icd10_codes::Column::DescriptionEn,
+                        icd10_codes::Column::DescriptionId,
+                    ])
+                    .to_owned(),
+            )
+            .exec_without_returning(txn)
+            .await?;
+    }
+    info!("✅ {} ICD-10 codes seeded successfully.", codes.len());
+
+    Ok(())
+}
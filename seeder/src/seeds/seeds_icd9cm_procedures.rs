@@ -0,0 +1,101 @@
+use entity::icd9cm_procedures;
+use log::info;
+use sea_orm::{DatabaseTransaction, DbErr, EntityTrait, PaginatorTrait};
+
+use crate::helpers::generate_icd9cm_procedures;
+
+pub async fn seeds_icd9cm_procedures(txn: &DatabaseTransaction) -> Result<(), DbErr> {
+    info!("🚀 Seeding ICD-9-CM procedures...");
+    let count = icd9cm_procedures::Entity::find().count(txn).await?;
+    if count > 0 {
+        info!("⚠️  ICD-9-CM procedures already seeded. Skipping...");
+        return Ok(());
+    }
+
+    // Procedures commonly coded in outpatient and emergency care: code, English and
+    // Indonesian description
+    let procedure_list = vec![
+        ("04.43", "Release of carpal tunnel", "Pembebasan terowongan karpal"),
+        ("08.20", "Removal of lesion of eyelid, not otherwise specified", "Eksisi lesi kelopak mata, tidak spesifik"),
+        ("13.41", "Phacoemulsification and aspiration of cataract", "Fakoemulsifikasi dan aspirasi katarak"),
+        ("16.21", "Ophthalmoscopy", "Oftalmoskopi"),
+        ("18.11", "Otoscopy", "Otoskopi"),
+        ("20.01", "Myringotomy with insertion of tube", "Miringotomi dengan pemasangan tabung"),
+        ("21.01", "Control of epistaxis by anterior nasal packing", "Kontrol epistaksis dengan tampon hidung anterior"),
+        ("21.21", "Rhinoscopy", "Rinoskopi"),
+        ("23.09", "Extraction of other tooth", "Ekstraksi gigi lainnya"),
+        ("23.2", "Restoration of tooth by filling", "Restorasi gigi dengan tambalan"),
+        ("24.31", "Excision of lesion or tissue of gum", "Eksisi lesi atau jaringan gusi"),
+        ("28.3", "Tonsillectomy with adenoidectomy", "Tonsilektomi dengan adenoidektomi"),
+        ("31.42", "Laryngoscopy and other tracheoscopy", "Laringoskopi dan trakeoskopi lainnya"),
+        ("33.22", "Fiber-optic bronchoscopy", "Bronkoskopi serat optik"),
+        ("34.04", "Insertion of intercostal catheter for drainage", "Pemasangan kateter interkostal untuk drainase"),
+        ("34.91", "Thoracentesis", "Torakosentesis"),
+        ("38.93", "Venous catheterization, not elsewhere classified", "Kateterisasi vena, tidak diklasifikasikan di tempat lain"),
+        ("38.99", "Other puncture of vein", "Pungsi vena lainnya"),
+        ("39.95", "Hemodialysis", "Hemodialisis"),
+        ("45.13", "Other endoscopy of small intestine", "Endoskopi usus halus lainnya"),
+        ("45.23", "Colonoscopy", "Kolonoskopi"),
+        ("47.09", "Other appendectomy", "Apendektomi lainnya"),
+        ("49.46", "Excision of hemorrhoids", "Eksisi hemoroid"),
+        ("51.23", "Laparoscopic cholecystectomy", "Kolesistektomi laparoskopik"),
+        ("53.00", "Unilateral repair of inguinal hernia, not otherwise specified", "Perbaikan hernia inguinalis unilateral, tidak spesifik"),
+        ("54.91", "Percutaneous abdominal drainage", "Drainase abdomen perkutan"),
+        ("57.94", "Insertion of indwelling urinary catheter", "Pemasangan kateter urin menetap"),
+        ("64.0", "Circumcision", "Sirkumsisi"),
+        ("69.7", "Insertion of contraceptive device", "Pemasangan alat kontrasepsi"),
+        ("70.21", "Vaginoscopy", "Vaginoskopi"),
+        ("73.59", "Other manually assisted delivery", "Persalinan dengan bantuan manual lainnya"),
+        ("73.6", "Episiotomy", "Episiotomi"),
+        ("74.1", "Low cervical cesarean section", "Seksio sesarea servikal rendah"),
+        ("75.34", "Other fetal monitoring", "Pemantauan janin lainnya"),
+        ("79.00", "Closed reduction of fracture without internal fixation, unspecified site", "Reduksi tertutup fraktur tanpa fiksasi interna, lokasi tidak spesifik"),
+        ("81.91", "Arthrocentesis", "Artrosentesis"),
+        ("83.21", "Open biopsy of soft tissue", "Biopsi terbuka jaringan lunak"),
+        ("86.01", "Aspiration of skin and subcutaneous tissue", "Aspirasi kulit dan jaringan subkutan"),
+        ("86.04", "Other incision with drainage of skin and subcutaneous tissue", "Insisi lainnya dengan drainase kulit dan jaringan subkutan"),
+        ("86.22", "Excisional debridement of wound, infection, or burn", "Debridemen eksisional luka, infeksi, atau luka bakar"),
+        ("86.28", "Nonexcisional debridement of wound, infection or burn", "Debridemen noneksisional luka, infeksi atau luka bakar"),
+        ("86.3", "Other local excision or destruction of lesion or tissue of skin and subcutaneous tissue", "Eksisi lokal atau destruksi lesi atau jaringan kulit dan subkutan lainnya"),
+        ("86.59", "Closure of skin and subcutaneous tissue of other sites", "Penutupan kulit dan jaringan subkutan di lokasi lain"),
+        ("87.03", "Computerized axial tomography of head", "Tomografi aksial terkomputerisasi kepala"),
+        ("87.44", "Routine chest x-ray, so described", "Foto toraks rutin"),
+        ("88.38", "Other computerized axial tomography", "Tomografi aksial terkomputerisasi lainnya"),
+        ("88.72", "Diagnostic ultrasound of heart", "Ultrasonografi diagnostik jantung"),
+        ("88.76", "Diagnostic ultrasound of abdomen and retroperitoneum", "Ultrasonografi diagnostik abdomen dan retroperitoneum"),
+        ("88.78", "Diagnostic ultrasound of gravid uterus", "Ultrasonografi diagnostik uterus gravid"),
+        ("89.03", "Interview and evaluation, described as comprehensive", "Wawancara dan evaluasi, komprehensif"),
+        ("89.52", "Electrocardiogram", "Elektrokardiogram"),
+        ("89.7", "General physical examination", "Pemeriksaan fisik umum"),
+        ("90.59", "Microscopic examination of blood, other microscopic examination", "Pemeriksaan mikroskopis darah, pemeriksaan mikroskopis lainnya"),
+        ("93.39", "Other physical therapy", "Terapi fisik lainnya"),
+        ("93.54", "Application of splint", "Pemasangan bidai"),
+        ("93.94", "Respiratory medication administered by nebulizer", "Pemberian obat pernapasan dengan nebulizer"),
+        ("96.04", "Insertion of endotracheal tube", "Pemasangan pipa endotrakeal"),
+        ("96.07", "Insertion of other (naso-)gastric tube", "Pemasangan selang (naso)gastrik lainnya"),
+        ("96.33", "Gastric lavage", "Bilas lambung"),
+        ("96.52", "Irrigation of ear", "Irigasi telinga"),
+        ("96.59", "Other irrigation of wound", "Irigasi luka lainnya"),
+        ("96.6", "Enteral infusion of concentrated nutritional substances", "Infus enteral zat gizi konsentrat"),
+        ("97.89", "Removal of other therapeutic device", "Pelepasan alat terapeutik lainnya"),
+        ("98.11", "Removal of intraluminal foreign body from ear without incision", "Pengambilan benda asing intraluminal dari telinga tanpa insisi"),
+        ("98.12", "Removal of intraluminal foreign body from nose without incision", "Pengambilan benda asing intraluminal dari hidung tanpa insisi"),
+        ("99.04", "Transfusion of packed cells", "Transfusi sel darah merah pekat"),
+        ("99.18", "Injection or infusion of electrolytes", "Injeksi atau infus elektrolit"),
+        ("99.21", "Injection of antibiotic", "Injeksi antibiotik"),
+        ("99.29", "Injection or infusion of other therapeutic or prophylactic substance", "Injeksi atau infus zat terapeutik atau profilaksis lainnya"),
+        ("99.38", "Administration of tetanus toxoid", "Pemberian toksoid tetanus"),
+        ("99.60", "Cardiopulmonary resuscitation, not otherwise specified", "Resusitasi jantung paru, tidak spesifik"),
+        ("99.62", "Other electric countershock of heart", "Kejut listrik jantung lainnya"),
+    ];
+
+    let procedures = generate_icd9cm_procedures(procedure_list);
+
+    icd9cm_procedures::Entity::insert_many(procedures)
+        .exec(txn)
+        .await?;
+
+    info!("✅ ICD-9-CM procedures seeded successfully.");
+
+    Ok(())
+}
@@ -101,7 +101,7 @@ impl TestContext {
 
     pub async fn seed_employees(&self) {
         let mut rng = rand::rng();
-        let employee_status = vec![
+        let employee_status = [
             "Permanent",
             "Contract",
             "Internship",
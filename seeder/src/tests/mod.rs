@@ -21,3 +21,7 @@ pub mod test_seeds_nurses;
 pub mod test_seeds_nurse_polyclinic_assignments;
 
 pub mod test_seeds_users;
+
+pub mod test_seeds_icd10_codes;
+
+pub mod test_seeds_icd9cm_procedures;
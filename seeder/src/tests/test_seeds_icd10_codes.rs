@@ -0,0 +1,111 @@
+use entity::icd10_codes;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, EntityTrait, IntoActiveModel, PaginatorTrait,
+    QueryFilter, TransactionTrait,
+};
+
+use crate::{
+    helpers::generate_icd10_codes,
+    seeds::seeds_icd10_codes::{seeds_icd10_codes, ICD10_CATALOG},
+    tests::context::{init_db_lock, with_db_lock, TestContext},
+};
+
+#[tokio::test]
+async fn test_seeds_icd10_codes_success() {
+    init_db_lock();
+
+    with_db_lock(|| async {
+        let ctx = TestContext::new().await;
+
+        let txn = ctx.db.begin().await.expect("Failed to begin transaction");
+        let result = seeds_icd10_codes(&txn).await;
+        assert!(
+            result.is_ok(),
+            "Seeding ICD-10 codes failed: {:?}",
+            result.err()
+        );
+
+        let bundled = generate_icd10_codes(ICD10_CATALOG).len() as u64;
+        let count = icd10_codes::Entity::find()
+            .count(&txn)
+            .await
+            .expect("Failed to count ICD-10 codes");
+        assert!(
+            count >= bundled,
+            "Every bundled ICD-10 code should be seeded"
+        );
+
+        let typhoid = icd10_codes::Entity::find()
+            .filter(icd10_codes::Column::Code.eq("A01.0"))
+            .one(&txn)
+            .await
+            .expect("Failed to find ICD-10 code");
+        assert!(
+            typhoid.is_some_and(|code| code.description_id == "Demam tifoid"),
+            "ICD-10 codes should carry their Indonesian description"
+        );
+
+        txn.rollback()
+            .await
+            .expect("Failed to rollback transaction");
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_seeds_icd10_codes_rerun_refreshes() {
+    init_db_lock();
+
+    with_db_lock(|| async {
+        let ctx = TestContext::new().await;
+
+        let txn = ctx.db.begin().await.expect("Failed to begin transaction");
+        seeds_icd10_codes(&txn)
+            .await
+            .expect("Failed to seed ICD-10 codes");
+
+        let before = icd10_codes::Entity::find()
+            .count(&txn)
+            .await
+            .expect("Failed to count ICD-10 codes");
+
+        let mut edited = icd10_codes::Entity::find()
+            .filter(icd10_codes::Column::Code.eq("I10"))
+            .one(&txn)
+            .await
+            .expect("Failed to find ICD-10 code")
+            .expect("I10 should be seeded")
+            .into_active_model();
+        edited.description_en = Set("Edited locally".to_string());
+        edited
+            .update(&txn)
+            .await
+            .expect("Failed to edit ICD-10 code");
+
+        seeds_icd10_codes(&txn)
+            .await
+            .expect("Failed to seed ICD-10 codes again");
+
+        let after = icd10_codes::Entity::find()
+            .count(&txn)
+            .await
+            .expect("Failed to count ICD-10 codes");
+        assert!(before == after, "Reseeding should not duplicate codes");
+
+        let refreshed = icd10_codes::Entity::find()
+            .filter(icd10_codes::Column::Code.eq("I10"))
+            .one(&txn)
+            .await
+            .expect("Failed to find ICD-10 code")
+            .expect("I10 should be seeded");
+        assert!(
+            refreshed.description_en == "Essential (primary) hypertension",
+            "Reseeding should restore the bundled description"
+        );
+
+        txn.rollback()
+            .await
+            .expect("Failed to rollback transaction");
+    })
+    .await;
+}
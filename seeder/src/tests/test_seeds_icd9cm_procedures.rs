@@ -0,0 +1,83 @@
+use entity::icd9cm_procedures;
+use sea_orm::{ActiveValue::Set, EntityTrait, PaginatorTrait, TransactionTrait};
+
+use crate::{
+    seeds::seeds_icd9cm_procedures::seeds_icd9cm_procedures,
+    tests::context::{init_db_lock, with_db_lock, TestContext},
+};
+
+#[tokio::test]
+async fn test_seeds_icd9cm_procedures_success() {
+    init_db_lock();
+
+    with_db_lock(|| async {
+        let ctx = TestContext::new().await;
+
+        let txn = ctx.db.begin().await.expect("Failed to begin transaction");
+        icd9cm_procedures::Entity::delete_many()
+            .exec(&txn)
+            .await
+            .expect("Failed to clear ICD-9-CM procedures");
+
+        let result = seeds_icd9cm_procedures(&txn).await;
+        assert!(
+            result.is_ok(),
+            "Seeding ICD-9-CM procedures failed: {:?}",
+            result.err()
+        );
+
+        let count = icd9cm_procedures::Entity::find()
+            .count(&txn)
+            .await
+            .expect("Failed to count ICD-9-CM procedures");
+
+        assert!(
+            count > 0,
+            "ICD-9-CM procedures should be seeded successfully"
+        );
+
+        txn.rollback()
+            .await
+            .expect("Failed to rollback transaction");
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_seeds_icd9cm_procedures_skip() {
+    init_db_lock();
+
+    with_db_lock(|| async {
+        let ctx = TestContext::new().await;
+
+        let txn = ctx.db.begin().await.expect("Failed to begin transaction");
+        icd9cm_procedures::Entity::delete_many()
+            .exec(&txn)
+            .await
+            .expect("Failed to clear ICD-9-CM procedures");
+        icd9cm_procedures::Entity::insert(icd9cm_procedures::ActiveModel {
+            code: Set("89.7".to_string()),
+            description_en: Set("General physical examination".to_string()),
+            description_id: Set("Pemeriksaan fisik umum".to_string()),
+            is_active: Set(true),
+            ..Default::default()
+        })
+        .exec(&txn)
+        .await
+        .expect("Failed to insert ICD-9-CM procedure");
+
+        let _ = seeds_icd9cm_procedures(&txn).await;
+
+        let after = icd9cm_procedures::Entity::find()
+            .count(&txn)
+            .await
+            .expect("Failed to count ICD-9-CM procedures");
+
+        assert!(after == 1, "ICD-9-CM procedures seed should be skipped");
+
+        txn.rollback()
+            .await
+            .expect("Failed to rollback transaction");
+    })
+    .await;
+}
@@ -0,0 +1,84 @@
+use entity::patient_allergies::{AllergyCategory, AllergySeverity};
+use serde::Deserialize;
+use validator::Validate;
+
+use crate::{
+    error_handling::app_error::AppError,
+    infra::api::ApiFieldError,
+    utils::helpers::{deserialize_active_enum, deserialize_optional_active_enum},
+};
+
+#[derive(Deserialize, Debug, Clone, Validate)]
+pub struct CreateAllergyRequest {
+    #[validate(length(min = 1, max = 255, message = "Substance is required"))]
+    pub substance: String,
+    #[serde(deserialize_with = "deserialize_active_enum")]
+    pub category: AllergyCategory,
+    #[validate(length(max = 1000, message = "Reaction must be at most 1000 characters"))]
+    pub reaction: Option<String>,
+    #[serde(deserialize_with = "deserialize_active_enum")]
+    pub severity: AllergySeverity,
+}
+
+/// Changing what the allergy is or how it presents clears an earlier verification.
+#[derive(Deserialize, Debug, Validate)]
+pub struct UpdateAllergyRequest {
+    #[validate(length(min = 1, max = 255, message = "Substance must not be empty"))]
+    pub substance: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_optional_active_enum")]
+    pub category: Option<AllergyCategory>,
+    #[validate(length(max = 1000, message = "Reaction must be at most 1000 characters"))]
+    pub reaction: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_optional_active_enum")]
+    pub severity: Option<AllergySeverity>,
+}
+
+impl UpdateAllergyRequest {
+    pub fn is_empty(&self) -> bool {
+        self.substance.is_none()
+            && self.category.is_none()
+            && self.reaction.is_none()
+            && self.severity.is_none()
+    }
+}
+
+/// Structured entries transcribed from the free-text allergy note recorded before the
+/// allergy list existed. The note is cleared once converted, so an empty list records
+/// that it held nothing to keep, such as "none".
+#[derive(Deserialize, Debug, Validate)]
+pub struct ConvertLegacyAllergiesRequest {
+    #[serde(default)]
+    pub allergies: Vec<CreateAllergyRequest>,
+}
+
+impl ConvertLegacyAllergiesRequest {
+    /// Field validation of each entry, reported by position such as
+    /// `allergies[0].substance`.
+    pub fn validate_all(&self) -> Result<(), AppError> {
+        let errors = allergy_field_errors(&self.allergies);
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(AppError::ValidationError(errors))
+        }
+    }
+}
+
+/// Field errors of a list of allergies sent along with another request, keyed by
+/// position such as `allergies[0].substance`.
+pub fn allergy_field_errors(allergies: &[CreateAllergyRequest]) -> Vec<ApiFieldError> {
+    let mut errors = Vec::new();
+    for (index, allergy) in allergies.iter().enumerate() {
+        if let Err(allergy_errors) = allergy.validate() {
+            errors.extend(
+                AppError::flatten_validation_errors(allergy_errors)
+                    .into_iter()
+                    .map(|error| ApiFieldError {
+                        field: format!("allergies[{}].{}", index, error.field),
+                        message: error.message,
+                    }),
+            );
+        }
+    }
+    errors
+}
@@ -0,0 +1,2 @@
+pub mod allergy_request;
+pub mod response;
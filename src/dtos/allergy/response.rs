@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AllergyResponse {
+    pub id: i32,
+    pub patient_id: i32,
+    pub substance: String,
+    pub category: String,
+    pub reaction: Option<String>,
+    pub severity: String,
+    pub recorded_by: Option<i32>,
+    pub verified_by: Option<i32>,
+    pub verified_at: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PatientAllergiesResponse {
+    pub patient_id: i32,
+    /// Free text recorded before structured allergies existed, kept for reference.
+    pub legacy_known_allergies: Option<String>,
+    pub data: Vec<AllergyResponse>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AllergyRemoved {
+    pub id: i32,
+    pub patient_id: i32,
+    pub removed_at: String,
+}
@@ -0,0 +1,2 @@
+pub mod auth_request;
+pub mod response;
@@ -0,0 +1,26 @@
+use serde::Deserialize;
+use validator::Validate;
+
+/// The SOAP sections of a draft. On update only the sections sent are replaced, and a
+/// blank section is cleared.
+#[derive(Deserialize, Debug, Validate)]
+pub struct ClinicalNoteRequest {
+    #[validate(length(max = 10000, message = "Subjective must be at most 10000 characters"))]
+    pub subjective: Option<String>,
+    #[validate(length(max = 10000, message = "Objective must be at most 10000 characters"))]
+    pub objective: Option<String>,
+    #[validate(length(max = 10000, message = "Assessment must be at most 10000 characters"))]
+    pub assessment: Option<String>,
+    #[validate(length(max = 10000, message = "Plan must be at most 10000 characters"))]
+    pub plan: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Validate)]
+pub struct AddendumRequest {
+    #[validate(length(
+        min = 1,
+        max = 5000,
+        message = "Addendum must be between 1 and 5000 characters"
+    ))]
+    pub content: String,
+}
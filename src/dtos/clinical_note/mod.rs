@@ -0,0 +1,2 @@
+pub mod clinical_note_request;
+pub mod response;
@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AddendumResponse {
+    pub id: i32,
+    pub note_id: i32,
+    pub doctor_id: i32,
+    pub doctor_name: String,
+    pub content: String,
+    pub created_at: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ClinicalNoteResponse {
+    pub id: i32,
+    pub visit_intent_id: i32,
+    pub patient_id: i32,
+    pub doctor_id: i32,
+    pub doctor_name: String,
+    pub status: String,
+    pub subjective: Option<String>,
+    pub objective: Option<String>,
+    pub assessment: Option<String>,
+    pub plan: Option<String>,
+    pub signed_at: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+    pub addenda: Vec<AddendumResponse>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct VisitNotesResponse {
+    pub visit_intent_id: i32,
+    pub patient_id: i32,
+    pub data: Vec<ClinicalNoteResponse>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PatientNoteTimelineResponse {
+    pub patient_id: i32,
+    pub data: Vec<ClinicalNoteResponse>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NoteDiscarded {
+    pub id: i32,
+    pub visit_intent_id: i32,
+    pub discarded_at: String,
+}
@@ -0,0 +1,32 @@
+use entity::visit_diagnoses::DiagnosisType;
+use serde::Deserialize;
+use validator::Validate;
+
+use crate::utils::helpers::{deserialize_active_enum, deserialize_optional_active_enum};
+
+/// Matches a code prefix, with or without the dot, or words of either description.
+#[derive(Deserialize, Debug, Validate)]
+pub struct Icd10SearchQuery {
+    #[validate(length(min = 2, message = "Search term must be at least 2 characters"))]
+    pub q: String,
+    #[validate(range(min = 1, max = 100, message = "Limit must be between 1 and 100"))]
+    pub limit: Option<u64>,
+}
+
+#[derive(Deserialize, Debug, Validate)]
+pub struct RecordDiagnosisRequest {
+    #[validate(length(min = 3, max = 10, message = "ICD-10 code must be 3 to 10 characters"))]
+    pub code: String,
+    #[serde(deserialize_with = "deserialize_active_enum")]
+    pub diagnosis_type: DiagnosisType,
+    #[validate(length(max = 1000, message = "Notes must be at most 1000 characters"))]
+    pub notes: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Validate)]
+pub struct UpdateDiagnosisRequest {
+    #[serde(default, deserialize_with = "deserialize_optional_active_enum")]
+    pub diagnosis_type: Option<DiagnosisType>,
+    #[validate(length(max = 1000, message = "Notes must be at most 1000 characters"))]
+    pub notes: Option<String>,
+}
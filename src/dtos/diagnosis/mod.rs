@@ -0,0 +1,2 @@
+pub mod diagnosis_request;
+pub mod response;
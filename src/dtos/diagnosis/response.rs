@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Icd10CodeResponse {
+    pub code: String,
+    pub description_en: String,
+    pub description_id: String,
+    pub is_active: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Icd10SearchResponse {
+    pub query: String,
+    pub data: Vec<Icd10CodeResponse>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DiagnosisResponse {
+    pub id: i32,
+    pub visit_intent_id: i32,
+    pub code: String,
+    pub description_en: String,
+    pub description_id: String,
+    pub diagnosis_type: String,
+    pub notes: Option<String>,
+    pub doctor_id: i32,
+    pub doctor_name: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct VisitDiagnosesResponse {
+    pub visit_intent_id: i32,
+    pub patient_id: i32,
+    pub data: Vec<DiagnosisResponse>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DiagnosisRemoved {
+    pub id: i32,
+    pub visit_intent_id: i32,
+    pub removed_at: String,
+}
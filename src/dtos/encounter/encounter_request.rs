@@ -0,0 +1,32 @@
+use entity::encounters::{EncounterDisposition, EncounterStatus};
+use serde::Deserialize;
+use validator::Validate;
+
+use crate::utils::helpers::{deserialize_active_enum, deserialize_optional_active_enum};
+
+/// Hands a completed visit to a polyclinic when triage did not queue it to one.
+#[derive(Deserialize, Debug, Validate)]
+pub struct OpenEncounterRequest {
+    pub visit_intent_id: i32,
+    #[validate(length(min = 1, message = "Polyclinic code is required"))]
+    pub polyclinic_code: String,
+    pub doctor_id: Option<i32>,
+}
+
+#[derive(Deserialize, Debug, Validate)]
+pub struct FinishEncounterRequest {
+    #[serde(deserialize_with = "deserialize_active_enum")]
+    pub disposition: EncounterDisposition,
+    /// Where the patient is referred or admitted to; required for a referral out.
+    #[validate(length(
+        max = 1000,
+        message = "Disposition notes must be at most 1000 characters"
+    ))]
+    pub disposition_notes: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct WorklistQuery {
+    #[serde(default, deserialize_with = "deserialize_optional_active_enum")]
+    pub status: Option<EncounterStatus>,
+}
@@ -0,0 +1,2 @@
+pub mod encounter_request;
+pub mod response;
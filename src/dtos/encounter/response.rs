@@ -0,0 +1,30 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct EncounterResponse {
+    pub id: i32,
+    pub visit_intent_id: i32,
+    pub patient_id: i32,
+    pub patient_name: String,
+    pub medical_record_number: Option<String>,
+    pub doctor_id: Option<i32>,
+    pub polyclinic_id: i32,
+    pub room_code: Option<String>,
+    pub service_date: NaiveDate,
+    pub priority: String,
+    pub status: String,
+    pub disposition: Option<String>,
+    pub disposition_notes: Option<String>,
+    pub started_at: Option<String>,
+    pub ended_at: Option<String>,
+    pub created_at: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DoctorWorklistResponse {
+    pub doctor_id: i32,
+    pub doctor_name: String,
+    pub service_date: NaiveDate,
+    pub data: Vec<EncounterResponse>,
+}
@@ -1 +1,11 @@
+pub mod allergy;
+pub mod auth;
+pub mod clinical_note;
+pub mod diagnosis;
+pub mod encounter;
+pub mod patient;
+pub mod pharmacy;
+pub mod prescription;
+pub mod procedure;
 pub mod triage;
+pub mod vitals;
@@ -0,0 +1,2 @@
+pub mod patient_request;
+pub mod response;
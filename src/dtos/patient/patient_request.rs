@@ -0,0 +1,161 @@
+use chrono::NaiveDate;
+use serde::Deserialize;
+use validator::{Validate, ValidationErrors};
+
+use crate::{
+    dtos::triage::create_triage_request::{
+        BloodType, CreateTriageRequest, Gender, naive_date_time_to_naive_date, optional_naive_date,
+        validate_phone,
+    },
+    utils::nik::{Nik, check_nik_profile, validate_nik},
+};
+
+/// Exact lookup by one of the identifiers printed on the patient's cards.
+#[derive(Deserialize, Debug, Validate)]
+pub struct PatientLookupQuery {
+    #[validate(length(equal = 16, message = "National ID must be 16 digits"))]
+    pub national_id: Option<String>,
+    #[validate(length(equal = 13, message = "BPJS number must be 13 digits"))]
+    pub bpjs_number: Option<String>,
+    #[validate(length(min = 1, message = "Medical record number must not be empty"))]
+    pub medical_record_number: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct MrnGapQuery {
+    /// Counter to audit: a year for yearly numbers, `ALL` for sequential ones. Defaults
+    /// to the counter currently in use.
+    pub scope: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Validate)]
+pub struct PatientSearchQuery {
+    #[validate(length(min = 2, message = "Name must be at least 2 characters"))]
+    pub name: Option<String>,
+    #[serde(default, deserialize_with = "optional_naive_date")]
+    pub date_of_birth: Option<NaiveDate>,
+    #[validate(range(min = 1, message = "Page starts at 1"))]
+    pub page: Option<u64>,
+    #[validate(range(min = 1, max = 100, message = "Per page must be between 1 and 100"))]
+    pub per_page: Option<u64>,
+}
+
+/// Demographics and emergency contacts a front desk may correct. The national ID is the
+/// identity of the record and is not editable here, and allergies are changed through
+/// the allergy endpoints.
+#[derive(Deserialize, Debug, Validate)]
+pub struct UpdatePatientRequest {
+    #[validate(length(min = 1, message = "Name is required"))]
+    pub name: Option<String>,
+    #[serde(default, deserialize_with = "optional_naive_date")]
+    pub date_of_birth: Option<NaiveDate>,
+    #[validate(length(equal = 13, message = "BPJS number must be 13 digits"))]
+    pub bpjs_number: Option<String>,
+    pub gender: Option<Gender>,
+    #[validate(length(min = 1, message = "Emergency contact name is required"))]
+    pub emergency_contact_name: Option<String>,
+    #[validate(custom(function = "validate_phone"))]
+    pub emergency_contact_phone: Option<String>,
+    #[validate(length(min = 1, message = "Emergency contact relationship is required"))]
+    pub emergency_contact_relationship: Option<String>,
+    pub blood_type: Option<BloodType>,
+}
+
+impl UpdatePatientRequest {
+    /// The demographics a registration clerk typed at triage, as a correction to the
+    /// stored record. Optional fields left empty are not treated as a change.
+    pub fn from_triage(payload: &CreateTriageRequest) -> Self {
+        UpdatePatientRequest {
+            name: Some(payload.name.clone()),
+            date_of_birth: Some(payload.date_of_birth),
+            bpjs_number: payload.bpjs_number.clone(),
+            gender: Some(payload.gender.clone()),
+            emergency_contact_name: Some(payload.emergency_contact_name.clone()),
+            emergency_contact_phone: Some(payload.emergency_contact_phone.clone()),
+            emergency_contact_relationship: Some(payload.emergency_contact_relationship.clone()),
+            blood_type: Some(payload.blood_type.clone()),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.name.is_none()
+            && self.date_of_birth.is_none()
+            && self.bpjs_number.is_none()
+            && self.gender.is_none()
+            && self.emergency_contact_name.is_none()
+            && self.emergency_contact_phone.is_none()
+            && self.emergency_contact_relationship.is_none()
+            && self.blood_type.is_none()
+    }
+}
+
+/// The real identity of a patient registered as unidentified, replacing the "Mr. X"
+/// placeholder and the estimated date of birth. When the patient already has a record
+/// under this NIK, a Conflict is returned and the placeholder is merged into that record
+/// through the merge endpoint instead.
+#[derive(Deserialize, Debug, Validate)]
+pub struct IdentifyPatientRequest {
+    #[validate(custom(function = "validate_nik"))]
+    pub national_id: String,
+    #[validate(length(min = 1, message = "Name is required"))]
+    pub name: String,
+    #[serde(deserialize_with = "naive_date_time_to_naive_date")]
+    pub date_of_birth: NaiveDate,
+    pub gender: Gender,
+    #[validate(length(equal = 13, message = "BPJS number must be 13 digits"))]
+    pub bpjs_number: Option<String>,
+}
+
+impl IdentifyPatientRequest {
+    /// Field validation plus the cross-checks of the national ID against the date of
+    /// birth and gender it encodes.
+    pub fn validate_identity(&self) -> Result<(), ValidationErrors> {
+        let mut errors = self.validate().err().unwrap_or_default();
+        if let Ok(nik) = Nik::parse(&self.national_id) {
+            check_nik_profile(
+                &nik,
+                Some(self.date_of_birth),
+                Some(self.gender.is_female()),
+                &mut errors,
+            );
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// The demographics as a correction to the placeholder record.
+    pub fn demographics(&self) -> UpdatePatientRequest {
+        UpdatePatientRequest {
+            name: Some(self.name.clone()),
+            date_of_birth: Some(self.date_of_birth),
+            bpjs_number: self.bpjs_number.clone(),
+            gender: Some(self.gender.clone()),
+            emergency_contact_name: None,
+            emergency_contact_phone: None,
+            emergency_contact_relationship: None,
+            blood_type: None,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Validate)]
+pub struct DuplicateCandidatesQuery {
+    /// Only candidates of this patient; all pairs when empty.
+    pub patient_id: Option<i32>,
+    #[validate(range(min = 0.0, max = 1.0, message = "Min score must be between 0 and 1"))]
+    pub min_score: Option<f64>,
+    #[validate(range(min = 1, max = 200, message = "Limit must be between 1 and 200"))]
+    pub limit: Option<u64>,
+}
+
+#[derive(Deserialize, Debug, Validate)]
+pub struct MergePatientRequest {
+    /// The duplicate folded into the patient in the path.
+    pub merged_patient_id: i32,
+    #[validate(length(max = 500, message = "Reason must be at most 500 characters"))]
+    pub reason: Option<String>,
+}
@@ -0,0 +1,113 @@
+use chrono::NaiveDate;
+
+use sea_orm::FromQueryResult;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PatientResponse {
+    pub id: i32,
+    pub medical_record_number: Option<String>,
+    pub name: String,
+    pub date_of_birth: NaiveDate,
+    pub national_id: Option<String>,
+    pub bpjs_number: Option<String>,
+    pub gender: String,
+    pub emergency_contact_name: Option<String>,
+    pub emergency_contact_phone: Option<String>,
+    pub emergency_contact_relationship: Option<String>,
+    pub blood_type: Option<String>,
+    pub known_allergies: Option<String>,
+    /// Set once this record was merged into another patient, which is then the one to use.
+    pub merged_into_id: Option<i32>,
+    /// A temporary emergency registration waiting to be merged into the real patient.
+    pub is_unidentified: bool,
+    pub date_of_birth_estimated: bool,
+    pub identification_notes: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PatientSearchResponse {
+    pub page: u64,
+    pub per_page: u64,
+    pub total_items: u64,
+    pub total_pages: u64,
+    pub data: Vec<PatientResponse>,
+}
+
+/// One field of a demographic revision, as stored before and after it.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct PatientFieldChange {
+    pub field: String,
+    pub previous: Option<String>,
+    pub current: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PatientHistoryEntry {
+    pub id: i32,
+    pub source: String,
+    pub changed_by: Option<i32>,
+    pub changes: Vec<PatientFieldChange>,
+    pub created_at: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PatientHistoryResponse {
+    pub patient_id: i32,
+    pub data: Vec<PatientHistoryEntry>,
+}
+
+/// A pair of patients that may be the same person, with the signals that matched.
+#[derive(Debug, Serialize, Deserialize, FromQueryResult)]
+pub struct DuplicateCandidate {
+    pub patient_id: i32,
+    pub patient_name: String,
+    pub candidate_id: i32,
+    pub candidate_name: String,
+    pub score: f64,
+    pub name_similarity: f64,
+    pub same_date_of_birth: bool,
+    pub same_phone: bool,
+    pub similar_national_id: bool,
+    pub similar_bpjs_number: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DuplicateCandidatesResponse {
+    pub min_score: f64,
+    pub data: Vec<DuplicateCandidate>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PatientMergeResponse {
+    pub id: i32,
+    pub survivor_id: i32,
+    pub merged_id: i32,
+    pub visit_intent_ids: Vec<i32>,
+    pub referral_document_ids: Vec<i32>,
+    pub allergy_ids: Vec<i32>,
+    pub reason: Option<String>,
+    pub merged_by: Option<i32>,
+    pub merged_at: String,
+    pub undone_by: Option<i32>,
+    pub undone_at: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PatientMergesResponse {
+    pub patient_id: i32,
+    pub data: Vec<PatientMergeResponse>,
+}
+
+/// Medical record numbers drawn from a counter but held by no patient. Numbers are drawn
+/// inside the registration transaction, so any entry here points at a manual change.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MrnGapReport {
+    pub scope: String,
+    pub last_number: i32,
+    pub assigned: i64,
+    pub missing_count: i64,
+    pub missing: Vec<i32>,
+}
@@ -0,0 +1,2 @@
+pub mod pharmacy_request;
+pub mod response;
@@ -0,0 +1,321 @@
+use std::collections::HashSet;
+
+use chrono::{DateTime, FixedOffset, NaiveDate};
+use entity::stock_movements::AdjustmentReason;
+use serde::Deserialize;
+use validator::Validate;
+
+use crate::{
+    error_handling::app_error::AppError, infra::api::ApiFieldError,
+    utils::helpers::deserialize_active_enum,
+};
+
+/// A formulary entry. The unit is what stock is counted and dispensed in, for example
+/// `TABLET` or `BOTTLE`, and the reorder level is the usable quantity at or below which
+/// the drug is reported as low on stock.
+#[derive(Deserialize, Debug, Validate)]
+pub struct CreateDrugRequest {
+    #[validate(length(
+        min = 1,
+        max = 30,
+        message = "Code must be between 1 and 30 characters"
+    ))]
+    pub code: String,
+    #[validate(length(
+        min = 1,
+        max = 150,
+        message = "Name must be between 1 and 150 characters"
+    ))]
+    pub name: String,
+    #[validate(length(
+        min = 1,
+        max = 150,
+        message = "Generic name must be between 1 and 150 characters"
+    ))]
+    pub generic_name: String,
+    #[validate(length(
+        min = 1,
+        max = 50,
+        message = "Dosage form must be between 1 and 50 characters"
+    ))]
+    pub dosage_form: String,
+    #[validate(length(
+        min = 1,
+        max = 50,
+        message = "Strength must be between 1 and 50 characters"
+    ))]
+    pub strength: String,
+    #[validate(length(
+        min = 1,
+        max = 30,
+        message = "Unit must be between 1 and 30 characters"
+    ))]
+    pub unit: String,
+    #[validate(length(
+        min = 1,
+        max = 100,
+        message = "Therapeutic class must be between 1 and 100 characters"
+    ))]
+    pub therapeutic_class: Option<String>,
+    #[validate(range(
+        min = 0,
+        max = 1000000,
+        message = "Reorder level must be between 0 and 1000000"
+    ))]
+    #[serde(default)]
+    pub reorder_level: i32,
+}
+
+/// The code of a drug never changes; a drug taken off the formulary is deactivated.
+#[derive(Deserialize, Debug, Validate)]
+pub struct UpdateDrugRequest {
+    #[validate(length(
+        min = 1,
+        max = 150,
+        message = "Name must be between 1 and 150 characters"
+    ))]
+    pub name: Option<String>,
+    #[validate(length(
+        min = 1,
+        max = 150,
+        message = "Generic name must be between 1 and 150 characters"
+    ))]
+    pub generic_name: Option<String>,
+    #[validate(length(
+        min = 1,
+        max = 50,
+        message = "Dosage form must be between 1 and 50 characters"
+    ))]
+    pub dosage_form: Option<String>,
+    #[validate(length(
+        min = 1,
+        max = 50,
+        message = "Strength must be between 1 and 50 characters"
+    ))]
+    pub strength: Option<String>,
+    #[validate(length(
+        min = 1,
+        max = 30,
+        message = "Unit must be between 1 and 30 characters"
+    ))]
+    pub unit: Option<String>,
+    #[validate(length(
+        min = 1,
+        max = 100,
+        message = "Therapeutic class must be between 1 and 100 characters"
+    ))]
+    pub therapeutic_class: Option<String>,
+    #[validate(range(
+        min = 0,
+        max = 1000000,
+        message = "Reorder level must be between 0 and 1000000"
+    ))]
+    pub reorder_level: Option<i32>,
+    pub is_active: Option<bool>,
+}
+
+impl UpdateDrugRequest {
+    pub fn is_empty(&self) -> bool {
+        self.name.is_none()
+            && self.generic_name.is_none()
+            && self.dosage_form.is_none()
+            && self.strength.is_none()
+            && self.unit.is_none()
+            && self.therapeutic_class.is_none()
+            && self.reorder_level.is_none()
+            && self.is_active.is_none()
+    }
+}
+
+/// Matches the start of a code or words of the brand or generic name; without a term
+/// the formulary is listed by name.
+#[derive(Deserialize, Debug, Validate)]
+pub struct DrugSearchQuery {
+    #[validate(length(min = 2, message = "Search term must be at least 2 characters"))]
+    pub q: Option<String>,
+    #[serde(default)]
+    pub include_inactive: bool,
+    #[validate(range(min = 1, max = 100, message = "Limit must be between 1 and 100"))]
+    pub limit: Option<u64>,
+}
+
+#[derive(Deserialize, Debug, Validate)]
+pub struct CreateLocationRequest {
+    #[validate(length(
+        min = 1,
+        max = 30,
+        message = "Code must be between 1 and 30 characters"
+    ))]
+    pub code: String,
+    #[validate(length(
+        min = 1,
+        max = 100,
+        message = "Name must be between 1 and 100 characters"
+    ))]
+    pub name: String,
+}
+
+/// One batch on the delivery note.
+#[derive(Deserialize, Debug, Validate)]
+pub struct GoodsReceiptItemRequest {
+    pub drug_id: i32,
+    #[validate(length(
+        min = 1,
+        max = 50,
+        message = "Batch number must be between 1 and 50 characters"
+    ))]
+    pub batch_no: String,
+    pub expiry_date: NaiveDate,
+    #[validate(range(
+        min = 1,
+        max = 1000000,
+        message = "Quantity must be between 1 and 1000000"
+    ))]
+    pub quantity: i32,
+}
+
+const MAX_RECEIPT_ITEMS: usize = 100;
+
+/// A delivery received into one storage location. The receipt time defaults to now.
+#[derive(Deserialize, Debug, Validate)]
+pub struct GoodsReceiptRequest {
+    pub location_id: i32,
+    #[validate(length(
+        min = 1,
+        max = 150,
+        message = "Supplier must be between 1 and 150 characters"
+    ))]
+    pub supplier: String,
+    #[validate(length(
+        min = 1,
+        max = 50,
+        message = "Reference number must be between 1 and 50 characters"
+    ))]
+    pub reference_no: Option<String>,
+    pub received_at: Option<DateTime<FixedOffset>>,
+    #[validate(length(max = 1000, message = "Notes must be at most 1000 characters"))]
+    pub notes: Option<String>,
+    pub items: Vec<GoodsReceiptItemRequest>,
+}
+
+impl GoodsReceiptRequest {
+    /// Field validation of the receipt and its items, reported by position such as
+    /// `items[2].quantity`, and the rule that a batch of a drug is listed once.
+    pub fn validate_all(&self) -> Result<(), AppError> {
+        let mut errors = self
+            .validate()
+            .err()
+            .map(AppError::flatten_validation_errors)
+            .unwrap_or_default();
+
+        if self.items.is_empty() || self.items.len() > MAX_RECEIPT_ITEMS {
+            errors.push(ApiFieldError {
+                field: "items".to_string(),
+                message: format!("A receipt has between 1 and {} items", MAX_RECEIPT_ITEMS),
+            });
+        }
+
+        let mut seen = HashSet::new();
+        for (index, item) in self.items.iter().enumerate() {
+            if let Err(item_errors) = item.validate() {
+                errors.extend(
+                    AppError::flatten_validation_errors(item_errors)
+                        .into_iter()
+                        .map(|error| ApiFieldError {
+                            field: format!("items[{}].{}", index, error.field),
+                            message: error.message,
+                        }),
+                );
+            }
+            if !seen.insert((item.drug_id, item.batch_no.trim().to_uppercase())) {
+                errors.push(ApiFieldError {
+                    field: format!("items[{}].batch_no", index),
+                    message: "This batch of the drug is already listed on the receipt".to_string(),
+                });
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(AppError::ValidationError(errors))
+        }
+    }
+}
+
+/// A correction to the quantity of one batch. Damaged, expired and lost stock only
+/// goes out, returned stock only comes in, and `OTHER` needs notes saying why.
+#[derive(Deserialize, Debug, Validate)]
+pub struct StockAdjustmentRequest {
+    #[validate(range(
+        min = -1000000,
+        max = 1000000,
+        message = "Quantity change must be between -1000000 and 1000000"
+    ))]
+    pub quantity_change: i32,
+    #[serde(deserialize_with = "deserialize_active_enum")]
+    pub reason: AdjustmentReason,
+    #[validate(length(max = 1000, message = "Notes must be at most 1000 characters"))]
+    pub notes: Option<String>,
+}
+
+impl StockAdjustmentRequest {
+    pub fn validate_all(&self) -> Result<(), AppError> {
+        let mut errors = self
+            .validate()
+            .err()
+            .map(AppError::flatten_validation_errors)
+            .unwrap_or_default();
+
+        let direction = match self.reason {
+            AdjustmentReason::Damaged | AdjustmentReason::Expired | AdjustmentReason::Lost
+                if self.quantity_change > 0 =>
+            {
+                Some("Damaged, expired or lost stock is taken out with a negative change")
+            }
+            AdjustmentReason::Returned if self.quantity_change < 0 => {
+                Some("Returned stock is put back with a positive change")
+            }
+            _ if self.quantity_change == 0 => Some("Quantity change must not be zero"),
+            _ => None,
+        };
+        if let Some(message) = direction {
+            errors.push(ApiFieldError {
+                field: "quantity_change".to_string(),
+                message: message.to_string(),
+            });
+        }
+
+        let has_notes = self
+            .notes
+            .as_deref()
+            .is_some_and(|notes| !notes.trim().is_empty());
+        if self.reason == AdjustmentReason::Other && !has_notes {
+            errors.push(ApiFieldError {
+                field: "notes".to_string(),
+                message: "Notes are required when the reason is OTHER".to_string(),
+            });
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(AppError::ValidationError(errors))
+        }
+    }
+}
+
+/// Stock across every location unless one is given.
+#[derive(Deserialize, Debug)]
+pub struct StockReportQuery {
+    pub location_id: Option<i32>,
+}
+
+/// Batches still holding stock that expire within the number of days, 90 by default,
+/// including the ones already expired.
+#[derive(Deserialize, Debug, Validate)]
+pub struct NearExpiryQuery {
+    pub location_id: Option<i32>,
+    #[validate(range(min = 1, max = 730, message = "Days must be between 1 and 730"))]
+    pub days: Option<i64>,
+}
@@ -0,0 +1,142 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DrugResponse {
+    pub id: i32,
+    pub code: String,
+    pub name: String,
+    pub generic_name: String,
+    pub dosage_form: String,
+    pub strength: String,
+    pub unit: String,
+    pub therapeutic_class: Option<String>,
+    pub reorder_level: i32,
+    pub is_active: bool,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DrugSearchResponse {
+    pub query: Option<String>,
+    pub data: Vec<DrugResponse>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LocationResponse {
+    pub id: i32,
+    pub code: String,
+    pub name: String,
+    pub is_active: bool,
+    pub created_at: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LocationsResponse {
+    pub data: Vec<LocationResponse>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BatchResponse {
+    pub id: i32,
+    pub drug_id: i32,
+    pub drug_code: String,
+    pub drug_name: String,
+    pub location_id: i32,
+    pub location_code: String,
+    pub batch_no: String,
+    pub expiry_date: NaiveDate,
+    pub days_to_expiry: i64,
+    pub is_expired: bool,
+    pub quantity_on_hand: i32,
+    pub unit: String,
+}
+
+/// Stock of a drug. Usable stock leaves out expired batches, which stay on hand until
+/// they are adjusted out.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DrugStockResponse {
+    pub drug: DrugResponse,
+    pub usable_quantity: i64,
+    pub expired_quantity: i64,
+    pub batches: Vec<BatchResponse>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct StockMovementResponse {
+    pub id: i32,
+    pub batch_id: i32,
+    pub movement_type: String,
+    pub quantity: i32,
+    pub balance_after: i32,
+    pub reason: Option<String>,
+    pub notes: Option<String>,
+    pub goods_receipt_id: Option<i32>,
+    pub prescription_item_id: Option<i32>,
+    pub performed_by: Option<i32>,
+    pub created_at: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BatchMovementsResponse {
+    pub batch: BatchResponse,
+    pub data: Vec<StockMovementResponse>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StockAdjustmentResponse {
+    pub batch: BatchResponse,
+    pub movement: StockMovementResponse,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct GoodsReceiptItemResponse {
+    pub movement_id: i32,
+    pub batch_id: i32,
+    pub drug_id: i32,
+    pub drug_name: String,
+    pub batch_no: String,
+    pub expiry_date: NaiveDate,
+    pub quantity: i32,
+    pub unit: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GoodsReceiptResponse {
+    pub id: i32,
+    pub location_id: i32,
+    pub location_code: String,
+    pub supplier: String,
+    pub reference_no: Option<String>,
+    pub received_at: String,
+    pub received_by: Option<i32>,
+    pub notes: Option<String>,
+    pub created_at: String,
+    pub items: Vec<GoodsReceiptItemResponse>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LowStockItem {
+    pub drug_id: i32,
+    pub code: String,
+    pub name: String,
+    pub unit: String,
+    pub reorder_level: i32,
+    pub usable_quantity: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LowStockReportResponse {
+    pub location_id: Option<i32>,
+    pub as_of: NaiveDate,
+    pub data: Vec<LowStockItem>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NearExpiryReportResponse {
+    pub location_id: Option<i32>,
+    pub as_of: NaiveDate,
+    pub until: NaiveDate,
+    pub data: Vec<BatchResponse>,
+}
@@ -0,0 +1,2 @@
+pub mod prescription_request;
+pub mod response;
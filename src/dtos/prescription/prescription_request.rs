@@ -0,0 +1,186 @@
+use entity::{prescription_items::DrugRoute, prescriptions::PrescriptionStatus};
+use serde::Deserialize;
+use validator::Validate;
+
+use crate::{
+    error_handling::app_error::AppError,
+    infra::api::ApiFieldError,
+    utils::helpers::{deserialize_active_enum, deserialize_optional_active_enum},
+};
+
+/// One ingredient of a racikan, the strength written as the doctor prescribes it.
+#[derive(Deserialize, Debug, Validate)]
+pub struct CompoundComponentRequest {
+    #[validate(length(
+        min = 1,
+        max = 150,
+        message = "Drug name must be between 1 and 150 characters"
+    ))]
+    pub drug_name: String,
+    #[validate(length(
+        min = 1,
+        max = 50,
+        message = "Strength must be between 1 and 50 characters"
+    ))]
+    pub strength: String,
+}
+
+/// A prescribed drug. A compounded item (racikan) names the preparation, for example
+/// "Puyer batuk anak", and lists what goes into it; the dose and quantity count the
+/// divided doses the pharmacy prepares. A plain item may name its formulary drug, and
+/// only such items are picked from stock when dispensed.
+#[derive(Deserialize, Debug, Validate)]
+pub struct PrescriptionItemRequest {
+    pub drug_id: Option<i32>,
+    #[validate(length(
+        min = 1,
+        max = 150,
+        message = "Drug name must be between 1 and 150 characters"
+    ))]
+    pub drug_name: String,
+    #[validate(length(
+        min = 1,
+        max = 50,
+        message = "Dose must be between 1 and 50 characters"
+    ))]
+    pub dose: String,
+    #[serde(deserialize_with = "deserialize_active_enum")]
+    pub route: DrugRoute,
+    #[validate(length(
+        min = 1,
+        max = 50,
+        message = "Frequency must be between 1 and 50 characters"
+    ))]
+    pub frequency: String,
+    #[validate(range(
+        min = 1,
+        max = 365,
+        message = "Duration must be between 1 and 365 days"
+    ))]
+    pub duration_days: i32,
+    #[validate(range(min = 1, max = 10000, message = "Quantity must be between 1 and 10000"))]
+    pub quantity: i32,
+    #[validate(length(
+        min = 1,
+        max = 30,
+        message = "Unit must be between 1 and 30 characters"
+    ))]
+    pub unit: String,
+    #[validate(length(max = 500, message = "Instructions must be at most 500 characters"))]
+    pub instructions: Option<String>,
+    #[serde(default)]
+    pub is_compounded: bool,
+    #[serde(default)]
+    pub components: Vec<CompoundComponentRequest>,
+}
+
+const MAX_ITEMS: usize = 30;
+
+/// A whole draft. On update the notes and every item are replaced.
+#[derive(Deserialize, Debug, Validate)]
+pub struct PrescriptionRequest {
+    #[validate(length(max = 1000, message = "Notes must be at most 1000 characters"))]
+    pub notes: Option<String>,
+    pub items: Vec<PrescriptionItemRequest>,
+}
+
+fn field_errors(errors: validator::ValidationErrors, prefix: &str) -> Vec<ApiFieldError> {
+    AppError::flatten_validation_errors(errors)
+        .into_iter()
+        .map(|error| ApiFieldError {
+            field: format!("{}.{}", prefix, error.field),
+            message: error.message,
+        })
+        .collect()
+}
+
+impl PrescriptionRequest {
+    /// Field validation of the prescription, its items and their components, reported
+    /// by position such as `items[1].components[0].strength`, and the racikan rule: a
+    /// compounded item lists its components and a plain item lists none.
+    pub fn validate_all(&self) -> Result<(), AppError> {
+        let mut errors = self
+            .validate()
+            .err()
+            .map(AppError::flatten_validation_errors)
+            .unwrap_or_default();
+
+        if self.items.is_empty() || self.items.len() > MAX_ITEMS {
+            errors.push(ApiFieldError {
+                field: "items".to_string(),
+                message: format!("A prescription has between 1 and {} items", MAX_ITEMS),
+            });
+        }
+
+        for (index, item) in self.items.iter().enumerate() {
+            let prefix = format!("items[{}]", index);
+            if let Err(item_errors) = item.validate() {
+                errors.extend(field_errors(item_errors, &prefix));
+            }
+
+            if item.is_compounded && item.components.is_empty() {
+                errors.push(ApiFieldError {
+                    field: format!("{}.components", prefix),
+                    message: "A compounded item needs at least one component".to_string(),
+                });
+            }
+            if item.is_compounded && item.drug_id.is_some() {
+                errors.push(ApiFieldError {
+                    field: format!("{}.drug_id", prefix),
+                    message: "A compounded item is not a formulary drug".to_string(),
+                });
+            }
+            if !item.is_compounded && !item.components.is_empty() {
+                errors.push(ApiFieldError {
+                    field: format!("{}.components", prefix),
+                    message: "Only a compounded item has components".to_string(),
+                });
+            }
+
+            for (component_index, component) in item.components.iter().enumerate() {
+                if let Err(component_errors) = component.validate() {
+                    errors.extend(field_errors(
+                        component_errors,
+                        &format!("{}.components[{}]", prefix, component_index),
+                    ));
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(AppError::ValidationError(errors))
+        }
+    }
+}
+
+/// Submission after the allergy or interaction check flagged items needs the prescriber's
+/// reason for going ahead; both are kept on the prescription.
+#[derive(Deserialize, Debug, Validate)]
+pub struct SubmitPrescriptionRequest {
+    #[validate(length(
+        min = 5,
+        max = 500,
+        message = "Override reason must be between 5 and 500 characters"
+    ))]
+    pub allergy_override_reason: Option<String>,
+    #[validate(length(
+        min = 5,
+        max = 500,
+        message = "Override reason must be between 5 and 500 characters"
+    ))]
+    pub interaction_override_reason: Option<String>,
+}
+
+/// The storage location the pharmacy dispenses from.
+#[derive(Deserialize, Debug)]
+pub struct DispensePrescriptionRequest {
+    pub location_id: i32,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct PharmacyQueueQuery {
+    #[serde(default, deserialize_with = "deserialize_optional_active_enum")]
+    pub status: Option<PrescriptionStatus>,
+}
@@ -0,0 +1,128 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CompoundComponentResponse {
+    pub id: i32,
+    pub drug_name: String,
+    pub strength: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PrescriptionItemResponse {
+    pub id: i32,
+    pub line_no: i32,
+    pub drug_id: Option<i32>,
+    pub drug_name: String,
+    pub dose: String,
+    pub route: String,
+    pub frequency: String,
+    pub duration_days: i32,
+    pub quantity: i32,
+    pub unit: String,
+    pub instructions: Option<String>,
+    pub is_compounded: bool,
+    pub components: Vec<CompoundComponentResponse>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PrescriptionResponse {
+    pub id: i32,
+    pub visit_intent_id: i32,
+    pub patient_id: i32,
+    pub doctor_id: i32,
+    pub doctor_name: String,
+    pub status: String,
+    pub notes: Option<String>,
+    pub allergy_override_reason: Option<String>,
+    pub interaction_override_reason: Option<String>,
+    pub submitted_at: Option<String>,
+    pub dispensed_at: Option<String>,
+    pub dispensed_by: Option<i32>,
+    pub created_at: String,
+    pub updated_at: String,
+    pub items: Vec<PrescriptionItemResponse>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct VisitPrescriptionsResponse {
+    pub visit_intent_id: i32,
+    pub patient_id: i32,
+    pub data: Vec<PrescriptionResponse>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PharmacyQueueResponse {
+    pub status: String,
+    pub data: Vec<PrescriptionResponse>,
+}
+
+/// A prescribed drug, or a component of a racikan, that names a substance the patient
+/// is recorded as allergic to.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AllergyMatch {
+    pub line_no: i32,
+    pub drug_name: String,
+    pub substance: String,
+    pub severity: Option<String>,
+    pub reaction: Option<String>,
+    /// `ALLERGY_LIST` for a recorded allergy, `KNOWN_ALLERGIES` for the free-text note
+    /// kept on the patient before allergies were recorded one by one.
+    pub source: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AllergyCheckResponse {
+    pub prescription_id: i32,
+    pub patient_id: i32,
+    pub matches: Vec<AllergyMatch>,
+}
+
+/// An interaction or duplicate therapy between a prescribed line and another line of the
+/// same prescription or a medication the patient is still taking.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct InteractionWarningResponse {
+    /// `INTERACTION` or `DUPLICATE_THERAPY` for the bundled checks.
+    pub check: String,
+    pub severity: String,
+    pub line_no: i32,
+    pub drug_name: String,
+    pub other_prescription_id: i32,
+    pub other_line_no: i32,
+    pub other_drug_name: String,
+    pub description: String,
+    pub needs_acknowledgement: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct InteractionCheckResponse {
+    pub prescription_id: i32,
+    pub patient_id: i32,
+    pub warnings: Vec<InteractionWarningResponse>,
+    pub requires_acknowledgement: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PrescriptionDiscarded {
+    pub id: i32,
+    pub visit_intent_id: i32,
+    pub discarded_at: String,
+}
+
+/// Stock taken from one batch for a prescription line.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct StockPickResponse {
+    pub line_no: i32,
+    pub drug_id: i32,
+    pub batch_id: i32,
+    pub batch_no: String,
+    pub expiry_date: NaiveDate,
+    pub quantity: i32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DispenseResponse {
+    pub prescription: PrescriptionResponse,
+    pub location_id: i32,
+    pub picks: Vec<StockPickResponse>,
+}
@@ -0,0 +1,2 @@
+pub mod procedure_request;
+pub mod response;
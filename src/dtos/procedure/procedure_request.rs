@@ -0,0 +1,30 @@
+use chrono::{DateTime, FixedOffset};
+use serde::Deserialize;
+use validator::Validate;
+
+/// Matches a code prefix, with or without the dot, or words of either description.
+#[derive(Deserialize, Debug, Validate)]
+pub struct ProcedureSearchQuery {
+    #[validate(length(min = 2, message = "Search term must be at least 2 characters"))]
+    pub q: String,
+    #[validate(range(min = 1, max = 100, message = "Limit must be between 1 and 100"))]
+    pub limit: Option<u64>,
+}
+
+/// A procedure done during a visit. The performing doctor defaults to the recording
+/// user, the room to the one on the doctor's schedule and the time to now.
+#[derive(Deserialize, Debug, Validate)]
+pub struct RecordProcedureRequest {
+    #[validate(length(
+        min = 2,
+        max = 10,
+        message = "ICD-9-CM code must be 2 to 10 characters"
+    ))]
+    pub code: String,
+    pub doctor_id: Option<i32>,
+    #[validate(length(min = 1, message = "Room code must not be empty"))]
+    pub room_code: Option<String>,
+    pub performed_at: Option<DateTime<FixedOffset>>,
+    #[validate(length(max = 1000, message = "Notes must be at most 1000 characters"))]
+    pub notes: Option<String>,
+}
@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ProcedureCodeResponse {
+    pub code: String,
+    pub description_en: String,
+    pub description_id: String,
+    pub is_active: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProcedureSearchResponse {
+    pub query: String,
+    pub data: Vec<ProcedureCodeResponse>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct VisitProcedureResponse {
+    pub id: i32,
+    pub visit_intent_id: i32,
+    pub code: String,
+    pub description_en: String,
+    pub description_id: String,
+    pub doctor_id: i32,
+    pub doctor_name: String,
+    pub polyclinic_id: Option<i32>,
+    pub room_code: String,
+    pub performed_at: String,
+    pub notes: Option<String>,
+    pub recorded_by: Option<i32>,
+    pub created_at: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct VisitProceduresResponse {
+    pub visit_intent_id: i32,
+    pub patient_id: i32,
+    pub data: Vec<VisitProcedureResponse>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProcedureRemoved {
+    pub id: i32,
+    pub visit_intent_id: i32,
+    pub removed_at: String,
+}
@@ -1,16 +1,24 @@
 use chrono::NaiveDate;
+use entity::patients_visit_intent::PriorityCategory;
 use regex::Regex;
+use sea_orm::ActiveEnum;
 use serde::{Deserialize, Deserializer};
 use strum_macros::Display;
 use validator::{Validate, ValidationError};
 
+use crate::{
+    dtos::allergy::allergy_request::{CreateAllergyRequest, allergy_field_errors},
+    error_handling::app_error::AppError,
+    utils::nik::{Nik, check_nik_profile, validate_nik},
+};
+
 #[derive(Deserialize, Debug, Validate)]
 pub struct CreateTriageRequest {
     #[validate(length(min = 1, message = "Name is required"))]
     pub name: String,
     #[serde(deserialize_with = "naive_date_time_to_naive_date")]
     pub date_of_birth: NaiveDate,
-    #[validate(length(equal = 16, message = "National ID must be 16 digits"))]
+    #[validate(custom(function = "validate_nik"))]
     pub national_id: String,
     #[validate(length(equal = 13, message = "BPJS number must be 13 digits"))]
     pub bpjs_number: Option<String>,
@@ -22,12 +30,71 @@ pub struct CreateTriageRequest {
     #[validate(length(min = 1, message = "Emergency contact relationship is required"))]
     pub emergency_contact_relationship: String,
     pub blood_type: BloodType,
-    pub known_allergies: Option<String>,
+    /// Recorded on the patient's allergy list; substances already on it are left as
+    /// they are.
+    #[serde(default)]
+    pub allergies: Vec<CreateAllergyRequest>,
 
     pub visit_type: VisitType,
+    #[validate(length(min = 1, message = "Polyclinic code must not be empty"))]
+    pub polyclinic_code: Option<String>,
+    pub doctor_id: Option<i32>,
+    /// Left empty, elderly patients are put in the elderly lane from their date of birth.
+    #[serde(default, deserialize_with = "deserialize_priority")]
+    pub priority: Option<PriorityCategory>,
+    /// What to do when a returning patient's details differ from the stored record.
+    #[serde(default)]
+    pub on_demographic_change: DemographicChangePolicy,
+}
+
+impl CreateTriageRequest {
+    /// Field validation, including the allergies reported by position such as
+    /// `allergies[0].substance`, plus the cross-checks of the national ID against the
+    /// date of birth and gender it encodes.
+    pub fn validate_registration(&self) -> Result<(), AppError> {
+        let mut errors = self.validate().err().unwrap_or_default();
+        if let Ok(nik) = Nik::parse(&self.national_id) {
+            check_nik_profile(
+                &nik,
+                Some(self.date_of_birth),
+                Some(self.gender.is_female()),
+                &mut errors,
+            );
+        }
+
+        let mut field_errors = AppError::flatten_validation_errors(errors);
+        field_errors.extend(allergy_field_errors(&self.allergies));
+        if field_errors.is_empty() {
+            Ok(())
+        } else {
+            Err(AppError::ValidationError(field_errors))
+        }
+    }
 }
 
-fn naive_date_time_to_naive_date<'de, D>(d: D) -> Result<NaiveDate, D::Error>
+/// Emergency registration of a patient who cannot be identified yet. The record is
+/// created under a "Mr. X" / "Mrs. X" placeholder with a date of birth estimated from
+/// the apparent age, and is merged into the real patient once they are identified.
+#[derive(Deserialize, Debug, Validate)]
+pub struct CreateUnidentifiedTriageRequest {
+    pub gender: Gender,
+    #[validate(range(max = 120, message = "Estimated age must be between 0 and 120"))]
+    pub estimated_age: u32,
+    /// What may help identify the patient later: where they were found, clothing, marks.
+    #[validate(length(
+        max = 1000,
+        message = "Identification notes must be at most 1000 characters"
+    ))]
+    pub identification_notes: Option<String>,
+    #[validate(length(min = 1, message = "Polyclinic code must not be empty"))]
+    pub polyclinic_code: Option<String>,
+    pub doctor_id: Option<i32>,
+    /// Left empty, the patient is put in the emergency lane.
+    #[serde(default, deserialize_with = "deserialize_priority")]
+    pub priority: Option<PriorityCategory>,
+}
+
+pub fn naive_date_time_to_naive_date<'de, D>(d: D) -> Result<NaiveDate, D::Error>
 where
     D: Deserializer<'de>,
 {
@@ -35,7 +102,31 @@ where
     NaiveDate::parse_from_str(&s, "%Y-%m-%d").map_err(serde::de::Error::custom)
 }
 
-fn validate_phone(phone: &str) -> Result<(), ValidationError> {
+pub fn optional_naive_date<'de, D>(d: D) -> Result<Option<NaiveDate>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let Some(s) = Option::<String>::deserialize(d)? else {
+        return Ok(None);
+    };
+    NaiveDate::parse_from_str(&s, "%Y-%m-%d")
+        .map(Some)
+        .map_err(serde::de::Error::custom)
+}
+
+fn deserialize_priority<'de, D>(d: D) -> Result<Option<PriorityCategory>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let Some(s) = Option::<String>::deserialize(d)? else {
+        return Ok(None);
+    };
+    PriorityCategory::try_from_value(&s.to_uppercase())
+        .map(Some)
+        .map_err(|_| serde::de::Error::custom(format!("Unknown priority: {}", s)))
+}
+
+pub fn validate_phone(phone: &str) -> Result<(), ValidationError> {
     let phone_regex = Regex::new(r"^\+?[1-9]\d{1,14}$").unwrap();
     if phone_regex.is_match(phone) {
         Ok(())
@@ -108,6 +199,12 @@ pub enum Gender {
     Female,
 }
 
+impl Gender {
+    pub fn is_female(&self) -> bool {
+        matches!(self, Gender::Female)
+    }
+}
+
 impl<'de> Deserialize<'de> for Gender {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -121,3 +218,33 @@ impl<'de> Deserialize<'de> for Gender {
         }
     }
 }
+
+/// Resolution for a repeat registration whose demographics differ from the stored patient.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DemographicChangePolicy {
+    /// Refuse the registration and report the differing fields.
+    #[default]
+    Reject,
+    /// Overwrite the stored record and write a history entry.
+    Apply,
+    /// Register against the stored record and drop the submitted details.
+    Keep,
+}
+
+impl<'de> Deserialize<'de> for DemographicChangePolicy {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.to_lowercase().as_str() {
+            "reject" => Ok(DemographicChangePolicy::Reject),
+            "apply" => Ok(DemographicChangePolicy::Apply),
+            "keep" => Ok(DemographicChangePolicy::Keep),
+            _ => Err(serde::de::Error::custom(format!(
+                "Unknown demographic change policy: {}",
+                s
+            ))),
+        }
+    }
+}
@@ -1,3 +1,5 @@
 pub mod create_triage_request;
+pub mod queue_event;
+pub mod queue_scope;
 pub mod referral_upload_metadata;
 pub mod response;
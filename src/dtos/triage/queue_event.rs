@@ -0,0 +1,99 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use entity::queue_ticket::{self, QueueStatus};
+use sea_orm::ActiveEnum;
+use serde::{Deserialize, Serialize};
+
+use crate::dtos::triage::{queue_scope::QueueScope, response::TriageQueueResponse};
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum QueueEventKind {
+    Registered,
+    Called,
+    Recalled,
+    Skipped,
+    NoShow,
+    Requeued,
+    Completed,
+    Canceled,
+    Expired,
+}
+
+impl QueueEventKind {
+    /// The event announcing that a ticket has moved into `status`.
+    pub fn for_status(status: QueueStatus) -> Self {
+        match status {
+            QueueStatus::Waiting => QueueEventKind::Requeued,
+            QueueStatus::Called => QueueEventKind::Called,
+            QueueStatus::Recalled => QueueEventKind::Recalled,
+            QueueStatus::Skipped => QueueEventKind::Skipped,
+            QueueStatus::NoShow => QueueEventKind::NoShow,
+            QueueStatus::Done => QueueEventKind::Completed,
+            QueueStatus::Canceled => QueueEventKind::Canceled,
+            QueueStatus::Expired => QueueEventKind::Expired,
+        }
+    }
+}
+
+/// A change to a single ticket, published to every server instance so display
+/// boards connected anywhere see it.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct QueueEvent {
+    pub event: QueueEventKind,
+    pub queue_key: String,
+    pub service_date: NaiveDate,
+    pub queue_number: i32,
+    pub queue_code: String,
+    pub queue_type: String,
+    pub polyclinic_code: Option<String>,
+    pub status: String,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl QueueEvent {
+    pub fn from_ticket(
+        event: QueueEventKind,
+        scope: &QueueScope,
+        ticket: &queue_ticket::Model,
+    ) -> Self {
+        QueueEvent {
+            event,
+            queue_key: ticket.queue_key.clone(),
+            service_date: ticket.service_date,
+            queue_number: ticket.queue_number,
+            queue_code: ticket.queue_code.clone(),
+            queue_type: ticket.queue_type.clone(),
+            polyclinic_code: scope.polyclinic().map(|poly| poly.code.clone()),
+            status: ticket.status.to_value(),
+            occurred_at: Utc::now(),
+        }
+    }
+}
+
+/// What a display board receives: the full waiting list once on connect (and again
+/// whenever it fell behind), then one message per ticket change.
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", content = "data", rename_all = "snake_case")]
+pub enum QueueFeedMessage {
+    Snapshot(TriageQueueResponse),
+    Event(QueueEvent),
+}
+
+impl QueueFeedMessage {
+    pub fn name(&self) -> &'static str {
+        match self {
+            QueueFeedMessage::Snapshot(_) => "snapshot",
+            QueueFeedMessage::Event(event) => match event.event {
+                QueueEventKind::Registered => "registered",
+                QueueEventKind::Called => "called",
+                QueueEventKind::Recalled => "recalled",
+                QueueEventKind::Skipped => "skipped",
+                QueueEventKind::NoShow => "no_show",
+                QueueEventKind::Requeued => "requeued",
+                QueueEventKind::Completed => "completed",
+                QueueEventKind::Canceled => "canceled",
+                QueueEventKind::Expired => "expired",
+            },
+        }
+    }
+}
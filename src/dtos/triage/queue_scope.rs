@@ -0,0 +1,148 @@
+use std::{collections::HashMap, fmt};
+
+use axum::extract::{FromRequestParts, Path, Query};
+use chrono::NaiveDate;
+use entity::polyclinic;
+use serde::Deserialize;
+
+use crate::{
+    dtos::triage::create_triage_request::VisitType,
+    error_handling::app_error::AppError,
+    parse_visit_type,
+    state::AppState,
+    use_cases::triage::{contracts::TriageTraitRepo, repo::triage_repo::TriageRepo},
+    utils::helpers::hospital_now,
+};
+
+/// The line a ticket is numbered in: the general registration queue of a visit type,
+/// or the queue of a single polyclinic.
+#[derive(Debug, Clone)]
+pub enum QueueScope {
+    VisitType(VisitType),
+    Polyclinic(polyclinic::Model),
+}
+
+impl QueueScope {
+    pub fn polyclinic(&self) -> Option<&polyclinic::Model> {
+        match self {
+            QueueScope::VisitType(_) => None,
+            QueueScope::Polyclinic(poly) => Some(poly),
+        }
+    }
+
+    pub fn prefix(&self) -> String {
+        match self {
+            QueueScope::VisitType(visit_type) => visit_type.to_string(),
+            QueueScope::Polyclinic(poly) => poly
+                .queue_prefix
+                .clone()
+                .unwrap_or_else(|| poly.code.clone()),
+        }
+    }
+
+    pub fn format_code(&self, queue_number: i32) -> String {
+        format!("{}-{:03}", self.prefix(), queue_number)
+    }
+
+    /// Key the daily counter and the ticket uniqueness are based on. Polyclinics are keyed
+    /// by id so renaming a code or prefix does not restart the numbering mid-day.
+    pub fn queue_key(&self) -> String {
+        match self {
+            QueueScope::VisitType(visit_type) => visit_type.to_string(),
+            QueueScope::Polyclinic(poly) => format!("POLY-{}", poly.id),
+        }
+    }
+
+    pub fn cache_key(&self) -> String {
+        match self {
+            QueueScope::VisitType(visit_type) => visit_type.to_string(),
+            QueueScope::Polyclinic(poly) => format!("poly:{}", poly.code),
+        }
+    }
+}
+
+impl fmt::Display for QueueScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QueueScope::VisitType(visit_type) => write!(f, "{}", visit_type),
+            QueueScope::Polyclinic(poly) => write!(f, "polyclinic {}", poly.code),
+        }
+    }
+}
+
+impl FromRequestParts<AppState> for QueueScope {
+    type Rejection = AppError;
+
+    async fn from_request_parts(
+        parts: &mut http::request::Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let Path(params) = Path::<HashMap<String, String>>::from_request_parts(parts, state)
+            .await
+            .map_err(|e| AppError::BadRequest(e.to_string()))?;
+
+        if let Some(code) = params.get("polyclinic_code") {
+            let poly =
+                <TriageRepo as TriageTraitRepo>::find_polyclinic_by_code(&state.db, code).await?;
+            return Ok(QueueScope::Polyclinic(poly));
+        }
+
+        if let Some(visit_type) = params.get("visit_type") {
+            let normalize_type: Result<VisitType, AppError> = parse_visit_type!(visit_type);
+            return Ok(QueueScope::VisitType(normalize_type?));
+        }
+
+        Err(AppError::BadRequest("Queue is not specified".into()))
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct QueueNumberPath {
+    pub queue_number: i32,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct RequeueQuery {
+    /// Number of waiting patients to place the ticket behind; defaults to the configured position.
+    pub position: Option<usize>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct QueueStatsQuery {
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ServiceDateQuery {
+    pub service_date: Option<NaiveDate>,
+}
+
+/// The hospital day a queue number belongs to. Numbers restart every day, so lookups
+/// read `?service_date=YYYY-MM-DD` and fall back to today in the hospital's timezone.
+#[derive(Debug, Clone, Copy)]
+pub struct ServiceDate(pub NaiveDate);
+
+impl ServiceDate {
+    pub fn today() -> Self {
+        ServiceDate(hospital_now().date_naive())
+    }
+}
+
+impl FromRequestParts<AppState> for ServiceDate {
+    type Rejection = AppError;
+
+    async fn from_request_parts(
+        parts: &mut http::request::Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let Query(query) = Query::<ServiceDateQuery>::from_request_parts(parts, state)
+            .await
+            .map_err(|e| AppError::BadRequest(e.to_string()))?;
+
+        Ok(query
+            .service_date
+            .map(ServiceDate)
+            .unwrap_or_else(Self::today))
+    }
+}
@@ -1,26 +1,39 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct CreateTriageResponse {
     pub patient_id: i32,
+    pub medical_record_number: Option<String>,
     pub visit_intent_id: i32,
     pub queue_number: i32,
+    pub queue_code: String,
     pub queue_type: String,
+    pub polyclinic_code: Option<String>,
+    pub doctor_id: Option<i32>,
+    pub service_date: NaiveDate,
+    pub priority: String,
     pub status: String,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct TriageQueueResponse {
-    pub visit_type: String,
+    pub visit_type: Option<String>,
+    pub polyclinic_code: Option<String>,
+    pub service_date: NaiveDate,
     pub data: Vec<TriageQueueItem>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct TriageQueueItem {
     pub queue_number: i32,
+    pub queue_code: String,
+    pub queue_type: String,
+    pub doctor_id: Option<i32>,
     pub patient_id: i32,
+    pub medical_record_number: Option<String>,
+    pub priority: String,
     pub status: String,
     pub created_at: DateTime<Utc>,
 }
@@ -28,14 +41,27 @@ pub struct TriageQueueItem {
 #[derive(Debug, Serialize, Deserialize)]
 pub struct TriageQueueStatus {
     pub queue_number: i32,
+    pub queue_code: String,
     pub queue_type: String,
+    pub service_date: NaiveDate,
     pub status: String,
     pub created_at: String,
+    pub eta: Option<TriageQueueEta>,
+}
+
+/// Where a waiting ticket stands; absent once the ticket has left the waiting line.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TriageQueueEta {
+    pub position: u64,
+    pub avg_service_secs: i64,
+    pub estimated_wait_secs: i64,
+    pub estimated_call_at: DateTime<Utc>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct TriagePatientCalled {
     pub queue_number: i32,
+    pub queue_code: String,
     pub queue_type: String,
     pub called_at: String,
 }
@@ -43,20 +69,56 @@ pub struct TriagePatientCalled {
 #[derive(Debug, Serialize, Deserialize)]
 pub struct TriageQueueComplete {
     pub queue_number: i32,
+    pub queue_code: String,
     pub queue_type: String,
     pub status: String,
     pub called_at: String,
     pub done_at: String,
+    /// The encounter opened with the polyclinic's doctors, for polyclinic queues.
+    pub encounter_id: Option<i32>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct TriagePatientCancel {
     pub queue_number: i32,
+    pub queue_code: String,
     pub queue_type: String,
     pub previous_status: String,
     pub new_status: String,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TriageQueueTransition {
+    pub queue_number: i32,
+    pub queue_code: String,
+    pub queue_type: String,
+    pub previous_status: String,
+    pub status: String,
+    pub call_count: i32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TriageQueueStatsResponse {
+    pub visit_type: Option<String>,
+    pub polyclinic_code: Option<String>,
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub rolling_avg_service_secs: Option<f64>,
+    pub rolling_sample_size: u64,
+    pub days: Vec<QueueDailyStats>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct QueueDailyStats {
+    pub service_date: NaiveDate,
+    pub total: i64,
+    pub completed: i64,
+    pub no_show: i64,
+    pub canceled: i64,
+    pub avg_wait_secs: Option<f64>,
+    pub avg_service_secs: Option<f64>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ReferralUploadResponse {
     pub status: String,
@@ -0,0 +1,65 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// Measurements in stored units, named after them.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct VitalSignsResponse {
+    pub id: i32,
+    pub visit_intent_id: i32,
+    pub systolic_bp: Option<i32>,
+    pub diastolic_bp: Option<i32>,
+    pub pulse_rate: Option<i32>,
+    pub respiratory_rate: Option<i32>,
+    pub temperature_celsius: Option<f64>,
+    pub spo2: Option<i32>,
+    pub weight_kg: Option<f64>,
+    pub height_cm: Option<f64>,
+    pub bmi: Option<f64>,
+    pub pain_score: Option<i32>,
+    pub notes: Option<String>,
+    pub nurse_id: Option<i32>,
+    pub recorded_by: Option<i32>,
+    pub measured_at: String,
+    pub created_at: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct VisitVitalsResponse {
+    pub visit_intent_id: i32,
+    pub patient_id: i32,
+    pub data: Vec<VitalSignsResponse>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PatientVitalsHistoryResponse {
+    pub patient_id: i32,
+    pub data: Vec<VitalSignsResponse>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct VitalTrendPoint {
+    pub visit_intent_id: i32,
+    pub measured_at: String,
+    pub value: f64,
+}
+
+/// One measure over time, oldest reading first.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct VitalTrend {
+    pub measure: String,
+    pub unit: String,
+    pub latest: Option<f64>,
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    /// Latest reading minus the earliest one in the range.
+    pub change: Option<f64>,
+    pub points: Vec<VitalTrendPoint>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PatientVitalsTrendResponse {
+    pub patient_id: i32,
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+    pub data: Vec<VitalTrend>,
+}
@@ -0,0 +1,539 @@
+use chrono::{DateTime, FixedOffset, NaiveDate};
+use entity::vital_signs;
+use serde::{Deserialize, Deserializer};
+use validator::{Validate, ValidationError, ValidationErrors};
+
+use crate::dtos::triage::create_triage_request::optional_naive_date;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TemperatureUnit {
+    #[default]
+    Celsius,
+    Fahrenheit,
+}
+
+impl<'de> Deserialize<'de> for TemperatureUnit {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.to_lowercase().as_str() {
+            "c" | "celsius" => Ok(TemperatureUnit::Celsius),
+            "f" | "fahrenheit" => Ok(TemperatureUnit::Fahrenheit),
+            _ => Err(serde::de::Error::custom(format!(
+                "Unknown temperature unit: {}",
+                s
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum WeightUnit {
+    #[default]
+    Kilogram,
+    Pound,
+}
+
+impl<'de> Deserialize<'de> for WeightUnit {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.to_lowercase().as_str() {
+            "kg" | "kilogram" => Ok(WeightUnit::Kilogram),
+            "lb" | "pound" => Ok(WeightUnit::Pound),
+            _ => Err(serde::de::Error::custom(format!(
+                "Unknown weight unit: {}",
+                s
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum HeightUnit {
+    #[default]
+    Centimetre,
+    Inch,
+}
+
+impl<'de> Deserialize<'de> for HeightUnit {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.to_lowercase().as_str() {
+            "cm" | "centimetre" | "centimeter" => Ok(HeightUnit::Centimetre),
+            "in" | "inch" => Ok(HeightUnit::Inch),
+            _ => Err(serde::de::Error::custom(format!(
+                "Unknown height unit: {}",
+                s
+            ))),
+        }
+    }
+}
+
+/// One set of measurements taken at the same time. Temperature, weight and height may
+/// be given in either unit and are stored in Celsius, kilograms and centimetres.
+#[derive(Deserialize, Debug, Validate)]
+pub struct RecordVitalsRequest {
+    /// mmHg
+    pub systolic_bp: Option<i32>,
+    /// mmHg
+    pub diastolic_bp: Option<i32>,
+    /// Beats per minute.
+    pub pulse_rate: Option<i32>,
+    /// Breaths per minute.
+    pub respiratory_rate: Option<i32>,
+    pub temperature: Option<f64>,
+    #[serde(default)]
+    pub temperature_unit: TemperatureUnit,
+    /// Oxygen saturation in percent.
+    pub spo2: Option<i32>,
+    pub weight: Option<f64>,
+    #[serde(default)]
+    pub weight_unit: WeightUnit,
+    pub height: Option<f64>,
+    #[serde(default)]
+    pub height_unit: HeightUnit,
+    /// Numeric rating scale, 0 (none) to 10 (worst imaginable).
+    pub pain_score: Option<i32>,
+    #[validate(length(max = 1000, message = "Notes must be at most 1000 characters"))]
+    pub notes: Option<String>,
+    /// When the measurements were taken, RFC 3339; now when empty.
+    pub measured_at: Option<DateTime<FixedOffset>>,
+}
+
+/// Measurements of a request in stored units.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct VitalMeasurements {
+    pub systolic_bp: Option<i32>,
+    pub diastolic_bp: Option<i32>,
+    pub pulse_rate: Option<i32>,
+    pub respiratory_rate: Option<i32>,
+    pub temperature_celsius: Option<f64>,
+    pub spo2: Option<i32>,
+    pub weight_kg: Option<f64>,
+    pub height_cm: Option<f64>,
+    pub pain_score: Option<i32>,
+}
+
+const POUNDS_PER_KILOGRAM: f64 = 2.204_622_6;
+const CENTIMETRES_PER_INCH: f64 = 2.54;
+
+fn range_error(message: &'static str) -> ValidationError {
+    ValidationError::new("physiologic_range").with_message(message.into())
+}
+
+fn check_range<T: PartialOrd + Copy>(
+    errors: &mut ValidationErrors,
+    field: &'static str,
+    value: Option<T>,
+    (min, max): (T, T),
+    message: &'static str,
+) {
+    if let Some(value) = value
+        && !(min..=max).contains(&value)
+    {
+        errors.add(field, range_error(message));
+    }
+}
+
+impl RecordVitalsRequest {
+    /// Field validation, conversion to stored units and the check of every measurement
+    /// against the limits a living patient can show. Values outside them are typing or
+    /// device errors rather than readings.
+    pub fn measurements(&self) -> Result<VitalMeasurements, ValidationErrors> {
+        let mut errors = self.validate().err().unwrap_or_default();
+
+        let measurements = VitalMeasurements {
+            systolic_bp: self.systolic_bp,
+            diastolic_bp: self.diastolic_bp,
+            pulse_rate: self.pulse_rate,
+            respiratory_rate: self.respiratory_rate,
+            temperature_celsius: self.temperature.map(|value| match self.temperature_unit {
+                TemperatureUnit::Celsius => value,
+                TemperatureUnit::Fahrenheit => (value - 32.0) * 5.0 / 9.0,
+            }),
+            spo2: self.spo2,
+            weight_kg: self.weight.map(|value| match self.weight_unit {
+                WeightUnit::Kilogram => value,
+                WeightUnit::Pound => value / POUNDS_PER_KILOGRAM,
+            }),
+            height_cm: self.height.map(|value| match self.height_unit {
+                HeightUnit::Centimetre => value,
+                HeightUnit::Inch => value * CENTIMETRES_PER_INCH,
+            }),
+            pain_score: self.pain_score,
+        };
+
+        let VitalMeasurements {
+            systolic_bp,
+            diastolic_bp,
+            pulse_rate,
+            respiratory_rate,
+            temperature_celsius,
+            spo2,
+            weight_kg,
+            height_cm,
+            pain_score,
+        } = measurements;
+
+        if [
+            systolic_bp,
+            diastolic_bp,
+            pulse_rate,
+            respiratory_rate,
+            spo2,
+            pain_score,
+        ]
+        .iter()
+        .all(Option::is_none)
+            && [temperature_celsius, weight_kg, height_cm]
+                .iter()
+                .all(Option::is_none)
+        {
+            errors.add(
+                "measurements",
+                ValidationError::new("required")
+                    .with_message("At least one measurement is required".into()),
+            );
+        }
+
+        match (systolic_bp, diastolic_bp) {
+            (Some(systolic), Some(diastolic)) if systolic <= diastolic => errors.add(
+                "diastolic_bp",
+                range_error("Diastolic pressure must be lower than systolic pressure"),
+            ),
+            (Some(_), None) | (None, Some(_)) => errors.add(
+                "systolic_bp",
+                ValidationError::new("required")
+                    .with_message("Blood pressure needs both systolic and diastolic".into()),
+            ),
+            _ => {}
+        }
+
+        check_range(
+            &mut errors,
+            "systolic_bp",
+            systolic_bp,
+            (40, 300),
+            "Systolic pressure must be between 40 and 300 mmHg",
+        );
+        check_range(
+            &mut errors,
+            "diastolic_bp",
+            diastolic_bp,
+            (20, 200),
+            "Diastolic pressure must be between 20 and 200 mmHg",
+        );
+        check_range(
+            &mut errors,
+            "pulse_rate",
+            pulse_rate,
+            (20, 300),
+            "Pulse rate must be between 20 and 300 beats per minute",
+        );
+        check_range(
+            &mut errors,
+            "respiratory_rate",
+            respiratory_rate,
+            (4, 80),
+            "Respiratory rate must be between 4 and 80 breaths per minute",
+        );
+        check_range(
+            &mut errors,
+            "temperature",
+            temperature_celsius,
+            (25.0, 45.0),
+            "Temperature must be between 25 and 45 °C (77 and 113 °F)",
+        );
+        check_range(
+            &mut errors,
+            "spo2",
+            spo2,
+            (50, 100),
+            "SpO2 must be between 50 and 100 percent",
+        );
+        check_range(
+            &mut errors,
+            "weight",
+            weight_kg,
+            (0.3, 500.0),
+            "Weight must be between 0.3 and 500 kg",
+        );
+        check_range(
+            &mut errors,
+            "height",
+            height_cm,
+            (20.0, 280.0),
+            "Height must be between 20 and 280 cm",
+        );
+        check_range(
+            &mut errors,
+            "pain_score",
+            pain_score,
+            (0, 10),
+            "Pain score must be between 0 and 10",
+        );
+
+        if errors.is_empty() {
+            Ok(measurements)
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Validate)]
+pub struct VitalsHistoryQuery {
+    #[serde(default, deserialize_with = "optional_naive_date")]
+    pub from: Option<NaiveDate>,
+    #[serde(default, deserialize_with = "optional_naive_date")]
+    pub to: Option<NaiveDate>,
+    #[validate(range(min = 1, max = 500, message = "Limit must be between 1 and 500"))]
+    pub limit: Option<u64>,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum VitalMeasure {
+    SystolicBp,
+    DiastolicBp,
+    PulseRate,
+    RespiratoryRate,
+    Temperature,
+    Spo2,
+    Weight,
+    Height,
+    Bmi,
+    PainScore,
+}
+
+impl VitalMeasure {
+    pub const ALL: [VitalMeasure; 10] = [
+        VitalMeasure::SystolicBp,
+        VitalMeasure::DiastolicBp,
+        VitalMeasure::PulseRate,
+        VitalMeasure::RespiratoryRate,
+        VitalMeasure::Temperature,
+        VitalMeasure::Spo2,
+        VitalMeasure::Weight,
+        VitalMeasure::Height,
+        VitalMeasure::Bmi,
+        VitalMeasure::PainScore,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            VitalMeasure::SystolicBp => "systolic_bp",
+            VitalMeasure::DiastolicBp => "diastolic_bp",
+            VitalMeasure::PulseRate => "pulse_rate",
+            VitalMeasure::RespiratoryRate => "respiratory_rate",
+            VitalMeasure::Temperature => "temperature",
+            VitalMeasure::Spo2 => "spo2",
+            VitalMeasure::Weight => "weight",
+            VitalMeasure::Height => "height",
+            VitalMeasure::Bmi => "bmi",
+            VitalMeasure::PainScore => "pain_score",
+        }
+    }
+
+    /// The unit the measure is stored and reported in.
+    pub fn unit(&self) -> &'static str {
+        match self {
+            VitalMeasure::SystolicBp | VitalMeasure::DiastolicBp => "mmHg",
+            VitalMeasure::PulseRate => "beats/min",
+            VitalMeasure::RespiratoryRate => "breaths/min",
+            VitalMeasure::Temperature => "°C",
+            VitalMeasure::Spo2 => "%",
+            VitalMeasure::Weight => "kg",
+            VitalMeasure::Height => "cm",
+            VitalMeasure::Bmi => "kg/m²",
+            VitalMeasure::PainScore => "0-10",
+        }
+    }
+
+    pub fn value(&self, vitals: &vital_signs::Model) -> Option<f64> {
+        match self {
+            VitalMeasure::SystolicBp => vitals.systolic_bp.map(f64::from),
+            VitalMeasure::DiastolicBp => vitals.diastolic_bp.map(f64::from),
+            VitalMeasure::PulseRate => vitals.pulse_rate.map(f64::from),
+            VitalMeasure::RespiratoryRate => vitals.respiratory_rate.map(f64::from),
+            VitalMeasure::Temperature => vitals.temperature_celsius,
+            VitalMeasure::Spo2 => vitals.spo2.map(f64::from),
+            VitalMeasure::Weight => vitals.weight_kg,
+            VitalMeasure::Height => vitals.height_cm,
+            VitalMeasure::Bmi => vitals.bmi,
+            VitalMeasure::PainScore => vitals.pain_score.map(f64::from),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct VitalsTrendQuery {
+    /// Only this measure; every measure when empty.
+    pub measure: Option<VitalMeasure>,
+    #[serde(default, deserialize_with = "optional_naive_date")]
+    pub from: Option<NaiveDate>,
+    #[serde(default, deserialize_with = "optional_naive_date")]
+    pub to: Option<NaiveDate>,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{Value, json};
+
+    use super::*;
+
+    fn request(body: Value) -> RecordVitalsRequest {
+        serde_json::from_value(body).unwrap()
+    }
+
+    fn error_fields(body: Value) -> Vec<String> {
+        let errors = request(body).measurements().unwrap_err();
+        let mut fields: Vec<String> = errors
+            .field_errors()
+            .keys()
+            .map(|field| field.to_string())
+            .collect();
+        fields.sort();
+        fields
+    }
+
+    fn close(actual: Option<f64>, expected: f64) -> bool {
+        actual.is_some_and(|actual| (actual - expected).abs() < 0.01)
+    }
+
+    #[test]
+    fn keeps_metric_units() {
+        let measurements = request(json!({
+            "temperature": 37.2,
+            "weight": 70.5,
+            "height": 172.0,
+        }))
+        .measurements()
+        .unwrap();
+
+        assert!(close(measurements.temperature_celsius, 37.2));
+        assert!(close(measurements.weight_kg, 70.5));
+        assert!(close(measurements.height_cm, 172.0));
+    }
+
+    #[test]
+    fn converts_fahrenheit_pounds_and_inches() {
+        let measurements = request(json!({
+            "temperature": 98.6,
+            "temperature_unit": "F",
+            "weight": 154.32,
+            "weight_unit": "lb",
+            "height": 70.0,
+            "height_unit": "in",
+        }))
+        .measurements()
+        .unwrap();
+
+        assert!(close(measurements.temperature_celsius, 37.0));
+        assert!(close(measurements.weight_kg, 70.0));
+        assert!(close(measurements.height_cm, 177.8));
+    }
+
+    #[test]
+    fn checks_range_after_conversion() {
+        // 113.5 °F is 45.3 °C, above the limit even though the number looks plausible
+        assert_eq!(
+            error_fields(json!({"temperature": 113.5, "temperature_unit": "fahrenheit"})),
+            ["temperature"]
+        );
+        assert_eq!(
+            error_fields(json!({"weight": 1200.0, "weight_unit": "pound"})),
+            ["weight"]
+        );
+        assert_eq!(
+            error_fields(json!({"height": 5.0, "height_unit": "inch"})),
+            ["height"]
+        );
+    }
+
+    #[test]
+    fn accepts_values_on_the_limits() {
+        assert!(
+            request(json!({
+                "systolic_bp": 300,
+                "diastolic_bp": 200,
+                "pulse_rate": 20,
+                "respiratory_rate": 80,
+                "temperature": 25.0,
+                "spo2": 100,
+                "weight": 0.3,
+                "height": 280.0,
+                "pain_score": 0,
+            }))
+            .measurements()
+            .is_ok()
+        );
+    }
+
+    #[test]
+    fn rejects_values_outside_the_limits() {
+        assert_eq!(
+            error_fields(json!({
+                "systolic_bp": 301,
+                "diastolic_bp": 19,
+                "pulse_rate": 19,
+                "respiratory_rate": 81,
+                "temperature": 45.1,
+                "spo2": 49,
+                "weight": 0.2,
+                "height": 281.0,
+                "pain_score": 11,
+            })),
+            [
+                "diastolic_bp",
+                "height",
+                "pain_score",
+                "pulse_rate",
+                "respiratory_rate",
+                "spo2",
+                "systolic_bp",
+                "temperature",
+                "weight",
+            ]
+        );
+    }
+
+    #[test]
+    fn requires_systolic_above_diastolic() {
+        assert_eq!(
+            error_fields(json!({"systolic_bp": 80, "diastolic_bp": 80})),
+            ["diastolic_bp"]
+        );
+        assert_eq!(
+            error_fields(json!({"systolic_bp": 70, "diastolic_bp": 90})),
+            ["diastolic_bp"]
+        );
+        assert!(
+            request(json!({"systolic_bp": 120, "diastolic_bp": 80}))
+                .measurements()
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn requires_both_blood_pressure_values() {
+        assert_eq!(error_fields(json!({"systolic_bp": 120})), ["systolic_bp"]);
+        assert_eq!(error_fields(json!({"diastolic_bp": 80})), ["systolic_bp"]);
+    }
+
+    #[test]
+    fn requires_at_least_one_measurement() {
+        assert_eq!(
+            error_fields(json!({"notes": "Patient refused"})),
+            ["measurements"]
+        );
+    }
+}
@@ -27,6 +27,18 @@ pub enum AppError {
     #[error("Not found: {0}")]
     NotFound(String),
 
+    #[error("Unauthorized: {0}")]
+    Unauthorized(String),
+
+    #[error("Forbidden: {0}")]
+    Forbidden(String),
+
+    #[error("Conflict: {0}")]
+    Conflict(String),
+
+    #[error("Conflict: {0}")]
+    FieldConflict(String, Vec<ApiFieldError>),
+
     #[error("Internal server error: {0}")]
     Internal(String),
 }
@@ -66,6 +78,10 @@ impl IntoResponse for AppError {
                 Some(api_field_errors),
             ),
             AppError::NotFound(msg) => (StatusCode::NOT_FOUND, msg, None),
+            AppError::Unauthorized(msg) => (StatusCode::UNAUTHORIZED, msg, None),
+            AppError::Forbidden(msg) => (StatusCode::FORBIDDEN, msg, None),
+            AppError::Conflict(msg) => (StatusCode::CONFLICT, msg, None),
+            AppError::FieldConflict(msg, fields) => (StatusCode::CONFLICT, msg, Some(fields)),
             AppError::Internal(msg) => (StatusCode::INTERNAL_SERVER_ERROR, msg, None),
         };
 
@@ -126,6 +142,17 @@ impl From<serde_json::Error> for AppError {
     }
 }
 
+impl From<jsonwebtoken::errors::Error> for AppError {
+    fn from(value: jsonwebtoken::errors::Error) -> Self {
+        match value.kind() {
+            jsonwebtoken::errors::ErrorKind::ExpiredSignature => {
+                AppError::Unauthorized("Token has expired".into())
+            }
+            _ => AppError::Unauthorized("Invalid token".into()),
+        }
+    }
+}
+
 impl From<aws_sdk_s3::Error> for AppError {
     fn from(value: aws_sdk_s3::Error) -> Self {
         AppError::Internal(value.to_string())
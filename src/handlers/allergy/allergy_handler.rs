@@ -0,0 +1,154 @@
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+};
+use validator::Validate;
+
+use crate::{
+    dtos::allergy::{
+        allergy_request::{
+            ConvertLegacyAllergiesRequest, CreateAllergyRequest, UpdateAllergyRequest,
+        },
+        response::{AllergyRemoved, AllergyResponse, PatientAllergiesResponse},
+    },
+    error_handling::app_error::AppError,
+    infra::api::ApiResponse,
+    middleware::{auth_middleware::CurrentUser, request_middleware::RequestId},
+    state::AppState,
+    use_cases::allergy::service::allergy_service::{AllergyService, AllergyServiceContracts},
+};
+
+pub async fn get_patient_allergies(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    Path(patient_id): Path<i32>,
+) -> Result<Json<ApiResponse<PatientAllergiesResponse>>, AppError> {
+    let db = &state.db;
+
+    let result =
+        <AllergyService as AllergyServiceContracts>::get_patient_allergies(db, patient_id).await?;
+
+    let response = ApiResponse {
+        message: "Get patient allergies successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn record_allergy(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    user: CurrentUser,
+    Path(patient_id): Path<i32>,
+    Json(payload): Json<CreateAllergyRequest>,
+) -> Result<Json<ApiResponse<AllergyResponse>>, AppError> {
+    payload.validate().map_err(AppError::from)?;
+
+    let db = &state.db;
+
+    let result = <AllergyService as AllergyServiceContracts>::record_allergy(
+        db, patient_id, payload, user.id,
+    )
+    .await?;
+
+    let response = ApiResponse {
+        message: "Record allergy successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn convert_legacy_allergies(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    user: CurrentUser,
+    Path(patient_id): Path<i32>,
+    Json(payload): Json<ConvertLegacyAllergiesRequest>,
+) -> Result<Json<ApiResponse<PatientAllergiesResponse>>, AppError> {
+    payload.validate_all()?;
+
+    let db = &state.db;
+
+    let result = <AllergyService as AllergyServiceContracts>::convert_legacy_allergies(
+        db, patient_id, payload, user.id,
+    )
+    .await?;
+
+    let response = ApiResponse {
+        message: "Convert legacy allergies successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn update_allergy(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    Path((patient_id, allergy_id)): Path<(i32, i32)>,
+    Json(payload): Json<UpdateAllergyRequest>,
+) -> Result<Json<ApiResponse<AllergyResponse>>, AppError> {
+    payload.validate().map_err(AppError::from)?;
+
+    let db = &state.db;
+
+    let result = <AllergyService as AllergyServiceContracts>::update_allergy(
+        db, patient_id, allergy_id, payload,
+    )
+    .await?;
+
+    let response = ApiResponse {
+        message: "Update allergy successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn verify_allergy(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    user: CurrentUser,
+    Path((patient_id, allergy_id)): Path<(i32, i32)>,
+) -> Result<Json<ApiResponse<AllergyResponse>>, AppError> {
+    let db = &state.db;
+
+    let result = <AllergyService as AllergyServiceContracts>::verify_allergy(
+        db, patient_id, allergy_id, user.id,
+    )
+    .await?;
+
+    let response = ApiResponse {
+        message: "Verify allergy successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn remove_allergy(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    Path((patient_id, allergy_id)): Path<(i32, i32)>,
+) -> Result<Json<ApiResponse<AllergyRemoved>>, AppError> {
+    let db = &state.db;
+
+    let result =
+        <AllergyService as AllergyServiceContracts>::remove_allergy(db, patient_id, allergy_id)
+            .await?;
+
+    let response = ApiResponse {
+        message: "Remove allergy successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
@@ -0,0 +1,106 @@
+use axum::{Extension, Json, extract::State};
+use http::HeaderMap;
+use validator::Validate;
+
+use crate::{
+    dtos::auth::{
+        auth_request::{LoginRequest, RefreshTokenRequest},
+        response::{DisplayTokenResponse, LoginResponse, LogoutResponse, RefreshTokenResponse},
+    },
+    error_handling::app_error::AppError,
+    infra::api::ApiResponse,
+    middleware::{
+        auth_middleware::{CurrentUser, bearer_token},
+        request_middleware::RequestId,
+    },
+    state::AppState,
+    use_cases::auth::{contracts::AuthServiceContracts, service::auth_service::AuthService},
+};
+
+pub async fn auth_login(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    Json(payload): Json<LoginRequest>,
+) -> Result<Json<ApiResponse<LoginResponse>>, AppError> {
+    payload.validate().map_err(AppError::from)?;
+
+    let db = &state.db;
+    let redis = &state.redis;
+
+    let result =
+        <AuthService as AuthServiceContracts>::login(db, redis, &state.jwt, payload).await?;
+
+    let response = ApiResponse {
+        message: "Login successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn auth_refresh_token(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    Json(payload): Json<RefreshTokenRequest>,
+) -> Result<Json<ApiResponse<RefreshTokenResponse>>, AppError> {
+    payload.validate().map_err(AppError::from)?;
+
+    let db = &state.db;
+    let redis = &state.redis;
+
+    let result =
+        <AuthService as AuthServiceContracts>::refresh_token(db, redis, &state.jwt, payload)
+            .await?;
+
+    let response = ApiResponse {
+        message: "Refresh token successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn auth_display_token(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    user: CurrentUser,
+) -> Result<Json<ApiResponse<DisplayTokenResponse>>, AppError> {
+    let db = &state.db;
+
+    let result =
+        <AuthService as AuthServiceContracts>::issue_display_token(db, &state.jwt, user.id).await?;
+
+    let response = ApiResponse {
+        message: "Issue display token successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn auth_logout(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    headers: HeaderMap,
+    Json(payload): Json<RefreshTokenRequest>,
+) -> Result<Json<ApiResponse<LogoutResponse>>, AppError> {
+    payload.validate().map_err(AppError::from)?;
+
+    let redis = &state.redis;
+    let access_token = bearer_token(&headers).map(|token| token.to_string());
+
+    let result =
+        <AuthService as AuthServiceContracts>::logout(redis, &state.jwt, payload, access_token)
+            .await?;
+
+    let response = ApiResponse {
+        message: "Logout successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
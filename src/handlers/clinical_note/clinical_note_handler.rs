@@ -0,0 +1,208 @@
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+};
+use validator::Validate;
+
+use crate::{
+    dtos::clinical_note::{
+        clinical_note_request::{AddendumRequest, ClinicalNoteRequest},
+        response::{
+            ClinicalNoteResponse, NoteDiscarded, PatientNoteTimelineResponse, VisitNotesResponse,
+        },
+    },
+    error_handling::app_error::AppError,
+    infra::api::ApiResponse,
+    middleware::{auth_middleware::CurrentUser, request_middleware::RequestId},
+    state::AppState,
+    use_cases::clinical_note::service::clinical_note_service::{
+        ClinicalNoteService, ClinicalNoteServiceContracts,
+    },
+};
+
+pub async fn create_note(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    user: CurrentUser,
+    Path(visit_intent_id): Path<i32>,
+    Json(payload): Json<ClinicalNoteRequest>,
+) -> Result<Json<ApiResponse<ClinicalNoteResponse>>, AppError> {
+    payload.validate().map_err(AppError::from)?;
+
+    let db = &state.db;
+
+    let result = <ClinicalNoteService as ClinicalNoteServiceContracts>::create_note(
+        db,
+        visit_intent_id,
+        user.id,
+        payload,
+    )
+    .await?;
+
+    let response = ApiResponse {
+        message: "Create clinical note successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn get_note(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    user: CurrentUser,
+    Path(note_id): Path<i32>,
+) -> Result<Json<ApiResponse<ClinicalNoteResponse>>, AppError> {
+    let db = &state.db;
+
+    let result =
+        <ClinicalNoteService as ClinicalNoteServiceContracts>::get_note(db, note_id, user.id)
+            .await?;
+
+    let response = ApiResponse {
+        message: "Get clinical note successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn update_note(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    user: CurrentUser,
+    Path(note_id): Path<i32>,
+    Json(payload): Json<ClinicalNoteRequest>,
+) -> Result<Json<ApiResponse<ClinicalNoteResponse>>, AppError> {
+    payload.validate().map_err(AppError::from)?;
+
+    let db = &state.db;
+
+    let result = <ClinicalNoteService as ClinicalNoteServiceContracts>::update_note(
+        db, note_id, user.id, payload,
+    )
+    .await?;
+
+    let response = ApiResponse {
+        message: "Update clinical note successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn sign_note(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    user: CurrentUser,
+    Path(note_id): Path<i32>,
+) -> Result<Json<ApiResponse<ClinicalNoteResponse>>, AppError> {
+    let db = &state.db;
+
+    let result =
+        <ClinicalNoteService as ClinicalNoteServiceContracts>::sign_note(db, note_id, user.id)
+            .await?;
+
+    let response = ApiResponse {
+        message: "Sign clinical note successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn discard_note(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    user: CurrentUser,
+    Path(note_id): Path<i32>,
+) -> Result<Json<ApiResponse<NoteDiscarded>>, AppError> {
+    let db = &state.db;
+
+    let result =
+        <ClinicalNoteService as ClinicalNoteServiceContracts>::discard_note(db, note_id, user.id)
+            .await?;
+
+    let response = ApiResponse {
+        message: "Discard clinical note successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn add_addendum(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    user: CurrentUser,
+    Path(note_id): Path<i32>,
+    Json(payload): Json<AddendumRequest>,
+) -> Result<Json<ApiResponse<ClinicalNoteResponse>>, AppError> {
+    payload.validate().map_err(AppError::from)?;
+
+    let db = &state.db;
+
+    let result = <ClinicalNoteService as ClinicalNoteServiceContracts>::add_addendum(
+        db, note_id, user.id, payload,
+    )
+    .await?;
+
+    let response = ApiResponse {
+        message: "Add addendum successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn get_visit_notes(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    user: CurrentUser,
+    Path(visit_intent_id): Path<i32>,
+) -> Result<Json<ApiResponse<VisitNotesResponse>>, AppError> {
+    let db = &state.db;
+
+    let result = <ClinicalNoteService as ClinicalNoteServiceContracts>::get_visit_notes(
+        db,
+        visit_intent_id,
+        user.id,
+    )
+    .await?;
+
+    let response = ApiResponse {
+        message: "Get visit notes successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn get_patient_timeline(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    user: CurrentUser,
+    Path(patient_id): Path<i32>,
+) -> Result<Json<ApiResponse<PatientNoteTimelineResponse>>, AppError> {
+    let db = &state.db;
+
+    let result = <ClinicalNoteService as ClinicalNoteServiceContracts>::get_patient_timeline(
+        db, patient_id, user.id,
+    )
+    .await?;
+
+    let response = ApiResponse {
+        message: "Get patient note timeline successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
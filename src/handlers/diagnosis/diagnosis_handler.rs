@@ -0,0 +1,162 @@
+use axum::{
+    Extension, Json,
+    extract::{Path, Query, State},
+};
+use validator::Validate;
+
+use crate::{
+    dtos::diagnosis::{
+        diagnosis_request::{Icd10SearchQuery, RecordDiagnosisRequest, UpdateDiagnosisRequest},
+        response::{
+            DiagnosisRemoved, DiagnosisResponse, Icd10CodeResponse, Icd10SearchResponse,
+            VisitDiagnosesResponse,
+        },
+    },
+    error_handling::app_error::AppError,
+    infra::api::ApiResponse,
+    middleware::{auth_middleware::CurrentUser, request_middleware::RequestId},
+    state::AppState,
+    use_cases::diagnosis::service::diagnosis_service::{
+        DiagnosisService, DiagnosisServiceContracts,
+    },
+};
+
+pub async fn search_icd10_codes(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    Query(query): Query<Icd10SearchQuery>,
+) -> Result<Json<ApiResponse<Icd10SearchResponse>>, AppError> {
+    query.validate().map_err(AppError::from)?;
+
+    let db = &state.db;
+
+    let result = <DiagnosisService as DiagnosisServiceContracts>::search_codes(db, query).await?;
+
+    let response = ApiResponse {
+        message: "Search ICD-10 codes successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn get_icd10_code(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    Path(code): Path<String>,
+) -> Result<Json<ApiResponse<Icd10CodeResponse>>, AppError> {
+    let db = &state.db;
+
+    let result = <DiagnosisService as DiagnosisServiceContracts>::get_code(db, &code).await?;
+
+    let response = ApiResponse {
+        message: "Get ICD-10 code successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn record_diagnosis(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    user: CurrentUser,
+    Path(visit_intent_id): Path<i32>,
+    Json(payload): Json<RecordDiagnosisRequest>,
+) -> Result<Json<ApiResponse<DiagnosisResponse>>, AppError> {
+    payload.validate().map_err(AppError::from)?;
+
+    let db = &state.db;
+
+    let result = <DiagnosisService as DiagnosisServiceContracts>::record_diagnosis(
+        db,
+        visit_intent_id,
+        user.id,
+        payload,
+    )
+    .await?;
+
+    let response = ApiResponse {
+        message: "Record diagnosis successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn update_diagnosis(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    user: CurrentUser,
+    Path((visit_intent_id, diagnosis_id)): Path<(i32, i32)>,
+    Json(payload): Json<UpdateDiagnosisRequest>,
+) -> Result<Json<ApiResponse<DiagnosisResponse>>, AppError> {
+    payload.validate().map_err(AppError::from)?;
+
+    let db = &state.db;
+
+    let result = <DiagnosisService as DiagnosisServiceContracts>::update_diagnosis(
+        db,
+        visit_intent_id,
+        diagnosis_id,
+        user.id,
+        payload,
+    )
+    .await?;
+
+    let response = ApiResponse {
+        message: "Update diagnosis successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn remove_diagnosis(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    user: CurrentUser,
+    Path((visit_intent_id, diagnosis_id)): Path<(i32, i32)>,
+) -> Result<Json<ApiResponse<DiagnosisRemoved>>, AppError> {
+    let db = &state.db;
+
+    let result = <DiagnosisService as DiagnosisServiceContracts>::remove_diagnosis(
+        db,
+        visit_intent_id,
+        diagnosis_id,
+        user.id,
+    )
+    .await?;
+
+    let response = ApiResponse {
+        message: "Remove diagnosis successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn get_visit_diagnoses(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    Path(visit_intent_id): Path<i32>,
+) -> Result<Json<ApiResponse<VisitDiagnosesResponse>>, AppError> {
+    let db = &state.db;
+
+    let result =
+        <DiagnosisService as DiagnosisServiceContracts>::get_visit_diagnoses(db, visit_intent_id)
+            .await?;
+
+    let response = ApiResponse {
+        message: "Get visit diagnoses successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
@@ -0,0 +1,163 @@
+use axum::{
+    Extension, Json,
+    extract::{Path, Query, State},
+};
+use validator::Validate;
+
+use crate::{
+    dtos::{
+        encounter::{
+            encounter_request::{FinishEncounterRequest, OpenEncounterRequest, WorklistQuery},
+            response::{DoctorWorklistResponse, EncounterResponse},
+        },
+        triage::queue_scope::ServiceDate,
+    },
+    error_handling::app_error::AppError,
+    infra::api::ApiResponse,
+    middleware::{auth_middleware::CurrentUser, request_middleware::RequestId},
+    state::AppState,
+    use_cases::encounter::service::encounter_service::{
+        EncounterService, EncounterServiceContracts,
+    },
+};
+
+pub async fn open_encounter(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    Json(payload): Json<OpenEncounterRequest>,
+) -> Result<Json<ApiResponse<EncounterResponse>>, AppError> {
+    payload.validate().map_err(AppError::from)?;
+
+    let db = &state.db;
+
+    let result =
+        <EncounterService as EncounterServiceContracts>::open_encounter(db, payload).await?;
+
+    let response = ApiResponse {
+        message: "Open encounter successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn get_encounter(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    Path(encounter_id): Path<i32>,
+) -> Result<Json<ApiResponse<EncounterResponse>>, AppError> {
+    let db = &state.db;
+
+    let result =
+        <EncounterService as EncounterServiceContracts>::get_encounter(db, encounter_id).await?;
+
+    let response = ApiResponse {
+        message: "Get encounter successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn start_encounter(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    user: CurrentUser,
+    Path(encounter_id): Path<i32>,
+) -> Result<Json<ApiResponse<EncounterResponse>>, AppError> {
+    let db = &state.db;
+
+    let result =
+        <EncounterService as EncounterServiceContracts>::start_encounter(db, encounter_id, user.id)
+            .await?;
+
+    let response = ApiResponse {
+        message: "Start encounter successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn finish_encounter(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    user: CurrentUser,
+    Path(encounter_id): Path<i32>,
+    Json(payload): Json<FinishEncounterRequest>,
+) -> Result<Json<ApiResponse<EncounterResponse>>, AppError> {
+    payload.validate().map_err(AppError::from)?;
+
+    let db = &state.db;
+
+    let result = <EncounterService as EncounterServiceContracts>::finish_encounter(
+        db,
+        encounter_id,
+        user.id,
+        payload,
+    )
+    .await?;
+
+    let response = ApiResponse {
+        message: "Finish encounter successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn my_worklist(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    user: CurrentUser,
+    ServiceDate(service_date): ServiceDate,
+    Query(query): Query<WorklistQuery>,
+) -> Result<Json<ApiResponse<DoctorWorklistResponse>>, AppError> {
+    let db = &state.db;
+
+    let result = <EncounterService as EncounterServiceContracts>::get_my_worklist(
+        db,
+        user.id,
+        service_date,
+        query.status,
+    )
+    .await?;
+
+    let response = ApiResponse {
+        message: "Get worklist successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn doctor_worklist(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    Path(doctor_id): Path<i32>,
+    ServiceDate(service_date): ServiceDate,
+    Query(query): Query<WorklistQuery>,
+) -> Result<Json<ApiResponse<DoctorWorklistResponse>>, AppError> {
+    let db = &state.db;
+
+    let result = <EncounterService as EncounterServiceContracts>::get_doctor_worklist(
+        db,
+        doctor_id,
+        service_date,
+        query.status,
+    )
+    .await?;
+
+    let response = ApiResponse {
+        message: "Get doctor worklist successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
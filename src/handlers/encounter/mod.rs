@@ -0,0 +1 @@
+pub mod encounter_handler;
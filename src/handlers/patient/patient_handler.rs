@@ -0,0 +1,255 @@
+use axum::{
+    Extension, Json,
+    extract::{Path, Query, State},
+};
+use validator::Validate;
+
+use crate::{
+    dtos::patient::{
+        patient_request::{
+            DuplicateCandidatesQuery, IdentifyPatientRequest, MergePatientRequest, MrnGapQuery,
+            PatientLookupQuery, PatientSearchQuery, UpdatePatientRequest,
+        },
+        response::{
+            DuplicateCandidatesResponse, MrnGapReport, PatientHistoryResponse,
+            PatientMergeResponse, PatientMergesResponse, PatientResponse, PatientSearchResponse,
+        },
+    },
+    error_handling::app_error::AppError,
+    infra::api::ApiResponse,
+    middleware::{auth_middleware::CurrentUser, request_middleware::RequestId},
+    state::AppState,
+    use_cases::patient::service::patient_service::{PatientService, PatientServiceContracts},
+};
+
+pub async fn get_patient(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    Path(patient_id): Path<i32>,
+) -> Result<Json<ApiResponse<PatientResponse>>, AppError> {
+    let db = &state.db;
+
+    let result = <PatientService as PatientServiceContracts>::get_patient(db, patient_id).await?;
+
+    let response = ApiResponse {
+        message: "Get patient successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn lookup_patient(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    Query(query): Query<PatientLookupQuery>,
+) -> Result<Json<ApiResponse<PatientResponse>>, AppError> {
+    query.validate().map_err(AppError::from)?;
+
+    let db = &state.db;
+
+    let result = <PatientService as PatientServiceContracts>::lookup_patient(db, query).await?;
+
+    let response = ApiResponse {
+        message: "Lookup patient successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn search_patients(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    Query(query): Query<PatientSearchQuery>,
+) -> Result<Json<ApiResponse<PatientSearchResponse>>, AppError> {
+    query.validate().map_err(AppError::from)?;
+
+    let db = &state.db;
+
+    let result = <PatientService as PatientServiceContracts>::search_patients(db, query).await?;
+
+    let response = ApiResponse {
+        message: "Search patients successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn update_patient(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    user: CurrentUser,
+    Path(patient_id): Path<i32>,
+    Json(payload): Json<UpdatePatientRequest>,
+) -> Result<Json<ApiResponse<PatientResponse>>, AppError> {
+    payload.validate().map_err(AppError::from)?;
+
+    let db = &state.db;
+
+    let result = <PatientService as PatientServiceContracts>::update_patient(
+        db, patient_id, payload, user.id,
+    )
+    .await?;
+
+    let response = ApiResponse {
+        message: "Update patient successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn identify_patient(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    user: CurrentUser,
+    Path(patient_id): Path<i32>,
+    Json(payload): Json<IdentifyPatientRequest>,
+) -> Result<Json<ApiResponse<PatientResponse>>, AppError> {
+    payload.validate_identity().map_err(AppError::from)?;
+
+    let db = &state.db;
+
+    let result = <PatientService as PatientServiceContracts>::identify_patient(
+        db, patient_id, payload, user.id,
+    )
+    .await?;
+
+    let response = ApiResponse {
+        message: "Identify patient successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn get_patient_history(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    Path(patient_id): Path<i32>,
+) -> Result<Json<ApiResponse<PatientHistoryResponse>>, AppError> {
+    let db = &state.db;
+
+    let result =
+        <PatientService as PatientServiceContracts>::get_patient_history(db, patient_id).await?;
+
+    let response = ApiResponse {
+        message: "Get patient history successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn find_duplicate_patients(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    Query(query): Query<DuplicateCandidatesQuery>,
+) -> Result<Json<ApiResponse<DuplicateCandidatesResponse>>, AppError> {
+    query.validate().map_err(AppError::from)?;
+
+    let db = &state.db;
+
+    let result =
+        <PatientService as PatientServiceContracts>::find_duplicate_candidates(db, query).await?;
+
+    let response = ApiResponse {
+        message: "Find duplicate patients successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn merge_patients(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    user: CurrentUser,
+    Path(patient_id): Path<i32>,
+    Json(payload): Json<MergePatientRequest>,
+) -> Result<Json<ApiResponse<PatientMergeResponse>>, AppError> {
+    payload.validate().map_err(AppError::from)?;
+
+    let db = &state.db;
+
+    let result = <PatientService as PatientServiceContracts>::merge_patients(
+        db, patient_id, payload, user.id,
+    )
+    .await?;
+
+    let response = ApiResponse {
+        message: "Merge patients successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn undo_patient_merge(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    user: CurrentUser,
+    Path(merge_id): Path<i32>,
+) -> Result<Json<ApiResponse<PatientMergeResponse>>, AppError> {
+    let db = &state.db;
+
+    let result =
+        <PatientService as PatientServiceContracts>::undo_patient_merge(db, merge_id, user.id)
+            .await?;
+
+    let response = ApiResponse {
+        message: "Undo patient merge successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn get_patient_merges(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    Path(patient_id): Path<i32>,
+) -> Result<Json<ApiResponse<PatientMergesResponse>>, AppError> {
+    let db = &state.db;
+
+    let result =
+        <PatientService as PatientServiceContracts>::get_patient_merges(db, patient_id).await?;
+
+    let response = ApiResponse {
+        message: "Get patient merges successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn find_mrn_gaps(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    Query(query): Query<MrnGapQuery>,
+) -> Result<Json<ApiResponse<MrnGapReport>>, AppError> {
+    let db = &state.db;
+
+    let result =
+        <PatientService as PatientServiceContracts>::find_mrn_gaps(db, &state.mrn, query).await?;
+
+    let response = ApiResponse {
+        message: "Find medical record number gaps successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
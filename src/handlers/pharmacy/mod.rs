@@ -0,0 +1 @@
+pub mod pharmacy_handler;
@@ -0,0 +1,284 @@
+use axum::{
+    Extension, Json,
+    extract::{Path, Query, State},
+};
+use validator::Validate;
+
+use crate::{
+    dtos::pharmacy::{
+        pharmacy_request::{
+            CreateDrugRequest, CreateLocationRequest, DrugSearchQuery, GoodsReceiptRequest,
+            NearExpiryQuery, StockAdjustmentRequest, StockReportQuery, UpdateDrugRequest,
+        },
+        response::{
+            BatchMovementsResponse, DrugResponse, DrugSearchResponse, DrugStockResponse,
+            GoodsReceiptResponse, LocationResponse, LocationsResponse, LowStockReportResponse,
+            NearExpiryReportResponse, StockAdjustmentResponse,
+        },
+    },
+    error_handling::app_error::AppError,
+    infra::api::ApiResponse,
+    middleware::{auth_middleware::CurrentUser, request_middleware::RequestId},
+    state::AppState,
+    use_cases::pharmacy::service::pharmacy_service::{PharmacyService, PharmacyServiceContracts},
+};
+
+pub async fn search_drugs(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    Query(query): Query<DrugSearchQuery>,
+) -> Result<Json<ApiResponse<DrugSearchResponse>>, AppError> {
+    query.validate().map_err(AppError::from)?;
+
+    let db = &state.db;
+
+    let result = <PharmacyService as PharmacyServiceContracts>::search_drugs(db, query).await?;
+
+    let response = ApiResponse {
+        message: "Search drugs successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn get_drug(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    Path(drug_id): Path<i32>,
+) -> Result<Json<ApiResponse<DrugResponse>>, AppError> {
+    let db = &state.db;
+
+    let result = <PharmacyService as PharmacyServiceContracts>::get_drug(db, drug_id).await?;
+
+    let response = ApiResponse {
+        message: "Get drug successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn create_drug(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    Json(payload): Json<CreateDrugRequest>,
+) -> Result<Json<ApiResponse<DrugResponse>>, AppError> {
+    payload.validate().map_err(AppError::from)?;
+
+    let db = &state.db;
+
+    let result = <PharmacyService as PharmacyServiceContracts>::create_drug(db, payload).await?;
+
+    let response = ApiResponse {
+        message: "Create drug successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn update_drug(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    Path(drug_id): Path<i32>,
+    Json(payload): Json<UpdateDrugRequest>,
+) -> Result<Json<ApiResponse<DrugResponse>>, AppError> {
+    payload.validate().map_err(AppError::from)?;
+
+    let db = &state.db;
+
+    let result =
+        <PharmacyService as PharmacyServiceContracts>::update_drug(db, drug_id, payload).await?;
+
+    let response = ApiResponse {
+        message: "Update drug successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn get_drug_stock(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    Path(drug_id): Path<i32>,
+) -> Result<Json<ApiResponse<DrugStockResponse>>, AppError> {
+    let db = &state.db;
+
+    let result = <PharmacyService as PharmacyServiceContracts>::get_drug_stock(db, drug_id).await?;
+
+    let response = ApiResponse {
+        message: "Get drug stock successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn get_locations(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+) -> Result<Json<ApiResponse<LocationsResponse>>, AppError> {
+    let db = &state.db;
+
+    let result = <PharmacyService as PharmacyServiceContracts>::get_locations(db).await?;
+
+    let response = ApiResponse {
+        message: "Get storage locations successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn create_location(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    Json(payload): Json<CreateLocationRequest>,
+) -> Result<Json<ApiResponse<LocationResponse>>, AppError> {
+    payload.validate().map_err(AppError::from)?;
+
+    let db = &state.db;
+
+    let result =
+        <PharmacyService as PharmacyServiceContracts>::create_location(db, payload).await?;
+
+    let response = ApiResponse {
+        message: "Create storage location successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn receive_goods(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    user: CurrentUser,
+    Json(payload): Json<GoodsReceiptRequest>,
+) -> Result<Json<ApiResponse<GoodsReceiptResponse>>, AppError> {
+    payload.validate_all()?;
+
+    let db = &state.db;
+
+    let result =
+        <PharmacyService as PharmacyServiceContracts>::receive_goods(db, user.id, payload).await?;
+
+    let response = ApiResponse {
+        message: "Receive goods successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn get_goods_receipt(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    Path(receipt_id): Path<i32>,
+) -> Result<Json<ApiResponse<GoodsReceiptResponse>>, AppError> {
+    let db = &state.db;
+
+    let result =
+        <PharmacyService as PharmacyServiceContracts>::get_goods_receipt(db, receipt_id).await?;
+
+    let response = ApiResponse {
+        message: "Get goods receipt successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn adjust_stock(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    user: CurrentUser,
+    Path(batch_id): Path<i32>,
+    Json(payload): Json<StockAdjustmentRequest>,
+) -> Result<Json<ApiResponse<StockAdjustmentResponse>>, AppError> {
+    payload.validate_all()?;
+
+    let db = &state.db;
+
+    let result =
+        <PharmacyService as PharmacyServiceContracts>::adjust_stock(db, batch_id, user.id, payload)
+            .await?;
+
+    let response = ApiResponse {
+        message: "Adjust stock successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn get_batch_movements(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    Path(batch_id): Path<i32>,
+) -> Result<Json<ApiResponse<BatchMovementsResponse>>, AppError> {
+    let db = &state.db;
+
+    let result =
+        <PharmacyService as PharmacyServiceContracts>::get_batch_movements(db, batch_id).await?;
+
+    let response = ApiResponse {
+        message: "Get batch movements successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn get_low_stock_report(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    Query(query): Query<StockReportQuery>,
+) -> Result<Json<ApiResponse<LowStockReportResponse>>, AppError> {
+    let db = &state.db;
+
+    let result =
+        <PharmacyService as PharmacyServiceContracts>::get_low_stock_report(db, query).await?;
+
+    let response = ApiResponse {
+        message: "Get low stock report successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn get_near_expiry_report(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    Query(query): Query<NearExpiryQuery>,
+) -> Result<Json<ApiResponse<NearExpiryReportResponse>>, AppError> {
+    query.validate().map_err(AppError::from)?;
+
+    let db = &state.db;
+
+    let result =
+        <PharmacyService as PharmacyServiceContracts>::get_near_expiry_report(db, query).await?;
+
+    let response = ApiResponse {
+        message: "Get near expiry report successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
@@ -0,0 +1,268 @@
+use axum::{
+    Extension, Json,
+    extract::{Path, Query, State},
+};
+use validator::Validate;
+
+use crate::{
+    dtos::prescription::{
+        prescription_request::{
+            DispensePrescriptionRequest, PharmacyQueueQuery, PrescriptionRequest,
+            SubmitPrescriptionRequest,
+        },
+        response::{
+            AllergyCheckResponse, DispenseResponse, InteractionCheckResponse,
+            PharmacyQueueResponse, PrescriptionDiscarded, PrescriptionResponse,
+            VisitPrescriptionsResponse,
+        },
+    },
+    error_handling::app_error::AppError,
+    infra::api::ApiResponse,
+    middleware::{auth_middleware::CurrentUser, request_middleware::RequestId},
+    state::AppState,
+    use_cases::prescription::service::prescription_service::{
+        PrescriptionService, PrescriptionServiceContracts,
+    },
+};
+
+pub async fn create_prescription(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    user: CurrentUser,
+    Path(visit_intent_id): Path<i32>,
+    Json(payload): Json<PrescriptionRequest>,
+) -> Result<Json<ApiResponse<PrescriptionResponse>>, AppError> {
+    payload.validate_all()?;
+
+    let db = &state.db;
+
+    let result = <PrescriptionService as PrescriptionServiceContracts>::create_prescription(
+        db,
+        visit_intent_id,
+        user.id,
+        payload,
+    )
+    .await?;
+
+    let response = ApiResponse {
+        message: "Create prescription successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn get_prescription(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    Path(prescription_id): Path<i32>,
+) -> Result<Json<ApiResponse<PrescriptionResponse>>, AppError> {
+    let db = &state.db;
+
+    let result = <PrescriptionService as PrescriptionServiceContracts>::get_prescription(
+        db,
+        prescription_id,
+    )
+    .await?;
+
+    let response = ApiResponse {
+        message: "Get prescription successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn update_prescription(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    user: CurrentUser,
+    Path(prescription_id): Path<i32>,
+    Json(payload): Json<PrescriptionRequest>,
+) -> Result<Json<ApiResponse<PrescriptionResponse>>, AppError> {
+    payload.validate_all()?;
+
+    let db = &state.db;
+
+    let result = <PrescriptionService as PrescriptionServiceContracts>::update_prescription(
+        db,
+        prescription_id,
+        user.id,
+        payload,
+    )
+    .await?;
+
+    let response = ApiResponse {
+        message: "Update prescription successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn discard_prescription(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    user: CurrentUser,
+    Path(prescription_id): Path<i32>,
+) -> Result<Json<ApiResponse<PrescriptionDiscarded>>, AppError> {
+    let db = &state.db;
+
+    let result = <PrescriptionService as PrescriptionServiceContracts>::discard_prescription(
+        db,
+        prescription_id,
+        user.id,
+    )
+    .await?;
+
+    let response = ApiResponse {
+        message: "Discard prescription successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn check_allergies(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    Path(prescription_id): Path<i32>,
+) -> Result<Json<ApiResponse<AllergyCheckResponse>>, AppError> {
+    let db = &state.db;
+
+    let result =
+        <PrescriptionService as PrescriptionServiceContracts>::check_allergies(db, prescription_id)
+            .await?;
+
+    let response = ApiResponse {
+        message: "Check prescription allergies successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn check_interactions(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    Path(prescription_id): Path<i32>,
+) -> Result<Json<ApiResponse<InteractionCheckResponse>>, AppError> {
+    let db = &state.db;
+
+    let result = <PrescriptionService as PrescriptionServiceContracts>::check_interactions(
+        db,
+        &state.interactions,
+        prescription_id,
+    )
+    .await?;
+
+    let response = ApiResponse {
+        message: "Check prescription interactions successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn submit_prescription(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    user: CurrentUser,
+    Path(prescription_id): Path<i32>,
+    Json(payload): Json<SubmitPrescriptionRequest>,
+) -> Result<Json<ApiResponse<PrescriptionResponse>>, AppError> {
+    payload.validate().map_err(AppError::from)?;
+
+    let db = &state.db;
+
+    let result = <PrescriptionService as PrescriptionServiceContracts>::submit_prescription(
+        db,
+        &state.interactions,
+        prescription_id,
+        user.id,
+        payload,
+    )
+    .await?;
+
+    let response = ApiResponse {
+        message: "Submit prescription successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn dispense_prescription(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    user: CurrentUser,
+    Path(prescription_id): Path<i32>,
+    Json(payload): Json<DispensePrescriptionRequest>,
+) -> Result<Json<ApiResponse<DispenseResponse>>, AppError> {
+    let db = &state.db;
+
+    let result = <PrescriptionService as PrescriptionServiceContracts>::dispense_prescription(
+        db,
+        prescription_id,
+        user.id,
+        payload,
+    )
+    .await?;
+
+    let response = ApiResponse {
+        message: "Dispense prescription successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn get_visit_prescriptions(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    Path(visit_intent_id): Path<i32>,
+) -> Result<Json<ApiResponse<VisitPrescriptionsResponse>>, AppError> {
+    let db = &state.db;
+
+    let result = <PrescriptionService as PrescriptionServiceContracts>::get_visit_prescriptions(
+        db,
+        visit_intent_id,
+    )
+    .await?;
+
+    let response = ApiResponse {
+        message: "Get visit prescriptions successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn get_pharmacy_queue(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    Query(query): Query<PharmacyQueueQuery>,
+) -> Result<Json<ApiResponse<PharmacyQueueResponse>>, AppError> {
+    let db = &state.db;
+
+    let result =
+        <PrescriptionService as PrescriptionServiceContracts>::get_pharmacy_queue(db, query)
+            .await?;
+
+    let response = ApiResponse {
+        message: "Get pharmacy queue successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
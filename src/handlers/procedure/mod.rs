@@ -0,0 +1 @@
+pub mod procedure_handler;
@@ -0,0 +1,134 @@
+use axum::{
+    Extension, Json,
+    extract::{Path, Query, State},
+};
+use validator::Validate;
+
+use crate::{
+    dtos::procedure::{
+        procedure_request::{ProcedureSearchQuery, RecordProcedureRequest},
+        response::{
+            ProcedureCodeResponse, ProcedureRemoved, ProcedureSearchResponse,
+            VisitProcedureResponse, VisitProceduresResponse,
+        },
+    },
+    error_handling::app_error::AppError,
+    infra::api::ApiResponse,
+    middleware::{auth_middleware::CurrentUser, request_middleware::RequestId},
+    state::AppState,
+    use_cases::procedure::service::procedure_service::{
+        ProcedureService, ProcedureServiceContracts,
+    },
+};
+
+pub async fn search_procedure_codes(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    Query(query): Query<ProcedureSearchQuery>,
+) -> Result<Json<ApiResponse<ProcedureSearchResponse>>, AppError> {
+    query.validate().map_err(AppError::from)?;
+
+    let db = &state.db;
+
+    let result = <ProcedureService as ProcedureServiceContracts>::search_codes(db, query).await?;
+
+    let response = ApiResponse {
+        message: "Search ICD-9-CM procedures successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn get_procedure_code(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    Path(code): Path<String>,
+) -> Result<Json<ApiResponse<ProcedureCodeResponse>>, AppError> {
+    let db = &state.db;
+
+    let result = <ProcedureService as ProcedureServiceContracts>::get_code(db, &code).await?;
+
+    let response = ApiResponse {
+        message: "Get ICD-9-CM procedure successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn record_procedure(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    user: CurrentUser,
+    Path(visit_intent_id): Path<i32>,
+    Json(payload): Json<RecordProcedureRequest>,
+) -> Result<Json<ApiResponse<VisitProcedureResponse>>, AppError> {
+    payload.validate().map_err(AppError::from)?;
+
+    let db = &state.db;
+
+    let result = <ProcedureService as ProcedureServiceContracts>::record_procedure(
+        db,
+        visit_intent_id,
+        user.id,
+        user.role,
+        payload,
+    )
+    .await?;
+
+    let response = ApiResponse {
+        message: "Record procedure successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn remove_procedure(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    user: CurrentUser,
+    Path((visit_intent_id, procedure_id)): Path<(i32, i32)>,
+) -> Result<Json<ApiResponse<ProcedureRemoved>>, AppError> {
+    let db = &state.db;
+
+    let result = <ProcedureService as ProcedureServiceContracts>::remove_procedure(
+        db,
+        visit_intent_id,
+        procedure_id,
+        user.id,
+    )
+    .await?;
+
+    let response = ApiResponse {
+        message: "Remove procedure successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn get_visit_procedures(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    Path(visit_intent_id): Path<i32>,
+) -> Result<Json<ApiResponse<VisitProceduresResponse>>, AppError> {
+    let db = &state.db;
+
+    let result =
+        <ProcedureService as ProcedureServiceContracts>::get_visit_procedures(db, visit_intent_id)
+            .await?;
+
+    let response = ApiResponse {
+        message: "Get visit procedures successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
@@ -1 +1,2 @@
+pub mod queue_feed_handler;
 pub mod triage_handler;
@@ -0,0 +1,166 @@
+use axum::{
+    extract::{
+        State, WebSocketUpgrade,
+        ws::{Message, WebSocket},
+    },
+    response::{
+        IntoResponse, Response,
+        sse::{Event, KeepAlive, Sse},
+    },
+};
+use chrono::NaiveDate;
+use futures::{Stream, StreamExt, stream};
+use log::{error, warn};
+use sea_orm::DatabaseConnection;
+use tokio::sync::broadcast::{self, error::RecvError};
+
+use crate::{
+    dtos::triage::{
+        queue_event::{QueueEvent, QueueFeedMessage},
+        queue_scope::{QueueScope, ServiceDate},
+    },
+    error_handling::app_error::AppError,
+    state::AppState,
+    use_cases::triage::service::triage_service::{TriageService, TriageServiceContracts},
+};
+
+/// One display board's view of a queue: the events of that queue on the day of its last
+/// snapshot only, with a fresh snapshot whenever the board fell too far behind to replay
+/// what it missed or the queue moved on to a new day.
+struct QueueFeed {
+    db: DatabaseConnection,
+    scope: QueueScope,
+    queue_key: String,
+    service_date: NaiveDate,
+    receiver: broadcast::Receiver<QueueEvent>,
+    resync: bool,
+}
+
+impl QueueFeed {
+    /// Subscribes before the first snapshot is read so no change can fall in between.
+    fn subscribe(state: &AppState, scope: QueueScope) -> Self {
+        QueueFeed {
+            db: state.db.clone(),
+            queue_key: scope.queue_key(),
+            service_date: ServiceDate::today().0,
+            scope,
+            receiver: state.queue_events.subscribe(),
+            resync: false,
+        }
+    }
+
+    async fn snapshot(&mut self) -> Result<QueueFeedMessage, AppError> {
+        let service_date = ServiceDate::today().0;
+        let snapshot = <TriageService as TriageServiceContracts>::get_triage_queue_snapshot(
+            &self.db,
+            &self.scope,
+            service_date,
+        )
+        .await?;
+        self.service_date = service_date;
+        Ok(QueueFeedMessage::Snapshot(snapshot))
+    }
+
+    async fn next(&mut self) -> Option<QueueFeedMessage> {
+        loop {
+            if self.resync {
+                match self.snapshot().await {
+                    Ok(snapshot) => {
+                        self.resync = false;
+                        return Some(snapshot);
+                    }
+                    Err(err) => {
+                        error!("Failed to resync queue feed for {}: {}", self.scope, err);
+                        return None;
+                    }
+                }
+            }
+
+            match self.receiver.recv().await {
+                Ok(event) if event.queue_key == self.queue_key => {
+                    // Numbers restart every day, so an event of another day is about a
+                    // different ticket, such as yesterday's ticket expired by the sweeper
+                    if event.service_date == self.service_date {
+                        return Some(QueueFeedMessage::Event(event));
+                    }
+                    if event.service_date > self.service_date {
+                        self.resync = true;
+                    }
+                }
+                Ok(_) => continue,
+                Err(RecvError::Lagged(skipped)) => {
+                    warn!("Queue feed for {} skipped {} events", self.scope, skipped);
+                    self.resync = true;
+                }
+                Err(RecvError::Closed) => return None,
+            }
+        }
+    }
+}
+
+fn sse_event(message: QueueFeedMessage) -> Result<Event, axum::Error> {
+    Event::default().event(message.name()).json_data(&message)
+}
+
+pub async fn triage_queue_events(
+    State(state): State<AppState>,
+    scope: QueueScope,
+) -> Result<Sse<impl Stream<Item = Result<Event, axum::Error>>>, AppError> {
+    let mut feed = QueueFeed::subscribe(&state, scope);
+    let snapshot = feed.snapshot().await?;
+
+    let updates = stream::unfold(feed, |mut feed| async move {
+        feed.next().await.map(|message| (message, feed))
+    });
+    let stream = stream::once(async { snapshot })
+        .chain(updates)
+        .map(sse_event);
+
+    Ok(Sse::new(stream).keep_alive(KeepAlive::default()))
+}
+
+pub async fn triage_queue_ws(
+    State(state): State<AppState>,
+    scope: QueueScope,
+    ws: WebSocketUpgrade,
+) -> Result<Response, AppError> {
+    let mut feed = QueueFeed::subscribe(&state, scope);
+    let snapshot = feed.snapshot().await?;
+
+    Ok(ws
+        .on_upgrade(move |socket| stream_queue_feed(socket, feed, snapshot))
+        .into_response())
+}
+
+async fn stream_queue_feed(mut socket: WebSocket, mut feed: QueueFeed, snapshot: QueueFeedMessage) {
+    let mut pending = Some(snapshot);
+
+    loop {
+        if let Some(message) = pending.take() {
+            let payload = match serde_json::to_string(&message) {
+                Ok(payload) => payload,
+                Err(err) => {
+                    error!("Failed to serialize queue feed message: {}", err);
+                    break;
+                }
+            };
+            if socket.send(Message::Text(payload.into())).await.is_err() {
+                break;
+            }
+        }
+
+        tokio::select! {
+            message = feed.next() => match message {
+                Some(message) => pending = Some(message),
+                None => break,
+            },
+            incoming = socket.recv() => match incoming {
+                // the board only listens; pings are answered by axum
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                Some(Ok(_)) => continue,
+            },
+        }
+    }
+
+    let _ = socket.send(Message::Close(None)).await;
+}
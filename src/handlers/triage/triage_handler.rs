@@ -1,23 +1,30 @@
 use axum::{
     Extension, Json,
-    extract::{Multipart, Path, State},
+    extract::{Multipart, Path, Query, State},
 };
 
+use chrono::Duration;
 use log::info;
 use validator::Validate;
 
 use crate::{
     dtos::triage::{
-        create_triage_request::CreateTriageRequest,
+        create_triage_request::{CreateTriageRequest, CreateUnidentifiedTriageRequest},
+        queue_scope::{QueueNumberPath, QueueScope, QueueStatsQuery, RequeueQuery, ServiceDate},
         referral_upload_metadata::ReferralUploadMetadata,
         response::{
             CreateTriageResponse, ReferralUploadResponse, TriagePatientCalled, TriagePatientCancel,
-            TriageQueueComplete, TriageQueueResponse, TriageQueueStatus,
+            TriageQueueComplete, TriageQueueResponse, TriageQueueStatsResponse, TriageQueueStatus,
+            TriageQueueTransition,
         },
     },
     error_handling::app_error::AppError,
     infra::api::ApiResponse,
-    middleware::request_middleware::RequestId,
+    middleware::{
+        auth_middleware::CurrentUser,
+        permission::Permission,
+        request_middleware::{IdempotencyKey, RequestId},
+    },
     state::AppState,
     use_cases::triage::service::triage_service::{TriageService, TriageServiceContracts},
     utils::helpers::read_bytes_from_multipart_field,
@@ -26,13 +33,24 @@ use crate::{
 pub async fn triage_patient(
     State(state): State<AppState>,
     Extension(request_id): Extension<RequestId>,
+    user: CurrentUser,
+    IdempotencyKey(idempotency_key): IdempotencyKey,
     Json(payload): Json<CreateTriageRequest>,
 ) -> Result<Json<ApiResponse<CreateTriageResponse>>, AppError> {
-    payload.validate().map_err(AppError::from)?;
+    payload.validate_registration()?;
 
     let db = &state.db;
+    let redis = &state.redis;
 
-    let result = <TriageService as TriageServiceContracts>::perform_triage(db, payload).await?;
+    let result = <TriageService as TriageServiceContracts>::perform_triage(
+        db,
+        redis,
+        &state.mrn,
+        payload,
+        user.id,
+        idempotency_key,
+    )
+    .await?;
 
     let response = ApiResponse {
         message: "Triage successful".to_string(),
@@ -43,16 +61,47 @@ pub async fn triage_patient(
     Ok(Json(response))
 }
 
+pub async fn triage_unidentified_patient(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    IdempotencyKey(idempotency_key): IdempotencyKey,
+    Json(payload): Json<CreateUnidentifiedTriageRequest>,
+) -> Result<Json<ApiResponse<CreateTriageResponse>>, AppError> {
+    payload.validate().map_err(AppError::from)?;
+
+    let db = &state.db;
+    let redis = &state.redis;
+
+    let result = <TriageService as TriageServiceContracts>::perform_unidentified_triage(
+        db,
+        redis,
+        &state.mrn,
+        payload,
+        idempotency_key,
+    )
+    .await?;
+
+    let response = ApiResponse {
+        message: "Unidentified triage successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
 pub async fn triage_queue(
     State(state): State<AppState>,
     Extension(request_id): Extension<RequestId>,
-    Path(visit_type): Path<String>,
+    scope: QueueScope,
+    ServiceDate(service_date): ServiceDate,
 ) -> Result<Json<ApiResponse<TriageQueueResponse>>, AppError> {
     let db = &state.db;
     let redis = &state.redis;
 
     let result =
-        <TriageService as TriageServiceContracts>::get_triage_queue(db, redis, visit_type).await?;
+        <TriageService as TriageServiceContracts>::get_triage_queue(db, redis, scope, service_date)
+            .await?;
 
     let response = ApiResponse {
         message: "Get triage queue successful".to_string(),
@@ -66,7 +115,9 @@ pub async fn triage_queue(
 pub async fn triage_queue_status(
     State(state): State<AppState>,
     Extension(request_id): Extension<RequestId>,
-    Path((visit_type, queue_number)): Path<(String, i32)>,
+    scope: QueueScope,
+    ServiceDate(service_date): ServiceDate,
+    Path(QueueNumberPath { queue_number }): Path<QueueNumberPath>,
 ) -> Result<Json<ApiResponse<TriageQueueStatus>>, AppError> {
     let db = &state.db;
     let redis = &state.redis;
@@ -74,7 +125,9 @@ pub async fn triage_queue_status(
     let result = <TriageService as TriageServiceContracts>::get_triage_queue_status_by_id(
         db,
         redis,
-        visit_type,
+        &state.queue,
+        scope,
+        service_date,
         queue_number,
     )
     .await?;
@@ -88,10 +141,42 @@ pub async fn triage_queue_status(
     Ok(Json(response))
 }
 
+pub async fn triage_queue_stats(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    scope: QueueScope,
+    Query(QueueStatsQuery { from, to }): Query<QueueStatsQuery>,
+) -> Result<Json<ApiResponse<TriageQueueStatsResponse>>, AppError> {
+    let db = &state.db;
+
+    let to = to.unwrap_or_else(|| ServiceDate::today().0);
+    let from = from.unwrap_or(to - Duration::days(DEFAULT_STATS_RANGE_DAYS));
+
+    let result = <TriageService as TriageServiceContracts>::get_triage_queue_stats(
+        db,
+        &state.queue,
+        scope,
+        from,
+        to,
+    )
+    .await?;
+
+    let response = ApiResponse {
+        message: "Get triage queue statistics successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
 pub async fn triage_call_patient(
     State(state): State<AppState>,
     Extension(request_id): Extension<RequestId>,
-    Path((visit_type, queue_number)): Path<(String, i32)>,
+    scope: QueueScope,
+    ServiceDate(service_date): ServiceDate,
+    Path(QueueNumberPath { queue_number }): Path<QueueNumberPath>,
+    IdempotencyKey(idempotency_key): IdempotencyKey,
 ) -> Result<Json<ApiResponse<TriagePatientCalled>>, AppError> {
     let db = &state.db;
     let redis = &state.redis;
@@ -99,8 +184,10 @@ pub async fn triage_call_patient(
     let result = <TriageService as TriageServiceContracts>::call_patient(
         db,
         redis,
-        visit_type,
+        scope,
+        service_date,
         queue_number,
+        idempotency_key,
     )
     .await?;
 
@@ -117,7 +204,10 @@ pub async fn triage_call_patient(
 pub async fn triage_complete(
     State(state): State<AppState>,
     Extension(request_id): Extension<RequestId>,
-    Path((visit_type, queue_number)): Path<(String, i32)>,
+    scope: QueueScope,
+    ServiceDate(service_date): ServiceDate,
+    Path(QueueNumberPath { queue_number }): Path<QueueNumberPath>,
+    IdempotencyKey(idempotency_key): IdempotencyKey,
 ) -> Result<Json<ApiResponse<TriageQueueComplete>>, AppError> {
     let db = &state.db;
     let redis = &state.redis;
@@ -125,8 +215,10 @@ pub async fn triage_complete(
     let result = <TriageService as TriageServiceContracts>::triage_complete(
         db,
         redis,
-        visit_type,
+        scope,
+        service_date,
         queue_number,
+        idempotency_key,
     )
     .await?;
 
@@ -143,7 +235,11 @@ pub async fn triage_complete(
 pub async fn triage_patient_cancel(
     State(state): State<AppState>,
     Extension(request_id): Extension<RequestId>,
-    Path((visit_type, queue_number)): Path<(String, i32)>,
+    current_user: CurrentUser,
+    scope: QueueScope,
+    ServiceDate(service_date): ServiceDate,
+    Path(QueueNumberPath { queue_number }): Path<QueueNumberPath>,
+    IdempotencyKey(idempotency_key): IdempotencyKey,
 ) -> Result<Json<ApiResponse<TriagePatientCancel>>, AppError> {
     let db = &state.db;
     let redis = &state.redis;
@@ -151,8 +247,11 @@ pub async fn triage_patient_cancel(
     let result = <TriageService as TriageServiceContracts>::cancel_patient_queue(
         db,
         redis,
-        visit_type,
+        scope,
+        service_date,
         queue_number,
+        current_user.can(Permission::TriageCancelFinalized),
+        idempotency_key,
     )
     .await?;
 
@@ -166,6 +265,135 @@ pub async fn triage_patient_cancel(
     Ok(Json(response))
 }
 
+pub async fn triage_recall_patient(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    scope: QueueScope,
+    ServiceDate(service_date): ServiceDate,
+    Path(QueueNumberPath { queue_number }): Path<QueueNumberPath>,
+    IdempotencyKey(idempotency_key): IdempotencyKey,
+) -> Result<Json<ApiResponse<TriageQueueTransition>>, AppError> {
+    let db = &state.db;
+    let redis = &state.redis;
+
+    let result = <TriageService as TriageServiceContracts>::recall_patient(
+        db,
+        redis,
+        scope,
+        service_date,
+        queue_number,
+        state.queue.max_calls,
+        idempotency_key,
+    )
+    .await?;
+
+    let response = ApiResponse {
+        message: "Recall patient successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.to_string(),
+        errors: None,
+    };
+
+    Ok(Json(response))
+}
+
+pub async fn triage_skip_patient(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    scope: QueueScope,
+    ServiceDate(service_date): ServiceDate,
+    Path(QueueNumberPath { queue_number }): Path<QueueNumberPath>,
+    IdempotencyKey(idempotency_key): IdempotencyKey,
+) -> Result<Json<ApiResponse<TriageQueueTransition>>, AppError> {
+    let db = &state.db;
+    let redis = &state.redis;
+
+    let result = <TriageService as TriageServiceContracts>::skip_patient(
+        db,
+        redis,
+        scope,
+        service_date,
+        queue_number,
+        idempotency_key,
+    )
+    .await?;
+
+    let response = ApiResponse {
+        message: "Skip patient successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.to_string(),
+        errors: None,
+    };
+
+    Ok(Json(response))
+}
+
+pub async fn triage_no_show(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    scope: QueueScope,
+    ServiceDate(service_date): ServiceDate,
+    Path(QueueNumberPath { queue_number }): Path<QueueNumberPath>,
+    IdempotencyKey(idempotency_key): IdempotencyKey,
+) -> Result<Json<ApiResponse<TriageQueueTransition>>, AppError> {
+    let db = &state.db;
+    let redis = &state.redis;
+
+    let result = <TriageService as TriageServiceContracts>::mark_no_show(
+        db,
+        redis,
+        scope,
+        service_date,
+        queue_number,
+        idempotency_key,
+    )
+    .await?;
+
+    let response = ApiResponse {
+        message: "Mark patient as no-show successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.to_string(),
+        errors: None,
+    };
+
+    Ok(Json(response))
+}
+
+pub async fn triage_requeue_patient(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    scope: QueueScope,
+    ServiceDate(service_date): ServiceDate,
+    Path(QueueNumberPath { queue_number }): Path<QueueNumberPath>,
+    Query(RequeueQuery { position }): Query<RequeueQuery>,
+    IdempotencyKey(idempotency_key): IdempotencyKey,
+) -> Result<Json<ApiResponse<TriageQueueTransition>>, AppError> {
+    let db = &state.db;
+    let redis = &state.redis;
+
+    let result = <TriageService as TriageServiceContracts>::requeue_patient(
+        db,
+        redis,
+        scope,
+        service_date,
+        queue_number,
+        position.unwrap_or(state.queue.requeue_position),
+        idempotency_key,
+    )
+    .await?;
+
+    let response = ApiResponse {
+        message: "Requeue patient successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.to_string(),
+        errors: None,
+    };
+
+    Ok(Json(response))
+}
+
+const DEFAULT_STATS_RANGE_DAYS: i64 = 30;
+
 const MAX_FILE_SIZE: usize = 1024 * 1024 * 10; // 10 MB
 
 pub async fn triage_referral_document_upload(
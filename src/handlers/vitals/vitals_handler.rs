@@ -0,0 +1,110 @@
+use axum::{
+    Extension, Json,
+    extract::{Path, Query, State},
+};
+use validator::Validate;
+
+use crate::{
+    dtos::vitals::{
+        response::{
+            PatientVitalsHistoryResponse, PatientVitalsTrendResponse, VisitVitalsResponse,
+            VitalSignsResponse,
+        },
+        vitals_request::{RecordVitalsRequest, VitalsHistoryQuery, VitalsTrendQuery},
+    },
+    error_handling::app_error::AppError,
+    infra::api::ApiResponse,
+    middleware::{auth_middleware::CurrentUser, request_middleware::RequestId},
+    state::AppState,
+    use_cases::vitals::service::vitals_service::{VitalsService, VitalsServiceContracts},
+};
+
+pub async fn record_vitals(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    user: CurrentUser,
+    Path(visit_intent_id): Path<i32>,
+    Json(payload): Json<RecordVitalsRequest>,
+) -> Result<Json<ApiResponse<VitalSignsResponse>>, AppError> {
+    let db = &state.db;
+
+    let result = <VitalsService as VitalsServiceContracts>::record_vitals(
+        db,
+        visit_intent_id,
+        payload,
+        user.id,
+        user.role,
+    )
+    .await?;
+
+    let response = ApiResponse {
+        message: "Record vitals successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn get_visit_vitals(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    Path(visit_intent_id): Path<i32>,
+) -> Result<Json<ApiResponse<VisitVitalsResponse>>, AppError> {
+    let db = &state.db;
+
+    let result =
+        <VitalsService as VitalsServiceContracts>::get_visit_vitals(db, visit_intent_id).await?;
+
+    let response = ApiResponse {
+        message: "Get visit vitals successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn get_patient_vitals(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    Path(patient_id): Path<i32>,
+    Query(query): Query<VitalsHistoryQuery>,
+) -> Result<Json<ApiResponse<PatientVitalsHistoryResponse>>, AppError> {
+    query.validate().map_err(AppError::from)?;
+
+    let db = &state.db;
+
+    let result =
+        <VitalsService as VitalsServiceContracts>::get_patient_vitals(db, patient_id, query)
+            .await?;
+
+    let response = ApiResponse {
+        message: "Get patient vitals successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
+
+pub async fn get_patient_vitals_trend(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<RequestId>,
+    Path(patient_id): Path<i32>,
+    Query(query): Query<VitalsTrendQuery>,
+) -> Result<Json<ApiResponse<PatientVitalsTrendResponse>>, AppError> {
+    let db = &state.db;
+
+    let result =
+        <VitalsService as VitalsServiceContracts>::get_patient_vitals_trend(db, patient_id, query)
+            .await?;
+
+    let response = ApiResponse {
+        message: "Get patient vitals trend successful".to_string(),
+        data: Some(result),
+        request_id: request_id.0.clone(),
+        errors: None,
+    };
+    Ok(Json(response))
+}
@@ -0,0 +1,182 @@
+//! Redis caching for triage reads and replay of mutating requests.
+//!
+//! The two concerns are kept apart on purpose: read caches may be dropped at any time
+//! and are keyed by a per-queue version that every state change bumps, while replay
+//! entries are only written for requests carrying an `Idempotency-Key` and are never
+//! consulted by reads.
+
+use std::future::Future;
+
+use bb8::Pool;
+use bb8_redis::RedisConnectionManager;
+use chrono::NaiveDate;
+use log::error;
+use redis::AsyncCommands;
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+
+use crate::{
+    dtos::triage::queue_scope::QueueScope,
+    error_handling::app_error::AppError,
+    middleware::request_middleware::IdempotentRequest,
+    utils::helpers::{delete_cache_data, set_cache_data},
+};
+
+type RedisPool = Pool<RedisConnectionManager>;
+
+pub const QUEUE_CACHE_TTL_SECS: u64 = 300;
+const QUEUE_VERSION_TTL_SECS: i64 = 60 * 60 * 48;
+
+pub const IDEMPOTENCY_TTL_SECS: u64 = 60 * 60 * 24;
+const IDEMPOTENCY_PENDING_TTL_SECS: u64 = 60;
+const IDEMPOTENCY_PENDING: &str = "PENDING:";
+
+/// Versioned read keys for one queue on one service day.
+///
+/// A reader resolves the version before it queries the database, so a result computed
+/// from a snapshot older than a concurrent write lands under a version nobody reads
+/// any more instead of overwriting the fresh entry.
+pub struct QueueCache {
+    base: String,
+    version: i64,
+}
+
+impl QueueCache {
+    fn base_key(scope: &QueueScope, service_date: NaiveDate) -> String {
+        format!("triage:queue:{}:{}", scope.cache_key(), service_date)
+    }
+
+    pub async fn resolve(
+        redis: &RedisPool,
+        scope: &QueueScope,
+        service_date: NaiveDate,
+    ) -> Result<Self, AppError> {
+        let base = Self::base_key(scope, service_date);
+        let mut redis_conn = redis.get().await?;
+        let version: Option<i64> = redis_conn.get(format!("{}:version", base)).await?;
+        Ok(QueueCache {
+            base,
+            version: version.unwrap_or(0),
+        })
+    }
+
+    pub fn queue_key(&self) -> String {
+        format!("{}:v{}", self.base, self.version)
+    }
+
+    pub fn ticket_key(&self, queue_number: i32) -> String {
+        format!("{}:v{}:id:{}", self.base, self.version, queue_number)
+    }
+
+    /// Called after every committed change to a ticket of the queue. Entries of older
+    /// versions are left to expire on their own.
+    pub async fn invalidate(redis: &RedisPool, scope: &QueueScope, service_date: NaiveDate) {
+        let version_key = format!("{}:version", Self::base_key(scope, service_date));
+        let result: Result<(), AppError> = async {
+            let mut redis_conn = redis.get().await?;
+            let _: i64 = redis_conn.incr(&version_key, 1).await?;
+            let _: bool = redis_conn
+                .expire(&version_key, QUEUE_VERSION_TTL_SECS)
+                .await?;
+            Ok(())
+        }
+        .await;
+
+        // the change is already committed; a stale read is bounded by the cache TTL
+        if let Err(err) = result {
+            error!("Failed to invalidate cache for {}: {}", version_key, err);
+        }
+    }
+}
+
+/// Where the outcome of an idempotent request is kept, and the fingerprint of the
+/// request the key was first used with.
+pub struct Replay {
+    key: String,
+    fingerprint: String,
+}
+
+pub fn replay(operation: &str, target: &str, request: &IdempotentRequest) -> Replay {
+    Replay {
+        key: format!("idempotency:{}:{}:{}", operation, target, request.key),
+        fingerprint: request.fingerprint.clone(),
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct StoredReplay<T> {
+    fingerprint: String,
+    response: T,
+}
+
+fn still_processing() -> AppError {
+    AppError::Conflict("A request with this Idempotency-Key is still being processed".into())
+}
+
+fn reused_key() -> AppError {
+    AppError::Conflict("This Idempotency-Key was already used for a different request".into())
+}
+
+/// Runs a mutating request at most once per idempotency key and replays its stored
+/// result on retries of the same request. Without a key the request always runs, so a
+/// repeated call is performed or rejected like any other. A key reused with a different
+/// request is rejected. Failed attempts are not stored and may be retried with the same key.
+pub async fn run_idempotent<T, F, Fut>(
+    redis: &RedisPool,
+    replay: Option<Replay>,
+    run: F,
+) -> Result<T, AppError>
+where
+    T: Serialize + DeserializeOwned,
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = Result<T, AppError>>,
+{
+    let Some(Replay { key, fingerprint }) = replay else {
+        return run().await;
+    };
+
+    let mut redis_conn = redis.get().await?;
+    let reserved: Option<String> = redis::cmd("SET")
+        .arg(&key)
+        .arg(format!("{}{}", IDEMPOTENCY_PENDING, fingerprint))
+        .arg("NX")
+        .arg("EX")
+        .arg(IDEMPOTENCY_PENDING_TTL_SECS)
+        .query_async(&mut *redis_conn)
+        .await?;
+
+    if reserved.is_none() {
+        let stored: Option<String> = redis_conn.get(&key).await?;
+        let Some(stored) = stored else {
+            return Err(still_processing());
+        };
+        if let Some(pending) = stored.strip_prefix(IDEMPOTENCY_PENDING) {
+            return Err(if pending == fingerprint {
+                still_processing()
+            } else {
+                reused_key()
+            });
+        }
+
+        let stored = serde_json::from_str::<StoredReplay<T>>(&stored).map_err(|_| reused_key())?;
+        if stored.fingerprint != fingerprint {
+            return Err(reused_key());
+        }
+        return Ok(stored.response);
+    }
+    drop(redis_conn);
+
+    match run().await {
+        Ok(response) => {
+            let stored = StoredReplay {
+                fingerprint,
+                response,
+            };
+            set_cache_data(redis, &key, &stored, IDEMPOTENCY_TTL_SECS).await?;
+            Ok(stored.response)
+        }
+        Err(err) => {
+            delete_cache_data(redis, &key).await?;
+            Err(err)
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use chrono::{Datelike, NaiveDate};
 use config::{Config, ConfigError, File};
 use serde::{Deserialize, de::DeserializeOwned};
 use tokio::task_local;
@@ -11,6 +12,9 @@ pub struct AppConfig {
     pub redis: RedisConfig,
     pub app: Application,
     pub s3: S3Config,
+    pub jwt: JwtConfig,
+    pub queue: QueueConfig,
+    pub mrn: MrnConfig,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -35,6 +39,74 @@ pub struct S3Config {
     pub s3_secret_key: String,
 }
 
+#[derive(Debug, Deserialize, Clone)]
+pub struct JwtConfig {
+    pub access_secret: String,
+    pub refresh_secret: String,
+    pub access_token_exp_secs: u64,
+    pub refresh_token_exp_secs: u64,
+    /// How long a display board has to open a queue feed with its display token.
+    pub display_token_exp_secs: u64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct QueueConfig {
+    /// Calls (first call plus recalls) before a recall marks the patient as no-show.
+    pub max_calls: i32,
+    /// How long a called patient may take to show up before the sweeper marks a no-show.
+    pub call_timeout_secs: i64,
+    /// How many waiting patients a re-queued ticket is placed behind by default.
+    pub requeue_position: usize,
+    pub sweep_interval_secs: u64,
+    /// Most recent completed tickets the waiting time estimate is averaged over.
+    pub eta_sample_size: u64,
+    /// Service time assumed for a queue that has no completed tickets yet.
+    pub default_service_secs: i64,
+}
+
+/// How medical record numbers (No. RM) are printed.
+#[derive(Debug, Deserialize, Clone)]
+pub struct MrnConfig {
+    pub scheme: MrnScheme,
+    /// Put in front of yearly numbers, e.g. `RM`.
+    pub prefix: String,
+    /// Zero-padded width of the running number, usually 6 or 8.
+    pub digits: usize,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum MrnScheme {
+    /// `RM-2025-000123`, numbering restarts every year.
+    Yearly,
+    /// `00012345`, one running number for the whole hospital.
+    Sequential,
+}
+
+impl MrnConfig {
+    /// Counter the next number is drawn from: the registration year, or a single
+    /// counter for sequential numbers.
+    pub fn counter_scope(&self, date: NaiveDate) -> String {
+        match self.scheme {
+            MrnScheme::Yearly => date.year().to_string(),
+            MrnScheme::Sequential => "ALL".to_string(),
+        }
+    }
+
+    pub fn format(&self, scope: &str, number: i32) -> String {
+        match self.scheme {
+            MrnScheme::Yearly => format!(
+                "{}-{}-{:0width$}",
+                self.prefix,
+                scope,
+                number,
+                width = self.digits
+            ),
+            MrnScheme::Sequential => format!("{:0width$}", number, width = self.digits),
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Clone)]
 pub struct Application {
     pub host: String,
@@ -0,0 +1,418 @@
+use std::sync::Arc;
+
+use log::warn;
+
+pub const INTERACTION_RULES: &str = include_str!("../../data/drug_interactions.tsv");
+
+/// Ordered from least to most serious, so warnings can be compared and sorted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum InteractionSeverity {
+    Minor,
+    Moderate,
+    Major,
+    Contraindicated,
+}
+
+impl InteractionSeverity {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_uppercase().as_str() {
+            "MINOR" => Some(InteractionSeverity::Minor),
+            "MODERATE" => Some(InteractionSeverity::Moderate),
+            "MAJOR" => Some(InteractionSeverity::Major),
+            "CONTRAINDICATED" => Some(InteractionSeverity::Contraindicated),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InteractionSeverity::Minor => "MINOR",
+            InteractionSeverity::Moderate => "MODERATE",
+            InteractionSeverity::Major => "MAJOR",
+            InteractionSeverity::Contraindicated => "CONTRAINDICATED",
+        }
+    }
+
+    /// Minor warnings are shown but do not hold up a submission.
+    pub fn needs_acknowledgement(&self) -> bool {
+        *self >= InteractionSeverity::Moderate
+    }
+}
+
+/// A prescription line as the checks see it. `names` holds the prescribed name, the
+/// generic name of its formulary drug and the components of a racikan, in lower case.
+#[derive(Clone, Debug)]
+pub struct Medication {
+    pub prescription_id: i32,
+    pub line_no: i32,
+    pub drug_id: Option<i32>,
+    pub drug_name: String,
+    pub names: Vec<String>,
+    pub therapeutic_class: Option<String>,
+}
+
+impl Medication {
+    fn contains(&self, substance: &str) -> bool {
+        self.names.iter().any(|name| name.contains(substance))
+    }
+}
+
+/// A problem between a line of the prescription being checked and another medication,
+/// either on the same prescription or one the patient is still taking.
+#[derive(Clone, Debug)]
+pub struct InteractionWarning {
+    pub check: &'static str,
+    pub severity: InteractionSeverity,
+    pub line_no: i32,
+    pub drug_name: String,
+    pub other_prescription_id: i32,
+    pub other_line_no: i32,
+    pub other_drug_name: String,
+    pub description: String,
+}
+
+/// One way of finding problems between medications. Each pair is offered once: the
+/// candidate lines against each other and every candidate line against every active one.
+pub trait InteractionCheck: Send + Sync {
+    fn name(&self) -> &'static str;
+    fn check_pair(&self, line: &Medication, other: &Medication) -> Option<InteractionWarning>;
+}
+
+#[derive(Clone, Debug)]
+struct InteractionRule {
+    substance_a: String,
+    substance_b: String,
+    severity: InteractionSeverity,
+    description: String,
+}
+
+/// Known interacting substance pairs, read from the dataset bundled with the service.
+/// A line matches a substance when any of its names contains it.
+pub struct InteractionRules {
+    rules: Vec<InteractionRule>,
+}
+
+impl InteractionRules {
+    /// Tab-separated `substance_a`, `substance_b`, `severity`, `description` after a
+    /// header line. Malformed lines are skipped with a warning.
+    pub fn parse(dataset: &str) -> Self {
+        let rules = dataset
+            .lines()
+            .skip(1)
+            .filter(|line| !line.trim().is_empty())
+            .filter_map(|line| {
+                let mut columns = line.split('\t').map(str::trim);
+                match (
+                    columns.next(),
+                    columns.next(),
+                    columns.next().and_then(InteractionSeverity::parse),
+                    columns.next(),
+                ) {
+                    (Some(substance_a), Some(substance_b), Some(severity), Some(description))
+                        if !substance_a.is_empty() && !substance_b.is_empty() =>
+                    {
+                        Some(InteractionRule {
+                            substance_a: substance_a.to_lowercase(),
+                            substance_b: substance_b.to_lowercase(),
+                            severity,
+                            description: description.to_string(),
+                        })
+                    }
+                    _ => {
+                        warn!("❌ Skipping malformed drug interaction line: {}", line);
+                        None
+                    }
+                }
+            })
+            .collect();
+
+        InteractionRules { rules }
+    }
+
+    pub fn bundled() -> Self {
+        Self::parse(INTERACTION_RULES)
+    }
+}
+
+impl InteractionCheck for InteractionRules {
+    fn name(&self) -> &'static str {
+        "INTERACTION"
+    }
+
+    /// The most serious rule that applies to the pair.
+    fn check_pair(&self, line: &Medication, other: &Medication) -> Option<InteractionWarning> {
+        self.rules
+            .iter()
+            .filter(|rule| {
+                (line.contains(&rule.substance_a) && other.contains(&rule.substance_b))
+                    || (line.contains(&rule.substance_b) && other.contains(&rule.substance_a))
+            })
+            .max_by_key(|rule| rule.severity)
+            .map(|rule| InteractionWarning {
+                check: self.name(),
+                severity: rule.severity,
+                line_no: line.line_no,
+                drug_name: line.drug_name.clone(),
+                other_prescription_id: other.prescription_id,
+                other_line_no: other.line_no,
+                other_drug_name: other.drug_name.clone(),
+                description: rule.description.clone(),
+            })
+    }
+}
+
+/// The same formulary drug twice, or two formulary drugs of the same therapeutic class.
+pub struct DuplicateTherapyCheck;
+
+impl InteractionCheck for DuplicateTherapyCheck {
+    fn name(&self) -> &'static str {
+        "DUPLICATE_THERAPY"
+    }
+
+    fn check_pair(&self, line: &Medication, other: &Medication) -> Option<InteractionWarning> {
+        let description = match (line.drug_id, other.drug_id) {
+            (Some(drug_id), Some(other_drug_id)) if drug_id == other_drug_id => {
+                "The same drug is prescribed twice".to_string()
+            }
+            _ => match (&line.therapeutic_class, &other.therapeutic_class) {
+                (Some(class), Some(other_class)) if class.eq_ignore_ascii_case(other_class) => {
+                    format!("Both drugs are in the therapeutic class {}", class)
+                }
+                _ => return None,
+            },
+        };
+
+        Some(InteractionWarning {
+            check: self.name(),
+            severity: InteractionSeverity::Moderate,
+            line_no: line.line_no,
+            drug_name: line.drug_name.clone(),
+            other_prescription_id: other.prescription_id,
+            other_line_no: other.line_no,
+            other_drug_name: other.drug_name.clone(),
+            description,
+        })
+    }
+}
+
+/// The checks a prescription goes through before it is submitted, shared by every
+/// request. Deployments add or replace checks when building the application state.
+#[derive(Clone)]
+pub struct InteractionCheckers {
+    checks: Arc<Vec<Box<dyn InteractionCheck>>>,
+}
+
+impl InteractionCheckers {
+    pub fn new(checks: Vec<Box<dyn InteractionCheck>>) -> Self {
+        InteractionCheckers {
+            checks: Arc::new(checks),
+        }
+    }
+
+    /// The bundled interaction rules and the duplicate therapy check.
+    pub fn bundled() -> Self {
+        Self::new(vec![
+            Box::new(InteractionRules::bundled()),
+            Box::new(DuplicateTherapyCheck),
+        ])
+    }
+
+    /// Every warning between the candidate lines and against the active medications,
+    /// most serious first.
+    pub fn check(
+        &self,
+        candidate: &[Medication],
+        active: &[Medication],
+    ) -> Vec<InteractionWarning> {
+        let mut warnings = Vec::new();
+        for (index, line) in candidate.iter().enumerate() {
+            let others = candidate[index + 1..].iter().chain(active);
+            for other in others {
+                for check in self.checks.iter() {
+                    warnings.extend(check.check_pair(line, other));
+                }
+            }
+        }
+
+        warnings.sort_by(|a, b| b.severity.cmp(&a.severity).then(a.line_no.cmp(&b.line_no)));
+        warnings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+
+    const HEADER: &str = "substance_a\tsubstance_b\tseverity\tdescription";
+
+    fn medication(line_no: i32, name: &str) -> Medication {
+        Medication {
+            prescription_id: 1,
+            line_no,
+            drug_id: None,
+            drug_name: name.to_string(),
+            names: vec![name.to_lowercase()],
+            therapeutic_class: None,
+        }
+    }
+
+    fn rules(lines: &[&str]) -> InteractionRules {
+        InteractionRules::parse(&[&[HEADER], lines].concat().join("\n"))
+    }
+
+    #[test]
+    fn bundled_dataset_parses() {
+        assert!(!InteractionRules::bundled().rules.is_empty());
+    }
+
+    #[test]
+    fn rule_matches_either_way_round() {
+        let rules = rules(&["warfarin\taspirin\tMAJOR\tBleeding risk"]);
+        let warfarin = medication(1, "Warfarin 2 mg");
+        let aspirin = medication(2, "Aspirin 80 mg");
+
+        let forward = rules.check_pair(&warfarin, &aspirin).unwrap();
+        let backward = rules.check_pair(&aspirin, &warfarin).unwrap();
+
+        assert_eq!(forward.severity, InteractionSeverity::Major);
+        assert_eq!(backward.severity, InteractionSeverity::Major);
+        assert_eq!(backward.line_no, 2);
+        assert_eq!(backward.other_line_no, 1);
+        assert!(
+            rules
+                .check_pair(&warfarin, &medication(3, "Paracetamol"))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn most_severe_rule_wins() {
+        let rules = rules(&[
+            "simvastatin\tamlodipine\tMINOR\tRaised statin levels",
+            "simvastatin\tamlodipine besylate\tCONTRAINDICATED\tMyopathy",
+            "statin\tamlodipine\tMODERATE\tStatin interaction",
+        ]);
+
+        let warning = rules
+            .check_pair(
+                &medication(1, "Simvastatin 20 mg"),
+                &medication(2, "Amlodipine besylate 5 mg"),
+            )
+            .unwrap();
+
+        assert_eq!(warning.severity, InteractionSeverity::Contraindicated);
+        assert_eq!(warning.description, "Myopathy");
+    }
+
+    #[test]
+    fn malformed_lines_are_skipped() {
+        let rules = rules(&[
+            "warfarin\taspirin\tMAJOR\tBleeding risk",
+            "warfarin\tibuprofen\tMAJOR",
+            "warfarin\tnaproxen\tSEVERE\tUnknown severity",
+            "\tnaproxen\tMAJOR\tMissing substance",
+            "",
+            "no tabs on this line",
+            "methotrexate\ttrimethoprim\tmajor\tBone marrow suppression",
+        ]);
+
+        let substances: Vec<_> = rules
+            .rules
+            .iter()
+            .map(|rule| (rule.substance_a.as_str(), rule.substance_b.as_str()))
+            .collect();
+        assert_eq!(
+            substances,
+            [("warfarin", "aspirin"), ("methotrexate", "trimethoprim")]
+        );
+    }
+
+    #[test]
+    fn same_drug_is_duplicate_therapy() {
+        let mut line = medication(1, "Amoxicillin 500 mg");
+        let mut other = medication(2, "Amoxicillin syrup");
+        line.drug_id = Some(7);
+        other.drug_id = Some(7);
+
+        let warning = DuplicateTherapyCheck.check_pair(&line, &other).unwrap();
+
+        assert_eq!(warning.severity, InteractionSeverity::Moderate);
+        assert_eq!(warning.description, "The same drug is prescribed twice");
+    }
+
+    #[test]
+    fn same_therapeutic_class_is_duplicate_therapy() {
+        let mut line = medication(1, "Omeprazole");
+        let mut other = medication(2, "Lansoprazole");
+        line.drug_id = Some(1);
+        other.drug_id = Some(2);
+        line.therapeutic_class = Some("Proton pump inhibitor".to_string());
+        other.therapeutic_class = Some("PROTON PUMP INHIBITOR".to_string());
+
+        let warning = DuplicateTherapyCheck.check_pair(&line, &other).unwrap();
+
+        assert_eq!(
+            warning.description,
+            "Both drugs are in the therapeutic class Proton pump inhibitor"
+        );
+
+        other.therapeutic_class = Some("Antacid".to_string());
+        assert!(DuplicateTherapyCheck.check_pair(&line, &other).is_none());
+        other.therapeutic_class = None;
+        assert!(DuplicateTherapyCheck.check_pair(&line, &other).is_none());
+    }
+
+    /// Records every pair it is offered.
+    struct RecordingCheck(Arc<Mutex<Vec<(i32, i32)>>>);
+
+    impl InteractionCheck for RecordingCheck {
+        fn name(&self) -> &'static str {
+            "RECORDING"
+        }
+
+        fn check_pair(&self, line: &Medication, other: &Medication) -> Option<InteractionWarning> {
+            self.0.lock().unwrap().push((line.line_no, other.line_no));
+            None
+        }
+    }
+
+    #[test]
+    fn each_pair_is_offered_once() {
+        let offered = Arc::new(Mutex::new(Vec::new()));
+        let checkers = InteractionCheckers::new(vec![Box::new(RecordingCheck(offered.clone()))]);
+        let candidate = [medication(1, "a"), medication(2, "b"), medication(3, "c")];
+        let active = [medication(10, "d")];
+
+        checkers.check(&candidate, &active);
+
+        assert_eq!(
+            *offered.lock().unwrap(),
+            [(1, 2), (1, 3), (1, 10), (2, 3), (2, 10), (3, 10)]
+        );
+    }
+
+    #[test]
+    fn warnings_are_sorted_most_serious_first() {
+        let checkers = InteractionCheckers::new(vec![Box::new(rules(&[
+            "warfarin\taspirin\tMINOR\tMinor note",
+            "clopidogrel\tomeprazole\tMAJOR\tReduced antiplatelet effect",
+        ]))]);
+        let candidate = [
+            medication(1, "Warfarin"),
+            medication(2, "Aspirin"),
+            medication(3, "Clopidogrel"),
+        ];
+        let active = [medication(1, "Omeprazole")];
+
+        let warnings = checkers.check(&candidate, &active);
+
+        let severities: Vec<_> = warnings.iter().map(|warning| warning.severity).collect();
+        assert_eq!(
+            severities,
+            [InteractionSeverity::Major, InteractionSeverity::Minor]
+        );
+        assert_eq!(warnings[0].line_no, 3);
+    }
+}
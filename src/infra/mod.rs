@@ -1,2 +1,5 @@
 pub mod api;
+pub mod cache;
 pub mod config;
+pub mod drug_interactions;
+pub mod queue_events;
@@ -0,0 +1,90 @@
+use std::time::Duration;
+
+use bb8::Pool;
+use bb8_redis::RedisConnectionManager;
+use futures::StreamExt;
+use log::{error, info, warn};
+use redis::AsyncCommands;
+use tokio::sync::broadcast;
+
+use crate::{dtos::triage::queue_event::QueueEvent, error_handling::app_error::AppError};
+
+pub const QUEUE_EVENTS_CHANNEL: &str = "triage:queue:events";
+
+const LOCAL_BUFFER: usize = 256;
+const RECONNECT_DELAY: Duration = Duration::from_secs(2);
+
+/// In-process fan-out of queue events. Every instance feeds it from the Redis channel,
+/// never directly, so all instances deliver the same events in the same order.
+#[derive(Clone)]
+pub struct QueueEventBus {
+    sender: broadcast::Sender<QueueEvent>,
+}
+
+impl QueueEventBus {
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(LOCAL_BUFFER);
+        QueueEventBus { sender }
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<QueueEvent> {
+        self.sender.subscribe()
+    }
+}
+
+impl Default for QueueEventBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Publishes after the change is committed; a failure here only delays the boards
+/// until their next snapshot, so it is logged rather than failing the request.
+pub async fn publish_queue_event(redis: &Pool<RedisConnectionManager>, event: &QueueEvent) {
+    let result: Result<(), AppError> = async {
+        let payload = serde_json::to_string(event)?;
+        let mut redis_conn = redis.get().await?;
+        let _: i64 = redis_conn.publish(QUEUE_EVENTS_CHANNEL, payload).await?;
+        Ok(())
+    }
+    .await;
+
+    if let Err(err) = result {
+        warn!(
+            "Failed to publish {:?} event for {}: {}",
+            event.event, event.queue_code, err
+        );
+    }
+}
+
+pub fn spawn_queue_event_listener(redis_url: String, bus: QueueEventBus) {
+    tokio::spawn(async move {
+        loop {
+            match listen(&redis_url, &bus).await {
+                Ok(()) => warn!("Queue event subscription closed, reconnecting"),
+                Err(err) => error!("Queue event subscription failed: {}", err),
+            }
+            tokio::time::sleep(RECONNECT_DELAY).await;
+        }
+    });
+}
+
+async fn listen(redis_url: &str, bus: &QueueEventBus) -> Result<(), AppError> {
+    let client = redis::Client::open(redis_url)?;
+    let mut pubsub = client.get_async_pubsub().await?;
+    pubsub.subscribe(QUEUE_EVENTS_CHANNEL).await?;
+    info!("Subscribed to {}", QUEUE_EVENTS_CHANNEL);
+
+    let mut messages = pubsub.on_message();
+    while let Some(message) = messages.next().await {
+        let payload: String = message.get_payload()?;
+        match serde_json::from_str::<QueueEvent>(&payload) {
+            // no receivers just means no board is connected to this instance
+            Ok(event) => {
+                let _ = bus.sender.send(event);
+            }
+            Err(err) => warn!("Ignoring malformed queue event: {}", err),
+        }
+    }
+    Ok(())
+}
@@ -1,9 +1,21 @@
 use axum::{Router, middleware};
 use hospital_management_system::{
-    infra::config::AppConfig,
+    infra::{
+        config::AppConfig,
+        drug_interactions::InteractionCheckers,
+        queue_events::{QueueEventBus, spawn_queue_event_listener},
+    },
     middleware::request_middleware::assign_request_id,
-    router::triage_route::triage_routes,
+    router::{
+        allergy_route::allergy_routes, auth_route::auth_routes,
+        clinical_note_route::clinical_note_routes, diagnosis_route::diagnosis_routes,
+        encounter_route::encounter_routes, patient_route::patient_routes,
+        pharmacy_route::pharmacy_routes, prescription_route::prescription_routes,
+        procedure_route::procedure_routes, triage_route::triage_routes,
+        vitals_route::vitals_routes,
+    },
     state::{self, init_database_connection, init_redis_pool, init_s3_client},
+    use_cases::triage::service::queue_sweeper::spawn_queue_sweeper,
 };
 use log::info;
 
@@ -24,6 +36,8 @@ async fn main() {
     // redis config
     let redis_url = app_config.redis.upstash_redis_url;
     let redis_pool = init_redis_pool(&redis_url).await;
+    let queue_events = QueueEventBus::new();
+    spawn_queue_event_listener(redis_url, queue_events.clone());
 
     // s3 config
     let s3_config = app_config.s3;
@@ -37,10 +51,31 @@ async fn main() {
         db,
         redis: redis_pool,
         s3,
+        jwt: app_config.jwt,
+        queue_events,
+        queue: app_config.queue,
+        mrn: app_config.mrn,
+        interactions: InteractionCheckers::bundled(),
     };
 
+    spawn_queue_sweeper(
+        app_state.db.clone(),
+        app_state.redis.clone(),
+        app_state.queue.clone(),
+    );
+
     let app = Router::new()
+        .nest("/api/v1", auth_routes(app_state.clone()))
         .nest("/api/v1", triage_routes(app_state.clone()))
+        .nest("/api/v1", patient_routes(app_state.clone()))
+        .nest("/api/v1", allergy_routes(app_state.clone()))
+        .nest("/api/v1", encounter_routes(app_state.clone()))
+        .nest("/api/v1", vitals_routes(app_state.clone()))
+        .nest("/api/v1", clinical_note_routes(app_state.clone()))
+        .nest("/api/v1", diagnosis_routes(app_state.clone()))
+        .nest("/api/v1", procedure_routes(app_state.clone()))
+        .nest("/api/v1", prescription_routes(app_state.clone()))
+        .nest("/api/v1", pharmacy_routes(app_state.clone()))
         .layer(ServiceBuilder::new().layer(middleware::from_fn(assign_request_id)))
         .layer(RequestBodyLimitLayer::new(10 * 1024 * 1024));
 
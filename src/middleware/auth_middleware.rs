@@ -0,0 +1,210 @@
+use std::pin::Pin;
+
+use axum::{
+    BoxError,
+    extract::{FromRequestParts, Query, Request},
+    response::{IntoResponse, Response},
+};
+use entity::user::Role;
+use http::{HeaderMap, Uri, header::AUTHORIZATION};
+use sea_orm::ActiveEnum;
+use serde::Deserialize;
+use tower::{Layer, Service};
+
+use crate::{
+    error_handling::app_error::AppError,
+    state::AppState,
+    utils::{
+        helpers::get_cache_data,
+        jwt::{Claims, TokenType, decode_token},
+    },
+};
+
+use super::permission::Permission;
+
+#[derive(Clone, Debug)]
+pub struct CurrentUser {
+    pub id: i32,
+    pub username: String,
+    pub role: Role,
+    pub token_id: String,
+}
+
+impl CurrentUser {
+    pub fn can(&self, permission: Permission) -> bool {
+        permission.is_granted_to(&self.role)
+    }
+}
+
+pub fn bearer_token(headers: &HeaderMap) -> Option<&str> {
+    headers
+        .get(AUTHORIZATION)
+        .and_then(|val| val.to_str().ok())
+        .and_then(|val| val.strip_prefix("Bearer "))
+}
+
+#[derive(Deserialize)]
+struct DisplayTokenQuery {
+    display_token: Option<String>,
+}
+
+pub async fn authenticate(headers: &HeaderMap, state: &AppState) -> Result<CurrentUser, AppError> {
+    let token =
+        bearer_token(headers).ok_or(AppError::Unauthorized("Missing bearer token".into()))?;
+
+    let claims = decode_token(token, &state.jwt.access_secret, TokenType::Access)?;
+    user_from_claims(claims, state).await
+}
+
+/// Browser `EventSource` and `WebSocket` cannot send an `Authorization` header, so a
+/// display board without one passes a display token as `?display_token=`.
+async fn authenticate_display(
+    headers: &HeaderMap,
+    uri: &Uri,
+    state: &AppState,
+) -> Result<CurrentUser, AppError> {
+    if bearer_token(headers).is_some() {
+        return authenticate(headers, state).await;
+    }
+
+    let Query(query) = Query::<DisplayTokenQuery>::try_from_uri(uri)
+        .map_err(|e| AppError::BadRequest(e.to_string()))?;
+    let token = query.display_token.ok_or(AppError::Unauthorized(
+        "Missing bearer token or display token".into(),
+    ))?;
+
+    let claims = decode_token(&token, &state.jwt.access_secret, TokenType::Display)?;
+    user_from_claims(claims, state).await
+}
+
+async fn user_from_claims(claims: Claims, state: &AppState) -> Result<CurrentUser, AppError> {
+    let revoked_key = format!("auth:revoked:{}", claims.jti);
+    if get_cache_data::<i32>(&state.redis, &revoked_key)
+        .await?
+        .is_some()
+    {
+        return Err(AppError::Unauthorized("Token has been revoked".into()));
+    }
+
+    let role = Role::try_from_value(&claims.role)
+        .map_err(|_| AppError::Unauthorized("Unknown role in token".into()))?;
+
+    Ok(CurrentUser {
+        id: claims.user_id()?,
+        username: claims.username,
+        role,
+        token_id: claims.jti,
+    })
+}
+
+impl FromRequestParts<AppState> for CurrentUser {
+    type Rejection = AppError;
+
+    async fn from_request_parts(
+        parts: &mut http::request::Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        if let Some(existing) = parts.extensions.get::<CurrentUser>() {
+            return Ok(existing.clone());
+        }
+
+        let user = authenticate(&parts.headers, state).await?;
+        parts.extensions.insert(user.clone());
+        Ok(user)
+    }
+}
+
+#[derive(Clone)]
+pub struct RequirePermissionLayer {
+    state: AppState,
+    permission: Permission,
+    accept_display_token: bool,
+}
+
+impl RequirePermissionLayer {
+    pub fn new(state: AppState, permission: Permission) -> Self {
+        Self {
+            state,
+            permission,
+            accept_display_token: false,
+        }
+    }
+
+    /// For queue display feeds, which also accept a display token in the query string.
+    pub fn display_feed(state: AppState, permission: Permission) -> Self {
+        Self {
+            state,
+            permission,
+            accept_display_token: true,
+        }
+    }
+}
+
+impl<S> Layer<S> for RequirePermissionLayer {
+    type Service = RequirePermission<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RequirePermission {
+            inner,
+            state: self.state.clone(),
+            permission: self.permission,
+            accept_display_token: self.accept_display_token,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct RequirePermission<S> {
+    inner: S,
+    state: AppState,
+    permission: Permission,
+    accept_display_token: bool,
+}
+
+impl<S> Service<Request> for RequirePermission<S>
+where
+    S: Service<Request, Response = Response> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    S::Error: Into<BoxError>,
+{
+    type Response = Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Response, Self::Error>> + Send>>;
+
+    fn poll_ready(
+        &mut self,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut req: Request) -> Self::Future {
+        let mut inner = self.inner.clone();
+        let state = self.state.clone();
+        let permission = self.permission;
+        let accept_display_token = self.accept_display_token;
+
+        Box::pin(async move {
+            let authenticated = if accept_display_token {
+                authenticate_display(req.headers(), req.uri(), &state).await
+            } else {
+                authenticate(req.headers(), &state).await
+            };
+            let user = match authenticated {
+                Ok(user) => user,
+                Err(err) => return Ok(err.into_response()),
+            };
+
+            if !user.can(permission) {
+                return Ok(AppError::Forbidden(format!(
+                    "Role {} is not allowed to perform this action",
+                    user.role
+                ))
+                .into_response());
+            }
+
+            req.extensions_mut().insert(user);
+            inner.call(req).await
+        })
+    }
+}
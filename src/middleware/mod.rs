@@ -1,2 +1,4 @@
+pub mod auth_middleware;
 pub mod error_handler_layer;
+pub mod permission;
 pub mod request_middleware;
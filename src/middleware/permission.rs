@@ -0,0 +1,112 @@
+use entity::user::Role;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Permission {
+    TriageRegister,
+    TriageRegisterUnidentified,
+    TriageQueueView,
+    TriageCall,
+    TriageComplete,
+    TriageCancel,
+    TriageCancelFinalized,
+    TriageQueueStats,
+    ReferralUpload,
+    PatientView,
+    PatientUpdate,
+    PatientMerge,
+    PatientRecordAudit,
+    AllergyManage,
+    AllergyVerify,
+    EncounterOpen,
+    EncounterView,
+    EncounterAttend,
+    VitalsRecord,
+    VitalsView,
+    ClinicalNoteWrite,
+    ClinicalNoteView,
+    DiagnosisWrite,
+    DiagnosisView,
+    ProcedureRecord,
+    ProcedureView,
+    PrescriptionWrite,
+    PrescriptionView,
+    PrescriptionDispense,
+    FormularyView,
+    FormularyManage,
+    StockView,
+    StockManage,
+}
+
+impl Permission {
+    /// Roles granted this permission. `Role::Superadmin` is the system owner and is
+    /// granted everything, so it is never listed here.
+    pub fn allowed_roles(&self) -> &'static [Role] {
+        match self {
+            Permission::TriageRegister => &[Role::Staff, Role::Nurse, Role::Emergency, Role::Admin],
+            Permission::TriageRegisterUnidentified => &[Role::Emergency],
+            Permission::TriageQueueView => &[
+                Role::Staff,
+                Role::Nurse,
+                Role::Doctor,
+                Role::Emergency,
+                Role::Admin,
+            ],
+            Permission::TriageCall => &[Role::Nurse, Role::Staff],
+            Permission::TriageComplete => &[Role::Nurse, Role::Staff, Role::Doctor],
+            Permission::TriageCancel => &[Role::Nurse, Role::Staff, Role::Admin],
+            Permission::TriageCancelFinalized => &[Role::Admin],
+            Permission::TriageQueueStats => &[Role::Admin],
+            Permission::ReferralUpload => &[Role::Staff, Role::Nurse, Role::Emergency, Role::Admin],
+            Permission::PatientView => &[
+                Role::Staff,
+                Role::Nurse,
+                Role::Doctor,
+                Role::Emergency,
+                Role::Admin,
+            ],
+            Permission::PatientUpdate => &[Role::Staff, Role::Nurse, Role::Emergency, Role::Admin],
+            Permission::PatientMerge => &[Role::Admin],
+            Permission::PatientRecordAudit => &[Role::Admin],
+            Permission::AllergyManage => &[Role::Staff, Role::Nurse, Role::Doctor, Role::Emergency],
+            Permission::AllergyVerify => &[Role::Nurse, Role::Doctor],
+            Permission::EncounterOpen => &[Role::Staff, Role::Nurse],
+            Permission::EncounterView => &[Role::Staff, Role::Nurse, Role::Doctor, Role::Admin],
+            Permission::EncounterAttend => &[Role::Doctor],
+            Permission::VitalsRecord => &[Role::Nurse, Role::Doctor, Role::Emergency],
+            Permission::VitalsView => &[Role::Nurse, Role::Doctor, Role::Emergency, Role::Admin],
+            Permission::ClinicalNoteWrite => &[Role::Doctor],
+            Permission::ClinicalNoteView => &[Role::Nurse, Role::Doctor, Role::Emergency],
+            Permission::DiagnosisWrite => &[Role::Doctor],
+            Permission::DiagnosisView => &[
+                Role::Staff,
+                Role::Nurse,
+                Role::Doctor,
+                Role::Emergency,
+                Role::Admin,
+            ],
+            Permission::ProcedureRecord => &[Role::Doctor, Role::Nurse, Role::Emergency],
+            Permission::ProcedureView => &[
+                Role::Staff,
+                Role::Nurse,
+                Role::Doctor,
+                Role::Emergency,
+                Role::Admin,
+            ],
+            Permission::PrescriptionWrite => &[Role::Doctor],
+            Permission::PrescriptionView => {
+                &[Role::Nurse, Role::Doctor, Role::Pharmacist, Role::Admin]
+            }
+            Permission::PrescriptionDispense => &[Role::Pharmacist],
+            Permission::FormularyView => {
+                &[Role::Nurse, Role::Doctor, Role::Pharmacist, Role::Admin]
+            }
+            Permission::FormularyManage => &[Role::Pharmacist, Role::Admin],
+            Permission::StockView => &[Role::Pharmacist, Role::Admin],
+            Permission::StockManage => &[Role::Pharmacist],
+        }
+    }
+
+    pub fn is_granted_to(&self, role: &Role) -> bool {
+        *role == Role::Superadmin || self.allowed_roles().contains(role)
+    }
+}
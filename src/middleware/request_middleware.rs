@@ -1,11 +1,19 @@
 use axum::{
-    body::Body, extract::FromRequestParts, http::Request, middleware::Next, response::IntoResponse,
+    body::{Body, to_bytes},
+    extract::FromRequestParts,
+    http::Request,
+    middleware::Next,
+    response::{IntoResponse, Response},
 };
 use http::HeaderValue;
+use sha2::{Digest, Sha256};
 use std::convert::Infallible;
 use uuid::Uuid;
 
-use crate::infra::config::REQUEST_ID;
+use crate::{
+    error_handling::app_error::AppError, infra::config::REQUEST_ID,
+    middleware::auth_middleware::CurrentUser, state::AppState,
+};
 
 #[derive(Clone, Debug)]
 pub struct RequestId(pub String);
@@ -30,6 +38,95 @@ where
     type Rejection = Infallible;
 }
 
+/// SHA-256 of the method, path, query and body of a request sent with an
+/// `Idempotency-Key`, set by [`fingerprint_request`].
+#[derive(Clone, Debug)]
+pub struct RequestFingerprint(pub String);
+
+/// An `Idempotency-Key` scoped to the user who sent it, with the fingerprint of the
+/// request it came with so a reused key can be told apart from a retry.
+#[derive(Clone, Debug)]
+pub struct IdempotentRequest {
+    pub key: String,
+    pub fingerprint: String,
+}
+
+/// Optional `Idempotency-Key` header a client sends to make a mutating request safe to retry.
+#[derive(Clone, Debug)]
+pub struct IdempotencyKey(pub Option<IdempotentRequest>);
+
+impl FromRequestParts<AppState> for IdempotencyKey {
+    async fn from_request_parts(
+        parts: &mut http::request::Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let Some(value) = parts.headers.get("idempotency-key") else {
+            return Ok(IdempotencyKey(None));
+        };
+
+        let key = value
+            .to_str()
+            .map(str::trim)
+            .map_err(|_| AppError::BadRequest("Idempotency-Key must be visible ASCII".into()))?;
+
+        if key.is_empty() || key.len() > 255 {
+            return Err(AppError::BadRequest(
+                "Idempotency-Key must be between 1 and 255 characters".into(),
+            ));
+        }
+
+        let key = key.to_string();
+        let user = CurrentUser::from_request_parts(parts, state).await?;
+        let fingerprint = parts
+            .extensions
+            .get::<RequestFingerprint>()
+            .map(|fingerprint| fingerprint.0.clone())
+            .ok_or(AppError::Internal(
+                "Idempotent route is missing the request fingerprint layer".into(),
+            ))?;
+
+        Ok(IdempotencyKey(Some(IdempotentRequest {
+            key: format!("user:{}:{}", user.id, key),
+            fingerprint,
+        })))
+    }
+
+    type Rejection = AppError;
+}
+
+/// Buffers the body of requests sent with an `Idempotency-Key` to fingerprint them;
+/// other requests pass through untouched.
+pub async fn fingerprint_request(req: Request<Body>, next: Next) -> Response {
+    if !req.headers().contains_key("idempotency-key") {
+        return next.run(req).await;
+    }
+
+    let (mut parts, body) = req.into_parts();
+    let bytes = match to_bytes(body, usize::MAX).await {
+        Ok(bytes) => bytes,
+        Err(err) => return AppError::BadRequest(err.to_string()).into_response(),
+    };
+
+    let mut hasher = Sha256::new();
+    hasher.update(parts.method.as_str());
+    hasher.update(b" ");
+    hasher.update(
+        parts
+            .uri
+            .path_and_query()
+            .map(|path| path.as_str())
+            .unwrap_or_default(),
+    );
+    hasher.update(b"\n");
+    hasher.update(&bytes);
+    parts
+        .extensions
+        .insert(RequestFingerprint(format!("{:x}", hasher.finalize())));
+
+    next.run(Request::from_parts(parts, Body::from(bytes)))
+        .await
+}
+
 pub async fn assign_request_id(mut req: Request<Body>, next: Next) -> impl IntoResponse {
     let request_id = req
         .headers()
@@ -0,0 +1,46 @@
+use axum::middleware;
+use axum::{
+    Router,
+    routing::{delete, get, patch, post},
+};
+
+use crate::handlers::allergy::allergy_handler::{
+    convert_legacy_allergies, get_patient_allergies, record_allergy, remove_allergy,
+    update_allergy, verify_allergy,
+};
+
+use crate::middleware::auth_middleware::RequirePermissionLayer;
+use crate::middleware::error_handler_layer::ErrorHandlingLayer;
+use crate::middleware::permission::Permission;
+use crate::middleware::request_middleware::assign_request_id;
+use crate::state::AppState;
+
+pub fn allergy_routes(app_state: AppState) -> Router {
+    let require =
+        |permission: Permission| RequirePermissionLayer::new(app_state.clone(), permission);
+
+    Router::new()
+        .layer(middleware::from_fn(assign_request_id))
+        .route(
+            "/patients/{patient_id}/allergies",
+            get(get_patient_allergies)
+                .route_layer(require(Permission::PatientView))
+                .merge(post(record_allergy).route_layer(require(Permission::AllergyManage))),
+        )
+        .route(
+            "/patients/{patient_id}/allergies/convert-legacy",
+            post(convert_legacy_allergies).route_layer(require(Permission::AllergyManage)),
+        )
+        .route(
+            "/patients/{patient_id}/allergies/{allergy_id}",
+            patch(update_allergy)
+                .route_layer(require(Permission::AllergyManage))
+                .merge(delete(remove_allergy).route_layer(require(Permission::AllergyManage))),
+        )
+        .route(
+            "/patients/{patient_id}/allergies/{allergy_id}/verify",
+            patch(verify_allergy).route_layer(require(Permission::AllergyVerify)),
+        )
+        .layer(ErrorHandlingLayer)
+        .with_state(app_state.clone())
+}
@@ -0,0 +1,29 @@
+use axum::middleware;
+use axum::{Router, routing::post};
+
+use crate::handlers::auth::auth_handler::{
+    auth_display_token, auth_login, auth_logout, auth_refresh_token,
+};
+
+use crate::middleware::auth_middleware::RequirePermissionLayer;
+use crate::middleware::error_handler_layer::ErrorHandlingLayer;
+use crate::middleware::permission::Permission;
+use crate::middleware::request_middleware::assign_request_id;
+use crate::state::AppState;
+
+pub fn auth_routes(app_state: AppState) -> Router {
+    Router::new()
+        .layer(middleware::from_fn(assign_request_id))
+        .route("/auth/login", post(auth_login))
+        .route("/auth/refresh", post(auth_refresh_token))
+        .route("/auth/logout", post(auth_logout))
+        .route(
+            "/auth/display-token",
+            post(auth_display_token).route_layer(RequirePermissionLayer::new(
+                app_state.clone(),
+                Permission::TriageQueueView,
+            )),
+        )
+        .layer(ErrorHandlingLayer)
+        .with_state(app_state)
+}
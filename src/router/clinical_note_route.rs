@@ -0,0 +1,54 @@
+use axum::middleware;
+use axum::{
+    Router,
+    routing::{get, patch, post},
+};
+
+use crate::handlers::clinical_note::clinical_note_handler::{
+    add_addendum, create_note, discard_note, get_note, get_patient_timeline, get_visit_notes,
+    sign_note, update_note,
+};
+
+use crate::middleware::auth_middleware::RequirePermissionLayer;
+use crate::middleware::error_handler_layer::ErrorHandlingLayer;
+use crate::middleware::permission::Permission;
+use crate::middleware::request_middleware::assign_request_id;
+use crate::state::AppState;
+
+pub fn clinical_note_routes(app_state: AppState) -> Router {
+    let require =
+        |permission: Permission| RequirePermissionLayer::new(app_state.clone(), permission);
+
+    Router::new()
+        .layer(middleware::from_fn(assign_request_id))
+        .route(
+            "/visits/{visit_intent_id}/notes",
+            get(get_visit_notes)
+                .route_layer(require(Permission::ClinicalNoteView))
+                .merge(post(create_note).route_layer(require(Permission::ClinicalNoteWrite))),
+        )
+        .route(
+            "/notes/{note_id}",
+            get(get_note)
+                .route_layer(require(Permission::ClinicalNoteView))
+                .merge(
+                    patch(update_note)
+                        .delete(discard_note)
+                        .route_layer(require(Permission::ClinicalNoteWrite)),
+                ),
+        )
+        .route(
+            "/notes/{note_id}/sign",
+            patch(sign_note).route_layer(require(Permission::ClinicalNoteWrite)),
+        )
+        .route(
+            "/notes/{note_id}/addenda",
+            post(add_addendum).route_layer(require(Permission::ClinicalNoteWrite)),
+        )
+        .route(
+            "/patients/{patient_id}/notes",
+            get(get_patient_timeline).route_layer(require(Permission::ClinicalNoteView)),
+        )
+        .layer(ErrorHandlingLayer)
+        .with_state(app_state.clone())
+}
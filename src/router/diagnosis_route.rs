@@ -0,0 +1,46 @@
+use axum::middleware;
+use axum::{
+    Router,
+    routing::{get, patch, post},
+};
+
+use crate::handlers::diagnosis::diagnosis_handler::{
+    get_icd10_code, get_visit_diagnoses, record_diagnosis, remove_diagnosis, search_icd10_codes,
+    update_diagnosis,
+};
+
+use crate::middleware::auth_middleware::RequirePermissionLayer;
+use crate::middleware::error_handler_layer::ErrorHandlingLayer;
+use crate::middleware::permission::Permission;
+use crate::middleware::request_middleware::assign_request_id;
+use crate::state::AppState;
+
+pub fn diagnosis_routes(app_state: AppState) -> Router {
+    let require =
+        |permission: Permission| RequirePermissionLayer::new(app_state.clone(), permission);
+
+    Router::new()
+        .layer(middleware::from_fn(assign_request_id))
+        .route(
+            "/icd10/codes",
+            get(search_icd10_codes).route_layer(require(Permission::DiagnosisView)),
+        )
+        .route(
+            "/icd10/codes/{code}",
+            get(get_icd10_code).route_layer(require(Permission::DiagnosisView)),
+        )
+        .route(
+            "/visits/{visit_intent_id}/diagnoses",
+            get(get_visit_diagnoses)
+                .route_layer(require(Permission::DiagnosisView))
+                .merge(post(record_diagnosis).route_layer(require(Permission::DiagnosisWrite))),
+        )
+        .route(
+            "/visits/{visit_intent_id}/diagnoses/{diagnosis_id}",
+            patch(update_diagnosis)
+                .delete(remove_diagnosis)
+                .route_layer(require(Permission::DiagnosisWrite)),
+        )
+        .layer(ErrorHandlingLayer)
+        .with_state(app_state.clone())
+}
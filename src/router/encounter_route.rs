@@ -0,0 +1,49 @@
+use axum::middleware;
+use axum::{
+    Router,
+    routing::{get, patch, post},
+};
+
+use crate::handlers::encounter::encounter_handler::{
+    doctor_worklist, finish_encounter, get_encounter, my_worklist, open_encounter, start_encounter,
+};
+
+use crate::middleware::auth_middleware::RequirePermissionLayer;
+use crate::middleware::error_handler_layer::ErrorHandlingLayer;
+use crate::middleware::permission::Permission;
+use crate::middleware::request_middleware::assign_request_id;
+use crate::state::AppState;
+
+pub fn encounter_routes(app_state: AppState) -> Router {
+    let require =
+        |permission: Permission| RequirePermissionLayer::new(app_state.clone(), permission);
+
+    Router::new()
+        .layer(middleware::from_fn(assign_request_id))
+        .route(
+            "/encounters",
+            post(open_encounter).route_layer(require(Permission::EncounterOpen)),
+        )
+        .route(
+            "/encounters/worklist",
+            get(my_worklist).route_layer(require(Permission::EncounterAttend)),
+        )
+        .route(
+            "/encounters/{encounter_id}",
+            get(get_encounter).route_layer(require(Permission::EncounterView)),
+        )
+        .route(
+            "/encounters/{encounter_id}/start",
+            patch(start_encounter).route_layer(require(Permission::EncounterAttend)),
+        )
+        .route(
+            "/encounters/{encounter_id}/finish",
+            patch(finish_encounter).route_layer(require(Permission::EncounterAttend)),
+        )
+        .route(
+            "/doctors/{doctor_id}/worklist",
+            get(doctor_worklist).route_layer(require(Permission::EncounterView)),
+        )
+        .layer(ErrorHandlingLayer)
+        .with_state(app_state.clone())
+}
@@ -1 +1,11 @@
+pub mod allergy_route;
+pub mod auth_route;
+pub mod clinical_note_route;
+pub mod diagnosis_route;
+pub mod encounter_route;
+pub mod patient_route;
+pub mod pharmacy_route;
+pub mod prescription_route;
+pub mod procedure_route;
 pub mod triage_route;
+pub mod vitals_route;
@@ -0,0 +1,69 @@
+use axum::middleware;
+use axum::{
+    Router,
+    routing::{get, patch, post},
+};
+
+use crate::handlers::patient::patient_handler::{
+    find_duplicate_patients, find_mrn_gaps, get_patient, get_patient_history, get_patient_merges,
+    identify_patient, lookup_patient, merge_patients, search_patients, undo_patient_merge,
+    update_patient,
+};
+
+use crate::middleware::auth_middleware::RequirePermissionLayer;
+use crate::middleware::error_handler_layer::ErrorHandlingLayer;
+use crate::middleware::permission::Permission;
+use crate::middleware::request_middleware::assign_request_id;
+use crate::state::AppState;
+
+pub fn patient_routes(app_state: AppState) -> Router {
+    let require =
+        |permission: Permission| RequirePermissionLayer::new(app_state.clone(), permission);
+
+    Router::new()
+        .layer(middleware::from_fn(assign_request_id))
+        .route(
+            "/patients",
+            get(search_patients).route_layer(require(Permission::PatientView)),
+        )
+        .route(
+            "/patients/duplicates",
+            get(find_duplicate_patients).route_layer(require(Permission::PatientMerge)),
+        )
+        .route(
+            "/patients/mrn-gaps",
+            get(find_mrn_gaps).route_layer(require(Permission::PatientRecordAudit)),
+        )
+        .route(
+            "/patients/merges/{merge_id}/undo",
+            post(undo_patient_merge).route_layer(require(Permission::PatientMerge)),
+        )
+        .route(
+            "/patients/lookup",
+            get(lookup_patient).route_layer(require(Permission::PatientView)),
+        )
+        .route(
+            "/patients/{patient_id}",
+            get(get_patient)
+                .route_layer(require(Permission::PatientView))
+                .merge(patch(update_patient).route_layer(require(Permission::PatientUpdate))),
+        )
+        .route(
+            "/patients/{patient_id}/history",
+            get(get_patient_history).route_layer(require(Permission::PatientView)),
+        )
+        .route(
+            "/patients/{patient_id}/identify",
+            post(identify_patient).route_layer(require(Permission::PatientUpdate)),
+        )
+        .route(
+            "/patients/{patient_id}/merge",
+            post(merge_patients).route_layer(require(Permission::PatientMerge)),
+        )
+        .route(
+            "/patients/{patient_id}/merges",
+            get(get_patient_merges).route_layer(require(Permission::PatientMerge)),
+        )
+        .layer(ErrorHandlingLayer)
+        .with_state(app_state.clone())
+}
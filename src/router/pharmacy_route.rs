@@ -0,0 +1,73 @@
+use axum::middleware;
+use axum::{
+    Router,
+    routing::{get, patch, post},
+};
+
+use crate::handlers::pharmacy::pharmacy_handler::{
+    adjust_stock, create_drug, create_location, get_batch_movements, get_drug, get_drug_stock,
+    get_goods_receipt, get_locations, get_low_stock_report, get_near_expiry_report, receive_goods,
+    search_drugs, update_drug,
+};
+
+use crate::middleware::auth_middleware::RequirePermissionLayer;
+use crate::middleware::error_handler_layer::ErrorHandlingLayer;
+use crate::middleware::permission::Permission;
+use crate::middleware::request_middleware::assign_request_id;
+use crate::state::AppState;
+
+pub fn pharmacy_routes(app_state: AppState) -> Router {
+    let require =
+        |permission: Permission| RequirePermissionLayer::new(app_state.clone(), permission);
+
+    Router::new()
+        .layer(middleware::from_fn(assign_request_id))
+        .route(
+            "/pharmacy/drugs",
+            get(search_drugs)
+                .route_layer(require(Permission::FormularyView))
+                .merge(post(create_drug).route_layer(require(Permission::FormularyManage))),
+        )
+        .route(
+            "/pharmacy/drugs/{drug_id}",
+            get(get_drug)
+                .route_layer(require(Permission::FormularyView))
+                .merge(patch(update_drug).route_layer(require(Permission::FormularyManage))),
+        )
+        .route(
+            "/pharmacy/drugs/{drug_id}/stock",
+            get(get_drug_stock).route_layer(require(Permission::StockView)),
+        )
+        .route(
+            "/pharmacy/locations",
+            get(get_locations)
+                .route_layer(require(Permission::StockView))
+                .merge(post(create_location).route_layer(require(Permission::FormularyManage))),
+        )
+        .route(
+            "/pharmacy/goods-receipts",
+            post(receive_goods).route_layer(require(Permission::StockManage)),
+        )
+        .route(
+            "/pharmacy/goods-receipts/{receipt_id}",
+            get(get_goods_receipt).route_layer(require(Permission::StockView)),
+        )
+        .route(
+            "/pharmacy/batches/{batch_id}/movements",
+            get(get_batch_movements).route_layer(require(Permission::StockView)),
+        )
+        .route(
+            "/pharmacy/batches/{batch_id}/adjustments",
+            post(adjust_stock).route_layer(require(Permission::StockManage)),
+        )
+        .route(
+            "/pharmacy/reports/low-stock",
+            get(get_low_stock_report).route_layer(require(Permission::StockView)),
+        )
+        .route(
+            "/pharmacy/reports/near-expiry",
+            get(get_near_expiry_report).route_layer(require(Permission::StockView)),
+        )
+        .layer(ErrorHandlingLayer)
+        .with_state(app_state.clone())
+}
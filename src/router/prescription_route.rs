@@ -0,0 +1,65 @@
+use axum::middleware;
+use axum::{
+    Router,
+    routing::{get, patch, post, put},
+};
+
+use crate::handlers::prescription::prescription_handler::{
+    check_allergies, check_interactions, create_prescription, discard_prescription,
+    dispense_prescription, get_pharmacy_queue, get_prescription, get_visit_prescriptions,
+    submit_prescription, update_prescription,
+};
+
+use crate::middleware::auth_middleware::RequirePermissionLayer;
+use crate::middleware::error_handler_layer::ErrorHandlingLayer;
+use crate::middleware::permission::Permission;
+use crate::middleware::request_middleware::assign_request_id;
+use crate::state::AppState;
+
+pub fn prescription_routes(app_state: AppState) -> Router {
+    let require =
+        |permission: Permission| RequirePermissionLayer::new(app_state.clone(), permission);
+
+    Router::new()
+        .layer(middleware::from_fn(assign_request_id))
+        .route(
+            "/visits/{visit_intent_id}/prescriptions",
+            get(get_visit_prescriptions)
+                .route_layer(require(Permission::PrescriptionView))
+                .merge(
+                    post(create_prescription).route_layer(require(Permission::PrescriptionWrite)),
+                ),
+        )
+        .route(
+            "/prescriptions/{prescription_id}",
+            get(get_prescription)
+                .route_layer(require(Permission::PrescriptionView))
+                .merge(
+                    put(update_prescription)
+                        .delete(discard_prescription)
+                        .route_layer(require(Permission::PrescriptionWrite)),
+                ),
+        )
+        .route(
+            "/prescriptions/{prescription_id}/allergy-check",
+            get(check_allergies).route_layer(require(Permission::PrescriptionView)),
+        )
+        .route(
+            "/prescriptions/{prescription_id}/interaction-check",
+            get(check_interactions).route_layer(require(Permission::PrescriptionView)),
+        )
+        .route(
+            "/prescriptions/{prescription_id}/submit",
+            patch(submit_prescription).route_layer(require(Permission::PrescriptionWrite)),
+        )
+        .route(
+            "/prescriptions/{prescription_id}/dispense",
+            patch(dispense_prescription).route_layer(require(Permission::PrescriptionDispense)),
+        )
+        .route(
+            "/pharmacy/prescriptions",
+            get(get_pharmacy_queue).route_layer(require(Permission::PrescriptionDispense)),
+        )
+        .layer(ErrorHandlingLayer)
+        .with_state(app_state.clone())
+}
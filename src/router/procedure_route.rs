@@ -0,0 +1,44 @@
+use axum::middleware;
+use axum::{
+    Router,
+    routing::{delete, get, post},
+};
+
+use crate::handlers::procedure::procedure_handler::{
+    get_procedure_code, get_visit_procedures, record_procedure, remove_procedure,
+    search_procedure_codes,
+};
+
+use crate::middleware::auth_middleware::RequirePermissionLayer;
+use crate::middleware::error_handler_layer::ErrorHandlingLayer;
+use crate::middleware::permission::Permission;
+use crate::middleware::request_middleware::assign_request_id;
+use crate::state::AppState;
+
+pub fn procedure_routes(app_state: AppState) -> Router {
+    let require =
+        |permission: Permission| RequirePermissionLayer::new(app_state.clone(), permission);
+
+    Router::new()
+        .layer(middleware::from_fn(assign_request_id))
+        .route(
+            "/icd9cm/procedures",
+            get(search_procedure_codes).route_layer(require(Permission::ProcedureView)),
+        )
+        .route(
+            "/icd9cm/procedures/{code}",
+            get(get_procedure_code).route_layer(require(Permission::ProcedureView)),
+        )
+        .route(
+            "/visits/{visit_intent_id}/procedures",
+            get(get_visit_procedures)
+                .route_layer(require(Permission::ProcedureView))
+                .merge(post(record_procedure).route_layer(require(Permission::ProcedureRecord))),
+        )
+        .route(
+            "/visits/{visit_intent_id}/procedures/{procedure_id}",
+            delete(remove_procedure).route_layer(require(Permission::ProcedureRecord)),
+        )
+        .layer(ErrorHandlingLayer)
+        .with_state(app_state.clone())
+}
@@ -2,40 +2,139 @@ use axum::middleware;
 use axum::routing::patch;
 use axum::{Router, routing::get, routing::post};
 
+use crate::handlers::triage::queue_feed_handler::{triage_queue_events, triage_queue_ws};
 use crate::handlers::triage::triage_handler::{
-    triage_call_patient, triage_complete, triage_patient, triage_patient_cancel, triage_queue,
-    triage_queue_status, triage_referral_document_upload,
+    triage_call_patient, triage_complete, triage_no_show, triage_patient, triage_patient_cancel,
+    triage_queue, triage_queue_stats, triage_queue_status, triage_recall_patient,
+    triage_referral_document_upload, triage_requeue_patient, triage_skip_patient,
+    triage_unidentified_patient,
 };
 
+use crate::middleware::auth_middleware::RequirePermissionLayer;
 use crate::middleware::error_handler_layer::ErrorHandlingLayer;
-use crate::middleware::request_middleware::assign_request_id;
+use crate::middleware::permission::Permission;
+use crate::middleware::request_middleware::{assign_request_id, fingerprint_request};
 use crate::state::AppState;
 
 pub fn triage_routes(app_state: AppState) -> Router {
+    let require =
+        |permission: Permission| RequirePermissionLayer::new(app_state.clone(), permission);
+    let display_feed = |permission: Permission| {
+        RequirePermissionLayer::display_feed(app_state.clone(), permission)
+    };
+
     Router::new()
         .layer(middleware::from_fn(assign_request_id))
-        .route("/triage", post(triage_patient))
-        .route("/triage/queue/{visit_type}", get(triage_queue))
+        .route(
+            "/triage",
+            post(triage_patient).route_layer(require(Permission::TriageRegister)),
+        )
+        .route(
+            "/triage/unidentified",
+            post(triage_unidentified_patient)
+                .route_layer(require(Permission::TriageRegisterUnidentified)),
+        )
+        .route(
+            "/triage/queue/{visit_type}",
+            get(triage_queue).route_layer(require(Permission::TriageQueueView)),
+        )
+        .route(
+            "/triage/queue/{visit_type}/events",
+            get(triage_queue_events).route_layer(display_feed(Permission::TriageQueueView)),
+        )
+        .route(
+            "/triage/queue/{visit_type}/stats",
+            get(triage_queue_stats).route_layer(require(Permission::TriageQueueStats)),
+        )
+        .route(
+            "/triage/queue/{visit_type}/ws",
+            get(triage_queue_ws).route_layer(display_feed(Permission::TriageQueueView)),
+        )
         .route(
             "/triage/queue/{visit_type}/{queue_number}",
-            get(triage_queue_status),
+            get(triage_queue_status).route_layer(require(Permission::TriageQueueView)),
         )
         .route(
             "/triage/call/{visit_type}/{queue_number}",
-            patch(triage_call_patient),
+            patch(triage_call_patient).route_layer(require(Permission::TriageCall)),
+        )
+        .route(
+            "/triage/recall/{visit_type}/{queue_number}",
+            patch(triage_recall_patient).route_layer(require(Permission::TriageCall)),
+        )
+        .route(
+            "/triage/skip/{visit_type}/{queue_number}",
+            patch(triage_skip_patient).route_layer(require(Permission::TriageCall)),
+        )
+        .route(
+            "/triage/no-show/{visit_type}/{queue_number}",
+            patch(triage_no_show).route_layer(require(Permission::TriageCall)),
+        )
+        .route(
+            "/triage/requeue/{visit_type}/{queue_number}",
+            patch(triage_requeue_patient).route_layer(require(Permission::TriageCall)),
         )
         .route(
             "/triage/complete/{visit_type}/{queue_number}",
-            patch(triage_complete),
+            patch(triage_complete).route_layer(require(Permission::TriageComplete)),
         )
         .route(
             "/triage/queue/{visit_type}/{queue_number}/cancel",
-            patch(triage_patient_cancel),
+            patch(triage_patient_cancel).route_layer(require(Permission::TriageCancel)),
+        )
+        .route(
+            "/triage/polyclinic/{polyclinic_code}/queue",
+            get(triage_queue).route_layer(require(Permission::TriageQueueView)),
+        )
+        .route(
+            "/triage/polyclinic/{polyclinic_code}/queue/events",
+            get(triage_queue_events).route_layer(display_feed(Permission::TriageQueueView)),
+        )
+        .route(
+            "/triage/polyclinic/{polyclinic_code}/queue/stats",
+            get(triage_queue_stats).route_layer(require(Permission::TriageQueueStats)),
+        )
+        .route(
+            "/triage/polyclinic/{polyclinic_code}/queue/ws",
+            get(triage_queue_ws).route_layer(display_feed(Permission::TriageQueueView)),
+        )
+        .route(
+            "/triage/polyclinic/{polyclinic_code}/queue/{queue_number}",
+            get(triage_queue_status).route_layer(require(Permission::TriageQueueView)),
+        )
+        .route(
+            "/triage/polyclinic/{polyclinic_code}/call/{queue_number}",
+            patch(triage_call_patient).route_layer(require(Permission::TriageCall)),
+        )
+        .route(
+            "/triage/polyclinic/{polyclinic_code}/recall/{queue_number}",
+            patch(triage_recall_patient).route_layer(require(Permission::TriageCall)),
+        )
+        .route(
+            "/triage/polyclinic/{polyclinic_code}/skip/{queue_number}",
+            patch(triage_skip_patient).route_layer(require(Permission::TriageCall)),
+        )
+        .route(
+            "/triage/polyclinic/{polyclinic_code}/no-show/{queue_number}",
+            patch(triage_no_show).route_layer(require(Permission::TriageCall)),
+        )
+        .route(
+            "/triage/polyclinic/{polyclinic_code}/requeue/{queue_number}",
+            patch(triage_requeue_patient).route_layer(require(Permission::TriageCall)),
+        )
+        .route(
+            "/triage/polyclinic/{polyclinic_code}/complete/{queue_number}",
+            patch(triage_complete).route_layer(require(Permission::TriageComplete)),
+        )
+        .route(
+            "/triage/polyclinic/{polyclinic_code}/queue/{queue_number}/cancel",
+            patch(triage_patient_cancel).route_layer(require(Permission::TriageCancel)),
         )
         .route(
             "/triage/patient/{patient_id}/{visit_id}/upload",
-            post(triage_referral_document_upload),
+            post(triage_referral_document_upload).route_layer(require(Permission::ReferralUpload)),
         )
+        .layer(middleware::from_fn(fingerprint_request))
         .layer(ErrorHandlingLayer)
-        .with_state(app_state)
+        .with_state(app_state.clone())
 }
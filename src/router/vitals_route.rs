@@ -0,0 +1,39 @@
+use axum::middleware;
+use axum::{
+    Router,
+    routing::{get, post},
+};
+
+use crate::handlers::vitals::vitals_handler::{
+    get_patient_vitals, get_patient_vitals_trend, get_visit_vitals, record_vitals,
+};
+
+use crate::middleware::auth_middleware::RequirePermissionLayer;
+use crate::middleware::error_handler_layer::ErrorHandlingLayer;
+use crate::middleware::permission::Permission;
+use crate::middleware::request_middleware::assign_request_id;
+use crate::state::AppState;
+
+pub fn vitals_routes(app_state: AppState) -> Router {
+    let require =
+        |permission: Permission| RequirePermissionLayer::new(app_state.clone(), permission);
+
+    Router::new()
+        .layer(middleware::from_fn(assign_request_id))
+        .route(
+            "/visits/{visit_intent_id}/vitals",
+            get(get_visit_vitals)
+                .route_layer(require(Permission::VitalsView))
+                .merge(post(record_vitals).route_layer(require(Permission::VitalsRecord))),
+        )
+        .route(
+            "/patients/{patient_id}/vitals",
+            get(get_patient_vitals).route_layer(require(Permission::VitalsView)),
+        )
+        .route(
+            "/patients/{patient_id}/vitals/trend",
+            get(get_patient_vitals_trend).route_layer(require(Permission::VitalsView)),
+        )
+        .layer(ErrorHandlingLayer)
+        .with_state(app_state.clone())
+}
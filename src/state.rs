@@ -9,7 +9,11 @@ use bb8::Pool;
 use bb8_redis::RedisConnectionManager;
 use sea_orm::{ConnectOptions, Database, DatabaseConnection};
 
-use crate::infra::config::S3Config;
+use crate::infra::{
+    config::{JwtConfig, MrnConfig, QueueConfig, S3Config},
+    drug_interactions::InteractionCheckers,
+    queue_events::QueueEventBus,
+};
 
 type RedisPool = Pool<RedisConnectionManager>;
 #[derive(Clone)]
@@ -17,6 +21,11 @@ pub struct AppState {
     pub db: DatabaseConnection,
     pub redis: RedisPool,
     pub s3: Client,
+    pub jwt: JwtConfig,
+    pub queue_events: QueueEventBus,
+    pub queue: QueueConfig,
+    pub mrn: MrnConfig,
+    pub interactions: InteractionCheckers,
 }
 
 pub async fn init_database_connection(url: &str) -> DatabaseConnection {
@@ -0,0 +1,57 @@
+use async_trait::async_trait;
+use entity::{patient_allergies, patients};
+use sea_orm::{DatabaseConnection, DatabaseTransaction};
+
+use crate::{
+    dtos::allergy::allergy_request::{CreateAllergyRequest, UpdateAllergyRequest},
+    error_handling::app_error::AppError,
+};
+
+#[async_trait]
+pub trait AllergyTraitRepo {
+    /// Allergies on record for a patient, most severe first. Removed entries are left out.
+    async fn find_patient_allergies(
+        db: &DatabaseConnection,
+        patient_id: i32,
+    ) -> Result<Vec<patient_allergies::Model>, AppError>;
+    async fn find_allergy_for_update(
+        txn: &DatabaseTransaction,
+        patient_id: i32,
+        allergy_id: i32,
+    ) -> Result<patient_allergies::Model, AppError>;
+    async fn create_allergy(
+        db: &DatabaseConnection,
+        patient_id: i32,
+        payload: CreateAllergyRequest,
+        recorded_by: i32,
+    ) -> Result<patient_allergies::Model, AppError>;
+    /// Records the allergies not already on the patient's list, as at triage; a
+    /// substance already recorded keeps its existing entry.
+    async fn record_missing_allergies(
+        txn: &DatabaseTransaction,
+        patient_id: i32,
+        allergies: Vec<CreateAllergyRequest>,
+        recorded_by: i32,
+    ) -> Result<Vec<patient_allergies::Model>, AppError>;
+    /// Clears the free-text allergy note once it has been converted into the allergy
+    /// list, writing the change to the patient history.
+    async fn clear_legacy_allergies(
+        txn: &DatabaseTransaction,
+        patient: patients::Model,
+        converted_by: i32,
+    ) -> Result<patients::Model, AppError>;
+    async fn update_allergy(
+        txn: &DatabaseTransaction,
+        allergy: patient_allergies::Model,
+        payload: UpdateAllergyRequest,
+    ) -> Result<patient_allergies::Model, AppError>;
+    async fn verify_allergy(
+        txn: &DatabaseTransaction,
+        allergy: patient_allergies::Model,
+        verified_by: i32,
+    ) -> Result<patient_allergies::Model, AppError>;
+    async fn remove_allergy(
+        txn: &DatabaseTransaction,
+        allergy: patient_allergies::Model,
+    ) -> Result<patient_allergies::Model, AppError>;
+}
@@ -0,0 +1,50 @@
+use async_trait::async_trait;
+use sea_orm::DatabaseConnection;
+
+use crate::{
+    dtos::allergy::{
+        allergy_request::{
+            ConvertLegacyAllergiesRequest, CreateAllergyRequest, UpdateAllergyRequest,
+        },
+        response::{AllergyRemoved, AllergyResponse, PatientAllergiesResponse},
+    },
+    error_handling::app_error::AppError,
+};
+
+#[async_trait]
+pub trait AllergyServiceContracts {
+    async fn get_patient_allergies(
+        db: &DatabaseConnection,
+        patient_id: i32,
+    ) -> Result<PatientAllergiesResponse, AppError>;
+    async fn record_allergy(
+        db: &DatabaseConnection,
+        patient_id: i32,
+        payload: CreateAllergyRequest,
+        recorded_by: i32,
+    ) -> Result<AllergyResponse, AppError>;
+    /// Records the entries transcribed from the free-text allergy note and clears it.
+    async fn convert_legacy_allergies(
+        db: &DatabaseConnection,
+        patient_id: i32,
+        payload: ConvertLegacyAllergiesRequest,
+        converted_by: i32,
+    ) -> Result<PatientAllergiesResponse, AppError>;
+    async fn update_allergy(
+        db: &DatabaseConnection,
+        patient_id: i32,
+        allergy_id: i32,
+        payload: UpdateAllergyRequest,
+    ) -> Result<AllergyResponse, AppError>;
+    async fn verify_allergy(
+        db: &DatabaseConnection,
+        patient_id: i32,
+        allergy_id: i32,
+        verified_by: i32,
+    ) -> Result<AllergyResponse, AppError>;
+    async fn remove_allergy(
+        db: &DatabaseConnection,
+        patient_id: i32,
+        allergy_id: i32,
+    ) -> Result<AllergyRemoved, AppError>;
+}
@@ -0,0 +1,4 @@
+pub mod allergy_repo_contract;
+pub mod allergy_service_contract;
+pub use self::allergy_repo_contract::AllergyTraitRepo;
+pub use self::allergy_service_contract::AllergyServiceContracts;
@@ -0,0 +1,260 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use entity::{
+    patient_allergies::{self, ActiveModel, AllergySeverity},
+    patient_history::{self, PatientChangeSource},
+    patients,
+};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, ConnectionTrait, DatabaseConnection,
+    DatabaseTransaction, EntityTrait, IntoActiveModel, QueryFilter, QueryOrder, QuerySelect,
+    sea_query::Expr,
+};
+
+use crate::{
+    dtos::{
+        allergy::allergy_request::{CreateAllergyRequest, UpdateAllergyRequest},
+        patient::response::PatientFieldChange,
+    },
+    error_handling::app_error::AppError,
+    use_cases::allergy::contracts::allergy_repo_contract::AllergyTraitRepo,
+};
+
+pub struct AllergyRepo;
+
+fn severity_rank(severity: AllergySeverity) -> u8 {
+    match severity {
+        AllergySeverity::LifeThreatening => 0,
+        AllergySeverity::Severe => 1,
+        AllergySeverity::Moderate => 2,
+        AllergySeverity::Mild => 3,
+    }
+}
+
+async fn substance_recorded(
+    db: &impl ConnectionTrait,
+    patient_id: i32,
+    substance: &str,
+    except_id: Option<i32>,
+) -> Result<bool, AppError> {
+    let mut query = patient_allergies::Entity::find()
+        .filter(patient_allergies::Column::PatientId.eq(patient_id))
+        .filter(patient_allergies::Column::DeletedAt.is_null())
+        .filter(Expr::cust_with_values(
+            "lower(substance) = lower($1)",
+            [substance.trim()],
+        ));
+    if let Some(except_id) = except_id {
+        query = query.filter(patient_allergies::Column::Id.ne(except_id));
+    }
+
+    Ok(query.one(db).await?.is_some())
+}
+
+async fn ensure_substance_unrecorded(
+    db: &impl ConnectionTrait,
+    patient_id: i32,
+    substance: &str,
+    except_id: Option<i32>,
+) -> Result<(), AppError> {
+    if substance_recorded(db, patient_id, substance, except_id).await? {
+        return Err(AppError::Conflict(format!(
+            "An allergy to {} is already recorded for this patient",
+            substance.trim()
+        )));
+    }
+    Ok(())
+}
+
+#[async_trait]
+impl AllergyTraitRepo for AllergyRepo {
+    async fn find_patient_allergies(
+        db: &DatabaseConnection,
+        patient_id: i32,
+    ) -> Result<Vec<patient_allergies::Model>, AppError> {
+        let mut allergies = patient_allergies::Entity::find()
+            .filter(patient_allergies::Column::PatientId.eq(patient_id))
+            .filter(patient_allergies::Column::DeletedAt.is_null())
+            .order_by_asc(patient_allergies::Column::Substance)
+            .all(db)
+            .await?;
+        allergies.sort_by_key(|allergy| severity_rank(allergy.severity));
+
+        Ok(allergies)
+    }
+
+    async fn find_allergy_for_update(
+        txn: &DatabaseTransaction,
+        patient_id: i32,
+        allergy_id: i32,
+    ) -> Result<patient_allergies::Model, AppError> {
+        patient_allergies::Entity::find_by_id(allergy_id)
+            .filter(patient_allergies::Column::PatientId.eq(patient_id))
+            .filter(patient_allergies::Column::DeletedAt.is_null())
+            .lock_exclusive()
+            .one(txn)
+            .await?
+            .ok_or(AppError::NotFound(format!(
+                "Allergy with id {} is not found for patient {}",
+                allergy_id, patient_id
+            )))
+    }
+
+    async fn create_allergy(
+        db: &DatabaseConnection,
+        patient_id: i32,
+        payload: CreateAllergyRequest,
+        recorded_by: i32,
+    ) -> Result<patient_allergies::Model, AppError> {
+        ensure_substance_unrecorded(db, patient_id, &payload.substance, None).await?;
+
+        let now = Utc::now().naive_utc();
+        let model = ActiveModel {
+            patient_id: Set(patient_id),
+            substance: Set(payload.substance.trim().to_string()),
+            category: Set(payload.category),
+            reaction: Set(payload.reaction),
+            severity: Set(payload.severity),
+            recorded_by: Set(Some(recorded_by)),
+            created_at: Set(now),
+            updated_at: Set(now),
+            ..Default::default()
+        };
+
+        Ok(model.insert(db).await?)
+    }
+
+    async fn record_missing_allergies(
+        txn: &DatabaseTransaction,
+        patient_id: i32,
+        allergies: Vec<CreateAllergyRequest>,
+        recorded_by: i32,
+    ) -> Result<Vec<patient_allergies::Model>, AppError> {
+        let mut recorded = Vec::new();
+        for payload in allergies {
+            if substance_recorded(txn, patient_id, &payload.substance, None).await? {
+                continue;
+            }
+
+            let now = Utc::now().naive_utc();
+            let model = ActiveModel {
+                patient_id: Set(patient_id),
+                substance: Set(payload.substance.trim().to_string()),
+                category: Set(payload.category),
+                reaction: Set(payload.reaction),
+                severity: Set(payload.severity),
+                recorded_by: Set(Some(recorded_by)),
+                created_at: Set(now),
+                updated_at: Set(now),
+                ..Default::default()
+            };
+            recorded.push(model.insert(txn).await?);
+        }
+
+        Ok(recorded)
+    }
+
+    async fn clear_legacy_allergies(
+        txn: &DatabaseTransaction,
+        patient: patients::Model,
+        converted_by: i32,
+    ) -> Result<patients::Model, AppError> {
+        let changes = vec![PatientFieldChange {
+            field: "known_allergies".to_string(),
+            previous: patient.known_allergies.clone(),
+            current: None,
+        }];
+
+        let patient_id = patient.id;
+        let now = Utc::now().naive_utc();
+        let mut model: patients::ActiveModel = patient.into_active_model();
+        model.known_allergies = Set(None);
+        model.updated_at = Set(now);
+        let patient = model.update(txn).await?;
+
+        patient_history::ActiveModel {
+            patient_id: Set(patient_id),
+            source: Set(PatientChangeSource::Registry),
+            changed_by: Set(Some(converted_by)),
+            changes: Set(serde_json::to_value(&changes)?),
+            created_at: Set(now),
+            ..Default::default()
+        }
+        .insert(txn)
+        .await?;
+
+        Ok(patient)
+    }
+
+    async fn update_allergy(
+        txn: &DatabaseTransaction,
+        allergy: patient_allergies::Model,
+        payload: UpdateAllergyRequest,
+    ) -> Result<patient_allergies::Model, AppError> {
+        if let Some(substance) = &payload.substance {
+            ensure_substance_unrecorded(txn, allergy.patient_id, substance, Some(allergy.id))
+                .await?;
+        }
+
+        let clinical_change =
+            payload.substance.as_deref().is_some_and(|substance| {
+                !substance.trim().eq_ignore_ascii_case(&allergy.substance)
+            }) || payload
+                .category
+                .is_some_and(|category| category != allergy.category)
+                || payload
+                    .reaction
+                    .as_ref()
+                    .is_some_and(|reaction| Some(reaction) != allergy.reaction.as_ref())
+                || payload
+                    .severity
+                    .is_some_and(|severity| severity != allergy.severity);
+
+        let mut model: ActiveModel = allergy.into_active_model();
+        if let Some(substance) = payload.substance {
+            model.substance = Set(substance.trim().to_string());
+        }
+        if let Some(category) = payload.category {
+            model.category = Set(category);
+        }
+        if let Some(reaction) = payload.reaction {
+            model.reaction = Set(Some(reaction));
+        }
+        if let Some(severity) = payload.severity {
+            model.severity = Set(severity);
+        }
+        if clinical_change {
+            model.verified_by = Set(None);
+            model.verified_at = Set(None);
+        }
+        model.updated_at = Set(Utc::now().naive_utc());
+
+        Ok(model.update(txn).await?)
+    }
+
+    async fn verify_allergy(
+        txn: &DatabaseTransaction,
+        allergy: patient_allergies::Model,
+        verified_by: i32,
+    ) -> Result<patient_allergies::Model, AppError> {
+        let now = Utc::now().naive_utc();
+        let mut model: ActiveModel = allergy.into_active_model();
+        model.verified_by = Set(Some(verified_by));
+        model.verified_at = Set(Some(now));
+        model.updated_at = Set(now);
+
+        Ok(model.update(txn).await?)
+    }
+
+    async fn remove_allergy(
+        txn: &DatabaseTransaction,
+        allergy: patient_allergies::Model,
+    ) -> Result<patient_allergies::Model, AppError> {
+        let now = Utc::now().naive_utc();
+        let mut model: ActiveModel = allergy.into_active_model();
+        model.deleted_at = Set(Some(now));
+        model.updated_at = Set(now);
+
+        Ok(model.update(txn).await?)
+    }
+}
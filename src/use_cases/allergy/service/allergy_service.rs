@@ -0,0 +1,165 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Local, NaiveDateTime, Utc};
+use entity::patient_allergies;
+use sea_orm::{ActiveEnum, DatabaseConnection, TransactionTrait};
+
+pub use crate::use_cases::allergy::contracts::allergy_service_contract::AllergyServiceContracts;
+use crate::{
+    dtos::allergy::{
+        allergy_request::{
+            ConvertLegacyAllergiesRequest, CreateAllergyRequest, UpdateAllergyRequest,
+        },
+        response::{AllergyRemoved, AllergyResponse, PatientAllergiesResponse},
+    },
+    error_handling::app_error::AppError,
+    format_created_at,
+    use_cases::{
+        allergy::{contracts::AllergyTraitRepo, repo::allergy_repo::AllergyRepo},
+        patient::{contracts::PatientTraitRepo, repo::patient_repo::PatientRepo},
+    },
+};
+
+pub struct AllergyService;
+
+fn allergy_response(allergy: patient_allergies::Model) -> AllergyResponse {
+    AllergyResponse {
+        id: allergy.id,
+        patient_id: allergy.patient_id,
+        substance: allergy.substance,
+        category: allergy.category.to_value(),
+        reaction: allergy.reaction,
+        severity: allergy.severity.to_value(),
+        recorded_by: allergy.recorded_by,
+        verified_by: allergy.verified_by,
+        verified_at: allergy
+            .verified_at
+            .map(|verified_at| format_created_at!(verified_at)),
+        created_at: format_created_at!(allergy.created_at),
+        updated_at: format_created_at!(allergy.updated_at),
+    }
+}
+
+#[async_trait]
+impl AllergyServiceContracts for AllergyService {
+    async fn get_patient_allergies(
+        db: &DatabaseConnection,
+        patient_id: i32,
+    ) -> Result<PatientAllergiesResponse, AppError> {
+        let patient = <PatientRepo as PatientTraitRepo>::find_patient_by_id(db, patient_id).await?;
+        let allergies =
+            <AllergyRepo as AllergyTraitRepo>::find_patient_allergies(db, patient_id).await?;
+
+        Ok(PatientAllergiesResponse {
+            patient_id,
+            legacy_known_allergies: patient.known_allergies,
+            data: allergies.into_iter().map(allergy_response).collect(),
+        })
+    }
+
+    async fn record_allergy(
+        db: &DatabaseConnection,
+        patient_id: i32,
+        payload: CreateAllergyRequest,
+        recorded_by: i32,
+    ) -> Result<AllergyResponse, AppError> {
+        <PatientRepo as PatientTraitRepo>::find_patient_by_id(db, patient_id).await?;
+        let allergy =
+            <AllergyRepo as AllergyTraitRepo>::create_allergy(db, patient_id, payload, recorded_by)
+                .await?;
+
+        Ok(allergy_response(allergy))
+    }
+
+    async fn convert_legacy_allergies(
+        db: &DatabaseConnection,
+        patient_id: i32,
+        payload: ConvertLegacyAllergiesRequest,
+        converted_by: i32,
+    ) -> Result<PatientAllergiesResponse, AppError> {
+        let txn = db.begin().await?;
+        let patient =
+            <PatientRepo as PatientTraitRepo>::find_patient_for_update(&txn, patient_id).await?;
+        if patient.known_allergies.is_none() {
+            return Err(AppError::BadRequest(format!(
+                "Patient {} has no free-text allergy note to convert",
+                patient_id
+            )));
+        }
+
+        <AllergyRepo as AllergyTraitRepo>::record_missing_allergies(
+            &txn,
+            patient_id,
+            payload.allergies,
+            converted_by,
+        )
+        .await?;
+        <AllergyRepo as AllergyTraitRepo>::clear_legacy_allergies(&txn, patient, converted_by)
+            .await?;
+        txn.commit().await?;
+
+        Self::get_patient_allergies(db, patient_id).await
+    }
+
+    async fn update_allergy(
+        db: &DatabaseConnection,
+        patient_id: i32,
+        allergy_id: i32,
+        payload: UpdateAllergyRequest,
+    ) -> Result<AllergyResponse, AppError> {
+        if payload.is_empty() {
+            return Err(AppError::BadRequest("Nothing to update".to_string()));
+        }
+
+        let txn = db.begin().await?;
+        let allergy = <AllergyRepo as AllergyTraitRepo>::find_allergy_for_update(
+            &txn, patient_id, allergy_id,
+        )
+        .await?;
+        let allergy =
+            <AllergyRepo as AllergyTraitRepo>::update_allergy(&txn, allergy, payload).await?;
+        txn.commit().await?;
+
+        Ok(allergy_response(allergy))
+    }
+
+    async fn verify_allergy(
+        db: &DatabaseConnection,
+        patient_id: i32,
+        allergy_id: i32,
+        verified_by: i32,
+    ) -> Result<AllergyResponse, AppError> {
+        let txn = db.begin().await?;
+        let allergy = <AllergyRepo as AllergyTraitRepo>::find_allergy_for_update(
+            &txn, patient_id, allergy_id,
+        )
+        .await?;
+        let allergy =
+            <AllergyRepo as AllergyTraitRepo>::verify_allergy(&txn, allergy, verified_by).await?;
+        txn.commit().await?;
+
+        Ok(allergy_response(allergy))
+    }
+
+    async fn remove_allergy(
+        db: &DatabaseConnection,
+        patient_id: i32,
+        allergy_id: i32,
+    ) -> Result<AllergyRemoved, AppError> {
+        let txn = db.begin().await?;
+        let allergy = <AllergyRepo as AllergyTraitRepo>::find_allergy_for_update(
+            &txn, patient_id, allergy_id,
+        )
+        .await?;
+        let allergy = <AllergyRepo as AllergyTraitRepo>::remove_allergy(&txn, allergy).await?;
+        txn.commit().await?;
+
+        Ok(AllergyRemoved {
+            id: allergy.id,
+            patient_id: allergy.patient_id,
+            removed_at: allergy
+                .deleted_at
+                .map(|deleted_at| format_created_at!(deleted_at))
+                .unwrap_or_default(),
+        })
+    }
+}
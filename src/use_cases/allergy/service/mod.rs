@@ -0,0 +1 @@
+pub mod allergy_service;
@@ -0,0 +1,21 @@
+use async_trait::async_trait;
+use entity::user;
+use sea_orm::DatabaseConnection;
+
+use crate::error_handling::app_error::AppError;
+
+#[async_trait]
+pub trait AuthTraitRepo {
+    async fn find_user_by_username(
+        db: &DatabaseConnection,
+        username: &str,
+    ) -> Result<Option<user::Model>, AppError>;
+    async fn find_user_by_id(
+        db: &DatabaseConnection,
+        user_id: i32,
+    ) -> Result<user::Model, AppError>;
+    async fn update_last_login(
+        db: &DatabaseConnection,
+        user: user::Model,
+    ) -> Result<user::Model, AppError>;
+}
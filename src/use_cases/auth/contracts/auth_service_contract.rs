@@ -0,0 +1,40 @@
+use async_trait::async_trait;
+use bb8::Pool;
+use bb8_redis::RedisConnectionManager;
+use sea_orm::DatabaseConnection;
+
+use crate::{
+    dtos::auth::{
+        auth_request::{LoginRequest, RefreshTokenRequest},
+        response::{DisplayTokenResponse, LoginResponse, LogoutResponse, RefreshTokenResponse},
+    },
+    error_handling::app_error::AppError,
+    infra::config::JwtConfig,
+};
+
+#[async_trait]
+pub trait AuthServiceContracts {
+    async fn login(
+        db: &DatabaseConnection,
+        redis: &Pool<RedisConnectionManager>,
+        jwt: &JwtConfig,
+        payload: LoginRequest,
+    ) -> Result<LoginResponse, AppError>;
+    async fn refresh_token(
+        db: &DatabaseConnection,
+        redis: &Pool<RedisConnectionManager>,
+        jwt: &JwtConfig,
+        payload: RefreshTokenRequest,
+    ) -> Result<RefreshTokenResponse, AppError>;
+    async fn issue_display_token(
+        db: &DatabaseConnection,
+        jwt: &JwtConfig,
+        user_id: i32,
+    ) -> Result<DisplayTokenResponse, AppError>;
+    async fn logout(
+        redis: &Pool<RedisConnectionManager>,
+        jwt: &JwtConfig,
+        payload: RefreshTokenRequest,
+        access_token: Option<String>,
+    ) -> Result<LogoutResponse, AppError>;
+}
@@ -0,0 +1,4 @@
+pub mod auth_repo_contract;
+pub mod auth_service_contract;
+pub use self::auth_repo_contract::AuthTraitRepo;
+pub use self::auth_service_contract::AuthServiceContracts;
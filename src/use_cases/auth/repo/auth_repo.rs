@@ -0,0 +1,45 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use entity::user;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter,
+};
+
+use crate::{
+    error_handling::app_error::AppError,
+    use_cases::auth::contracts::auth_repo_contract::AuthTraitRepo,
+};
+
+pub struct AuthRepo;
+
+#[async_trait]
+impl AuthTraitRepo for AuthRepo {
+    async fn find_user_by_username(
+        db: &DatabaseConnection,
+        username: &str,
+    ) -> Result<Option<user::Model>, AppError> {
+        Ok(user::Entity::find()
+            .filter(user::Column::Username.eq(username))
+            .one(db)
+            .await?)
+    }
+
+    async fn find_user_by_id(
+        db: &DatabaseConnection,
+        user_id: i32,
+    ) -> Result<user::Model, AppError> {
+        user::Entity::find_by_id(user_id)
+            .one(db)
+            .await?
+            .ok_or(AppError::Unauthorized("User no longer exists".into()))
+    }
+
+    async fn update_last_login(
+        db: &DatabaseConnection,
+        user: user::Model,
+    ) -> Result<user::Model, AppError> {
+        let mut active: user::ActiveModel = user.into();
+        active.last_login = Set(Some(Utc::now().naive_utc()));
+        Ok(active.update(db).await?)
+    }
+}
@@ -0,0 +1,208 @@
+use argon2::{Argon2, PasswordHash, PasswordVerifier};
+use async_trait::async_trait;
+use bb8::Pool;
+use bb8_redis::RedisConnectionManager;
+use chrono::{DateTime, Local, NaiveDateTime, Utc};
+use entity::user;
+use sea_orm::{ActiveEnum, DatabaseConnection};
+
+use crate::{
+    dtos::auth::{
+        auth_request::{LoginRequest, RefreshTokenRequest},
+        response::{
+            AuthUser, DisplayTokenResponse, LoginResponse, LogoutResponse, RefreshTokenResponse,
+        },
+    },
+    error_handling::app_error::AppError,
+    format_created_at,
+    infra::config::JwtConfig,
+    use_cases::auth::{
+        contracts::{
+            auth_repo_contract::AuthTraitRepo, auth_service_contract::AuthServiceContracts,
+        },
+        repo::auth_repo::AuthRepo,
+    },
+    utils::{
+        helpers::{set_cache_data, take_cache_data},
+        jwt::{TokenType, decode_token, issue_token},
+    },
+};
+
+pub struct AuthService;
+
+struct TokenPair {
+    access_token: String,
+    refresh_token: String,
+}
+
+async fn issue_token_pair(
+    redis: &Pool<RedisConnectionManager>,
+    jwt: &JwtConfig,
+    user: &user::Model,
+) -> Result<TokenPair, AppError> {
+    let (access_token, _) = issue_token(
+        user,
+        TokenType::Access,
+        &jwt.access_secret,
+        jwt.access_token_exp_secs,
+    )?;
+    let (refresh_token, refresh_claims) = issue_token(
+        user,
+        TokenType::Refresh,
+        &jwt.refresh_secret,
+        jwt.refresh_token_exp_secs,
+    )?;
+
+    // a refresh token is only usable while its session key lives in redis
+    let session_key = format!("auth:refresh:{}", refresh_claims.jti);
+    set_cache_data(redis, &session_key, &user.id, jwt.refresh_token_exp_secs).await?;
+
+    Ok(TokenPair {
+        access_token,
+        refresh_token,
+    })
+}
+
+#[async_trait]
+impl AuthServiceContracts for AuthService {
+    async fn login(
+        db: &DatabaseConnection,
+        redis: &Pool<RedisConnectionManager>,
+        jwt: &JwtConfig,
+        payload: LoginRequest,
+    ) -> Result<LoginResponse, AppError> {
+        let invalid_credentials = || AppError::Unauthorized("Invalid username or password".into());
+
+        let user = <AuthRepo as AuthTraitRepo>::find_user_by_username(db, &payload.username)
+            .await?
+            .ok_or_else(invalid_credentials)?;
+
+        let parsed_hash = PasswordHash::new(&user.password)
+            .map_err(|e| AppError::Internal(format!("Stored password hash is invalid: {e}")))?;
+        Argon2::default()
+            .verify_password(payload.password.as_bytes(), &parsed_hash)
+            .map_err(|_| invalid_credentials())?;
+
+        if !user.is_active {
+            return Err(AppError::Unauthorized("User account is inactive".into()));
+        }
+
+        let user = <AuthRepo as AuthTraitRepo>::update_last_login(db, user).await?;
+
+        let tokens = issue_token_pair(redis, jwt, &user).await?;
+
+        Ok(LoginResponse {
+            access_token: tokens.access_token,
+            refresh_token: tokens.refresh_token,
+            token_type: "Bearer".to_string(),
+            expires_in: jwt.access_token_exp_secs,
+            user: AuthUser {
+                id: user.id,
+                username: user.username,
+                role: user.role.to_value(),
+            },
+        })
+    }
+
+    async fn refresh_token(
+        db: &DatabaseConnection,
+        redis: &Pool<RedisConnectionManager>,
+        jwt: &JwtConfig,
+        payload: RefreshTokenRequest,
+    ) -> Result<RefreshTokenResponse, AppError> {
+        let claims = decode_token(
+            &payload.refresh_token,
+            &jwt.refresh_secret,
+            TokenType::Refresh,
+        )?;
+
+        // rotation: the old session is consumed, so a replayed refresh token is rejected
+        let session_key = format!("auth:refresh:{}", claims.jti);
+        let session_user_id =
+            take_cache_data::<i32>(redis, &session_key)
+                .await?
+                .ok_or(AppError::Unauthorized(
+                    "Refresh token has been revoked or already used".into(),
+                ))?;
+
+        if session_user_id != claims.user_id()? {
+            return Err(AppError::Unauthorized("Invalid refresh token".into()));
+        }
+
+        let user = <AuthRepo as AuthTraitRepo>::find_user_by_id(db, session_user_id).await?;
+        if !user.is_active {
+            return Err(AppError::Unauthorized("User account is inactive".into()));
+        }
+
+        let tokens = issue_token_pair(redis, jwt, &user).await?;
+
+        Ok(RefreshTokenResponse {
+            access_token: tokens.access_token,
+            refresh_token: tokens.refresh_token,
+            token_type: "Bearer".to_string(),
+            expires_in: jwt.access_token_exp_secs,
+        })
+    }
+
+    async fn issue_display_token(
+        db: &DatabaseConnection,
+        jwt: &JwtConfig,
+        user_id: i32,
+    ) -> Result<DisplayTokenResponse, AppError> {
+        let user = <AuthRepo as AuthTraitRepo>::find_user_by_id(db, user_id).await?;
+        if !user.is_active {
+            return Err(AppError::Unauthorized("User account is inactive".into()));
+        }
+
+        let (display_token, _) = issue_token(
+            &user,
+            TokenType::Display,
+            &jwt.access_secret,
+            jwt.display_token_exp_secs,
+        )?;
+
+        Ok(DisplayTokenResponse {
+            display_token,
+            expires_in: jwt.display_token_exp_secs,
+        })
+    }
+
+    async fn logout(
+        redis: &Pool<RedisConnectionManager>,
+        jwt: &JwtConfig,
+        payload: RefreshTokenRequest,
+        access_token: Option<String>,
+    ) -> Result<LogoutResponse, AppError> {
+        let claims = decode_token(
+            &payload.refresh_token,
+            &jwt.refresh_secret,
+            TokenType::Refresh,
+        )?;
+
+        let session_key = format!("auth:refresh:{}", claims.jti);
+        take_cache_data::<i32>(redis, &session_key).await?;
+
+        if let Some(token) = access_token {
+            let access_claims = decode_token(&token, &jwt.access_secret, TokenType::Access)?;
+            if access_claims.sub != claims.sub {
+                return Err(AppError::Unauthorized(
+                    "Access token does not belong to this session".into(),
+                ));
+            }
+
+            let revoked_key = format!("auth:revoked:{}", access_claims.jti);
+            set_cache_data(
+                redis,
+                &revoked_key,
+                &access_claims.user_id()?,
+                access_claims.remaining_secs(),
+            )
+            .await?;
+        }
+
+        Ok(LogoutResponse {
+            username: claims.username,
+            logged_out_at: format_created_at!(Utc::now().naive_utc()),
+        })
+    }
+}
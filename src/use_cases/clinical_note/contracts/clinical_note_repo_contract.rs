@@ -0,0 +1,81 @@
+use async_trait::async_trait;
+use entity::{clinical_note_addenda, clinical_notes, patients_visit_intent};
+use sea_orm::{DatabaseConnection, DatabaseTransaction};
+
+use crate::{
+    dtos::clinical_note::clinical_note_request::ClinicalNoteRequest,
+    error_handling::app_error::AppError,
+};
+
+/// A note with the patient it documents, its author and its addenda in the order
+/// they were written, each with its own author.
+pub struct ClinicalNoteRecord {
+    pub note: clinical_notes::Model,
+    pub patient_id: i32,
+    pub doctor_name: String,
+    pub addenda: Vec<(clinical_note_addenda::Model, String)>,
+}
+
+#[async_trait]
+pub trait ClinicalNoteTraitRepo {
+    /// The doctor profile of a user account, if it has one.
+    async fn find_doctor_id_for_user(
+        db: &DatabaseConnection,
+        user_id: i32,
+    ) -> Result<Option<i32>, AppError>;
+    async fn find_visit(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+    ) -> Result<patients_visit_intent::Model, AppError>;
+    async fn find_draft(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+        doctor_id: i32,
+    ) -> Result<Option<clinical_notes::Model>, AppError>;
+    async fn create_note(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+        doctor_id: i32,
+        payload: ClinicalNoteRequest,
+    ) -> Result<clinical_notes::Model, AppError>;
+    async fn find_note(
+        db: &DatabaseConnection,
+        note_id: i32,
+    ) -> Result<ClinicalNoteRecord, AppError>;
+    async fn find_note_for_update(
+        txn: &DatabaseTransaction,
+        note_id: i32,
+    ) -> Result<clinical_notes::Model, AppError>;
+    async fn update_draft(
+        txn: &DatabaseTransaction,
+        note: clinical_notes::Model,
+        payload: ClinicalNoteRequest,
+    ) -> Result<clinical_notes::Model, AppError>;
+    async fn sign_note(
+        txn: &DatabaseTransaction,
+        note: clinical_notes::Model,
+    ) -> Result<clinical_notes::Model, AppError>;
+    async fn delete_draft(
+        txn: &DatabaseTransaction,
+        note: clinical_notes::Model,
+    ) -> Result<(), AppError>;
+    async fn add_addendum(
+        txn: &DatabaseTransaction,
+        note_id: i32,
+        doctor_id: i32,
+        content: String,
+    ) -> Result<clinical_note_addenda::Model, AppError>;
+    /// Signed notes of a visit, oldest first, with the drafts of the viewing doctor.
+    async fn find_visit_notes(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+        viewer_doctor_id: Option<i32>,
+    ) -> Result<Vec<ClinicalNoteRecord>, AppError>;
+    /// Signed notes across all visits of a patient, most recently signed first, with
+    /// the drafts of the viewing doctor on top.
+    async fn find_patient_notes(
+        db: &DatabaseConnection,
+        patient_id: i32,
+        viewer_doctor_id: Option<i32>,
+    ) -> Result<Vec<ClinicalNoteRecord>, AppError>;
+}
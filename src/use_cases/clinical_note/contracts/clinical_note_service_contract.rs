@@ -0,0 +1,59 @@
+use async_trait::async_trait;
+use sea_orm::DatabaseConnection;
+
+use crate::{
+    dtos::clinical_note::{
+        clinical_note_request::{AddendumRequest, ClinicalNoteRequest},
+        response::{
+            ClinicalNoteResponse, NoteDiscarded, PatientNoteTimelineResponse, VisitNotesResponse,
+        },
+    },
+    error_handling::app_error::AppError,
+};
+
+#[async_trait]
+pub trait ClinicalNoteServiceContracts {
+    async fn create_note(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+        user_id: i32,
+        payload: ClinicalNoteRequest,
+    ) -> Result<ClinicalNoteResponse, AppError>;
+    async fn get_note(
+        db: &DatabaseConnection,
+        note_id: i32,
+        user_id: i32,
+    ) -> Result<ClinicalNoteResponse, AppError>;
+    async fn update_note(
+        db: &DatabaseConnection,
+        note_id: i32,
+        user_id: i32,
+        payload: ClinicalNoteRequest,
+    ) -> Result<ClinicalNoteResponse, AppError>;
+    async fn sign_note(
+        db: &DatabaseConnection,
+        note_id: i32,
+        user_id: i32,
+    ) -> Result<ClinicalNoteResponse, AppError>;
+    async fn discard_note(
+        db: &DatabaseConnection,
+        note_id: i32,
+        user_id: i32,
+    ) -> Result<NoteDiscarded, AppError>;
+    async fn add_addendum(
+        db: &DatabaseConnection,
+        note_id: i32,
+        user_id: i32,
+        payload: AddendumRequest,
+    ) -> Result<ClinicalNoteResponse, AppError>;
+    async fn get_visit_notes(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+        user_id: i32,
+    ) -> Result<VisitNotesResponse, AppError>;
+    async fn get_patient_timeline(
+        db: &DatabaseConnection,
+        patient_id: i32,
+        user_id: i32,
+    ) -> Result<PatientNoteTimelineResponse, AppError>;
+}
@@ -0,0 +1,4 @@
+pub mod clinical_note_repo_contract;
+pub mod clinical_note_service_contract;
+pub use self::clinical_note_repo_contract::ClinicalNoteTraitRepo;
+pub use self::clinical_note_service_contract::ClinicalNoteServiceContracts;
@@ -0,0 +1,327 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use entity::{
+    clinical_note_addenda,
+    clinical_notes::{self, ActiveModel, ClinicalNoteStatus},
+    doctors, patients_visit_intent, user,
+};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, Condition, DatabaseConnection,
+    DatabaseTransaction, EntityTrait, IntoActiveModel, ModelTrait, QueryFilter, QueryOrder,
+    QuerySelect, SqlErr,
+};
+
+use crate::{
+    dtos::clinical_note::clinical_note_request::ClinicalNoteRequest,
+    error_handling::app_error::AppError,
+    use_cases::clinical_note::contracts::clinical_note_repo_contract::{
+        ClinicalNoteRecord, ClinicalNoteTraitRepo,
+    },
+};
+
+pub struct ClinicalNoteRepo;
+
+/// A blank section is stored as absent.
+fn section(text: String) -> Option<String> {
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+/// Notes a viewer may read: every signed note, and drafts only to their author.
+fn visible_to(viewer_doctor_id: Option<i32>) -> Condition {
+    let visible =
+        Condition::any().add(clinical_notes::Column::Status.eq(ClinicalNoteStatus::Signed));
+    match viewer_doctor_id {
+        Some(doctor_id) => visible.add(clinical_notes::Column::DoctorId.eq(doctor_id)),
+        None => visible,
+    }
+}
+
+async fn with_details(
+    db: &DatabaseConnection,
+    notes: Vec<clinical_notes::Model>,
+) -> Result<Vec<ClinicalNoteRecord>, AppError> {
+    let note_ids = notes.iter().map(|note| note.id).collect::<Vec<_>>();
+    let visit_ids = notes
+        .iter()
+        .map(|note| note.visit_intent_id)
+        .collect::<Vec<_>>();
+
+    let mut addenda: HashMap<i32, Vec<clinical_note_addenda::Model>> = HashMap::new();
+    for addendum in clinical_note_addenda::Entity::find()
+        .filter(clinical_note_addenda::Column::NoteId.is_in(note_ids))
+        .order_by_asc(clinical_note_addenda::Column::CreatedAt)
+        .order_by_asc(clinical_note_addenda::Column::Id)
+        .all(db)
+        .await?
+    {
+        addenda.entry(addendum.note_id).or_default().push(addendum);
+    }
+
+    let patient_ids: HashMap<i32, i32> = patients_visit_intent::Entity::find()
+        .filter(patients_visit_intent::Column::Id.is_in(visit_ids))
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|visit| (visit.id, visit.patient_id))
+        .collect();
+
+    let doctor_ids = notes
+        .iter()
+        .map(|note| note.doctor_id)
+        .chain(
+            addenda
+                .values()
+                .flatten()
+                .map(|addendum| addendum.doctor_id),
+        )
+        .collect::<Vec<_>>();
+    let doctor_names: HashMap<i32, String> = doctors::Entity::find()
+        .filter(doctors::Column::Id.is_in(doctor_ids))
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|doctor| (doctor.id, doctor.name))
+        .collect();
+    let doctor_name = |doctor_id: i32| doctor_names.get(&doctor_id).cloned().unwrap_or_default();
+
+    Ok(notes
+        .into_iter()
+        .filter_map(|note| {
+            let patient_id = *patient_ids.get(&note.visit_intent_id)?;
+            let addenda = addenda
+                .remove(&note.id)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|addendum| {
+                    let name = doctor_name(addendum.doctor_id);
+                    (addendum, name)
+                })
+                .collect();
+            Some(ClinicalNoteRecord {
+                doctor_name: doctor_name(note.doctor_id),
+                patient_id,
+                addenda,
+                note,
+            })
+        })
+        .collect())
+}
+
+#[async_trait]
+impl ClinicalNoteTraitRepo for ClinicalNoteRepo {
+    async fn find_doctor_id_for_user(
+        db: &DatabaseConnection,
+        user_id: i32,
+    ) -> Result<Option<i32>, AppError> {
+        let Some(account) = user::Entity::find_by_id(user_id).one(db).await? else {
+            return Ok(None);
+        };
+
+        Ok(doctors::Entity::find()
+            .filter(doctors::Column::EmployeeId.eq(account.employee_id))
+            .one(db)
+            .await?
+            .map(|doctor| doctor.id))
+    }
+
+    async fn find_visit(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+    ) -> Result<patients_visit_intent::Model, AppError> {
+        patients_visit_intent::Entity::find_by_id(visit_intent_id)
+            .one(db)
+            .await?
+            .ok_or(AppError::NotFound(format!(
+                "Visit with id {} is not found",
+                visit_intent_id
+            )))
+    }
+
+    async fn find_draft(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+        doctor_id: i32,
+    ) -> Result<Option<clinical_notes::Model>, AppError> {
+        Ok(clinical_notes::Entity::find()
+            .filter(clinical_notes::Column::VisitIntentId.eq(visit_intent_id))
+            .filter(clinical_notes::Column::DoctorId.eq(doctor_id))
+            .filter(clinical_notes::Column::Status.eq(ClinicalNoteStatus::Draft))
+            .one(db)
+            .await?)
+    }
+
+    async fn create_note(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+        doctor_id: i32,
+        payload: ClinicalNoteRequest,
+    ) -> Result<clinical_notes::Model, AppError> {
+        let now = Utc::now().naive_utc();
+        let model = ActiveModel {
+            visit_intent_id: Set(visit_intent_id),
+            doctor_id: Set(doctor_id),
+            status: Set(ClinicalNoteStatus::Draft),
+            subjective: Set(payload.subjective.and_then(section)),
+            objective: Set(payload.objective.and_then(section)),
+            assessment: Set(payload.assessment.and_then(section)),
+            plan: Set(payload.plan.and_then(section)),
+            created_at: Set(now),
+            updated_at: Set(now),
+            ..Default::default()
+        };
+
+        model.insert(db).await.map_err(|err| match err.sql_err() {
+            Some(SqlErr::UniqueConstraintViolation(_)) => AppError::Conflict(format!(
+                "You already have a draft note for visit {}",
+                visit_intent_id
+            )),
+            _ => err.into(),
+        })
+    }
+
+    async fn find_note(
+        db: &DatabaseConnection,
+        note_id: i32,
+    ) -> Result<ClinicalNoteRecord, AppError> {
+        let note = clinical_notes::Entity::find_by_id(note_id)
+            .one(db)
+            .await?
+            .ok_or(AppError::NotFound(format!(
+                "Clinical note with id {} is not found",
+                note_id
+            )))?;
+
+        with_details(db, vec![note])
+            .await?
+            .pop()
+            .ok_or(AppError::NotFound(format!(
+                "Visit of clinical note {} is not found",
+                note_id
+            )))
+    }
+
+    async fn find_note_for_update(
+        txn: &DatabaseTransaction,
+        note_id: i32,
+    ) -> Result<clinical_notes::Model, AppError> {
+        clinical_notes::Entity::find_by_id(note_id)
+            .lock_exclusive()
+            .one(txn)
+            .await?
+            .ok_or(AppError::NotFound(format!(
+                "Clinical note with id {} is not found",
+                note_id
+            )))
+    }
+
+    async fn update_draft(
+        txn: &DatabaseTransaction,
+        note: clinical_notes::Model,
+        payload: ClinicalNoteRequest,
+    ) -> Result<clinical_notes::Model, AppError> {
+        let mut model: ActiveModel = note.into_active_model();
+        if let Some(subjective) = payload.subjective {
+            model.subjective = Set(section(subjective));
+        }
+        if let Some(objective) = payload.objective {
+            model.objective = Set(section(objective));
+        }
+        if let Some(assessment) = payload.assessment {
+            model.assessment = Set(section(assessment));
+        }
+        if let Some(plan) = payload.plan {
+            model.plan = Set(section(plan));
+        }
+        model.updated_at = Set(Utc::now().naive_utc());
+
+        Ok(model.update(txn).await?)
+    }
+
+    async fn sign_note(
+        txn: &DatabaseTransaction,
+        note: clinical_notes::Model,
+    ) -> Result<clinical_notes::Model, AppError> {
+        let now = Utc::now().naive_utc();
+        let mut model: ActiveModel = note.into_active_model();
+        model.status = Set(ClinicalNoteStatus::Signed);
+        model.signed_at = Set(Some(now));
+        model.updated_at = Set(now);
+
+        Ok(model.update(txn).await?)
+    }
+
+    async fn delete_draft(
+        txn: &DatabaseTransaction,
+        note: clinical_notes::Model,
+    ) -> Result<(), AppError> {
+        note.delete(txn).await?;
+        Ok(())
+    }
+
+    async fn add_addendum(
+        txn: &DatabaseTransaction,
+        note_id: i32,
+        doctor_id: i32,
+        content: String,
+    ) -> Result<clinical_note_addenda::Model, AppError> {
+        let model = clinical_note_addenda::ActiveModel {
+            note_id: Set(note_id),
+            doctor_id: Set(doctor_id),
+            content: Set(content.trim().to_string()),
+            created_at: Set(Utc::now().naive_utc()),
+            ..Default::default()
+        };
+
+        Ok(model.insert(txn).await?)
+    }
+
+    async fn find_visit_notes(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+        viewer_doctor_id: Option<i32>,
+    ) -> Result<Vec<ClinicalNoteRecord>, AppError> {
+        let notes = clinical_notes::Entity::find()
+            .filter(clinical_notes::Column::VisitIntentId.eq(visit_intent_id))
+            .filter(visible_to(viewer_doctor_id))
+            .order_by_asc(clinical_notes::Column::CreatedAt)
+            .order_by_asc(clinical_notes::Column::Id)
+            .all(db)
+            .await?;
+
+        with_details(db, notes).await
+    }
+
+    async fn find_patient_notes(
+        db: &DatabaseConnection,
+        patient_id: i32,
+        viewer_doctor_id: Option<i32>,
+    ) -> Result<Vec<ClinicalNoteRecord>, AppError> {
+        let visit_ids = patients_visit_intent::Entity::find()
+            .select_only()
+            .column(patients_visit_intent::Column::Id)
+            .filter(patients_visit_intent::Column::PatientId.eq(patient_id))
+            .into_tuple::<i32>()
+            .all(db)
+            .await?;
+
+        let mut notes = clinical_notes::Entity::find()
+            .filter(clinical_notes::Column::VisitIntentId.is_in(visit_ids))
+            .filter(visible_to(viewer_doctor_id))
+            .all(db)
+            .await?;
+        // Drafts have no signing time yet and sort ahead of everything signed
+        notes.sort_by(|a, b| {
+            b.signed_at
+                .is_none()
+                .cmp(&a.signed_at.is_none())
+                .then(b.signed_at.cmp(&a.signed_at))
+                .then(b.updated_at.cmp(&a.updated_at))
+                .then(b.id.cmp(&a.id))
+        });
+
+        with_details(db, notes).await
+    }
+}
@@ -0,0 +1 @@
+pub mod clinical_note_repo;
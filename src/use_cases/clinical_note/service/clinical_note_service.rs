@@ -0,0 +1,292 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Local, NaiveDateTime, Utc};
+use entity::clinical_notes::{self, ClinicalNoteStatus};
+use sea_orm::{ActiveEnum, DatabaseConnection, TransactionTrait};
+
+pub use crate::use_cases::clinical_note::contracts::clinical_note_service_contract::ClinicalNoteServiceContracts;
+use crate::{
+    dtos::clinical_note::{
+        clinical_note_request::{AddendumRequest, ClinicalNoteRequest},
+        response::{
+            AddendumResponse, ClinicalNoteResponse, NoteDiscarded, PatientNoteTimelineResponse,
+            VisitNotesResponse,
+        },
+    },
+    error_handling::app_error::AppError,
+    format_created_at,
+    use_cases::{
+        clinical_note::{
+            contracts::{ClinicalNoteTraitRepo, clinical_note_repo_contract::ClinicalNoteRecord},
+            repo::clinical_note_repo::ClinicalNoteRepo,
+        },
+        encounter::{contracts::EncounterTraitRepo, repo::encounter_repo::EncounterRepo},
+        patient::{contracts::PatientTraitRepo, repo::patient_repo::PatientRepo},
+    },
+};
+
+pub struct ClinicalNoteService;
+
+fn note_response(record: ClinicalNoteRecord) -> ClinicalNoteResponse {
+    let ClinicalNoteRecord {
+        note,
+        patient_id,
+        doctor_name,
+        addenda,
+    } = record;
+
+    ClinicalNoteResponse {
+        id: note.id,
+        visit_intent_id: note.visit_intent_id,
+        patient_id,
+        doctor_id: note.doctor_id,
+        doctor_name,
+        status: note.status.to_value(),
+        subjective: note.subjective,
+        objective: note.objective,
+        assessment: note.assessment,
+        plan: note.plan,
+        signed_at: note
+            .signed_at
+            .map(|signed_at| format_created_at!(signed_at)),
+        created_at: format_created_at!(note.created_at),
+        updated_at: format_created_at!(note.updated_at),
+        addenda: addenda
+            .into_iter()
+            .map(|(addendum, doctor_name)| AddendumResponse {
+                id: addendum.id,
+                note_id: addendum.note_id,
+                doctor_id: addendum.doctor_id,
+                doctor_name,
+                content: addendum.content,
+                created_at: format_created_at!(addendum.created_at),
+            })
+            .collect(),
+    }
+}
+
+/// Only the author changes a draft, and a signed note is changed by no one.
+fn ensure_editable(note: &clinical_notes::Model, doctor_id: i32) -> Result<(), AppError> {
+    if note.status == ClinicalNoteStatus::Signed {
+        return Err(AppError::Conflict(format!(
+            "Clinical note {} is signed and can only be amended with an addendum",
+            note.id
+        )));
+    }
+    if note.doctor_id != doctor_id {
+        return Err(AppError::Forbidden(format!(
+            "Clinical note {} is a draft of another doctor",
+            note.id
+        )));
+    }
+    Ok(())
+}
+
+#[async_trait]
+impl ClinicalNoteServiceContracts for ClinicalNoteService {
+    async fn create_note(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+        user_id: i32,
+        payload: ClinicalNoteRequest,
+    ) -> Result<ClinicalNoteResponse, AppError> {
+        let doctor =
+            <EncounterRepo as EncounterTraitRepo>::find_doctor_for_user(db, user_id).await?;
+        let visit =
+            <EncounterRepo as EncounterTraitRepo>::find_completed_visit(db, visit_intent_id)
+                .await?;
+
+        if let Some(draft) =
+            <ClinicalNoteRepo as ClinicalNoteTraitRepo>::find_draft(db, visit.id, doctor.id).await?
+        {
+            return Err(AppError::Conflict(format!(
+                "You already have draft note {} for visit {}",
+                draft.id, visit.id
+            )));
+        }
+
+        let note = <ClinicalNoteRepo as ClinicalNoteTraitRepo>::create_note(
+            db, visit.id, doctor.id, payload,
+        )
+        .await?;
+
+        let record = <ClinicalNoteRepo as ClinicalNoteTraitRepo>::find_note(db, note.id).await?;
+        Ok(note_response(record))
+    }
+
+    async fn get_note(
+        db: &DatabaseConnection,
+        note_id: i32,
+        user_id: i32,
+    ) -> Result<ClinicalNoteResponse, AppError> {
+        let record = <ClinicalNoteRepo as ClinicalNoteTraitRepo>::find_note(db, note_id).await?;
+
+        // A draft is not part of the record until it is signed
+        if record.note.status == ClinicalNoteStatus::Draft {
+            let viewer =
+                <ClinicalNoteRepo as ClinicalNoteTraitRepo>::find_doctor_id_for_user(db, user_id)
+                    .await?;
+            if viewer != Some(record.note.doctor_id) {
+                return Err(AppError::NotFound(format!(
+                    "Clinical note with id {} is not found",
+                    note_id
+                )));
+            }
+        }
+
+        Ok(note_response(record))
+    }
+
+    async fn update_note(
+        db: &DatabaseConnection,
+        note_id: i32,
+        user_id: i32,
+        payload: ClinicalNoteRequest,
+    ) -> Result<ClinicalNoteResponse, AppError> {
+        let doctor =
+            <EncounterRepo as EncounterTraitRepo>::find_doctor_for_user(db, user_id).await?;
+
+        let txn = db.begin().await?;
+        let note = <ClinicalNoteRepo as ClinicalNoteTraitRepo>::find_note_for_update(&txn, note_id)
+            .await?;
+        ensure_editable(&note, doctor.id)?;
+
+        <ClinicalNoteRepo as ClinicalNoteTraitRepo>::update_draft(&txn, note, payload).await?;
+        txn.commit().await?;
+
+        let record = <ClinicalNoteRepo as ClinicalNoteTraitRepo>::find_note(db, note_id).await?;
+        Ok(note_response(record))
+    }
+
+    async fn sign_note(
+        db: &DatabaseConnection,
+        note_id: i32,
+        user_id: i32,
+    ) -> Result<ClinicalNoteResponse, AppError> {
+        let doctor =
+            <EncounterRepo as EncounterTraitRepo>::find_doctor_for_user(db, user_id).await?;
+
+        let txn = db.begin().await?;
+        let note = <ClinicalNoteRepo as ClinicalNoteTraitRepo>::find_note_for_update(&txn, note_id)
+            .await?;
+        ensure_editable(&note, doctor.id)?;
+
+        if note.assessment.is_none() || note.plan.is_none() {
+            return Err(AppError::BadRequest(
+                "A note needs an assessment and a plan before it is signed".to_string(),
+            ));
+        }
+
+        <ClinicalNoteRepo as ClinicalNoteTraitRepo>::sign_note(&txn, note).await?;
+        txn.commit().await?;
+
+        let record = <ClinicalNoteRepo as ClinicalNoteTraitRepo>::find_note(db, note_id).await?;
+        Ok(note_response(record))
+    }
+
+    async fn discard_note(
+        db: &DatabaseConnection,
+        note_id: i32,
+        user_id: i32,
+    ) -> Result<NoteDiscarded, AppError> {
+        let doctor =
+            <EncounterRepo as EncounterTraitRepo>::find_doctor_for_user(db, user_id).await?;
+
+        let txn = db.begin().await?;
+        let note = <ClinicalNoteRepo as ClinicalNoteTraitRepo>::find_note_for_update(&txn, note_id)
+            .await?;
+        ensure_editable(&note, doctor.id)?;
+
+        let visit_intent_id = note.visit_intent_id;
+        <ClinicalNoteRepo as ClinicalNoteTraitRepo>::delete_draft(&txn, note).await?;
+        txn.commit().await?;
+
+        Ok(NoteDiscarded {
+            id: note_id,
+            visit_intent_id,
+            discarded_at: format_created_at!(Utc::now().naive_utc()),
+        })
+    }
+
+    async fn add_addendum(
+        db: &DatabaseConnection,
+        note_id: i32,
+        user_id: i32,
+        payload: AddendumRequest,
+    ) -> Result<ClinicalNoteResponse, AppError> {
+        let doctor =
+            <EncounterRepo as EncounterTraitRepo>::find_doctor_for_user(db, user_id).await?;
+        if payload.content.trim().is_empty() {
+            return Err(AppError::BadRequest(
+                "Addendum content is required".to_string(),
+            ));
+        }
+
+        // Share the lock with signing so an addendum never lands on a draft
+        let txn = db.begin().await?;
+        let note = <ClinicalNoteRepo as ClinicalNoteTraitRepo>::find_note_for_update(&txn, note_id)
+            .await?;
+        if note.status != ClinicalNoteStatus::Signed {
+            return Err(AppError::Conflict(format!(
+                "Clinical note {} is still a draft; edit it instead",
+                note_id
+            )));
+        }
+
+        <ClinicalNoteRepo as ClinicalNoteTraitRepo>::add_addendum(
+            &txn,
+            note.id,
+            doctor.id,
+            payload.content,
+        )
+        .await?;
+        txn.commit().await?;
+
+        let record = <ClinicalNoteRepo as ClinicalNoteTraitRepo>::find_note(db, note_id).await?;
+        Ok(note_response(record))
+    }
+
+    async fn get_visit_notes(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+        user_id: i32,
+    ) -> Result<VisitNotesResponse, AppError> {
+        let visit =
+            <ClinicalNoteRepo as ClinicalNoteTraitRepo>::find_visit(db, visit_intent_id).await?;
+        let viewer =
+            <ClinicalNoteRepo as ClinicalNoteTraitRepo>::find_doctor_id_for_user(db, user_id)
+                .await?;
+
+        let notes = <ClinicalNoteRepo as ClinicalNoteTraitRepo>::find_visit_notes(
+            db,
+            visit_intent_id,
+            viewer,
+        )
+        .await?;
+
+        Ok(VisitNotesResponse {
+            visit_intent_id,
+            patient_id: visit.patient_id,
+            data: notes.into_iter().map(note_response).collect(),
+        })
+    }
+
+    async fn get_patient_timeline(
+        db: &DatabaseConnection,
+        patient_id: i32,
+        user_id: i32,
+    ) -> Result<PatientNoteTimelineResponse, AppError> {
+        <PatientRepo as PatientTraitRepo>::find_patient_by_id(db, patient_id).await?;
+        let viewer =
+            <ClinicalNoteRepo as ClinicalNoteTraitRepo>::find_doctor_id_for_user(db, user_id)
+                .await?;
+
+        let notes =
+            <ClinicalNoteRepo as ClinicalNoteTraitRepo>::find_patient_notes(db, patient_id, viewer)
+                .await?;
+
+        Ok(PatientNoteTimelineResponse {
+            patient_id,
+            data: notes.into_iter().map(note_response).collect(),
+        })
+    }
+}
@@ -0,0 +1,67 @@
+use async_trait::async_trait;
+use entity::{
+    doctors, icd10_codes, patients_visit_intent,
+    visit_diagnoses::{self, DiagnosisType},
+};
+use sea_orm::{DatabaseConnection, DatabaseTransaction};
+
+use crate::error_handling::app_error::AppError;
+
+/// A diagnosis with its catalog entry and the doctor who recorded it.
+pub type DiagnosisRecord = (visit_diagnoses::Model, icd10_codes::Model, doctors::Model);
+
+#[async_trait]
+pub trait DiagnosisTraitRepo {
+    /// Active codes whose code starts with the term, ignoring the dot, followed by the
+    /// ones whose English or Indonesian description contains it.
+    async fn search_codes(
+        db: &DatabaseConnection,
+        term: &str,
+        limit: u64,
+    ) -> Result<Vec<icd10_codes::Model>, AppError>;
+    /// A catalog entry by its code, written with or without the dot.
+    async fn find_code(db: &DatabaseConnection, code: &str)
+    -> Result<icd10_codes::Model, AppError>;
+    async fn find_visit(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+    ) -> Result<patients_visit_intent::Model, AppError>;
+    async fn find_diagnosis(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+        diagnosis_id: i32,
+    ) -> Result<DiagnosisRecord, AppError>;
+    async fn find_diagnosis_for_update(
+        txn: &DatabaseTransaction,
+        visit_intent_id: i32,
+        diagnosis_id: i32,
+    ) -> Result<visit_diagnoses::Model, AppError>;
+    /// Diagnoses of a visit, the primary one first.
+    async fn find_visit_diagnoses(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+    ) -> Result<Vec<DiagnosisRecord>, AppError>;
+    /// Turns the current primary diagnosis of a visit, if any, into a secondary one.
+    async fn demote_primary(
+        txn: &DatabaseTransaction,
+        visit_intent_id: i32,
+    ) -> Result<(), AppError>;
+    async fn create_diagnosis(
+        txn: &DatabaseTransaction,
+        visit_intent_id: i32,
+        code: &icd10_codes::Model,
+        doctor_id: i32,
+        diagnosis_type: DiagnosisType,
+        notes: Option<String>,
+    ) -> Result<visit_diagnoses::Model, AppError>;
+    async fn update_diagnosis(
+        txn: &DatabaseTransaction,
+        diagnosis: visit_diagnoses::Model,
+        diagnosis_type: Option<DiagnosisType>,
+        notes: Option<String>,
+    ) -> Result<visit_diagnoses::Model, AppError>;
+    async fn delete_diagnosis(
+        txn: &DatabaseTransaction,
+        diagnosis: visit_diagnoses::Model,
+    ) -> Result<(), AppError>;
+}
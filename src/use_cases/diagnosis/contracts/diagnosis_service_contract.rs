@@ -0,0 +1,45 @@
+use async_trait::async_trait;
+use sea_orm::DatabaseConnection;
+
+use crate::{
+    dtos::diagnosis::{
+        diagnosis_request::{Icd10SearchQuery, RecordDiagnosisRequest, UpdateDiagnosisRequest},
+        response::{
+            DiagnosisRemoved, DiagnosisResponse, Icd10CodeResponse, Icd10SearchResponse,
+            VisitDiagnosesResponse,
+        },
+    },
+    error_handling::app_error::AppError,
+};
+
+#[async_trait]
+pub trait DiagnosisServiceContracts {
+    async fn search_codes(
+        db: &DatabaseConnection,
+        query: Icd10SearchQuery,
+    ) -> Result<Icd10SearchResponse, AppError>;
+    async fn get_code(db: &DatabaseConnection, code: &str) -> Result<Icd10CodeResponse, AppError>;
+    async fn record_diagnosis(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+        user_id: i32,
+        payload: RecordDiagnosisRequest,
+    ) -> Result<DiagnosisResponse, AppError>;
+    async fn update_diagnosis(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+        diagnosis_id: i32,
+        user_id: i32,
+        payload: UpdateDiagnosisRequest,
+    ) -> Result<DiagnosisResponse, AppError>;
+    async fn remove_diagnosis(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+        diagnosis_id: i32,
+        user_id: i32,
+    ) -> Result<DiagnosisRemoved, AppError>;
+    async fn get_visit_diagnoses(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+    ) -> Result<VisitDiagnosesResponse, AppError>;
+}
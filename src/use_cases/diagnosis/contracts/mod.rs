@@ -0,0 +1,4 @@
+pub mod diagnosis_repo_contract;
+pub mod diagnosis_service_contract;
+pub use self::diagnosis_repo_contract::DiagnosisTraitRepo;
+pub use self::diagnosis_service_contract::DiagnosisServiceContracts;
@@ -0,0 +1,259 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use entity::{
+    doctors, icd10_codes, patients_visit_intent,
+    visit_diagnoses::{self, ActiveModel, DiagnosisType},
+};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, DatabaseConnection, DatabaseTransaction,
+    EntityTrait, IntoActiveModel, ModelTrait, QueryFilter, QueryOrder, QuerySelect, SqlErr,
+    sea_query::Expr,
+};
+
+use crate::{
+    error_handling::app_error::AppError,
+    use_cases::diagnosis::contracts::diagnosis_repo_contract::{
+        DiagnosisRecord, DiagnosisTraitRepo,
+    },
+};
+
+pub struct DiagnosisRepo;
+
+/// Codes are compared upper-cased and without the dot, so `j069` finds `J06.9`.
+fn normalize_code(code: &str) -> String {
+    code.trim().replace('.', "").to_uppercase()
+}
+
+fn notes(text: String) -> Option<String> {
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+async fn with_catalog(
+    db: &DatabaseConnection,
+    diagnoses: Vec<visit_diagnoses::Model>,
+) -> Result<Vec<DiagnosisRecord>, AppError> {
+    let codes = diagnoses
+        .iter()
+        .map(|diagnosis| diagnosis.icd10_code.clone())
+        .collect::<Vec<_>>();
+    let doctor_ids = diagnoses
+        .iter()
+        .map(|diagnosis| diagnosis.doctor_id)
+        .collect::<Vec<_>>();
+
+    let catalog: HashMap<String, icd10_codes::Model> = icd10_codes::Entity::find()
+        .filter(icd10_codes::Column::Code.is_in(codes))
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|code| (code.code.clone(), code))
+        .collect();
+    let doctors: HashMap<i32, doctors::Model> = doctors::Entity::find()
+        .filter(doctors::Column::Id.is_in(doctor_ids))
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|doctor| (doctor.id, doctor))
+        .collect();
+
+    Ok(diagnoses
+        .into_iter()
+        .filter_map(|diagnosis| {
+            let code = catalog.get(&diagnosis.icd10_code)?.clone();
+            let doctor = doctors.get(&diagnosis.doctor_id)?.clone();
+            Some((diagnosis, code, doctor))
+        })
+        .collect())
+}
+
+#[async_trait]
+impl DiagnosisTraitRepo for DiagnosisRepo {
+    async fn search_codes(
+        db: &DatabaseConnection,
+        term: &str,
+        limit: u64,
+    ) -> Result<Vec<icd10_codes::Model>, AppError> {
+        let term = term.trim();
+        let code_prefix = normalize_code(term);
+
+        // The catalog is a few thousand rows at most, a scan is cheap enough
+        Ok(icd10_codes::Entity::find()
+            .filter(icd10_codes::Column::IsActive.eq(true))
+            .filter(Expr::cust_with_values(
+                "(starts_with(replace(code, '.', ''), $1) \
+                 OR strpos(lower(description_en), lower($2)) > 0 \
+                 OR strpos(lower(description_id), lower($3)) > 0)",
+                [code_prefix.as_str(), term, term],
+            ))
+            .order_by_asc(Expr::cust_with_values(
+                "CASE WHEN starts_with(replace(code, '.', ''), $1) THEN 0 ELSE 1 END",
+                [code_prefix.as_str()],
+            ))
+            .order_by_asc(icd10_codes::Column::Code)
+            .limit(limit)
+            .all(db)
+            .await?)
+    }
+
+    async fn find_code(
+        db: &DatabaseConnection,
+        code: &str,
+    ) -> Result<icd10_codes::Model, AppError> {
+        icd10_codes::Entity::find()
+            .filter(Expr::cust_with_values(
+                "replace(code, '.', '') = $1",
+                [normalize_code(code)],
+            ))
+            .one(db)
+            .await?
+            .ok_or(AppError::NotFound(format!(
+                "ICD-10 code {} is not found",
+                code.trim()
+            )))
+    }
+
+    async fn find_visit(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+    ) -> Result<patients_visit_intent::Model, AppError> {
+        patients_visit_intent::Entity::find_by_id(visit_intent_id)
+            .one(db)
+            .await?
+            .ok_or(AppError::NotFound(format!(
+                "Visit with id {} is not found",
+                visit_intent_id
+            )))
+    }
+
+    async fn find_diagnosis(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+        diagnosis_id: i32,
+    ) -> Result<DiagnosisRecord, AppError> {
+        let diagnosis = visit_diagnoses::Entity::find_by_id(diagnosis_id)
+            .filter(visit_diagnoses::Column::VisitIntentId.eq(visit_intent_id))
+            .one(db)
+            .await?
+            .ok_or(AppError::NotFound(format!(
+                "Diagnosis with id {} is not found for visit {}",
+                diagnosis_id, visit_intent_id
+            )))?;
+
+        with_catalog(db, vec![diagnosis])
+            .await?
+            .pop()
+            .ok_or(AppError::NotFound(format!(
+                "Diagnosis with id {} is not found for visit {}",
+                diagnosis_id, visit_intent_id
+            )))
+    }
+
+    async fn find_diagnosis_for_update(
+        txn: &DatabaseTransaction,
+        visit_intent_id: i32,
+        diagnosis_id: i32,
+    ) -> Result<visit_diagnoses::Model, AppError> {
+        visit_diagnoses::Entity::find_by_id(diagnosis_id)
+            .filter(visit_diagnoses::Column::VisitIntentId.eq(visit_intent_id))
+            .lock_exclusive()
+            .one(txn)
+            .await?
+            .ok_or(AppError::NotFound(format!(
+                "Diagnosis with id {} is not found for visit {}",
+                diagnosis_id, visit_intent_id
+            )))
+    }
+
+    async fn find_visit_diagnoses(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+    ) -> Result<Vec<DiagnosisRecord>, AppError> {
+        let mut diagnoses = visit_diagnoses::Entity::find()
+            .filter(visit_diagnoses::Column::VisitIntentId.eq(visit_intent_id))
+            .order_by_asc(visit_diagnoses::Column::CreatedAt)
+            .order_by_asc(visit_diagnoses::Column::Id)
+            .all(db)
+            .await?;
+        diagnoses.sort_by_key(|diagnosis| diagnosis.diagnosis_type != DiagnosisType::Primary);
+
+        with_catalog(db, diagnoses).await
+    }
+
+    async fn demote_primary(
+        txn: &DatabaseTransaction,
+        visit_intent_id: i32,
+    ) -> Result<(), AppError> {
+        visit_diagnoses::Entity::update_many()
+            .col_expr(
+                visit_diagnoses::Column::DiagnosisType,
+                Expr::value(DiagnosisType::Secondary),
+            )
+            .col_expr(
+                visit_diagnoses::Column::UpdatedAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .filter(visit_diagnoses::Column::VisitIntentId.eq(visit_intent_id))
+            .filter(visit_diagnoses::Column::DiagnosisType.eq(DiagnosisType::Primary))
+            .exec(txn)
+            .await?;
+        Ok(())
+    }
+
+    async fn create_diagnosis(
+        txn: &DatabaseTransaction,
+        visit_intent_id: i32,
+        code: &icd10_codes::Model,
+        doctor_id: i32,
+        diagnosis_type: DiagnosisType,
+        notes_text: Option<String>,
+    ) -> Result<visit_diagnoses::Model, AppError> {
+        let now = Utc::now().naive_utc();
+        let model = ActiveModel {
+            visit_intent_id: Set(visit_intent_id),
+            icd10_code: Set(code.code.clone()),
+            doctor_id: Set(doctor_id),
+            diagnosis_type: Set(diagnosis_type),
+            notes: Set(notes_text.and_then(notes)),
+            created_at: Set(now),
+            updated_at: Set(now),
+            ..Default::default()
+        };
+
+        model.insert(txn).await.map_err(|err| match err.sql_err() {
+            Some(SqlErr::UniqueConstraintViolation(_)) => AppError::Conflict(format!(
+                "Visit {} is already diagnosed with {}",
+                visit_intent_id, code.code
+            )),
+            _ => err.into(),
+        })
+    }
+
+    async fn update_diagnosis(
+        txn: &DatabaseTransaction,
+        diagnosis: visit_diagnoses::Model,
+        diagnosis_type: Option<DiagnosisType>,
+        notes_text: Option<String>,
+    ) -> Result<visit_diagnoses::Model, AppError> {
+        let mut model: ActiveModel = diagnosis.into_active_model();
+        if let Some(diagnosis_type) = diagnosis_type {
+            model.diagnosis_type = Set(diagnosis_type);
+        }
+        if let Some(notes_text) = notes_text {
+            model.notes = Set(notes(notes_text));
+        }
+        model.updated_at = Set(Utc::now().naive_utc());
+
+        Ok(model.update(txn).await?)
+    }
+
+    async fn delete_diagnosis(
+        txn: &DatabaseTransaction,
+        diagnosis: visit_diagnoses::Model,
+    ) -> Result<(), AppError> {
+        diagnosis.delete(txn).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1 @@
+pub mod diagnosis_repo;
@@ -0,0 +1,219 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Local, NaiveDateTime, Utc};
+use entity::{
+    icd10_codes,
+    visit_diagnoses::{self, DiagnosisType},
+};
+use sea_orm::{ActiveEnum, DatabaseConnection, TransactionTrait};
+
+pub use crate::use_cases::diagnosis::contracts::diagnosis_service_contract::DiagnosisServiceContracts;
+use crate::{
+    dtos::diagnosis::{
+        diagnosis_request::{Icd10SearchQuery, RecordDiagnosisRequest, UpdateDiagnosisRequest},
+        response::{
+            DiagnosisRemoved, DiagnosisResponse, Icd10CodeResponse, Icd10SearchResponse,
+            VisitDiagnosesResponse,
+        },
+    },
+    error_handling::app_error::AppError,
+    format_created_at,
+    use_cases::{
+        diagnosis::{
+            contracts::{DiagnosisTraitRepo, diagnosis_repo_contract::DiagnosisRecord},
+            repo::diagnosis_repo::DiagnosisRepo,
+        },
+        encounter::{contracts::EncounterTraitRepo, repo::encounter_repo::EncounterRepo},
+    },
+};
+
+pub struct DiagnosisService;
+
+const DEFAULT_SEARCH_LIMIT: u64 = 20;
+
+fn code_response(code: icd10_codes::Model) -> Icd10CodeResponse {
+    Icd10CodeResponse {
+        code: code.code,
+        description_en: code.description_en,
+        description_id: code.description_id,
+        is_active: code.is_active,
+    }
+}
+
+fn diagnosis_response((diagnosis, code, doctor): DiagnosisRecord) -> DiagnosisResponse {
+    DiagnosisResponse {
+        id: diagnosis.id,
+        visit_intent_id: diagnosis.visit_intent_id,
+        code: code.code,
+        description_en: code.description_en,
+        description_id: code.description_id,
+        diagnosis_type: diagnosis.diagnosis_type.to_value(),
+        notes: diagnosis.notes,
+        doctor_id: doctor.id,
+        doctor_name: doctor.name,
+        created_at: format_created_at!(diagnosis.created_at),
+        updated_at: format_created_at!(diagnosis.updated_at),
+    }
+}
+
+/// A diagnosis is changed only by the doctor who recorded it.
+fn ensure_author(diagnosis: &visit_diagnoses::Model, doctor_id: i32) -> Result<(), AppError> {
+    if diagnosis.doctor_id != doctor_id {
+        return Err(AppError::Forbidden(format!(
+            "Diagnosis {} was recorded by another doctor",
+            diagnosis.id
+        )));
+    }
+    Ok(())
+}
+
+#[async_trait]
+impl DiagnosisServiceContracts for DiagnosisService {
+    async fn search_codes(
+        db: &DatabaseConnection,
+        query: Icd10SearchQuery,
+    ) -> Result<Icd10SearchResponse, AppError> {
+        let codes = <DiagnosisRepo as DiagnosisTraitRepo>::search_codes(
+            db,
+            &query.q,
+            query.limit.unwrap_or(DEFAULT_SEARCH_LIMIT),
+        )
+        .await?;
+
+        Ok(Icd10SearchResponse {
+            query: query.q,
+            data: codes.into_iter().map(code_response).collect(),
+        })
+    }
+
+    async fn get_code(db: &DatabaseConnection, code: &str) -> Result<Icd10CodeResponse, AppError> {
+        let code = <DiagnosisRepo as DiagnosisTraitRepo>::find_code(db, code).await?;
+        Ok(code_response(code))
+    }
+
+    async fn record_diagnosis(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+        user_id: i32,
+        payload: RecordDiagnosisRequest,
+    ) -> Result<DiagnosisResponse, AppError> {
+        let doctor =
+            <EncounterRepo as EncounterTraitRepo>::find_doctor_for_user(db, user_id).await?;
+        let visit =
+            <EncounterRepo as EncounterTraitRepo>::find_completed_visit(db, visit_intent_id)
+                .await?;
+        let code = <DiagnosisRepo as DiagnosisTraitRepo>::find_code(db, &payload.code).await?;
+        if !code.is_active {
+            return Err(AppError::BadRequest(format!(
+                "ICD-10 code {} is retired and cannot be used for new diagnoses",
+                code.code
+            )));
+        }
+
+        // A new primary diagnosis takes over from the current one
+        let txn = db.begin().await?;
+        if payload.diagnosis_type == DiagnosisType::Primary {
+            <DiagnosisRepo as DiagnosisTraitRepo>::demote_primary(&txn, visit.id).await?;
+        }
+        let diagnosis = <DiagnosisRepo as DiagnosisTraitRepo>::create_diagnosis(
+            &txn,
+            visit.id,
+            &code,
+            doctor.id,
+            payload.diagnosis_type,
+            payload.notes,
+        )
+        .await?;
+        txn.commit().await?;
+
+        let record =
+            <DiagnosisRepo as DiagnosisTraitRepo>::find_diagnosis(db, visit.id, diagnosis.id)
+                .await?;
+        Ok(diagnosis_response(record))
+    }
+
+    async fn update_diagnosis(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+        diagnosis_id: i32,
+        user_id: i32,
+        payload: UpdateDiagnosisRequest,
+    ) -> Result<DiagnosisResponse, AppError> {
+        let doctor =
+            <EncounterRepo as EncounterTraitRepo>::find_doctor_for_user(db, user_id).await?;
+
+        let txn = db.begin().await?;
+        let diagnosis = <DiagnosisRepo as DiagnosisTraitRepo>::find_diagnosis_for_update(
+            &txn,
+            visit_intent_id,
+            diagnosis_id,
+        )
+        .await?;
+        ensure_author(&diagnosis, doctor.id)?;
+
+        if payload.diagnosis_type == Some(DiagnosisType::Primary)
+            && diagnosis.diagnosis_type != DiagnosisType::Primary
+        {
+            <DiagnosisRepo as DiagnosisTraitRepo>::demote_primary(&txn, visit_intent_id).await?;
+        }
+        <DiagnosisRepo as DiagnosisTraitRepo>::update_diagnosis(
+            &txn,
+            diagnosis,
+            payload.diagnosis_type,
+            payload.notes,
+        )
+        .await?;
+        txn.commit().await?;
+
+        let record = <DiagnosisRepo as DiagnosisTraitRepo>::find_diagnosis(
+            db,
+            visit_intent_id,
+            diagnosis_id,
+        )
+        .await?;
+        Ok(diagnosis_response(record))
+    }
+
+    async fn remove_diagnosis(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+        diagnosis_id: i32,
+        user_id: i32,
+    ) -> Result<DiagnosisRemoved, AppError> {
+        let doctor =
+            <EncounterRepo as EncounterTraitRepo>::find_doctor_for_user(db, user_id).await?;
+
+        let txn = db.begin().await?;
+        let diagnosis = <DiagnosisRepo as DiagnosisTraitRepo>::find_diagnosis_for_update(
+            &txn,
+            visit_intent_id,
+            diagnosis_id,
+        )
+        .await?;
+        ensure_author(&diagnosis, doctor.id)?;
+
+        <DiagnosisRepo as DiagnosisTraitRepo>::delete_diagnosis(&txn, diagnosis).await?;
+        txn.commit().await?;
+
+        Ok(DiagnosisRemoved {
+            id: diagnosis_id,
+            visit_intent_id,
+            removed_at: format_created_at!(Utc::now().naive_utc()),
+        })
+    }
+
+    async fn get_visit_diagnoses(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+    ) -> Result<VisitDiagnosesResponse, AppError> {
+        let visit = <DiagnosisRepo as DiagnosisTraitRepo>::find_visit(db, visit_intent_id).await?;
+        let diagnoses =
+            <DiagnosisRepo as DiagnosisTraitRepo>::find_visit_diagnoses(db, visit_intent_id)
+                .await?;
+
+        Ok(VisitDiagnosesResponse {
+            visit_intent_id,
+            patient_id: visit.patient_id,
+            data: diagnoses.into_iter().map(diagnosis_response).collect(),
+        })
+    }
+}
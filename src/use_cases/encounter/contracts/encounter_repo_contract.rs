@@ -0,0 +1,72 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use entity::{
+    doctors,
+    encounters::{self, EncounterStatus},
+    patients, patients_visit_intent,
+};
+use sea_orm::{DatabaseConnection, DatabaseTransaction};
+
+use crate::{
+    dtos::encounter::encounter_request::FinishEncounterRequest, error_handling::app_error::AppError,
+};
+
+/// An encounter with the visit it belongs to and the patient seen.
+pub type EncounterRecord = (
+    encounters::Model,
+    patients_visit_intent::Model,
+    patients::Model,
+);
+
+#[async_trait]
+pub trait EncounterTraitRepo {
+    /// The doctor profile of a user account, matched through their employee record.
+    async fn find_doctor_for_user(
+        db: &DatabaseConnection,
+        user_id: i32,
+    ) -> Result<doctors::Model, AppError>;
+    async fn find_doctor_by_id(
+        db: &DatabaseConnection,
+        doctor_id: i32,
+    ) -> Result<doctors::Model, AppError>;
+    /// A visit whose triage has been completed, the only kind that can reach a doctor.
+    async fn find_completed_visit(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+    ) -> Result<patients_visit_intent::Model, AppError>;
+    /// Opens the encounter of a visit in a polyclinic, or returns the one already open.
+    async fn open_encounter(
+        txn: &DatabaseTransaction,
+        visit_intent_id: i32,
+        polyclinic_id: i32,
+        doctor_id: Option<i32>,
+        service_date: NaiveDate,
+    ) -> Result<encounters::Model, AppError>;
+    async fn find_encounter(
+        db: &DatabaseConnection,
+        encounter_id: i32,
+    ) -> Result<EncounterRecord, AppError>;
+    async fn find_encounter_for_update(
+        txn: &DatabaseTransaction,
+        encounter_id: i32,
+    ) -> Result<encounters::Model, AppError>;
+    async fn start_encounter(
+        txn: &DatabaseTransaction,
+        encounter: encounters::Model,
+        doctor_id: i32,
+    ) -> Result<encounters::Model, AppError>;
+    async fn finish_encounter(
+        txn: &DatabaseTransaction,
+        encounter: encounters::Model,
+        payload: FinishEncounterRequest,
+    ) -> Result<encounters::Model, AppError>;
+    /// Encounters assigned to the doctor on a day, together with the unassigned ones of
+    /// the polyclinics the doctor works in that day. In-progress encounters come first,
+    /// then the waiting ones in the order they were handed over.
+    async fn find_worklist(
+        db: &DatabaseConnection,
+        doctor: &doctors::Model,
+        service_date: NaiveDate,
+        status: Option<EncounterStatus>,
+    ) -> Result<Vec<EncounterRecord>, AppError>;
+}
@@ -0,0 +1,47 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use entity::encounters::EncounterStatus;
+use sea_orm::DatabaseConnection;
+
+use crate::{
+    dtos::encounter::{
+        encounter_request::{FinishEncounterRequest, OpenEncounterRequest},
+        response::{DoctorWorklistResponse, EncounterResponse},
+    },
+    error_handling::app_error::AppError,
+};
+
+#[async_trait]
+pub trait EncounterServiceContracts {
+    async fn open_encounter(
+        db: &DatabaseConnection,
+        payload: OpenEncounterRequest,
+    ) -> Result<EncounterResponse, AppError>;
+    async fn get_encounter(
+        db: &DatabaseConnection,
+        encounter_id: i32,
+    ) -> Result<EncounterResponse, AppError>;
+    async fn start_encounter(
+        db: &DatabaseConnection,
+        encounter_id: i32,
+        user_id: i32,
+    ) -> Result<EncounterResponse, AppError>;
+    async fn finish_encounter(
+        db: &DatabaseConnection,
+        encounter_id: i32,
+        user_id: i32,
+        payload: FinishEncounterRequest,
+    ) -> Result<EncounterResponse, AppError>;
+    async fn get_my_worklist(
+        db: &DatabaseConnection,
+        user_id: i32,
+        service_date: NaiveDate,
+        status: Option<EncounterStatus>,
+    ) -> Result<DoctorWorklistResponse, AppError>;
+    async fn get_doctor_worklist(
+        db: &DatabaseConnection,
+        doctor_id: i32,
+        service_date: NaiveDate,
+        status: Option<EncounterStatus>,
+    ) -> Result<DoctorWorklistResponse, AppError>;
+}
@@ -0,0 +1,4 @@
+pub mod encounter_repo_contract;
+pub mod encounter_service_contract;
+pub use self::encounter_repo_contract::EncounterTraitRepo;
+pub use self::encounter_service_contract::EncounterServiceContracts;
@@ -0,0 +1,3 @@
+pub mod contracts;
+pub mod repo;
+pub mod service;
@@ -0,0 +1,317 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use chrono::{NaiveDate, Utc};
+use entity::{
+    doctor_schedules, doctors,
+    encounters::{self, ActiveModel, EncounterDisposition, EncounterStatus},
+    patients,
+    patients_visit_intent::{self, VisitStatus},
+    polyclinic, user,
+};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, Condition, ConnectionTrait,
+    DatabaseConnection, DatabaseTransaction, EntityTrait, IntoActiveModel, QueryFilter, QueryOrder,
+    QuerySelect,
+};
+
+use crate::{
+    dtos::encounter::encounter_request::FinishEncounterRequest,
+    error_handling::app_error::AppError,
+    use_cases::encounter::contracts::encounter_repo_contract::{
+        EncounterRecord, EncounterTraitRepo,
+    },
+};
+
+pub struct EncounterRepo;
+
+fn status_rank(status: EncounterStatus) -> u8 {
+    match status {
+        EncounterStatus::InProgress => 0,
+        EncounterStatus::Waiting => 1,
+        EncounterStatus::Finished => 2,
+    }
+}
+
+/// The room a doctor sees patients in: the one on their active schedule in the
+/// polyclinic for that weekday, else their usual room. Without a doctor it is the
+/// polyclinic's own room.
+async fn resolve_room(
+    db: &impl ConnectionTrait,
+    polyclinic_id: i32,
+    doctor_id: Option<i32>,
+    service_date: NaiveDate,
+) -> Result<Option<String>, AppError> {
+    if let Some(doctor_id) = doctor_id {
+        let day_of_week = service_date.format("%A").to_string();
+        let scheduled = doctor_schedules::Entity::find()
+            .filter(doctor_schedules::Column::DoctorId.eq(doctor_id))
+            .filter(doctor_schedules::Column::PolyclinicId.eq(polyclinic_id))
+            .all(db)
+            .await?
+            .into_iter()
+            .find(|schedule| {
+                schedule.day_of_week.eq_ignore_ascii_case(&day_of_week)
+                    && schedule.status.eq_ignore_ascii_case("ACTIVE")
+            });
+        if let Some(schedule) = scheduled {
+            return Ok(Some(schedule.room_code));
+        }
+        if let Some(doctor) = doctors::Entity::find_by_id(doctor_id).one(db).await? {
+            return Ok(Some(doctor.room_code));
+        }
+    }
+
+    Ok(polyclinic::Entity::find_by_id(polyclinic_id)
+        .one(db)
+        .await?
+        .map(|poly| poly.room_code))
+}
+
+async fn with_patients(
+    db: &DatabaseConnection,
+    rows: Vec<(encounters::Model, Option<patients_visit_intent::Model>)>,
+) -> Result<Vec<EncounterRecord>, AppError> {
+    let patient_ids = rows
+        .iter()
+        .filter_map(|(_, visit)| visit.as_ref().map(|v| v.patient_id))
+        .collect::<Vec<_>>();
+    let patients: HashMap<i32, patients::Model> = patients::Entity::find()
+        .filter(patients::Column::Id.is_in(patient_ids))
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|patient| (patient.id, patient))
+        .collect();
+
+    Ok(rows
+        .into_iter()
+        .filter_map(|(encounter, visit)| {
+            let visit = visit?;
+            let patient = patients.get(&visit.patient_id)?.clone();
+            Some((encounter, visit, patient))
+        })
+        .collect())
+}
+
+#[async_trait]
+impl EncounterTraitRepo for EncounterRepo {
+    async fn find_doctor_for_user(
+        db: &DatabaseConnection,
+        user_id: i32,
+    ) -> Result<doctors::Model, AppError> {
+        let account =
+            user::Entity::find_by_id(user_id)
+                .one(db)
+                .await?
+                .ok_or(AppError::NotFound(format!(
+                    "User with id {} is not found",
+                    user_id
+                )))?;
+
+        doctors::Entity::find()
+            .filter(doctors::Column::EmployeeId.eq(account.employee_id))
+            .one(db)
+            .await?
+            .ok_or(AppError::Forbidden(
+                "Your account is not linked to a doctor".to_string(),
+            ))
+    }
+
+    async fn find_doctor_by_id(
+        db: &DatabaseConnection,
+        doctor_id: i32,
+    ) -> Result<doctors::Model, AppError> {
+        doctors::Entity::find_by_id(doctor_id)
+            .one(db)
+            .await?
+            .ok_or(AppError::NotFound(format!(
+                "Doctor with id {} is not found",
+                doctor_id
+            )))
+    }
+
+    async fn find_completed_visit(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+    ) -> Result<patients_visit_intent::Model, AppError> {
+        let visit = patients_visit_intent::Entity::find_by_id(visit_intent_id)
+            .one(db)
+            .await?
+            .ok_or(AppError::NotFound(format!(
+                "Visit with id {} is not found",
+                visit_intent_id
+            )))?;
+
+        if visit.status != VisitStatus::Done {
+            return Err(AppError::BadRequest(format!(
+                "Visit {} is {} and has not completed triage",
+                visit_intent_id, visit.status
+            )));
+        }
+        Ok(visit)
+    }
+
+    async fn open_encounter(
+        txn: &DatabaseTransaction,
+        visit_intent_id: i32,
+        polyclinic_id: i32,
+        doctor_id: Option<i32>,
+        service_date: NaiveDate,
+    ) -> Result<encounters::Model, AppError> {
+        if let Some(existing) = encounters::Entity::find()
+            .filter(encounters::Column::VisitIntentId.eq(visit_intent_id))
+            .one(txn)
+            .await?
+        {
+            return Ok(existing);
+        }
+
+        let now = Utc::now().naive_utc();
+        let model = ActiveModel {
+            visit_intent_id: Set(visit_intent_id),
+            doctor_id: Set(doctor_id),
+            polyclinic_id: Set(polyclinic_id),
+            room_code: Set(resolve_room(txn, polyclinic_id, doctor_id, service_date).await?),
+            service_date: Set(service_date),
+            status: Set(EncounterStatus::Waiting),
+            created_at: Set(now),
+            updated_at: Set(now),
+            ..Default::default()
+        };
+
+        Ok(model.insert(txn).await?)
+    }
+
+    async fn find_encounter(
+        db: &DatabaseConnection,
+        encounter_id: i32,
+    ) -> Result<EncounterRecord, AppError> {
+        let row = encounters::Entity::find_by_id(encounter_id)
+            .find_also_related(patients_visit_intent::Entity)
+            .one(db)
+            .await?
+            .ok_or(AppError::NotFound(format!(
+                "Encounter with id {} is not found",
+                encounter_id
+            )))?;
+
+        with_patients(db, vec![row])
+            .await?
+            .pop()
+            .ok_or(AppError::NotFound(format!(
+                "Patient of encounter {} is not found",
+                encounter_id
+            )))
+    }
+
+    async fn find_encounter_for_update(
+        txn: &DatabaseTransaction,
+        encounter_id: i32,
+    ) -> Result<encounters::Model, AppError> {
+        encounters::Entity::find_by_id(encounter_id)
+            .lock_exclusive()
+            .one(txn)
+            .await?
+            .ok_or(AppError::NotFound(format!(
+                "Encounter with id {} is not found",
+                encounter_id
+            )))
+    }
+
+    async fn start_encounter(
+        txn: &DatabaseTransaction,
+        encounter: encounters::Model,
+        doctor_id: i32,
+    ) -> Result<encounters::Model, AppError> {
+        let room_code = resolve_room(
+            txn,
+            encounter.polyclinic_id,
+            Some(doctor_id),
+            encounter.service_date,
+        )
+        .await?;
+
+        let now = Utc::now().naive_utc();
+        let mut model: ActiveModel = encounter.into_active_model();
+        model.doctor_id = Set(Some(doctor_id));
+        model.room_code = Set(room_code);
+        model.status = Set(EncounterStatus::InProgress);
+        model.started_at = Set(Some(now));
+        model.updated_at = Set(now);
+
+        Ok(model.update(txn).await?)
+    }
+
+    async fn finish_encounter(
+        txn: &DatabaseTransaction,
+        encounter: encounters::Model,
+        payload: FinishEncounterRequest,
+    ) -> Result<encounters::Model, AppError> {
+        let notes = payload
+            .disposition_notes
+            .filter(|notes| !notes.trim().is_empty());
+        if payload.disposition == EncounterDisposition::ReferralOut && notes.is_none() {
+            return Err(AppError::BadRequest(
+                "A referral out needs the destination in disposition_notes".to_string(),
+            ));
+        }
+
+        let now = Utc::now().naive_utc();
+        let mut model: ActiveModel = encounter.into_active_model();
+        model.status = Set(EncounterStatus::Finished);
+        model.disposition = Set(Some(payload.disposition));
+        model.disposition_notes = Set(notes);
+        model.ended_at = Set(Some(now));
+        model.updated_at = Set(now);
+
+        Ok(model.update(txn).await?)
+    }
+
+    async fn find_worklist(
+        db: &DatabaseConnection,
+        doctor: &doctors::Model,
+        service_date: NaiveDate,
+        status: Option<EncounterStatus>,
+    ) -> Result<Vec<EncounterRecord>, AppError> {
+        let day_of_week = service_date.format("%A").to_string();
+        let mut polyclinic_ids = doctor_schedules::Entity::find()
+            .filter(doctor_schedules::Column::DoctorId.eq(doctor.id))
+            .all(db)
+            .await?
+            .into_iter()
+            .filter(|schedule| {
+                schedule.day_of_week.eq_ignore_ascii_case(&day_of_week)
+                    && schedule.status.eq_ignore_ascii_case("ACTIVE")
+            })
+            .map(|schedule| schedule.polyclinic_id)
+            .collect::<Vec<_>>();
+        polyclinic_ids.push(doctor.polyclinic_id);
+
+        let mut query = encounters::Entity::find()
+            .filter(encounters::Column::ServiceDate.eq(service_date))
+            .filter(
+                Condition::any()
+                    .add(encounters::Column::DoctorId.eq(doctor.id))
+                    .add(
+                        Condition::all()
+                            .add(encounters::Column::DoctorId.is_null())
+                            .add(encounters::Column::PolyclinicId.is_in(polyclinic_ids)),
+                    ),
+            );
+        if let Some(status) = status {
+            query = query.filter(encounters::Column::Status.eq(status));
+        }
+
+        let rows = query
+            .order_by_asc(encounters::Column::CreatedAt)
+            .order_by_asc(encounters::Column::Id)
+            .find_also_related(patients_visit_intent::Entity)
+            .all(db)
+            .await?;
+
+        let mut worklist = with_patients(db, rows).await?;
+        worklist.sort_by_key(|(encounter, _, _)| status_rank(encounter.status));
+        Ok(worklist)
+    }
+}
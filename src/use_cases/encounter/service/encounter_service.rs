@@ -0,0 +1,215 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, Utc};
+use entity::encounters::EncounterStatus;
+use sea_orm::{ActiveEnum, DatabaseConnection, TransactionTrait};
+
+pub use crate::use_cases::encounter::contracts::encounter_service_contract::EncounterServiceContracts;
+use crate::{
+    dtos::encounter::{
+        encounter_request::{FinishEncounterRequest, OpenEncounterRequest},
+        response::{DoctorWorklistResponse, EncounterResponse},
+    },
+    error_handling::app_error::AppError,
+    format_created_at,
+    use_cases::{
+        encounter::{
+            contracts::{EncounterTraitRepo, encounter_repo_contract::EncounterRecord},
+            repo::encounter_repo::EncounterRepo,
+        },
+        triage::{contracts::triage_repo_contract::TriageTraitRepo, repo::triage_repo::TriageRepo},
+    },
+    utils::helpers::hospital_now,
+};
+
+pub struct EncounterService;
+
+fn encounter_response((encounter, visit, patient): EncounterRecord) -> EncounterResponse {
+    EncounterResponse {
+        id: encounter.id,
+        visit_intent_id: encounter.visit_intent_id,
+        patient_id: patient.id,
+        patient_name: patient.name,
+        medical_record_number: patient.medical_record_number,
+        doctor_id: encounter.doctor_id,
+        polyclinic_id: encounter.polyclinic_id,
+        room_code: encounter.room_code,
+        service_date: encounter.service_date,
+        priority: visit.priority.to_value(),
+        status: encounter.status.to_value(),
+        disposition: encounter
+            .disposition
+            .map(|disposition| disposition.to_value()),
+        disposition_notes: encounter.disposition_notes,
+        started_at: encounter
+            .started_at
+            .map(|started_at| format_created_at!(started_at)),
+        ended_at: encounter
+            .ended_at
+            .map(|ended_at| format_created_at!(ended_at)),
+        created_at: format_created_at!(encounter.created_at),
+    }
+}
+
+#[async_trait]
+impl EncounterServiceContracts for EncounterService {
+    async fn open_encounter(
+        db: &DatabaseConnection,
+        payload: OpenEncounterRequest,
+    ) -> Result<EncounterResponse, AppError> {
+        let visit = <EncounterRepo as EncounterTraitRepo>::find_completed_visit(
+            db,
+            payload.visit_intent_id,
+        )
+        .await?;
+        let poly =
+            <TriageRepo as TriageTraitRepo>::find_polyclinic_by_code(db, &payload.polyclinic_code)
+                .await?;
+
+        let service_date = hospital_now().date_naive();
+        if let Some(doctor_id) = payload.doctor_id {
+            let today = service_date.format("%A").to_string();
+            <TriageRepo as TriageTraitRepo>::find_scheduled_doctor(db, doctor_id, poly.id, &today)
+                .await?;
+        }
+
+        let txn = db.begin().await?;
+        let encounter = <EncounterRepo as EncounterTraitRepo>::open_encounter(
+            &txn,
+            visit.id,
+            poly.id,
+            payload.doctor_id,
+            service_date,
+        )
+        .await?;
+        txn.commit().await?;
+
+        let record =
+            <EncounterRepo as EncounterTraitRepo>::find_encounter(db, encounter.id).await?;
+        Ok(encounter_response(record))
+    }
+
+    async fn get_encounter(
+        db: &DatabaseConnection,
+        encounter_id: i32,
+    ) -> Result<EncounterResponse, AppError> {
+        let record =
+            <EncounterRepo as EncounterTraitRepo>::find_encounter(db, encounter_id).await?;
+        Ok(encounter_response(record))
+    }
+
+    async fn start_encounter(
+        db: &DatabaseConnection,
+        encounter_id: i32,
+        user_id: i32,
+    ) -> Result<EncounterResponse, AppError> {
+        let doctor =
+            <EncounterRepo as EncounterTraitRepo>::find_doctor_for_user(db, user_id).await?;
+
+        let txn = db.begin().await?;
+        let encounter =
+            <EncounterRepo as EncounterTraitRepo>::find_encounter_for_update(&txn, encounter_id)
+                .await?;
+
+        if encounter.status != EncounterStatus::Waiting {
+            return Err(AppError::Conflict(format!(
+                "Encounter {} is {} and cannot be started",
+                encounter_id,
+                encounter.status.to_value()
+            )));
+        }
+        match encounter.doctor_id {
+            Some(assigned) if assigned != doctor.id => {
+                return Err(AppError::Forbidden(format!(
+                    "Encounter {} is assigned to another doctor",
+                    encounter_id
+                )));
+            }
+            Some(_) => {}
+            // An unassigned encounter is taken by a doctor on the polyclinic's schedule
+            None => {
+                let day_of_week = encounter.service_date.format("%A").to_string();
+                <TriageRepo as TriageTraitRepo>::find_scheduled_doctor(
+                    db,
+                    doctor.id,
+                    encounter.polyclinic_id,
+                    &day_of_week,
+                )
+                .await?;
+            }
+        }
+
+        <EncounterRepo as EncounterTraitRepo>::start_encounter(&txn, encounter, doctor.id).await?;
+        txn.commit().await?;
+
+        let record =
+            <EncounterRepo as EncounterTraitRepo>::find_encounter(db, encounter_id).await?;
+        Ok(encounter_response(record))
+    }
+
+    async fn finish_encounter(
+        db: &DatabaseConnection,
+        encounter_id: i32,
+        user_id: i32,
+        payload: FinishEncounterRequest,
+    ) -> Result<EncounterResponse, AppError> {
+        let doctor =
+            <EncounterRepo as EncounterTraitRepo>::find_doctor_for_user(db, user_id).await?;
+
+        let txn = db.begin().await?;
+        let encounter =
+            <EncounterRepo as EncounterTraitRepo>::find_encounter_for_update(&txn, encounter_id)
+                .await?;
+
+        if encounter.status != EncounterStatus::InProgress {
+            return Err(AppError::Conflict(format!(
+                "Encounter {} is {} and cannot be finished",
+                encounter_id,
+                encounter.status.to_value()
+            )));
+        }
+        if encounter.doctor_id != Some(doctor.id) {
+            return Err(AppError::Forbidden(format!(
+                "Encounter {} is attended by another doctor",
+                encounter_id
+            )));
+        }
+
+        <EncounterRepo as EncounterTraitRepo>::finish_encounter(&txn, encounter, payload).await?;
+        txn.commit().await?;
+
+        let record =
+            <EncounterRepo as EncounterTraitRepo>::find_encounter(db, encounter_id).await?;
+        Ok(encounter_response(record))
+    }
+
+    async fn get_my_worklist(
+        db: &DatabaseConnection,
+        user_id: i32,
+        service_date: NaiveDate,
+        status: Option<EncounterStatus>,
+    ) -> Result<DoctorWorklistResponse, AppError> {
+        let doctor =
+            <EncounterRepo as EncounterTraitRepo>::find_doctor_for_user(db, user_id).await?;
+        Self::get_doctor_worklist(db, doctor.id, service_date, status).await
+    }
+
+    async fn get_doctor_worklist(
+        db: &DatabaseConnection,
+        doctor_id: i32,
+        service_date: NaiveDate,
+        status: Option<EncounterStatus>,
+    ) -> Result<DoctorWorklistResponse, AppError> {
+        let doctor =
+            <EncounterRepo as EncounterTraitRepo>::find_doctor_by_id(db, doctor_id).await?;
+        let worklist =
+            <EncounterRepo as EncounterTraitRepo>::find_worklist(db, &doctor, service_date, status)
+                .await?;
+
+        Ok(DoctorWorklistResponse {
+            doctor_id: doctor.id,
+            doctor_name: doctor.name,
+            service_date,
+            data: worklist.into_iter().map(encounter_response).collect(),
+        })
+    }
+}
@@ -0,0 +1,4 @@
+pub mod patient_repo_contract;
+pub mod patient_service_contract;
+pub use self::patient_repo_contract::PatientTraitRepo;
+pub use self::patient_service_contract::PatientServiceContracts;
@@ -0,0 +1,112 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use entity::{
+    patient_history::{self, PatientChangeSource},
+    patient_merges, patients,
+};
+use sea_orm::{DatabaseConnection, DatabaseTransaction};
+
+use crate::{
+    dtos::patient::{
+        patient_request::{IdentifyPatientRequest, UpdatePatientRequest},
+        response::{DuplicateCandidate, MrnGapReport},
+    },
+    error_handling::app_error::AppError,
+    infra::config::MrnConfig,
+};
+
+#[async_trait]
+pub trait PatientTraitRepo {
+    async fn find_patient_by_id(
+        db: &DatabaseConnection,
+        patient_id: i32,
+    ) -> Result<patients::Model, AppError>;
+    async fn find_patient_by_national_id(
+        db: &DatabaseConnection,
+        national_id: &str,
+    ) -> Result<Option<patients::Model>, AppError>;
+    async fn find_patient_by_medical_record_number(
+        db: &DatabaseConnection,
+        medical_record_number: &str,
+    ) -> Result<Option<patients::Model>, AppError>;
+    async fn find_patient_by_bpjs_number(
+        db: &DatabaseConnection,
+        bpjs_number: &str,
+    ) -> Result<Option<patients::Model>, AppError>;
+    /// Patients whose name is similar to `name` (trigram) and, when given, born on
+    /// `date_of_birth`, best matches first. Returns the page and the total item count.
+    async fn search_patients(
+        db: &DatabaseConnection,
+        name: Option<&str>,
+        date_of_birth: Option<NaiveDate>,
+        page: u64,
+        per_page: u64,
+    ) -> Result<(Vec<patients::Model>, u64), AppError>;
+    async fn find_patient_for_update(
+        txn: &DatabaseTransaction,
+        patient_id: i32,
+    ) -> Result<patients::Model, AppError>;
+    /// Writes whatever in `payload` differs from `patient` and records the revision in the
+    /// patient history. Returns the patient unchanged when nothing differs.
+    async fn apply_patient_changes(
+        txn: &DatabaseTransaction,
+        patient: patients::Model,
+        payload: UpdatePatientRequest,
+        source: PatientChangeSource,
+        changed_by: Option<i32>,
+    ) -> Result<patients::Model, AppError>;
+    /// Gives an unidentified patient their national ID and real demographics, recording
+    /// the change in the patient history. Fails with a Conflict when the NIK or BPJS
+    /// number already belongs to another patient.
+    async fn identify_patient(
+        txn: &DatabaseTransaction,
+        patient: patients::Model,
+        payload: IdentifyPatientRequest,
+        identified_by: i32,
+    ) -> Result<patients::Model, AppError>;
+    async fn find_patient_history(
+        db: &DatabaseConnection,
+        patient_id: i32,
+    ) -> Result<Vec<patient_history::Model>, AppError>;
+    /// Pairs of unmerged patients scoring at least `min_score`, best first. With a
+    /// `patient_id` only that patient's candidates are returned.
+    async fn find_duplicate_candidates(
+        db: &DatabaseConnection,
+        patient_id: Option<i32>,
+        min_score: f64,
+        limit: u64,
+    ) -> Result<Vec<DuplicateCandidate>, AppError>;
+    /// Re-points the visit intents and referral documents of `merged_id` to `survivor_id`,
+    /// marks the duplicate as merged and logs what was moved.
+    async fn merge_patients(
+        txn: &DatabaseTransaction,
+        survivor_id: i32,
+        merged_id: i32,
+        reason: Option<String>,
+        merged_by: i32,
+    ) -> Result<patient_merges::Model, AppError>;
+    /// Moves the rows recorded in a merge log back to the duplicate and reinstates it.
+    async fn undo_patient_merge(
+        txn: &DatabaseTransaction,
+        merge_id: i32,
+        undone_by: i32,
+    ) -> Result<patient_merges::Model, AppError>;
+    async fn find_patient_merges(
+        db: &DatabaseConnection,
+        patient_id: i32,
+    ) -> Result<Vec<patient_merges::Model>, AppError>;
+    /// Gives `patient` the next medical record number of the counter in effect on `date`,
+    /// unless it already has one.
+    async fn assign_medical_record_number(
+        txn: &DatabaseTransaction,
+        patient: patients::Model,
+        mrn: &MrnConfig,
+        date: NaiveDate,
+    ) -> Result<patients::Model, AppError>;
+    /// Numbers drawn from the counter of `scope` that no patient holds, up to `limit`.
+    async fn find_mrn_gaps(
+        db: &DatabaseConnection,
+        scope: &str,
+        limit: u64,
+    ) -> Result<MrnGapReport, AppError>;
+}
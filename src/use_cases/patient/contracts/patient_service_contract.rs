@@ -0,0 +1,74 @@
+use async_trait::async_trait;
+use sea_orm::DatabaseConnection;
+
+use crate::{
+    dtos::patient::{
+        patient_request::{
+            DuplicateCandidatesQuery, IdentifyPatientRequest, MergePatientRequest, MrnGapQuery,
+            PatientLookupQuery, PatientSearchQuery, UpdatePatientRequest,
+        },
+        response::{
+            DuplicateCandidatesResponse, MrnGapReport, PatientHistoryResponse,
+            PatientMergeResponse, PatientMergesResponse, PatientResponse, PatientSearchResponse,
+        },
+    },
+    error_handling::app_error::AppError,
+    infra::config::MrnConfig,
+};
+
+#[async_trait]
+pub trait PatientServiceContracts {
+    async fn get_patient(
+        db: &DatabaseConnection,
+        patient_id: i32,
+    ) -> Result<PatientResponse, AppError>;
+    async fn lookup_patient(
+        db: &DatabaseConnection,
+        query: PatientLookupQuery,
+    ) -> Result<PatientResponse, AppError>;
+    async fn search_patients(
+        db: &DatabaseConnection,
+        query: PatientSearchQuery,
+    ) -> Result<PatientSearchResponse, AppError>;
+    async fn update_patient(
+        db: &DatabaseConnection,
+        patient_id: i32,
+        payload: UpdatePatientRequest,
+        changed_by: i32,
+    ) -> Result<PatientResponse, AppError>;
+    /// Replaces an unidentified patient's placeholder with their real identity.
+    async fn identify_patient(
+        db: &DatabaseConnection,
+        patient_id: i32,
+        payload: IdentifyPatientRequest,
+        identified_by: i32,
+    ) -> Result<PatientResponse, AppError>;
+    async fn get_patient_history(
+        db: &DatabaseConnection,
+        patient_id: i32,
+    ) -> Result<PatientHistoryResponse, AppError>;
+    async fn find_duplicate_candidates(
+        db: &DatabaseConnection,
+        query: DuplicateCandidatesQuery,
+    ) -> Result<DuplicateCandidatesResponse, AppError>;
+    async fn merge_patients(
+        db: &DatabaseConnection,
+        survivor_id: i32,
+        payload: MergePatientRequest,
+        merged_by: i32,
+    ) -> Result<PatientMergeResponse, AppError>;
+    async fn undo_patient_merge(
+        db: &DatabaseConnection,
+        merge_id: i32,
+        undone_by: i32,
+    ) -> Result<PatientMergeResponse, AppError>;
+    async fn get_patient_merges(
+        db: &DatabaseConnection,
+        patient_id: i32,
+    ) -> Result<PatientMergesResponse, AppError>;
+    async fn find_mrn_gaps(
+        db: &DatabaseConnection,
+        mrn: &MrnConfig,
+        query: MrnGapQuery,
+    ) -> Result<MrnGapReport, AppError>;
+}
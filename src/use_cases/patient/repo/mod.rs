@@ -0,0 +1,2 @@
+pub mod patient_changes;
+pub mod patient_repo;
@@ -0,0 +1,80 @@
+use entity::patients;
+
+use crate::{
+    dtos::patient::{patient_request::UpdatePatientRequest, response::PatientFieldChange},
+    infra::api::ApiFieldError,
+};
+
+/// Fields of `payload` that differ from the stored `patient`. Fields the payload leaves
+/// empty are not compared, so an omitted BPJS number never clears the stored one.
+pub fn diff_patient(
+    patient: &patients::Model,
+    payload: &UpdatePatientRequest,
+) -> Vec<PatientFieldChange> {
+    let mut changes = Vec::new();
+    let mut compare = |field: &str, stored: Option<String>, submitted: Option<String>| {
+        if let Some(submitted) = submitted
+            && stored.as_deref() != Some(submitted.as_str())
+        {
+            changes.push(PatientFieldChange {
+                field: field.to_string(),
+                previous: stored,
+                current: Some(submitted),
+            });
+        }
+    };
+
+    compare("name", Some(patient.name.clone()), payload.name.clone());
+    compare(
+        "date_of_birth",
+        Some(patient.date_of_birth.to_string()),
+        payload.date_of_birth.map(|dob| dob.to_string()),
+    );
+    compare(
+        "bpjs_number",
+        patient.bpjs_number.clone(),
+        payload.bpjs_number.clone(),
+    );
+    compare(
+        "gender",
+        Some(patient.gender.clone()),
+        payload.gender.as_ref().map(ToString::to_string),
+    );
+    compare(
+        "emergency_contact_name",
+        patient.emergency_contact_name.clone(),
+        payload.emergency_contact_name.clone(),
+    );
+    compare(
+        "emergency_contact_phone",
+        patient.emergency_contact_phone.clone(),
+        payload.emergency_contact_phone.clone(),
+    );
+    compare(
+        "emergency_contact_relationship",
+        patient.emergency_contact_relationship.clone(),
+        payload.emergency_contact_relationship.clone(),
+    );
+    compare(
+        "blood_type",
+        patient.blood_type.clone(),
+        payload.blood_type.as_ref().map(ToString::to_string),
+    );
+
+    changes
+}
+
+/// Differences reported back to the front desk when a repeat registration is rejected.
+pub fn conflict_fields(changes: &[PatientFieldChange]) -> Vec<ApiFieldError> {
+    changes
+        .iter()
+        .map(|change| ApiFieldError {
+            field: change.field.clone(),
+            message: format!(
+                "Stored '{}', submitted '{}'",
+                change.previous.as_deref().unwrap_or_default(),
+                change.current.as_deref().unwrap_or_default()
+            ),
+        })
+        .collect()
+}
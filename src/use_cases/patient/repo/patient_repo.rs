@@ -0,0 +1,686 @@
+use async_trait::async_trait;
+use chrono::{NaiveDate, Utc};
+use entity::{
+    patient_allergies,
+    patient_history::{self, PatientChangeSource},
+    patient_merges,
+    patients::{self, ActiveModel},
+    patients_visit_intent, referral_documents,
+};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, ConnectionTrait, DatabaseConnection,
+    DatabaseTransaction, DbBackend, EntityTrait, FromQueryResult, IntoActiveModel, PaginatorTrait,
+    QueryFilter, QueryOrder, QuerySelect, Statement, sea_query::Expr,
+};
+
+use crate::{
+    dtos::patient::{
+        patient_request::{IdentifyPatientRequest, UpdatePatientRequest},
+        response::{DuplicateCandidate, MrnGapReport, PatientFieldChange},
+    },
+    error_handling::app_error::AppError,
+    infra::config::MrnConfig,
+    use_cases::patient::{
+        contracts::patient_repo_contract::PatientTraitRepo, repo::patient_changes::diff_patient,
+    },
+};
+
+pub struct PatientRepo;
+
+/// Weighted match score of two patients: name similarity counts for up to 0.4, the same
+/// date of birth 0.25, the same emergency phone 0.15, and a NIK or BPJS number one edit
+/// apart (a typo) 0.1 each. Only pairs with similar names or the same birth date are
+/// compared so the trigram and date of birth indexes can be used. Two unidentified
+/// emergency records share a placeholder name and are never paired with each other.
+const DUPLICATE_CANDIDATES_SQL: &str = r#"
+    SELECT * FROM (
+        SELECT
+            a.id AS patient_id,
+            a.name AS patient_name,
+            b.id AS candidate_id,
+            b.name AS candidate_name,
+            similarity(lower(a.name), lower(b.name))::float8 AS name_similarity,
+            a.date_of_birth = b.date_of_birth AS same_date_of_birth,
+            COALESCE(a.emergency_contact_phone = b.emergency_contact_phone, false) AS same_phone,
+            COALESCE(levenshtein(a.national_id, b.national_id) <= 1, false) AS similar_national_id,
+            COALESCE(levenshtein(a.bpjs_number, b.bpjs_number) <= 1, false) AS similar_bpjs_number
+        FROM patients a
+        JOIN patients b
+            ON b.id <> a.id
+            AND (lower(a.name) % lower(b.name) OR a.date_of_birth = b.date_of_birth)
+        WHERE a.merged_into_id IS NULL
+            AND b.merged_into_id IS NULL
+            AND NOT (a.is_unidentified AND b.is_unidentified)
+            AND (a.id = $1 OR ($1 IS NULL AND a.id < b.id))
+    ) pairs
+    CROSS JOIN LATERAL (
+        SELECT 0.4 * name_similarity
+            + CASE WHEN same_date_of_birth THEN 0.25 ELSE 0 END
+            + CASE WHEN same_phone THEN 0.15 ELSE 0 END
+            + CASE WHEN similar_national_id THEN 0.1 ELSE 0 END
+            + CASE WHEN similar_bpjs_number THEN 0.1 ELSE 0 END AS score
+    ) scored
+    WHERE score >= $2
+    ORDER BY score DESC, patient_id, candidate_id
+    LIMIT $3
+"#;
+
+#[derive(FromQueryResult)]
+struct MrnNumber {
+    number: i32,
+}
+
+#[derive(FromQueryResult)]
+struct MrnCounts {
+    last_number: i32,
+    assigned: i64,
+}
+
+fn json_ids(ids: &serde_json::Value) -> Vec<i32> {
+    serde_json::from_value(ids.clone()).unwrap_or_default()
+}
+
+#[async_trait]
+impl PatientTraitRepo for PatientRepo {
+    async fn find_patient_by_id(
+        db: &DatabaseConnection,
+        patient_id: i32,
+    ) -> Result<patients::Model, AppError> {
+        patients::Entity::find_by_id(patient_id)
+            .one(db)
+            .await?
+            .ok_or(AppError::NotFound(format!(
+                "Patient with id {} is not found",
+                patient_id
+            )))
+    }
+
+    async fn find_patient_by_national_id(
+        db: &DatabaseConnection,
+        national_id: &str,
+    ) -> Result<Option<patients::Model>, AppError> {
+        Ok(patients::Entity::find()
+            .filter(patients::Column::NationalId.eq(national_id))
+            .one(db)
+            .await?)
+    }
+
+    async fn find_patient_by_medical_record_number(
+        db: &DatabaseConnection,
+        medical_record_number: &str,
+    ) -> Result<Option<patients::Model>, AppError> {
+        Ok(patients::Entity::find()
+            .filter(patients::Column::MedicalRecordNumber.eq(medical_record_number.to_uppercase()))
+            .one(db)
+            .await?)
+    }
+
+    async fn find_patient_by_bpjs_number(
+        db: &DatabaseConnection,
+        bpjs_number: &str,
+    ) -> Result<Option<patients::Model>, AppError> {
+        Ok(patients::Entity::find()
+            .filter(patients::Column::BpjsNumber.eq(bpjs_number))
+            .one(db)
+            .await?)
+    }
+
+    async fn search_patients(
+        db: &DatabaseConnection,
+        name: Option<&str>,
+        date_of_birth: Option<NaiveDate>,
+        page: u64,
+        per_page: u64,
+    ) -> Result<(Vec<patients::Model>, u64), AppError> {
+        let mut query = patients::Entity::find().filter(patients::Column::MergedIntoId.is_null());
+
+        if let Some(date_of_birth) = date_of_birth {
+            query = query.filter(patients::Column::DateOfBirth.eq(date_of_birth));
+        }
+
+        query = match name {
+            // `%` is the pg_trgm similarity operator; strpos keeps exact substrings such as
+            // a single given name matching even when they are too short to be similar
+            Some(name) => query
+                .filter(Expr::cust_with_values(
+                    "(lower(name) % lower($1) OR strpos(lower(name), lower($2)) > 0)",
+                    [name, name],
+                ))
+                .order_by_desc(Expr::cust_with_values(
+                    "similarity(lower(name), lower($1))",
+                    [name],
+                )),
+            None => query.order_by_asc(patients::Column::Name),
+        };
+
+        let paginator = query
+            .order_by_asc(patients::Column::Id)
+            .paginate(db, per_page);
+        let total_items = paginator.num_items().await?;
+        let patients = paginator.fetch_page(page - 1).await?;
+
+        Ok((patients, total_items))
+    }
+
+    async fn find_patient_for_update(
+        txn: &DatabaseTransaction,
+        patient_id: i32,
+    ) -> Result<patients::Model, AppError> {
+        patients::Entity::find_by_id(patient_id)
+            .lock_exclusive()
+            .one(txn)
+            .await?
+            .ok_or(AppError::NotFound(format!(
+                "Patient with id {} is not found",
+                patient_id
+            )))
+    }
+
+    async fn apply_patient_changes(
+        txn: &DatabaseTransaction,
+        patient: patients::Model,
+        payload: UpdatePatientRequest,
+        source: PatientChangeSource,
+        changed_by: Option<i32>,
+    ) -> Result<patients::Model, AppError> {
+        let changes = diff_patient(&patient, &payload);
+        if changes.is_empty() {
+            return Ok(patient);
+        }
+
+        if let Some(bpjs_number) = &payload.bpjs_number
+            && patients::Entity::find()
+                .filter(patients::Column::BpjsNumber.eq(bpjs_number))
+                .filter(patients::Column::Id.ne(patient.id))
+                .one(txn)
+                .await?
+                .is_some()
+        {
+            return Err(AppError::Conflict(format!(
+                "BPJS number {} is already registered to another patient",
+                bpjs_number
+            )));
+        }
+
+        let patient_id = patient.id;
+        let mut model: ActiveModel = patient.into_active_model();
+        if let Some(name) = payload.name {
+            model.name = Set(name);
+        }
+        if let Some(date_of_birth) = payload.date_of_birth {
+            model.date_of_birth = Set(date_of_birth);
+            model.date_of_birth_estimated = Set(false);
+        }
+        if let Some(bpjs_number) = payload.bpjs_number {
+            model.bpjs_number = Set(Some(bpjs_number));
+        }
+        if let Some(gender) = payload.gender {
+            model.gender = Set(gender.to_string());
+        }
+        if let Some(name) = payload.emergency_contact_name {
+            model.emergency_contact_name = Set(Some(name));
+        }
+        if let Some(phone) = payload.emergency_contact_phone {
+            model.emergency_contact_phone = Set(Some(phone));
+        }
+        if let Some(relationship) = payload.emergency_contact_relationship {
+            model.emergency_contact_relationship = Set(Some(relationship));
+        }
+        if let Some(blood_type) = payload.blood_type {
+            model.blood_type = Set(Some(blood_type.to_string()));
+        }
+        model.updated_at = Set(Utc::now().naive_utc());
+        let patient = model.update(txn).await?;
+
+        patient_history::ActiveModel {
+            patient_id: Set(patient_id),
+            source: Set(source),
+            changed_by: Set(changed_by),
+            changes: Set(serde_json::to_value(&changes)?),
+            created_at: Set(Utc::now().naive_utc()),
+            ..Default::default()
+        }
+        .insert(txn)
+        .await?;
+
+        Ok(patient)
+    }
+
+    async fn identify_patient(
+        txn: &DatabaseTransaction,
+        patient: patients::Model,
+        payload: IdentifyPatientRequest,
+        identified_by: i32,
+    ) -> Result<patients::Model, AppError> {
+        // A patient who already has a record is reconciled by merging the placeholder
+        // into it rather than by registering the NIK twice
+        if let Some(existing) = patients::Entity::find()
+            .filter(patients::Column::NationalId.eq(&payload.national_id))
+            .one(txn)
+            .await?
+        {
+            return Err(AppError::Conflict(format!(
+                "National ID {} is already registered to patient {}; merge this record into it instead",
+                payload.national_id, existing.id
+            )));
+        }
+
+        if let Some(bpjs_number) = &payload.bpjs_number
+            && patients::Entity::find()
+                .filter(patients::Column::BpjsNumber.eq(bpjs_number))
+                .filter(patients::Column::Id.ne(patient.id))
+                .one(txn)
+                .await?
+                .is_some()
+        {
+            return Err(AppError::Conflict(format!(
+                "BPJS number {} is already registered to another patient",
+                bpjs_number
+            )));
+        }
+
+        let mut changes = vec![PatientFieldChange {
+            field: "national_id".to_string(),
+            previous: patient.national_id.clone(),
+            current: Some(payload.national_id.clone()),
+        }];
+        changes.extend(diff_patient(&patient, &payload.demographics()));
+        changes.push(PatientFieldChange {
+            field: "is_unidentified".to_string(),
+            previous: Some(true.to_string()),
+            current: Some(false.to_string()),
+        });
+
+        let patient_id = patient.id;
+        let now = Utc::now().naive_utc();
+        let mut model: ActiveModel = patient.into_active_model();
+        model.national_id = Set(Some(payload.national_id));
+        model.name = Set(payload.name);
+        model.date_of_birth = Set(payload.date_of_birth);
+        model.date_of_birth_estimated = Set(false);
+        model.gender = Set(payload.gender.to_string());
+        if let Some(bpjs_number) = payload.bpjs_number {
+            model.bpjs_number = Set(Some(bpjs_number));
+        }
+        model.is_unidentified = Set(false);
+        model.updated_at = Set(now);
+        let patient = model.update(txn).await?;
+
+        patient_history::ActiveModel {
+            patient_id: Set(patient_id),
+            source: Set(PatientChangeSource::Registry),
+            changed_by: Set(Some(identified_by)),
+            changes: Set(serde_json::to_value(&changes)?),
+            created_at: Set(now),
+            ..Default::default()
+        }
+        .insert(txn)
+        .await?;
+
+        Ok(patient)
+    }
+
+    async fn find_patient_history(
+        db: &DatabaseConnection,
+        patient_id: i32,
+    ) -> Result<Vec<patient_history::Model>, AppError> {
+        Ok(patient_history::Entity::find()
+            .filter(patient_history::Column::PatientId.eq(patient_id))
+            .order_by_desc(patient_history::Column::CreatedAt)
+            .order_by_desc(patient_history::Column::Id)
+            .all(db)
+            .await?)
+    }
+
+    async fn find_duplicate_candidates(
+        db: &DatabaseConnection,
+        patient_id: Option<i32>,
+        min_score: f64,
+        limit: u64,
+    ) -> Result<Vec<DuplicateCandidate>, AppError> {
+        Ok(
+            DuplicateCandidate::find_by_statement(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                DUPLICATE_CANDIDATES_SQL,
+                [patient_id.into(), min_score.into(), (limit as i64).into()],
+            ))
+            .all(db)
+            .await?,
+        )
+    }
+
+    async fn merge_patients(
+        txn: &DatabaseTransaction,
+        survivor_id: i32,
+        merged_id: i32,
+        reason: Option<String>,
+        merged_by: i32,
+    ) -> Result<patient_merges::Model, AppError> {
+        if survivor_id == merged_id {
+            return Err(AppError::BadRequest(
+                "A patient cannot be merged into itself".to_string(),
+            ));
+        }
+
+        // Locked in id order so two merges over the same pair cannot deadlock
+        let locked = patients::Entity::find()
+            .filter(patients::Column::Id.is_in([survivor_id, merged_id]))
+            .order_by_asc(patients::Column::Id)
+            .lock_exclusive()
+            .all(txn)
+            .await?;
+        let find = |id: i32| {
+            locked
+                .iter()
+                .find(|patient| patient.id == id)
+                .cloned()
+                .ok_or(AppError::NotFound(format!(
+                    "Patient with id {} is not found",
+                    id
+                )))
+        };
+        let survivor = find(survivor_id)?;
+        let merged = find(merged_id)?;
+
+        for patient in [&survivor, &merged] {
+            if let Some(into) = patient.merged_into_id {
+                return Err(AppError::Conflict(format!(
+                    "Patient {} has already been merged into patient {}",
+                    patient.id, into
+                )));
+            }
+        }
+        // A temporary emergency record is reconciled by merging it into the identified
+        // patient, never the other way round
+        if survivor.is_unidentified && !merged.is_unidentified {
+            return Err(AppError::Conflict(format!(
+                "Patient {} is an unidentified emergency registration, merge it into patient {} instead",
+                survivor.id, merged.id
+            )));
+        }
+
+        let now = Utc::now().naive_utc();
+
+        let visit_intent_ids: Vec<i32> = patients_visit_intent::Entity::find()
+            .select_only()
+            .column(patients_visit_intent::Column::Id)
+            .filter(patients_visit_intent::Column::PatientId.eq(merged_id))
+            .into_tuple()
+            .all(txn)
+            .await?;
+        patients_visit_intent::Entity::update_many()
+            .col_expr(
+                patients_visit_intent::Column::PatientId,
+                Expr::value(survivor_id),
+            )
+            .col_expr(patients_visit_intent::Column::UpdatedAt, Expr::value(now))
+            .filter(patients_visit_intent::Column::Id.is_in(visit_intent_ids.clone()))
+            .exec(txn)
+            .await?;
+
+        let referral_document_ids: Vec<i32> = referral_documents::Entity::find()
+            .select_only()
+            .column(referral_documents::Column::Id)
+            .filter(referral_documents::Column::PatientsId.eq(merged_id))
+            .into_tuple()
+            .all(txn)
+            .await?;
+        referral_documents::Entity::update_many()
+            .col_expr(
+                referral_documents::Column::PatientsId,
+                Expr::value(survivor_id),
+            )
+            .col_expr(referral_documents::Column::UpdatedAt, Expr::value(now))
+            .filter(referral_documents::Column::Id.is_in(referral_document_ids.clone()))
+            .exec(txn)
+            .await?;
+
+        // An allergy the survivor already has on file stays with the merged record
+        let allergy_ids: Vec<i32> = patient_allergies::Entity::find()
+            .select_only()
+            .column(patient_allergies::Column::Id)
+            .filter(patient_allergies::Column::PatientId.eq(merged_id))
+            .filter(patient_allergies::Column::DeletedAt.is_null())
+            .filter(Expr::cust_with_values(
+                "lower(substance) NOT IN (SELECT lower(substance) FROM patient_allergies \
+                WHERE patient_id = $1 AND deleted_at IS NULL)",
+                [survivor_id],
+            ))
+            .into_tuple()
+            .all(txn)
+            .await?;
+        patient_allergies::Entity::update_many()
+            .col_expr(
+                patient_allergies::Column::PatientId,
+                Expr::value(survivor_id),
+            )
+            .col_expr(patient_allergies::Column::UpdatedAt, Expr::value(now))
+            .filter(patient_allergies::Column::Id.is_in(allergy_ids.clone()))
+            .exec(txn)
+            .await?;
+
+        let mut merged: ActiveModel = merged.into_active_model();
+        merged.merged_into_id = Set(Some(survivor_id));
+        merged.updated_at = Set(now);
+        merged.update(txn).await?;
+
+        Ok(patient_merges::ActiveModel {
+            survivor_id: Set(survivor_id),
+            merged_id: Set(merged_id),
+            visit_intent_ids: Set(serde_json::to_value(&visit_intent_ids)?),
+            referral_document_ids: Set(serde_json::to_value(&referral_document_ids)?),
+            allergy_ids: Set(serde_json::to_value(&allergy_ids)?),
+            reason: Set(reason),
+            merged_by: Set(Some(merged_by)),
+            merged_at: Set(now),
+            ..Default::default()
+        }
+        .insert(txn)
+        .await?)
+    }
+
+    async fn undo_patient_merge(
+        txn: &DatabaseTransaction,
+        merge_id: i32,
+        undone_by: i32,
+    ) -> Result<patient_merges::Model, AppError> {
+        let merge = patient_merges::Entity::find_by_id(merge_id)
+            .lock_exclusive()
+            .one(txn)
+            .await?
+            .ok_or(AppError::NotFound(format!(
+                "Patient merge with id {} is not found",
+                merge_id
+            )))?;
+
+        if merge.undone_at.is_some() {
+            return Err(AppError::Conflict(format!(
+                "Patient merge {} has already been undone",
+                merge_id
+            )));
+        }
+
+        let locked = patients::Entity::find()
+            .filter(patients::Column::Id.is_in([merge.survivor_id, merge.merged_id]))
+            .order_by_asc(patients::Column::Id)
+            .lock_exclusive()
+            .all(txn)
+            .await?;
+        let survivor = locked
+            .iter()
+            .find(|patient| patient.id == merge.survivor_id);
+        let merged = locked.iter().find(|patient| patient.id == merge.merged_id);
+        let (Some(survivor), Some(merged)) = (survivor, merged) else {
+            return Err(AppError::NotFound(format!(
+                "Patients of merge {} are not found",
+                merge_id
+            )));
+        };
+
+        // Merges stack: a survivor merged away since has to be restored first, otherwise
+        // the rows recorded here are no longer where this merge put them
+        if let Some(into) = survivor.merged_into_id {
+            return Err(AppError::Conflict(format!(
+                "Patient {} has since been merged into patient {}, undo that merge first",
+                survivor.id, into
+            )));
+        }
+        if merged.merged_into_id != Some(merge.survivor_id) {
+            return Err(AppError::Conflict(format!(
+                "Patient {} is no longer merged into patient {}",
+                merged.id, merge.survivor_id
+            )));
+        }
+
+        let now = Utc::now().naive_utc();
+
+        // Only rows that still belong to the survivor go back; anything re-assigned
+        // since the merge is left alone
+        patients_visit_intent::Entity::update_many()
+            .col_expr(
+                patients_visit_intent::Column::PatientId,
+                Expr::value(merge.merged_id),
+            )
+            .col_expr(patients_visit_intent::Column::UpdatedAt, Expr::value(now))
+            .filter(patients_visit_intent::Column::Id.is_in(json_ids(&merge.visit_intent_ids)))
+            .filter(patients_visit_intent::Column::PatientId.eq(merge.survivor_id))
+            .exec(txn)
+            .await?;
+        referral_documents::Entity::update_many()
+            .col_expr(
+                referral_documents::Column::PatientsId,
+                Expr::value(merge.merged_id),
+            )
+            .col_expr(referral_documents::Column::UpdatedAt, Expr::value(now))
+            .filter(referral_documents::Column::Id.is_in(json_ids(&merge.referral_document_ids)))
+            .filter(referral_documents::Column::PatientsId.eq(merge.survivor_id))
+            .exec(txn)
+            .await?;
+        patient_allergies::Entity::update_many()
+            .col_expr(
+                patient_allergies::Column::PatientId,
+                Expr::value(merge.merged_id),
+            )
+            .col_expr(patient_allergies::Column::UpdatedAt, Expr::value(now))
+            .filter(patient_allergies::Column::Id.is_in(json_ids(&merge.allergy_ids)))
+            .filter(patient_allergies::Column::PatientId.eq(merge.survivor_id))
+            .exec(txn)
+            .await?;
+
+        let mut merged: ActiveModel = merged.clone().into_active_model();
+        merged.merged_into_id = Set(None);
+        merged.updated_at = Set(now);
+        merged.update(txn).await?;
+
+        let mut merge = merge.into_active_model();
+        merge.undone_by = Set(Some(undone_by));
+        merge.undone_at = Set(Some(now));
+        Ok(merge.update(txn).await?)
+    }
+
+    async fn find_patient_merges(
+        db: &DatabaseConnection,
+        patient_id: i32,
+    ) -> Result<Vec<patient_merges::Model>, AppError> {
+        Ok(patient_merges::Entity::find()
+            .filter(
+                patient_merges::Column::SurvivorId
+                    .eq(patient_id)
+                    .or(patient_merges::Column::MergedId.eq(patient_id)),
+            )
+            .order_by_desc(patient_merges::Column::MergedAt)
+            .order_by_desc(patient_merges::Column::Id)
+            .all(db)
+            .await?)
+    }
+
+    async fn assign_medical_record_number(
+        txn: &DatabaseTransaction,
+        patient: patients::Model,
+        mrn: &MrnConfig,
+        date: NaiveDate,
+    ) -> Result<patients::Model, AppError> {
+        if patient.medical_record_number.is_some() {
+            return Ok(patient);
+        }
+
+        let scope = mrn.counter_scope(date);
+        // Drawn in the registration transaction: a rolled back registration also rolls
+        // back its number, so the sequence has no gaps of its own making
+        let row = txn
+            .query_one(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"INSERT INTO mrn_counters (scope, last_number, updated_at)
+                VALUES ($1, 1, CURRENT_TIMESTAMP)
+                ON CONFLICT (scope)
+                DO UPDATE SET last_number = mrn_counters.last_number + 1,
+                    updated_at = CURRENT_TIMESTAMP
+                RETURNING last_number"#,
+                [scope.clone().into()],
+            ))
+            .await?
+            .ok_or(AppError::Internal(
+                "Failed to allocate a medical record number".into(),
+            ))?;
+        let number = row.try_get::<i32>("", "last_number")?;
+
+        let mut model: ActiveModel = patient.into_active_model();
+        model.medical_record_number = Set(Some(mrn.format(&scope, number)));
+        model.mrn_scope = Set(Some(scope));
+        model.mrn_sequence = Set(Some(number));
+        model.updated_at = Set(Utc::now().naive_utc());
+
+        Ok(model.update(txn).await?)
+    }
+
+    async fn find_mrn_gaps(
+        db: &DatabaseConnection,
+        scope: &str,
+        limit: u64,
+    ) -> Result<MrnGapReport, AppError> {
+        let counts = MrnCounts::find_by_statement(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            r#"SELECT
+                COALESCE((SELECT last_number FROM mrn_counters WHERE scope = $1), 0) AS last_number,
+                (SELECT count(*) FROM patients
+                    WHERE mrn_scope = $1
+                    AND mrn_sequence <= COALESCE(
+                        (SELECT last_number FROM mrn_counters WHERE scope = $1), 0)
+                ) AS assigned"#,
+            [scope.into()],
+        ))
+        .one(db)
+        .await?
+        .ok_or(AppError::Internal(
+            "Failed to count medical record numbers".into(),
+        ))?;
+
+        let missing = MrnNumber::find_by_statement(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            r#"SELECT gs.number
+            FROM mrn_counters c
+            CROSS JOIN LATERAL generate_series(1, c.last_number) AS gs(number)
+            WHERE c.scope = $1
+                AND NOT EXISTS (
+                    SELECT 1 FROM patients p
+                    WHERE p.mrn_scope = $1 AND p.mrn_sequence = gs.number
+                )
+            ORDER BY gs.number
+            LIMIT $2"#,
+            [scope.into(), (limit as i64).into()],
+        ))
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|row| row.number)
+        .collect();
+
+        Ok(MrnGapReport {
+            scope: scope.to_string(),
+            last_number: counts.last_number,
+            assigned: counts.assigned,
+            missing_count: i64::from(counts.last_number) - counts.assigned,
+            missing,
+        })
+    }
+}
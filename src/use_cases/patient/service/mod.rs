@@ -0,0 +1 @@
+pub mod patient_service;
@@ -0,0 +1,369 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Local, NaiveDateTime, Utc};
+use entity::{patient_history::PatientChangeSource, patient_merges, patients};
+use sea_orm::{ActiveEnum, DatabaseConnection, TransactionTrait};
+use validator::ValidationErrors;
+
+pub use crate::use_cases::patient::contracts::patient_service_contract::PatientServiceContracts;
+use crate::{
+    dtos::{
+        patient::{
+            patient_request::{
+                DuplicateCandidatesQuery, IdentifyPatientRequest, MergePatientRequest, MrnGapQuery,
+                PatientLookupQuery, PatientSearchQuery, UpdatePatientRequest,
+            },
+            response::{
+                DuplicateCandidatesResponse, MrnGapReport, PatientHistoryEntry,
+                PatientHistoryResponse, PatientMergeResponse, PatientMergesResponse,
+                PatientResponse, PatientSearchResponse,
+            },
+        },
+        triage::create_triage_request::Gender,
+    },
+    error_handling::app_error::AppError,
+    format_created_at,
+    infra::config::MrnConfig,
+    use_cases::patient::{contracts::PatientTraitRepo, repo::patient_repo::PatientRepo},
+    utils::{
+        helpers::hospital_now,
+        nik::{Nik, check_nik_profile},
+    },
+};
+
+pub struct PatientService;
+
+const DEFAULT_PER_PAGE: u64 = 20;
+
+const DEFAULT_DUPLICATE_MIN_SCORE: f64 = 0.6;
+const DEFAULT_DUPLICATE_LIMIT: u64 = 50;
+
+/// Most missing numbers listed by the record number audit.
+const MRN_GAP_LIMIT: u64 = 1000;
+
+fn patient_response(patient: patients::Model) -> PatientResponse {
+    PatientResponse {
+        id: patient.id,
+        medical_record_number: patient.medical_record_number,
+        name: patient.name,
+        date_of_birth: patient.date_of_birth,
+        national_id: patient.national_id,
+        bpjs_number: patient.bpjs_number,
+        gender: patient.gender,
+        emergency_contact_name: patient.emergency_contact_name,
+        emergency_contact_phone: patient.emergency_contact_phone,
+        emergency_contact_relationship: patient.emergency_contact_relationship,
+        blood_type: patient.blood_type,
+        known_allergies: patient.known_allergies,
+        merged_into_id: patient.merged_into_id,
+        is_unidentified: patient.is_unidentified,
+        date_of_birth_estimated: patient.date_of_birth_estimated,
+        identification_notes: patient.identification_notes,
+        created_at: format_created_at!(patient.created_at),
+        updated_at: format_created_at!(patient.updated_at),
+    }
+}
+
+fn merge_response(merge: patient_merges::Model) -> Result<PatientMergeResponse, AppError> {
+    Ok(PatientMergeResponse {
+        id: merge.id,
+        survivor_id: merge.survivor_id,
+        merged_id: merge.merged_id,
+        visit_intent_ids: serde_json::from_value(merge.visit_intent_ids)?,
+        referral_document_ids: serde_json::from_value(merge.referral_document_ids)?,
+        allergy_ids: serde_json::from_value(merge.allergy_ids)?,
+        reason: merge.reason,
+        merged_by: merge.merged_by,
+        merged_at: format_created_at!(merge.merged_at),
+        undone_by: merge.undone_by,
+        undone_at: merge
+            .undone_at
+            .map(|undone_at| format_created_at!(undone_at)),
+    })
+}
+
+#[async_trait]
+impl PatientServiceContracts for PatientService {
+    async fn get_patient(
+        db: &DatabaseConnection,
+        patient_id: i32,
+    ) -> Result<PatientResponse, AppError> {
+        let patient = <PatientRepo as PatientTraitRepo>::find_patient_by_id(db, patient_id).await?;
+        Ok(patient_response(patient))
+    }
+
+    async fn lookup_patient(
+        db: &DatabaseConnection,
+        query: PatientLookupQuery,
+    ) -> Result<PatientResponse, AppError> {
+        let patient = match (
+            query.national_id,
+            query.bpjs_number,
+            query.medical_record_number,
+        ) {
+            (Some(national_id), None, None) => {
+                <PatientRepo as PatientTraitRepo>::find_patient_by_national_id(db, &national_id)
+                    .await?
+                    .ok_or(AppError::NotFound(format!(
+                        "Patient with national ID {} is not found",
+                        national_id
+                    )))?
+            }
+            (None, Some(bpjs_number), None) => {
+                <PatientRepo as PatientTraitRepo>::find_patient_by_bpjs_number(db, &bpjs_number)
+                    .await?
+                    .ok_or(AppError::NotFound(format!(
+                        "Patient with BPJS number {} is not found",
+                        bpjs_number
+                    )))?
+            }
+            (None, None, Some(medical_record_number)) => {
+                <PatientRepo as PatientTraitRepo>::find_patient_by_medical_record_number(
+                    db,
+                    &medical_record_number,
+                )
+                .await?
+                .ok_or(AppError::NotFound(format!(
+                    "Patient with medical record number {} is not found",
+                    medical_record_number
+                )))?
+            }
+            _ => {
+                return Err(AppError::BadRequest(
+                    "Exactly one of national_id, bpjs_number or medical_record_number is required"
+                        .to_string(),
+                ));
+            }
+        };
+
+        Ok(patient_response(patient))
+    }
+
+    async fn search_patients(
+        db: &DatabaseConnection,
+        query: PatientSearchQuery,
+    ) -> Result<PatientSearchResponse, AppError> {
+        let name = query.name.as_deref().map(str::trim);
+        if name.is_none() && query.date_of_birth.is_none() {
+            return Err(AppError::BadRequest(
+                "Search by name, date_of_birth or both".to_string(),
+            ));
+        }
+
+        let page = query.page.unwrap_or(1);
+        let per_page = query.per_page.unwrap_or(DEFAULT_PER_PAGE);
+
+        let (patients, total_items) = <PatientRepo as PatientTraitRepo>::search_patients(
+            db,
+            name,
+            query.date_of_birth,
+            page,
+            per_page,
+        )
+        .await?;
+
+        Ok(PatientSearchResponse {
+            page,
+            per_page,
+            total_items,
+            total_pages: total_items.div_ceil(per_page),
+            data: patients.into_iter().map(patient_response).collect(),
+        })
+    }
+
+    async fn update_patient(
+        db: &DatabaseConnection,
+        patient_id: i32,
+        payload: UpdatePatientRequest,
+        changed_by: i32,
+    ) -> Result<PatientResponse, AppError> {
+        if payload.is_empty() {
+            return Err(AppError::BadRequest("Nothing to update".to_string()));
+        }
+        if let Some(date_of_birth) = payload.date_of_birth
+            && date_of_birth > hospital_now().date_naive()
+        {
+            return Err(AppError::BadRequest(
+                "Date of birth cannot be in the future".to_string(),
+            ));
+        }
+
+        let txn = db.begin().await?;
+        let patient =
+            <PatientRepo as PatientTraitRepo>::find_patient_for_update(&txn, patient_id).await?;
+
+        // A corrected date of birth or gender must still agree with the stored NIK
+        if let Some(national_id) = patient.national_id.as_deref()
+            && let Ok(nik) = Nik::parse(national_id)
+        {
+            let mut errors = ValidationErrors::new();
+            check_nik_profile(
+                &nik,
+                payload.date_of_birth,
+                payload.gender.as_ref().map(Gender::is_female),
+                &mut errors,
+            );
+            if !errors.is_empty() {
+                return Err(errors.into());
+            }
+        }
+
+        let patient = <PatientRepo as PatientTraitRepo>::apply_patient_changes(
+            &txn,
+            patient,
+            payload,
+            PatientChangeSource::Registry,
+            Some(changed_by),
+        )
+        .await?;
+        txn.commit().await?;
+
+        Ok(patient_response(patient))
+    }
+
+    async fn identify_patient(
+        db: &DatabaseConnection,
+        patient_id: i32,
+        payload: IdentifyPatientRequest,
+        identified_by: i32,
+    ) -> Result<PatientResponse, AppError> {
+        if payload.date_of_birth > hospital_now().date_naive() {
+            return Err(AppError::BadRequest(
+                "Date of birth cannot be in the future".to_string(),
+            ));
+        }
+
+        let txn = db.begin().await?;
+        let patient =
+            <PatientRepo as PatientTraitRepo>::find_patient_for_update(&txn, patient_id).await?;
+        if patient.merged_into_id.is_some() {
+            return Err(AppError::Conflict(format!(
+                "Patient {} has been merged into another record",
+                patient_id
+            )));
+        }
+        if !patient.is_unidentified {
+            return Err(AppError::Conflict(format!(
+                "Patient {} is already identified",
+                patient_id
+            )));
+        }
+
+        let patient = <PatientRepo as PatientTraitRepo>::identify_patient(
+            &txn,
+            patient,
+            payload,
+            identified_by,
+        )
+        .await?;
+        txn.commit().await?;
+
+        Ok(patient_response(patient))
+    }
+
+    async fn get_patient_history(
+        db: &DatabaseConnection,
+        patient_id: i32,
+    ) -> Result<PatientHistoryResponse, AppError> {
+        <PatientRepo as PatientTraitRepo>::find_patient_by_id(db, patient_id).await?;
+        let history =
+            <PatientRepo as PatientTraitRepo>::find_patient_history(db, patient_id).await?;
+
+        let data = history
+            .into_iter()
+            .map(|entry| {
+                Ok(PatientHistoryEntry {
+                    id: entry.id,
+                    source: entry.source.to_value(),
+                    changed_by: entry.changed_by,
+                    changes: serde_json::from_value(entry.changes)?,
+                    created_at: format_created_at!(entry.created_at),
+                })
+            })
+            .collect::<Result<Vec<_>, AppError>>()?;
+
+        Ok(PatientHistoryResponse { patient_id, data })
+    }
+
+    async fn find_duplicate_candidates(
+        db: &DatabaseConnection,
+        query: DuplicateCandidatesQuery,
+    ) -> Result<DuplicateCandidatesResponse, AppError> {
+        if let Some(patient_id) = query.patient_id {
+            <PatientRepo as PatientTraitRepo>::find_patient_by_id(db, patient_id).await?;
+        }
+
+        let min_score = query.min_score.unwrap_or(DEFAULT_DUPLICATE_MIN_SCORE);
+        let data = <PatientRepo as PatientTraitRepo>::find_duplicate_candidates(
+            db,
+            query.patient_id,
+            min_score,
+            query.limit.unwrap_or(DEFAULT_DUPLICATE_LIMIT),
+        )
+        .await?;
+
+        Ok(DuplicateCandidatesResponse { min_score, data })
+    }
+
+    async fn merge_patients(
+        db: &DatabaseConnection,
+        survivor_id: i32,
+        payload: MergePatientRequest,
+        merged_by: i32,
+    ) -> Result<PatientMergeResponse, AppError> {
+        let txn = db.begin().await?;
+        let merge = <PatientRepo as PatientTraitRepo>::merge_patients(
+            &txn,
+            survivor_id,
+            payload.merged_patient_id,
+            payload.reason,
+            merged_by,
+        )
+        .await?;
+        txn.commit().await?;
+
+        merge_response(merge)
+    }
+
+    async fn undo_patient_merge(
+        db: &DatabaseConnection,
+        merge_id: i32,
+        undone_by: i32,
+    ) -> Result<PatientMergeResponse, AppError> {
+        let txn = db.begin().await?;
+        let merge =
+            <PatientRepo as PatientTraitRepo>::undo_patient_merge(&txn, merge_id, undone_by)
+                .await?;
+        txn.commit().await?;
+
+        merge_response(merge)
+    }
+
+    async fn get_patient_merges(
+        db: &DatabaseConnection,
+        patient_id: i32,
+    ) -> Result<PatientMergesResponse, AppError> {
+        <PatientRepo as PatientTraitRepo>::find_patient_by_id(db, patient_id).await?;
+        let merges = <PatientRepo as PatientTraitRepo>::find_patient_merges(db, patient_id).await?;
+
+        Ok(PatientMergesResponse {
+            patient_id,
+            data: merges
+                .into_iter()
+                .map(merge_response)
+                .collect::<Result<Vec<_>, AppError>>()?,
+        })
+    }
+
+    async fn find_mrn_gaps(
+        db: &DatabaseConnection,
+        mrn: &MrnConfig,
+        query: MrnGapQuery,
+    ) -> Result<MrnGapReport, AppError> {
+        let scope = query
+            .scope
+            .map(|scope| scope.trim().to_uppercase())
+            .unwrap_or_else(|| mrn.counter_scope(hospital_now().date_naive()));
+
+        <PatientRepo as PatientTraitRepo>::find_mrn_gaps(db, &scope, MRN_GAP_LIMIT).await
+    }
+}
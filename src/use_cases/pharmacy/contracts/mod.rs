@@ -0,0 +1,4 @@
+pub mod pharmacy_repo_contract;
+pub mod pharmacy_service_contract;
+pub use self::pharmacy_repo_contract::PharmacyTraitRepo;
+pub use self::pharmacy_service_contract::PharmacyServiceContracts;
@@ -0,0 +1,148 @@
+use async_trait::async_trait;
+use chrono::{NaiveDate, NaiveDateTime};
+use entity::{
+    drug_batches, drugs, goods_receipts,
+    stock_movements::{self, AdjustmentReason, StockMovementType},
+    storage_locations,
+};
+use sea_orm::{DatabaseConnection, DatabaseTransaction};
+
+use crate::{
+    dtos::pharmacy::pharmacy_request::{
+        CreateDrugRequest, CreateLocationRequest, UpdateDrugRequest,
+    },
+    error_handling::app_error::AppError,
+};
+
+/// A batch with its drug and the location it is stored in.
+pub type BatchRecord = (drug_batches::Model, drugs::Model, storage_locations::Model);
+
+/// A goods receipt with its location and the movement, batch and drug of every line.
+pub struct GoodsReceiptRecord {
+    pub receipt: goods_receipts::Model,
+    pub location: storage_locations::Model,
+    pub lines: Vec<(stock_movements::Model, drug_batches::Model, drugs::Model)>,
+}
+
+/// The header of a delivery, checked against the locations, ready to store.
+pub struct NewGoodsReceipt {
+    pub location_id: i32,
+    pub supplier: String,
+    pub reference_no: Option<String>,
+    pub received_at: NaiveDateTime,
+    pub received_by: i32,
+    pub notes: Option<String>,
+}
+
+/// A signed change to the quantity of a batch and what caused it.
+pub struct NewStockMovement {
+    pub movement_type: StockMovementType,
+    pub quantity: i32,
+    pub reason: Option<AdjustmentReason>,
+    pub notes: Option<String>,
+    pub goods_receipt_id: Option<i32>,
+    pub prescription_item_id: Option<i32>,
+    pub performed_by: i32,
+}
+
+#[async_trait]
+pub trait PharmacyTraitRepo {
+    /// Drugs whose code starts with the term, followed by the ones whose brand or generic
+    /// name contains it; every drug by name without a term.
+    async fn search_drugs(
+        db: &DatabaseConnection,
+        term: Option<&str>,
+        include_inactive: bool,
+        limit: u64,
+    ) -> Result<Vec<drugs::Model>, AppError>;
+    async fn find_drug(db: &DatabaseConnection, drug_id: i32) -> Result<drugs::Model, AppError>;
+    async fn find_drugs(
+        db: &DatabaseConnection,
+        drug_ids: Vec<i32>,
+    ) -> Result<Vec<drugs::Model>, AppError>;
+    async fn find_active_drugs(db: &DatabaseConnection) -> Result<Vec<drugs::Model>, AppError>;
+    async fn create_drug(
+        db: &DatabaseConnection,
+        payload: CreateDrugRequest,
+    ) -> Result<drugs::Model, AppError>;
+    async fn update_drug(
+        db: &DatabaseConnection,
+        drug: drugs::Model,
+        payload: UpdateDrugRequest,
+    ) -> Result<drugs::Model, AppError>;
+    async fn find_locations(
+        db: &DatabaseConnection,
+    ) -> Result<Vec<storage_locations::Model>, AppError>;
+    async fn find_location(
+        db: &DatabaseConnection,
+        location_id: i32,
+    ) -> Result<storage_locations::Model, AppError>;
+    async fn create_location(
+        db: &DatabaseConnection,
+        payload: CreateLocationRequest,
+    ) -> Result<storage_locations::Model, AppError>;
+    async fn create_goods_receipt(
+        txn: &DatabaseTransaction,
+        receipt: NewGoodsReceipt,
+    ) -> Result<goods_receipts::Model, AppError>;
+    async fn find_goods_receipt(
+        db: &DatabaseConnection,
+        receipt_id: i32,
+    ) -> Result<GoodsReceiptRecord, AppError>;
+    /// The batch of a drug stocked at a location under a batch number, locked.
+    async fn find_batch_by_number_for_update(
+        txn: &DatabaseTransaction,
+        drug_id: i32,
+        location_id: i32,
+        batch_no: &str,
+    ) -> Result<Option<drug_batches::Model>, AppError>;
+    /// A batch with nothing on hand yet; stock comes in through a movement.
+    async fn create_batch(
+        txn: &DatabaseTransaction,
+        drug_id: i32,
+        location_id: i32,
+        batch_no: &str,
+        expiry_date: NaiveDate,
+    ) -> Result<drug_batches::Model, AppError>;
+    async fn find_batch_for_update(
+        txn: &DatabaseTransaction,
+        batch_id: i32,
+    ) -> Result<drug_batches::Model, AppError>;
+    /// Batches of a drug at a location with stock that expire after a date, locked and
+    /// ordered first expiry first.
+    async fn find_pickable_batches(
+        txn: &DatabaseTransaction,
+        drug_id: i32,
+        location_id: i32,
+        expiring_after: NaiveDate,
+    ) -> Result<Vec<drug_batches::Model>, AppError>;
+    /// Applies a movement to a locked batch and records it with the balance it leaves.
+    async fn record_movement(
+        txn: &DatabaseTransaction,
+        batch: drug_batches::Model,
+        movement: NewStockMovement,
+    ) -> Result<(drug_batches::Model, stock_movements::Model), AppError>;
+    async fn find_batch(db: &DatabaseConnection, batch_id: i32) -> Result<BatchRecord, AppError>;
+    /// Movements of a batch, latest first.
+    async fn find_batch_movements(
+        db: &DatabaseConnection,
+        batch_id: i32,
+    ) -> Result<Vec<stock_movements::Model>, AppError>;
+    /// Batches of a drug still holding stock, first expiry first.
+    async fn find_drug_batches(
+        db: &DatabaseConnection,
+        drug_id: i32,
+    ) -> Result<Vec<BatchRecord>, AppError>;
+    /// Quantity on hand per drug in batches that expire after a date.
+    async fn find_usable_totals(
+        db: &DatabaseConnection,
+        location_id: Option<i32>,
+        expiring_after: NaiveDate,
+    ) -> Result<Vec<(i32, i64)>, AppError>;
+    /// Batches still holding stock that expire on or before a date, first expiry first.
+    async fn find_expiring_batches(
+        db: &DatabaseConnection,
+        location_id: Option<i32>,
+        until: NaiveDate,
+    ) -> Result<Vec<BatchRecord>, AppError>;
+}
@@ -0,0 +1,71 @@
+use async_trait::async_trait;
+use sea_orm::DatabaseConnection;
+
+use crate::{
+    dtos::pharmacy::{
+        pharmacy_request::{
+            CreateDrugRequest, CreateLocationRequest, DrugSearchQuery, GoodsReceiptRequest,
+            NearExpiryQuery, StockAdjustmentRequest, StockReportQuery, UpdateDrugRequest,
+        },
+        response::{
+            BatchMovementsResponse, DrugResponse, DrugSearchResponse, DrugStockResponse,
+            GoodsReceiptResponse, LocationResponse, LocationsResponse, LowStockReportResponse,
+            NearExpiryReportResponse, StockAdjustmentResponse,
+        },
+    },
+    error_handling::app_error::AppError,
+};
+
+#[async_trait]
+pub trait PharmacyServiceContracts {
+    async fn search_drugs(
+        db: &DatabaseConnection,
+        query: DrugSearchQuery,
+    ) -> Result<DrugSearchResponse, AppError>;
+    async fn get_drug(db: &DatabaseConnection, drug_id: i32) -> Result<DrugResponse, AppError>;
+    async fn create_drug(
+        db: &DatabaseConnection,
+        payload: CreateDrugRequest,
+    ) -> Result<DrugResponse, AppError>;
+    async fn update_drug(
+        db: &DatabaseConnection,
+        drug_id: i32,
+        payload: UpdateDrugRequest,
+    ) -> Result<DrugResponse, AppError>;
+    async fn get_drug_stock(
+        db: &DatabaseConnection,
+        drug_id: i32,
+    ) -> Result<DrugStockResponse, AppError>;
+    async fn get_locations(db: &DatabaseConnection) -> Result<LocationsResponse, AppError>;
+    async fn create_location(
+        db: &DatabaseConnection,
+        payload: CreateLocationRequest,
+    ) -> Result<LocationResponse, AppError>;
+    async fn receive_goods(
+        db: &DatabaseConnection,
+        user_id: i32,
+        payload: GoodsReceiptRequest,
+    ) -> Result<GoodsReceiptResponse, AppError>;
+    async fn get_goods_receipt(
+        db: &DatabaseConnection,
+        receipt_id: i32,
+    ) -> Result<GoodsReceiptResponse, AppError>;
+    async fn adjust_stock(
+        db: &DatabaseConnection,
+        batch_id: i32,
+        user_id: i32,
+        payload: StockAdjustmentRequest,
+    ) -> Result<StockAdjustmentResponse, AppError>;
+    async fn get_batch_movements(
+        db: &DatabaseConnection,
+        batch_id: i32,
+    ) -> Result<BatchMovementsResponse, AppError>;
+    async fn get_low_stock_report(
+        db: &DatabaseConnection,
+        query: StockReportQuery,
+    ) -> Result<LowStockReportResponse, AppError>;
+    async fn get_near_expiry_report(
+        db: &DatabaseConnection,
+        query: NearExpiryQuery,
+    ) -> Result<NearExpiryReportResponse, AppError>;
+}
@@ -0,0 +1,510 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use chrono::{NaiveDate, Utc};
+use entity::{drug_batches, drugs, goods_receipts, stock_movements, storage_locations};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, DatabaseConnection, DatabaseTransaction,
+    EntityTrait, IntoActiveModel, QueryFilter, QueryOrder, QuerySelect, SqlErr, sea_query::Expr,
+};
+
+use crate::{
+    dtos::pharmacy::pharmacy_request::{
+        CreateDrugRequest, CreateLocationRequest, UpdateDrugRequest,
+    },
+    error_handling::app_error::AppError,
+    use_cases::pharmacy::contracts::pharmacy_repo_contract::{
+        BatchRecord, GoodsReceiptRecord, NewGoodsReceipt, NewStockMovement, PharmacyTraitRepo,
+    },
+};
+
+pub struct PharmacyRepo;
+
+/// Codes are stored trimmed and in capitals, so `pct500` finds `PCT500`.
+fn normalize_code(code: &str) -> String {
+    code.trim().to_uppercase()
+}
+
+/// Blank free text is stored as absent.
+fn trimmed(text: Option<String>) -> Option<String> {
+    text.map(|text| text.trim().to_string())
+        .filter(|text| !text.is_empty())
+}
+
+async fn with_drug_and_location(
+    db: &DatabaseConnection,
+    batches: Vec<drug_batches::Model>,
+) -> Result<Vec<BatchRecord>, AppError> {
+    let drug_ids = batches
+        .iter()
+        .map(|batch| batch.drug_id)
+        .collect::<Vec<_>>();
+    let location_ids = batches
+        .iter()
+        .map(|batch| batch.location_id)
+        .collect::<Vec<_>>();
+
+    let drugs: HashMap<i32, drugs::Model> = drugs::Entity::find()
+        .filter(drugs::Column::Id.is_in(drug_ids))
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|drug| (drug.id, drug))
+        .collect();
+    let locations: HashMap<i32, storage_locations::Model> = storage_locations::Entity::find()
+        .filter(storage_locations::Column::Id.is_in(location_ids))
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|location| (location.id, location))
+        .collect();
+
+    Ok(batches
+        .into_iter()
+        .filter_map(|batch| {
+            let drug = drugs.get(&batch.drug_id)?.clone();
+            let location = locations.get(&batch.location_id)?.clone();
+            Some((batch, drug, location))
+        })
+        .collect())
+}
+
+#[async_trait]
+impl PharmacyTraitRepo for PharmacyRepo {
+    async fn search_drugs(
+        db: &DatabaseConnection,
+        term: Option<&str>,
+        include_inactive: bool,
+        limit: u64,
+    ) -> Result<Vec<drugs::Model>, AppError> {
+        let mut query = drugs::Entity::find();
+        if !include_inactive {
+            query = query.filter(drugs::Column::IsActive.eq(true));
+        }
+        if let Some(term) = term.map(str::trim) {
+            let code_prefix = normalize_code(term);
+            query = query
+                .filter(Expr::cust_with_values(
+                    "(starts_with(code, $1) \
+                     OR strpos(lower(name), lower($2)) > 0 \
+                     OR strpos(lower(generic_name), lower($3)) > 0)",
+                    [code_prefix.as_str(), term, term],
+                ))
+                .order_by_asc(Expr::cust_with_values(
+                    "CASE WHEN starts_with(code, $1) THEN 0 ELSE 1 END",
+                    [code_prefix.as_str()],
+                ));
+        }
+
+        Ok(query
+            .order_by_asc(drugs::Column::Name)
+            .order_by_asc(drugs::Column::Code)
+            .limit(limit)
+            .all(db)
+            .await?)
+    }
+
+    async fn find_drug(db: &DatabaseConnection, drug_id: i32) -> Result<drugs::Model, AppError> {
+        drugs::Entity::find_by_id(drug_id)
+            .one(db)
+            .await?
+            .ok_or(AppError::NotFound(format!(
+                "Drug with id {} is not found",
+                drug_id
+            )))
+    }
+
+    async fn find_drugs(
+        db: &DatabaseConnection,
+        drug_ids: Vec<i32>,
+    ) -> Result<Vec<drugs::Model>, AppError> {
+        Ok(drugs::Entity::find()
+            .filter(drugs::Column::Id.is_in(drug_ids))
+            .all(db)
+            .await?)
+    }
+
+    async fn find_active_drugs(db: &DatabaseConnection) -> Result<Vec<drugs::Model>, AppError> {
+        Ok(drugs::Entity::find()
+            .filter(drugs::Column::IsActive.eq(true))
+            .order_by_asc(drugs::Column::Name)
+            .all(db)
+            .await?)
+    }
+
+    async fn create_drug(
+        db: &DatabaseConnection,
+        payload: CreateDrugRequest,
+    ) -> Result<drugs::Model, AppError> {
+        let now = Utc::now().naive_utc();
+        let code = normalize_code(&payload.code);
+        let model = drugs::ActiveModel {
+            code: Set(code.clone()),
+            name: Set(payload.name.trim().to_string()),
+            generic_name: Set(payload.generic_name.trim().to_string()),
+            dosage_form: Set(payload.dosage_form.trim().to_string()),
+            strength: Set(payload.strength.trim().to_string()),
+            unit: Set(normalize_code(&payload.unit)),
+            therapeutic_class: Set(trimmed(payload.therapeutic_class)),
+            reorder_level: Set(payload.reorder_level),
+            is_active: Set(true),
+            created_at: Set(now),
+            updated_at: Set(now),
+            ..Default::default()
+        };
+
+        model.insert(db).await.map_err(|err| match err.sql_err() {
+            Some(SqlErr::UniqueConstraintViolation(_)) => {
+                AppError::Conflict(format!("Drug with code {} already exists", code))
+            }
+            _ => err.into(),
+        })
+    }
+
+    async fn update_drug(
+        db: &DatabaseConnection,
+        drug: drugs::Model,
+        payload: UpdateDrugRequest,
+    ) -> Result<drugs::Model, AppError> {
+        let mut model = drug.into_active_model();
+        if let Some(name) = payload.name {
+            model.name = Set(name.trim().to_string());
+        }
+        if let Some(generic_name) = payload.generic_name {
+            model.generic_name = Set(generic_name.trim().to_string());
+        }
+        if let Some(dosage_form) = payload.dosage_form {
+            model.dosage_form = Set(dosage_form.trim().to_string());
+        }
+        if let Some(strength) = payload.strength {
+            model.strength = Set(strength.trim().to_string());
+        }
+        if let Some(unit) = payload.unit {
+            model.unit = Set(normalize_code(&unit));
+        }
+        if payload.therapeutic_class.is_some() {
+            model.therapeutic_class = Set(trimmed(payload.therapeutic_class));
+        }
+        if let Some(reorder_level) = payload.reorder_level {
+            model.reorder_level = Set(reorder_level);
+        }
+        if let Some(is_active) = payload.is_active {
+            model.is_active = Set(is_active);
+        }
+        model.updated_at = Set(Utc::now().naive_utc());
+
+        Ok(model.update(db).await?)
+    }
+
+    async fn find_locations(
+        db: &DatabaseConnection,
+    ) -> Result<Vec<storage_locations::Model>, AppError> {
+        Ok(storage_locations::Entity::find()
+            .order_by_asc(storage_locations::Column::Code)
+            .all(db)
+            .await?)
+    }
+
+    async fn find_location(
+        db: &DatabaseConnection,
+        location_id: i32,
+    ) -> Result<storage_locations::Model, AppError> {
+        storage_locations::Entity::find_by_id(location_id)
+            .one(db)
+            .await?
+            .ok_or(AppError::NotFound(format!(
+                "Storage location with id {} is not found",
+                location_id
+            )))
+    }
+
+    async fn create_location(
+        db: &DatabaseConnection,
+        payload: CreateLocationRequest,
+    ) -> Result<storage_locations::Model, AppError> {
+        let code = normalize_code(&payload.code);
+        let model = storage_locations::ActiveModel {
+            code: Set(code.clone()),
+            name: Set(payload.name.trim().to_string()),
+            is_active: Set(true),
+            created_at: Set(Utc::now().naive_utc()),
+            ..Default::default()
+        };
+
+        model.insert(db).await.map_err(|err| match err.sql_err() {
+            Some(SqlErr::UniqueConstraintViolation(_)) => {
+                AppError::Conflict(format!("Storage location {} already exists", code))
+            }
+            _ => err.into(),
+        })
+    }
+
+    async fn create_goods_receipt(
+        txn: &DatabaseTransaction,
+        receipt: NewGoodsReceipt,
+    ) -> Result<goods_receipts::Model, AppError> {
+        let model = goods_receipts::ActiveModel {
+            location_id: Set(receipt.location_id),
+            supplier: Set(receipt.supplier.trim().to_string()),
+            reference_no: Set(trimmed(receipt.reference_no)),
+            received_at: Set(receipt.received_at),
+            received_by: Set(Some(receipt.received_by)),
+            notes: Set(trimmed(receipt.notes)),
+            created_at: Set(Utc::now().naive_utc()),
+            ..Default::default()
+        };
+        Ok(model.insert(txn).await?)
+    }
+
+    async fn find_goods_receipt(
+        db: &DatabaseConnection,
+        receipt_id: i32,
+    ) -> Result<GoodsReceiptRecord, AppError> {
+        let receipt = goods_receipts::Entity::find_by_id(receipt_id)
+            .one(db)
+            .await?
+            .ok_or(AppError::NotFound(format!(
+                "Goods receipt with id {} is not found",
+                receipt_id
+            )))?;
+        let location = Self::find_location(db, receipt.location_id).await?;
+
+        let movements = stock_movements::Entity::find()
+            .filter(stock_movements::Column::GoodsReceiptId.eq(receipt_id))
+            .order_by_asc(stock_movements::Column::Id)
+            .all(db)
+            .await?;
+        let batches = drug_batches::Entity::find()
+            .filter(
+                drug_batches::Column::Id.is_in(
+                    movements
+                        .iter()
+                        .map(|movement| movement.batch_id)
+                        .collect::<Vec<_>>(),
+                ),
+            )
+            .all(db)
+            .await?;
+        let batches: HashMap<i32, (drug_batches::Model, drugs::Model)> =
+            with_drug_and_location(db, batches)
+                .await?
+                .into_iter()
+                .map(|(batch, drug, _)| (batch.id, (batch, drug)))
+                .collect();
+
+        let lines = movements
+            .into_iter()
+            .filter_map(|movement| {
+                let (batch, drug) = batches.get(&movement.batch_id)?.clone();
+                Some((movement, batch, drug))
+            })
+            .collect();
+
+        Ok(GoodsReceiptRecord {
+            receipt,
+            location,
+            lines,
+        })
+    }
+
+    async fn find_batch_by_number_for_update(
+        txn: &DatabaseTransaction,
+        drug_id: i32,
+        location_id: i32,
+        batch_no: &str,
+    ) -> Result<Option<drug_batches::Model>, AppError> {
+        Ok(drug_batches::Entity::find()
+            .filter(drug_batches::Column::DrugId.eq(drug_id))
+            .filter(drug_batches::Column::LocationId.eq(location_id))
+            .filter(drug_batches::Column::BatchNo.eq(normalize_code(batch_no)))
+            .lock_exclusive()
+            .one(txn)
+            .await?)
+    }
+
+    async fn create_batch(
+        txn: &DatabaseTransaction,
+        drug_id: i32,
+        location_id: i32,
+        batch_no: &str,
+        expiry_date: NaiveDate,
+    ) -> Result<drug_batches::Model, AppError> {
+        let now = Utc::now().naive_utc();
+        let batch_no = normalize_code(batch_no);
+        let model = drug_batches::ActiveModel {
+            drug_id: Set(drug_id),
+            location_id: Set(location_id),
+            batch_no: Set(batch_no.clone()),
+            expiry_date: Set(expiry_date),
+            quantity_on_hand: Set(0),
+            created_at: Set(now),
+            updated_at: Set(now),
+            ..Default::default()
+        };
+
+        model.insert(txn).await.map_err(|err| match err.sql_err() {
+            Some(SqlErr::UniqueConstraintViolation(_)) => AppError::Conflict(format!(
+                "Batch {} was stocked by another receipt at the same time; try again",
+                batch_no
+            )),
+            _ => err.into(),
+        })
+    }
+
+    async fn find_batch_for_update(
+        txn: &DatabaseTransaction,
+        batch_id: i32,
+    ) -> Result<drug_batches::Model, AppError> {
+        drug_batches::Entity::find_by_id(batch_id)
+            .lock_exclusive()
+            .one(txn)
+            .await?
+            .ok_or(AppError::NotFound(format!(
+                "Batch with id {} is not found",
+                batch_id
+            )))
+    }
+
+    async fn find_pickable_batches(
+        txn: &DatabaseTransaction,
+        drug_id: i32,
+        location_id: i32,
+        expiring_after: NaiveDate,
+    ) -> Result<Vec<drug_batches::Model>, AppError> {
+        Ok(drug_batches::Entity::find()
+            .filter(drug_batches::Column::DrugId.eq(drug_id))
+            .filter(drug_batches::Column::LocationId.eq(location_id))
+            .filter(drug_batches::Column::QuantityOnHand.gt(0))
+            .filter(drug_batches::Column::ExpiryDate.gt(expiring_after))
+            .order_by_asc(drug_batches::Column::ExpiryDate)
+            .order_by_asc(drug_batches::Column::Id)
+            .lock_exclusive()
+            .all(txn)
+            .await?)
+    }
+
+    async fn record_movement(
+        txn: &DatabaseTransaction,
+        batch: drug_batches::Model,
+        movement: NewStockMovement,
+    ) -> Result<(drug_batches::Model, stock_movements::Model), AppError> {
+        let now = Utc::now().naive_utc();
+        let balance_after = batch.quantity_on_hand + movement.quantity;
+        if balance_after < 0 {
+            return Err(AppError::Conflict(format!(
+                "Batch {} holds {} and cannot give {}",
+                batch.batch_no, batch.quantity_on_hand, -movement.quantity
+            )));
+        }
+
+        let batch_id = batch.id;
+        let mut model = batch.into_active_model();
+        model.quantity_on_hand = Set(balance_after);
+        model.updated_at = Set(now);
+        let batch = model.update(txn).await?;
+
+        let movement = stock_movements::ActiveModel {
+            batch_id: Set(batch_id),
+            movement_type: Set(movement.movement_type),
+            quantity: Set(movement.quantity),
+            balance_after: Set(balance_after),
+            reason: Set(movement.reason),
+            notes: Set(trimmed(movement.notes)),
+            goods_receipt_id: Set(movement.goods_receipt_id),
+            prescription_item_id: Set(movement.prescription_item_id),
+            performed_by: Set(Some(movement.performed_by)),
+            created_at: Set(now),
+            ..Default::default()
+        }
+        .insert(txn)
+        .await?;
+
+        Ok((batch, movement))
+    }
+
+    async fn find_batch(db: &DatabaseConnection, batch_id: i32) -> Result<BatchRecord, AppError> {
+        let batch = drug_batches::Entity::find_by_id(batch_id)
+            .one(db)
+            .await?
+            .ok_or(AppError::NotFound(format!(
+                "Batch with id {} is not found",
+                batch_id
+            )))?;
+
+        with_drug_and_location(db, vec![batch])
+            .await?
+            .pop()
+            .ok_or(AppError::NotFound(format!(
+                "Batch with id {} is not found",
+                batch_id
+            )))
+    }
+
+    async fn find_batch_movements(
+        db: &DatabaseConnection,
+        batch_id: i32,
+    ) -> Result<Vec<stock_movements::Model>, AppError> {
+        Ok(stock_movements::Entity::find()
+            .filter(stock_movements::Column::BatchId.eq(batch_id))
+            .order_by_desc(stock_movements::Column::CreatedAt)
+            .order_by_desc(stock_movements::Column::Id)
+            .all(db)
+            .await?)
+    }
+
+    async fn find_drug_batches(
+        db: &DatabaseConnection,
+        drug_id: i32,
+    ) -> Result<Vec<BatchRecord>, AppError> {
+        let batches = drug_batches::Entity::find()
+            .filter(drug_batches::Column::DrugId.eq(drug_id))
+            .filter(drug_batches::Column::QuantityOnHand.gt(0))
+            .order_by_asc(drug_batches::Column::ExpiryDate)
+            .order_by_asc(drug_batches::Column::Id)
+            .all(db)
+            .await?;
+
+        with_drug_and_location(db, batches).await
+    }
+
+    async fn find_usable_totals(
+        db: &DatabaseConnection,
+        location_id: Option<i32>,
+        expiring_after: NaiveDate,
+    ) -> Result<Vec<(i32, i64)>, AppError> {
+        let mut query = drug_batches::Entity::find()
+            .select_only()
+            .column(drug_batches::Column::DrugId)
+            .column_as(
+                Expr::col(drug_batches::Column::QuantityOnHand).sum(),
+                "on_hand",
+            )
+            .filter(drug_batches::Column::ExpiryDate.gt(expiring_after))
+            .group_by(drug_batches::Column::DrugId);
+        if let Some(location_id) = location_id {
+            query = query.filter(drug_batches::Column::LocationId.eq(location_id));
+        }
+
+        Ok(query.into_tuple().all(db).await?)
+    }
+
+    async fn find_expiring_batches(
+        db: &DatabaseConnection,
+        location_id: Option<i32>,
+        until: NaiveDate,
+    ) -> Result<Vec<BatchRecord>, AppError> {
+        let mut query = drug_batches::Entity::find()
+            .filter(drug_batches::Column::QuantityOnHand.gt(0))
+            .filter(drug_batches::Column::ExpiryDate.lte(until));
+        if let Some(location_id) = location_id {
+            query = query.filter(drug_batches::Column::LocationId.eq(location_id));
+        }
+
+        let batches = query
+            .order_by_asc(drug_batches::Column::ExpiryDate)
+            .order_by_asc(drug_batches::Column::Id)
+            .all(db)
+            .await?;
+        with_drug_and_location(db, batches).await
+    }
+}
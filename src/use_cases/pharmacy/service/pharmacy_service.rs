@@ -0,0 +1,632 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Local, NaiveDate, NaiveDateTime, Utc};
+use entity::{
+    drug_batches, drugs,
+    stock_movements::{self, StockMovementType},
+    storage_locations,
+};
+use sea_orm::{ActiveEnum, DatabaseConnection, TransactionTrait};
+
+pub use crate::use_cases::pharmacy::contracts::pharmacy_service_contract::PharmacyServiceContracts;
+use crate::{
+    dtos::pharmacy::{
+        pharmacy_request::{
+            CreateDrugRequest, CreateLocationRequest, DrugSearchQuery, GoodsReceiptRequest,
+            NearExpiryQuery, StockAdjustmentRequest, StockReportQuery, UpdateDrugRequest,
+        },
+        response::{
+            BatchMovementsResponse, BatchResponse, DrugResponse, DrugSearchResponse,
+            DrugStockResponse, GoodsReceiptItemResponse, GoodsReceiptResponse, LocationResponse,
+            LocationsResponse, LowStockItem, LowStockReportResponse, NearExpiryReportResponse,
+            StockAdjustmentResponse, StockMovementResponse,
+        },
+    },
+    error_handling::app_error::AppError,
+    format_created_at,
+    infra::api::ApiFieldError,
+    use_cases::pharmacy::{
+        contracts::{
+            PharmacyTraitRepo,
+            pharmacy_repo_contract::{
+                BatchRecord, GoodsReceiptRecord, NewGoodsReceipt, NewStockMovement,
+            },
+        },
+        repo::pharmacy_repo::PharmacyRepo,
+    },
+    utils::helpers::{hospital_now, hospital_time},
+};
+
+pub struct PharmacyService;
+
+const DEFAULT_SEARCH_LIMIT: u64 = 20;
+
+const DEFAULT_EXPIRY_WINDOW_DAYS: i64 = 90;
+
+/// How far ahead of the server clock a delivery may be stamped.
+const RECEIVED_AT_TOLERANCE_MINUTES: i64 = 5;
+
+/// Takes a quantity from batches first-expiry-first-out, the earliest batch first when
+/// two expire on the same day, returning how much comes from each batch and how much is
+/// still missing when they run out.
+pub fn plan_fefo_picks(
+    mut batches: Vec<drug_batches::Model>,
+    quantity: i32,
+) -> (Vec<(drug_batches::Model, i32)>, i32) {
+    batches.sort_by_key(|batch| (batch.expiry_date, batch.id));
+
+    let mut remaining = quantity;
+    let mut picks = Vec::new();
+    for batch in batches {
+        if remaining == 0 {
+            break;
+        }
+        let taken = remaining.min(batch.quantity_on_hand);
+        if taken > 0 {
+            remaining -= taken;
+            picks.push((batch, taken));
+        }
+    }
+    (picks, remaining)
+}
+
+fn drug_response(drug: drugs::Model) -> DrugResponse {
+    DrugResponse {
+        id: drug.id,
+        code: drug.code,
+        name: drug.name,
+        generic_name: drug.generic_name,
+        dosage_form: drug.dosage_form,
+        strength: drug.strength,
+        unit: drug.unit,
+        therapeutic_class: drug.therapeutic_class,
+        reorder_level: drug.reorder_level,
+        is_active: drug.is_active,
+        created_at: format_created_at!(drug.created_at),
+        updated_at: format_created_at!(drug.updated_at),
+    }
+}
+
+fn location_response(location: storage_locations::Model) -> LocationResponse {
+    LocationResponse {
+        id: location.id,
+        code: location.code,
+        name: location.name,
+        is_active: location.is_active,
+        created_at: format_created_at!(location.created_at),
+    }
+}
+
+/// A batch is expired from its expiry date on and is never picked again.
+fn batch_response((batch, drug, location): BatchRecord, today: NaiveDate) -> BatchResponse {
+    BatchResponse {
+        id: batch.id,
+        drug_id: drug.id,
+        drug_code: drug.code,
+        drug_name: drug.name,
+        location_id: location.id,
+        location_code: location.code,
+        batch_no: batch.batch_no,
+        expiry_date: batch.expiry_date,
+        days_to_expiry: (batch.expiry_date - today).num_days(),
+        is_expired: batch.expiry_date <= today,
+        quantity_on_hand: batch.quantity_on_hand,
+        unit: drug.unit,
+    }
+}
+
+fn movement_response(movement: stock_movements::Model) -> StockMovementResponse {
+    StockMovementResponse {
+        id: movement.id,
+        batch_id: movement.batch_id,
+        movement_type: movement.movement_type.to_value(),
+        quantity: movement.quantity,
+        balance_after: movement.balance_after,
+        reason: movement.reason.map(|reason| reason.to_value()),
+        notes: movement.notes,
+        goods_receipt_id: movement.goods_receipt_id,
+        prescription_item_id: movement.prescription_item_id,
+        performed_by: movement.performed_by,
+        created_at: format_created_at!(movement.created_at),
+    }
+}
+
+fn goods_receipt_response(record: GoodsReceiptRecord) -> GoodsReceiptResponse {
+    let GoodsReceiptRecord {
+        receipt,
+        location,
+        lines,
+    } = record;
+
+    GoodsReceiptResponse {
+        id: receipt.id,
+        location_id: location.id,
+        location_code: location.code,
+        supplier: receipt.supplier,
+        reference_no: receipt.reference_no,
+        received_at: format_created_at!(receipt.received_at),
+        received_by: receipt.received_by,
+        notes: receipt.notes,
+        created_at: format_created_at!(receipt.created_at),
+        items: lines
+            .into_iter()
+            .map(|(movement, batch, drug)| GoodsReceiptItemResponse {
+                movement_id: movement.id,
+                batch_id: batch.id,
+                drug_id: drug.id,
+                drug_name: drug.name,
+                batch_no: batch.batch_no,
+                expiry_date: batch.expiry_date,
+                quantity: movement.quantity,
+                unit: drug.unit,
+            })
+            .collect(),
+    }
+}
+
+/// Stock is only received into a location that is in use.
+async fn find_active_location(
+    db: &DatabaseConnection,
+    location_id: i32,
+) -> Result<storage_locations::Model, AppError> {
+    let location = <PharmacyRepo as PharmacyTraitRepo>::find_location(db, location_id).await?;
+    if !location.is_active {
+        return Err(AppError::Conflict(format!(
+            "Storage location {} is no longer in use",
+            location.code
+        )));
+    }
+    Ok(location)
+}
+
+#[async_trait]
+impl PharmacyServiceContracts for PharmacyService {
+    async fn search_drugs(
+        db: &DatabaseConnection,
+        query: DrugSearchQuery,
+    ) -> Result<DrugSearchResponse, AppError> {
+        let drugs = <PharmacyRepo as PharmacyTraitRepo>::search_drugs(
+            db,
+            query.q.as_deref(),
+            query.include_inactive,
+            query.limit.unwrap_or(DEFAULT_SEARCH_LIMIT),
+        )
+        .await?;
+
+        Ok(DrugSearchResponse {
+            query: query.q,
+            data: drugs.into_iter().map(drug_response).collect(),
+        })
+    }
+
+    async fn get_drug(db: &DatabaseConnection, drug_id: i32) -> Result<DrugResponse, AppError> {
+        let drug = <PharmacyRepo as PharmacyTraitRepo>::find_drug(db, drug_id).await?;
+        Ok(drug_response(drug))
+    }
+
+    async fn create_drug(
+        db: &DatabaseConnection,
+        payload: CreateDrugRequest,
+    ) -> Result<DrugResponse, AppError> {
+        let drug = <PharmacyRepo as PharmacyTraitRepo>::create_drug(db, payload).await?;
+        Ok(drug_response(drug))
+    }
+
+    async fn update_drug(
+        db: &DatabaseConnection,
+        drug_id: i32,
+        payload: UpdateDrugRequest,
+    ) -> Result<DrugResponse, AppError> {
+        if payload.is_empty() {
+            return Err(AppError::BadRequest("Nothing to update".to_string()));
+        }
+
+        let drug = <PharmacyRepo as PharmacyTraitRepo>::find_drug(db, drug_id).await?;
+        let drug = <PharmacyRepo as PharmacyTraitRepo>::update_drug(db, drug, payload).await?;
+        Ok(drug_response(drug))
+    }
+
+    async fn get_drug_stock(
+        db: &DatabaseConnection,
+        drug_id: i32,
+    ) -> Result<DrugStockResponse, AppError> {
+        let drug = <PharmacyRepo as PharmacyTraitRepo>::find_drug(db, drug_id).await?;
+        let today = hospital_now().date_naive();
+
+        let batches = <PharmacyRepo as PharmacyTraitRepo>::find_drug_batches(db, drug_id)
+            .await?
+            .into_iter()
+            .map(|record| batch_response(record, today))
+            .collect::<Vec<_>>();
+        let (expired, usable): (Vec<&BatchResponse>, Vec<&BatchResponse>) =
+            batches.iter().partition(|batch| batch.is_expired);
+
+        Ok(DrugStockResponse {
+            drug: drug_response(drug),
+            usable_quantity: usable
+                .iter()
+                .map(|batch| i64::from(batch.quantity_on_hand))
+                .sum(),
+            expired_quantity: expired
+                .iter()
+                .map(|batch| i64::from(batch.quantity_on_hand))
+                .sum(),
+            batches,
+        })
+    }
+
+    async fn get_locations(db: &DatabaseConnection) -> Result<LocationsResponse, AppError> {
+        let locations = <PharmacyRepo as PharmacyTraitRepo>::find_locations(db).await?;
+        Ok(LocationsResponse {
+            data: locations.into_iter().map(location_response).collect(),
+        })
+    }
+
+    async fn create_location(
+        db: &DatabaseConnection,
+        payload: CreateLocationRequest,
+    ) -> Result<LocationResponse, AppError> {
+        let location = <PharmacyRepo as PharmacyTraitRepo>::create_location(db, payload).await?;
+        Ok(location_response(location))
+    }
+
+    async fn receive_goods(
+        db: &DatabaseConnection,
+        user_id: i32,
+        payload: GoodsReceiptRequest,
+    ) -> Result<GoodsReceiptResponse, AppError> {
+        let now = Utc::now().naive_utc();
+        let received_at = payload
+            .received_at
+            .map(|received_at| received_at.naive_utc())
+            .unwrap_or(now);
+        if received_at > now + Duration::minutes(RECEIVED_AT_TOLERANCE_MINUTES) {
+            return Err(AppError::BadRequest(
+                "Receipt time cannot be in the future".to_string(),
+            ));
+        }
+        let received_on = hospital_time(received_at).date_naive();
+
+        let location = find_active_location(db, payload.location_id).await?;
+        let drugs: HashMap<i32, drugs::Model> = <PharmacyRepo as PharmacyTraitRepo>::find_drugs(
+            db,
+            payload.items.iter().map(|item| item.drug_id).collect(),
+        )
+        .await?
+        .into_iter()
+        .map(|drug| (drug.id, drug))
+        .collect();
+
+        let mut errors = Vec::new();
+        for (index, item) in payload.items.iter().enumerate() {
+            match drugs.get(&item.drug_id) {
+                None => errors.push(ApiFieldError {
+                    field: format!("items[{}].drug_id", index),
+                    message: format!("Drug with id {} is not found", item.drug_id),
+                }),
+                Some(drug) if !drug.is_active => errors.push(ApiFieldError {
+                    field: format!("items[{}].drug_id", index),
+                    message: format!("Drug {} is no longer on the formulary", drug.code),
+                }),
+                Some(_) => {}
+            }
+            if item.expiry_date <= received_on {
+                errors.push(ApiFieldError {
+                    field: format!("items[{}].expiry_date", index),
+                    message: "Batch is already expired on the day it is received".to_string(),
+                });
+            }
+        }
+        if !errors.is_empty() {
+            return Err(AppError::ValidationError(errors));
+        }
+
+        let txn = db.begin().await?;
+        let receipt = <PharmacyRepo as PharmacyTraitRepo>::create_goods_receipt(
+            &txn,
+            NewGoodsReceipt {
+                location_id: location.id,
+                supplier: payload.supplier,
+                reference_no: payload.reference_no,
+                received_at,
+                received_by: user_id,
+                notes: payload.notes,
+            },
+        )
+        .await?;
+
+        let mut conflicts = Vec::new();
+        for (index, item) in payload.items.into_iter().enumerate() {
+            let existing = <PharmacyRepo as PharmacyTraitRepo>::find_batch_by_number_for_update(
+                &txn,
+                item.drug_id,
+                location.id,
+                &item.batch_no,
+            )
+            .await?;
+
+            // A batch number is printed with one expiry date; a mismatch is a typing error
+            let batch = match existing {
+                Some(batch) if batch.expiry_date != item.expiry_date => {
+                    conflicts.push(ApiFieldError {
+                        field: format!("items[{}].expiry_date", index),
+                        message: format!(
+                            "Batch {} is already stocked here with expiry date {}",
+                            batch.batch_no, batch.expiry_date
+                        ),
+                    });
+                    continue;
+                }
+                Some(batch) => batch,
+                None => {
+                    <PharmacyRepo as PharmacyTraitRepo>::create_batch(
+                        &txn,
+                        item.drug_id,
+                        location.id,
+                        &item.batch_no,
+                        item.expiry_date,
+                    )
+                    .await?
+                }
+            };
+
+            <PharmacyRepo as PharmacyTraitRepo>::record_movement(
+                &txn,
+                batch,
+                NewStockMovement {
+                    movement_type: StockMovementType::Receipt,
+                    quantity: item.quantity,
+                    reason: None,
+                    notes: None,
+                    goods_receipt_id: Some(receipt.id),
+                    prescription_item_id: None,
+                    performed_by: user_id,
+                },
+            )
+            .await?;
+        }
+        if !conflicts.is_empty() {
+            return Err(AppError::FieldConflict(
+                "Some batches do not match the stock on hand".to_string(),
+                conflicts,
+            ));
+        }
+        txn.commit().await?;
+
+        let record =
+            <PharmacyRepo as PharmacyTraitRepo>::find_goods_receipt(db, receipt.id).await?;
+        Ok(goods_receipt_response(record))
+    }
+
+    async fn get_goods_receipt(
+        db: &DatabaseConnection,
+        receipt_id: i32,
+    ) -> Result<GoodsReceiptResponse, AppError> {
+        let record =
+            <PharmacyRepo as PharmacyTraitRepo>::find_goods_receipt(db, receipt_id).await?;
+        Ok(goods_receipt_response(record))
+    }
+
+    async fn adjust_stock(
+        db: &DatabaseConnection,
+        batch_id: i32,
+        user_id: i32,
+        payload: StockAdjustmentRequest,
+    ) -> Result<StockAdjustmentResponse, AppError> {
+        let txn = db.begin().await?;
+        let batch =
+            <PharmacyRepo as PharmacyTraitRepo>::find_batch_for_update(&txn, batch_id).await?;
+        let (_, movement) = <PharmacyRepo as PharmacyTraitRepo>::record_movement(
+            &txn,
+            batch,
+            NewStockMovement {
+                movement_type: StockMovementType::Adjustment,
+                quantity: payload.quantity_change,
+                reason: Some(payload.reason),
+                notes: payload.notes,
+                goods_receipt_id: None,
+                prescription_item_id: None,
+                performed_by: user_id,
+            },
+        )
+        .await?;
+        txn.commit().await?;
+
+        let record = <PharmacyRepo as PharmacyTraitRepo>::find_batch(db, batch_id).await?;
+        Ok(StockAdjustmentResponse {
+            batch: batch_response(record, hospital_now().date_naive()),
+            movement: movement_response(movement),
+        })
+    }
+
+    async fn get_batch_movements(
+        db: &DatabaseConnection,
+        batch_id: i32,
+    ) -> Result<BatchMovementsResponse, AppError> {
+        let record = <PharmacyRepo as PharmacyTraitRepo>::find_batch(db, batch_id).await?;
+        let movements =
+            <PharmacyRepo as PharmacyTraitRepo>::find_batch_movements(db, batch_id).await?;
+
+        Ok(BatchMovementsResponse {
+            batch: batch_response(record, hospital_now().date_naive()),
+            data: movements.into_iter().map(movement_response).collect(),
+        })
+    }
+
+    async fn get_low_stock_report(
+        db: &DatabaseConnection,
+        query: StockReportQuery,
+    ) -> Result<LowStockReportResponse, AppError> {
+        if let Some(location_id) = query.location_id {
+            <PharmacyRepo as PharmacyTraitRepo>::find_location(db, location_id).await?;
+        }
+        let today = hospital_now().date_naive();
+
+        let totals: HashMap<i32, i64> =
+            <PharmacyRepo as PharmacyTraitRepo>::find_usable_totals(db, query.location_id, today)
+                .await?
+                .into_iter()
+                .collect();
+        let mut data = <PharmacyRepo as PharmacyTraitRepo>::find_active_drugs(db)
+            .await?
+            .into_iter()
+            .filter_map(|drug| {
+                let usable_quantity = totals.get(&drug.id).copied().unwrap_or(0);
+                (usable_quantity <= i64::from(drug.reorder_level)).then_some(LowStockItem {
+                    drug_id: drug.id,
+                    code: drug.code,
+                    name: drug.name,
+                    unit: drug.unit,
+                    reorder_level: drug.reorder_level,
+                    usable_quantity,
+                })
+            })
+            .collect::<Vec<_>>();
+        // Out of stock first, then the furthest below the reorder level
+        data.sort_by_key(|item| {
+            (
+                item.usable_quantity > 0,
+                item.usable_quantity - i64::from(item.reorder_level),
+            )
+        });
+
+        Ok(LowStockReportResponse {
+            location_id: query.location_id,
+            as_of: today,
+            data,
+        })
+    }
+
+    async fn get_near_expiry_report(
+        db: &DatabaseConnection,
+        query: NearExpiryQuery,
+    ) -> Result<NearExpiryReportResponse, AppError> {
+        if let Some(location_id) = query.location_id {
+            <PharmacyRepo as PharmacyTraitRepo>::find_location(db, location_id).await?;
+        }
+        let today = hospital_now().date_naive();
+        let until = today + Duration::days(query.days.unwrap_or(DEFAULT_EXPIRY_WINDOW_DAYS));
+
+        let batches = <PharmacyRepo as PharmacyTraitRepo>::find_expiring_batches(
+            db,
+            query.location_id,
+            until,
+        )
+        .await?;
+
+        Ok(NearExpiryReportResponse {
+            location_id: query.location_id,
+            as_of: today,
+            until,
+            data: batches
+                .into_iter()
+                .map(|record| batch_response(record, today))
+                .collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use entity::drug_batches;
+
+    use super::plan_fefo_picks;
+
+    fn batch(id: i32, expiry: (i32, u32, u32), quantity_on_hand: i32) -> drug_batches::Model {
+        let now = NaiveDate::from_ymd_opt(2025, 1, 1)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+        drug_batches::Model {
+            id,
+            drug_id: 1,
+            location_id: 1,
+            batch_no: format!("B{:03}", id),
+            expiry_date: NaiveDate::from_ymd_opt(expiry.0, expiry.1, expiry.2).unwrap(),
+            quantity_on_hand,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    fn picked(picks: &[(drug_batches::Model, i32)]) -> Vec<(i32, i32)> {
+        picks
+            .iter()
+            .map(|(batch, taken)| (batch.id, *taken))
+            .collect()
+    }
+
+    #[test]
+    fn takes_from_the_earliest_expiring_batch_first() {
+        let batches = vec![batch(1, (2025, 3, 1), 30), batch(2, (2025, 9, 1), 50)];
+
+        let (picks, missing) = plan_fefo_picks(batches, 20);
+
+        assert_eq!(picked(&picks), [(1, 20)]);
+        assert_eq!(missing, 0);
+    }
+
+    #[test]
+    fn splits_a_pick_across_batches_in_expiry_order() {
+        let batches = vec![
+            batch(3, (2025, 2, 1), 10),
+            batch(1, (2025, 6, 1), 15),
+            batch(2, (2026, 1, 1), 40),
+        ];
+
+        let (picks, missing) = plan_fefo_picks(batches, 30);
+
+        assert_eq!(picked(&picks), [(3, 10), (1, 15), (2, 5)]);
+        assert_eq!(missing, 0);
+    }
+
+    #[test]
+    fn picks_unsorted_batches_by_expiry() {
+        let batches = vec![
+            batch(1, (2026, 1, 1), 40),
+            batch(2, (2025, 2, 1), 10),
+            batch(4, (2025, 6, 1), 5),
+            batch(3, (2025, 6, 1), 5),
+        ];
+
+        let (picks, missing) = plan_fefo_picks(batches, 25);
+
+        assert_eq!(picked(&picks), [(2, 10), (3, 5), (4, 5), (1, 5)]);
+        assert_eq!(missing, 0);
+    }
+
+    #[test]
+    fn returns_the_short_quantity_as_missing() {
+        let batches = vec![batch(1, (2025, 3, 1), 8), batch(2, (2025, 9, 1), 4)];
+
+        let (picks, missing) = plan_fefo_picks(batches, 20);
+
+        assert_eq!(picked(&picks), [(1, 8), (2, 4)]);
+        assert_eq!(missing, 8);
+    }
+
+    #[test]
+    fn skips_batches_without_stock() {
+        let batches = vec![
+            batch(1, (2025, 2, 1), 0),
+            batch(2, (2025, 4, 1), 5),
+            batch(3, (2025, 6, 1), 0),
+            batch(4, (2025, 8, 1), 10),
+        ];
+
+        let (picks, missing) = plan_fefo_picks(batches, 12);
+
+        assert_eq!(picked(&picks), [(2, 5), (4, 7)]);
+        assert_eq!(missing, 0);
+    }
+
+    #[test]
+    fn misses_everything_without_batches() {
+        let (picks, missing) = plan_fefo_picks(Vec::new(), 6);
+
+        assert!(picks.is_empty());
+        assert_eq!(missing, 6);
+    }
+}
@@ -0,0 +1,4 @@
+pub mod prescription_repo_contract;
+pub mod prescription_service_contract;
+pub use self::prescription_repo_contract::PrescriptionTraitRepo;
+pub use self::prescription_service_contract::PrescriptionServiceContracts;
@@ -0,0 +1,94 @@
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use entity::{
+    patients_visit_intent, prescription_item_components, prescription_items,
+    prescriptions::{self, PrescriptionStatus},
+};
+use sea_orm::{DatabaseConnection, DatabaseTransaction};
+
+use crate::{
+    dtos::prescription::prescription_request::PrescriptionRequest,
+    error_handling::app_error::AppError, infra::drug_interactions::InteractionWarning,
+};
+
+/// A prescription with the patient it is written for, its prescriber and its items in
+/// line order, each with the components of a racikan.
+pub struct PrescriptionRecord {
+    pub prescription: prescriptions::Model,
+    pub patient_id: i32,
+    pub doctor_name: String,
+    pub items: Vec<(
+        prescription_items::Model,
+        Vec<prescription_item_components::Model>,
+    )>,
+}
+
+#[async_trait]
+pub trait PrescriptionTraitRepo {
+    async fn find_visit(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+    ) -> Result<patients_visit_intent::Model, AppError>;
+    async fn find_draft(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+        doctor_id: i32,
+    ) -> Result<Option<prescriptions::Model>, AppError>;
+    async fn create_prescription(
+        txn: &DatabaseTransaction,
+        visit_intent_id: i32,
+        doctor_id: i32,
+        payload: PrescriptionRequest,
+    ) -> Result<prescriptions::Model, AppError>;
+    async fn find_prescription(
+        db: &DatabaseConnection,
+        prescription_id: i32,
+    ) -> Result<PrescriptionRecord, AppError>;
+    async fn find_prescription_for_update(
+        txn: &DatabaseTransaction,
+        prescription_id: i32,
+    ) -> Result<prescriptions::Model, AppError>;
+    /// Replaces the notes and every item of a draft.
+    async fn replace_draft(
+        txn: &DatabaseTransaction,
+        prescription: prescriptions::Model,
+        payload: PrescriptionRequest,
+    ) -> Result<(), AppError>;
+    async fn delete_draft(
+        txn: &DatabaseTransaction,
+        prescription: prescriptions::Model,
+    ) -> Result<(), AppError>;
+    /// Submits a draft with the reasons given for going ahead and keeps the interaction
+    /// warnings it was submitted with.
+    async fn submit(
+        txn: &DatabaseTransaction,
+        prescription: prescriptions::Model,
+        allergy_override_reason: Option<String>,
+        interaction_override_reason: Option<String>,
+        warnings: Vec<InteractionWarning>,
+    ) -> Result<(), AppError>;
+    async fn dispense(
+        txn: &DatabaseTransaction,
+        prescription: prescriptions::Model,
+        dispensed_by: i32,
+    ) -> Result<(), AppError>;
+    /// Prescriptions of a visit in the order they were written.
+    async fn find_visit_prescriptions(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+    ) -> Result<Vec<PrescriptionRecord>, AppError>;
+    /// Submitted and dispensed prescriptions of a patient, across visits, submitted since
+    /// a time.
+    async fn find_patient_prescriptions(
+        db: &DatabaseConnection,
+        patient_id: i32,
+        submitted_since: NaiveDateTime,
+    ) -> Result<Vec<PrescriptionRecord>, AppError>;
+    /// Submitted prescriptions oldest first, or the ones dispensed since a time, latest
+    /// first.
+    async fn find_queue(
+        db: &DatabaseConnection,
+        status: PrescriptionStatus,
+        since: NaiveDateTime,
+    ) -> Result<Vec<PrescriptionRecord>, AppError>;
+}
@@ -0,0 +1,73 @@
+use async_trait::async_trait;
+use sea_orm::DatabaseConnection;
+
+use crate::{
+    dtos::prescription::{
+        prescription_request::{
+            DispensePrescriptionRequest, PharmacyQueueQuery, PrescriptionRequest,
+            SubmitPrescriptionRequest,
+        },
+        response::{
+            AllergyCheckResponse, DispenseResponse, InteractionCheckResponse,
+            PharmacyQueueResponse, PrescriptionDiscarded, PrescriptionResponse,
+            VisitPrescriptionsResponse,
+        },
+    },
+    error_handling::app_error::AppError,
+    infra::drug_interactions::InteractionCheckers,
+};
+
+#[async_trait]
+pub trait PrescriptionServiceContracts {
+    async fn create_prescription(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+        user_id: i32,
+        payload: PrescriptionRequest,
+    ) -> Result<PrescriptionResponse, AppError>;
+    async fn get_prescription(
+        db: &DatabaseConnection,
+        prescription_id: i32,
+    ) -> Result<PrescriptionResponse, AppError>;
+    async fn update_prescription(
+        db: &DatabaseConnection,
+        prescription_id: i32,
+        user_id: i32,
+        payload: PrescriptionRequest,
+    ) -> Result<PrescriptionResponse, AppError>;
+    async fn discard_prescription(
+        db: &DatabaseConnection,
+        prescription_id: i32,
+        user_id: i32,
+    ) -> Result<PrescriptionDiscarded, AppError>;
+    async fn check_allergies(
+        db: &DatabaseConnection,
+        prescription_id: i32,
+    ) -> Result<AllergyCheckResponse, AppError>;
+    async fn check_interactions(
+        db: &DatabaseConnection,
+        checks: &InteractionCheckers,
+        prescription_id: i32,
+    ) -> Result<InteractionCheckResponse, AppError>;
+    async fn submit_prescription(
+        db: &DatabaseConnection,
+        checks: &InteractionCheckers,
+        prescription_id: i32,
+        user_id: i32,
+        payload: SubmitPrescriptionRequest,
+    ) -> Result<PrescriptionResponse, AppError>;
+    async fn dispense_prescription(
+        db: &DatabaseConnection,
+        prescription_id: i32,
+        user_id: i32,
+        payload: DispensePrescriptionRequest,
+    ) -> Result<DispenseResponse, AppError>;
+    async fn get_visit_prescriptions(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+    ) -> Result<VisitPrescriptionsResponse, AppError>;
+    async fn get_pharmacy_queue(
+        db: &DatabaseConnection,
+        query: PharmacyQueueQuery,
+    ) -> Result<PharmacyQueueResponse, AppError>;
+}
@@ -0,0 +1 @@
+pub mod prescription_repo;
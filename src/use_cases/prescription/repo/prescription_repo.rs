@@ -0,0 +1,394 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use chrono::{NaiveDateTime, Utc};
+use entity::{
+    doctors, patients_visit_intent, prescription_interaction_alerts, prescription_item_components,
+    prescription_items,
+    prescriptions::{self, ActiveModel, PrescriptionStatus},
+};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, DatabaseConnection, DatabaseTransaction,
+    EntityTrait, IntoActiveModel, ModelTrait, QueryFilter, QueryOrder, QuerySelect, SqlErr,
+};
+
+use crate::{
+    dtos::prescription::prescription_request::{PrescriptionItemRequest, PrescriptionRequest},
+    error_handling::app_error::AppError,
+    infra::drug_interactions::InteractionWarning,
+    use_cases::prescription::contracts::prescription_repo_contract::{
+        PrescriptionRecord, PrescriptionTraitRepo,
+    },
+};
+
+pub struct PrescriptionRepo;
+
+/// Blank free text is stored as absent.
+fn trimmed(text: Option<String>) -> Option<String> {
+    text.map(|text| text.trim().to_string())
+        .filter(|text| !text.is_empty())
+}
+
+async fn insert_items(
+    txn: &DatabaseTransaction,
+    prescription_id: i32,
+    items: Vec<PrescriptionItemRequest>,
+) -> Result<(), AppError> {
+    let now = Utc::now().naive_utc();
+    for (line_no, item) in (1..).zip(items) {
+        let saved = prescription_items::ActiveModel {
+            prescription_id: Set(prescription_id),
+            line_no: Set(line_no),
+            drug_id: Set(item.drug_id),
+            drug_name: Set(item.drug_name.trim().to_string()),
+            dose: Set(item.dose.trim().to_string()),
+            route: Set(item.route),
+            frequency: Set(item.frequency.trim().to_string()),
+            duration_days: Set(item.duration_days),
+            quantity: Set(item.quantity),
+            unit: Set(item.unit.trim().to_string()),
+            instructions: Set(trimmed(item.instructions)),
+            is_compounded: Set(item.is_compounded),
+            created_at: Set(now),
+            ..Default::default()
+        }
+        .insert(txn)
+        .await?;
+
+        if item.components.is_empty() {
+            continue;
+        }
+        prescription_item_components::Entity::insert_many(item.components.into_iter().map(
+            |component| prescription_item_components::ActiveModel {
+                item_id: Set(saved.id),
+                drug_name: Set(component.drug_name.trim().to_string()),
+                strength: Set(component.strength.trim().to_string()),
+                ..Default::default()
+            },
+        ))
+        .exec_without_returning(txn)
+        .await?;
+    }
+    Ok(())
+}
+
+async fn with_details(
+    db: &DatabaseConnection,
+    prescriptions: Vec<prescriptions::Model>,
+) -> Result<Vec<PrescriptionRecord>, AppError> {
+    let prescription_ids = prescriptions
+        .iter()
+        .map(|prescription| prescription.id)
+        .collect::<Vec<_>>();
+    let visit_ids = prescriptions
+        .iter()
+        .map(|prescription| prescription.visit_intent_id)
+        .collect::<Vec<_>>();
+    let doctor_ids = prescriptions
+        .iter()
+        .map(|prescription| prescription.doctor_id)
+        .collect::<Vec<_>>();
+
+    let items = prescription_items::Entity::find()
+        .filter(prescription_items::Column::PrescriptionId.is_in(prescription_ids))
+        .order_by_asc(prescription_items::Column::LineNo)
+        .all(db)
+        .await?;
+
+    let mut components: HashMap<i32, Vec<prescription_item_components::Model>> = HashMap::new();
+    for component in prescription_item_components::Entity::find()
+        .filter(
+            prescription_item_components::Column::ItemId
+                .is_in(items.iter().map(|item| item.id).collect::<Vec<_>>()),
+        )
+        .order_by_asc(prescription_item_components::Column::Id)
+        .all(db)
+        .await?
+    {
+        components
+            .entry(component.item_id)
+            .or_default()
+            .push(component);
+    }
+
+    let mut items_by_prescription: HashMap<i32, Vec<_>> = HashMap::new();
+    for item in items {
+        let item_components = components.remove(&item.id).unwrap_or_default();
+        items_by_prescription
+            .entry(item.prescription_id)
+            .or_default()
+            .push((item, item_components));
+    }
+
+    let patient_ids: HashMap<i32, i32> = patients_visit_intent::Entity::find()
+        .filter(patients_visit_intent::Column::Id.is_in(visit_ids))
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|visit| (visit.id, visit.patient_id))
+        .collect();
+    let doctor_names: HashMap<i32, String> = doctors::Entity::find()
+        .filter(doctors::Column::Id.is_in(doctor_ids))
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|doctor| (doctor.id, doctor.name))
+        .collect();
+
+    Ok(prescriptions
+        .into_iter()
+        .filter_map(|prescription| {
+            let patient_id = *patient_ids.get(&prescription.visit_intent_id)?;
+            Some(PrescriptionRecord {
+                patient_id,
+                doctor_name: doctor_names
+                    .get(&prescription.doctor_id)
+                    .cloned()
+                    .unwrap_or_default(),
+                items: items_by_prescription
+                    .remove(&prescription.id)
+                    .unwrap_or_default(),
+                prescription,
+            })
+        })
+        .collect())
+}
+
+#[async_trait]
+impl PrescriptionTraitRepo for PrescriptionRepo {
+    async fn find_visit(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+    ) -> Result<patients_visit_intent::Model, AppError> {
+        patients_visit_intent::Entity::find_by_id(visit_intent_id)
+            .one(db)
+            .await?
+            .ok_or(AppError::NotFound(format!(
+                "Visit with id {} is not found",
+                visit_intent_id
+            )))
+    }
+
+    async fn find_draft(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+        doctor_id: i32,
+    ) -> Result<Option<prescriptions::Model>, AppError> {
+        Ok(prescriptions::Entity::find()
+            .filter(prescriptions::Column::VisitIntentId.eq(visit_intent_id))
+            .filter(prescriptions::Column::DoctorId.eq(doctor_id))
+            .filter(prescriptions::Column::Status.eq(PrescriptionStatus::Draft))
+            .one(db)
+            .await?)
+    }
+
+    async fn create_prescription(
+        txn: &DatabaseTransaction,
+        visit_intent_id: i32,
+        doctor_id: i32,
+        payload: PrescriptionRequest,
+    ) -> Result<prescriptions::Model, AppError> {
+        let now = Utc::now().naive_utc();
+        let model = ActiveModel {
+            visit_intent_id: Set(visit_intent_id),
+            doctor_id: Set(doctor_id),
+            status: Set(PrescriptionStatus::Draft),
+            notes: Set(trimmed(payload.notes)),
+            created_at: Set(now),
+            updated_at: Set(now),
+            ..Default::default()
+        };
+
+        let prescription = model.insert(txn).await.map_err(|err| match err.sql_err() {
+            Some(SqlErr::UniqueConstraintViolation(_)) => AppError::Conflict(format!(
+                "You already have a draft prescription for visit {}",
+                visit_intent_id
+            )),
+            _ => err.into(),
+        })?;
+
+        insert_items(txn, prescription.id, payload.items).await?;
+        Ok(prescription)
+    }
+
+    async fn find_prescription(
+        db: &DatabaseConnection,
+        prescription_id: i32,
+    ) -> Result<PrescriptionRecord, AppError> {
+        let prescription = prescriptions::Entity::find_by_id(prescription_id)
+            .one(db)
+            .await?
+            .ok_or(AppError::NotFound(format!(
+                "Prescription with id {} is not found",
+                prescription_id
+            )))?;
+
+        with_details(db, vec![prescription])
+            .await?
+            .pop()
+            .ok_or(AppError::NotFound(format!(
+                "Prescription with id {} is not found",
+                prescription_id
+            )))
+    }
+
+    async fn find_prescription_for_update(
+        txn: &DatabaseTransaction,
+        prescription_id: i32,
+    ) -> Result<prescriptions::Model, AppError> {
+        prescriptions::Entity::find_by_id(prescription_id)
+            .lock_exclusive()
+            .one(txn)
+            .await?
+            .ok_or(AppError::NotFound(format!(
+                "Prescription with id {} is not found",
+                prescription_id
+            )))
+    }
+
+    async fn replace_draft(
+        txn: &DatabaseTransaction,
+        prescription: prescriptions::Model,
+        payload: PrescriptionRequest,
+    ) -> Result<(), AppError> {
+        let prescription_id = prescription.id;
+
+        // Components go with their items
+        prescription_items::Entity::delete_many()
+            .filter(prescription_items::Column::PrescriptionId.eq(prescription_id))
+            .exec(txn)
+            .await?;
+        insert_items(txn, prescription_id, payload.items).await?;
+
+        let mut model = prescription.into_active_model();
+        model.notes = Set(trimmed(payload.notes));
+        model.updated_at = Set(Utc::now().naive_utc());
+        model.update(txn).await?;
+        Ok(())
+    }
+
+    async fn delete_draft(
+        txn: &DatabaseTransaction,
+        prescription: prescriptions::Model,
+    ) -> Result<(), AppError> {
+        prescription.delete(txn).await?;
+        Ok(())
+    }
+
+    async fn submit(
+        txn: &DatabaseTransaction,
+        prescription: prescriptions::Model,
+        allergy_override_reason: Option<String>,
+        interaction_override_reason: Option<String>,
+        warnings: Vec<InteractionWarning>,
+    ) -> Result<(), AppError> {
+        let now = Utc::now().naive_utc();
+        let prescription_id = prescription.id;
+        let mut model = prescription.into_active_model();
+        model.status = Set(PrescriptionStatus::Submitted);
+        model.allergy_override_reason = Set(trimmed(allergy_override_reason));
+        model.interaction_override_reason = Set(trimmed(interaction_override_reason));
+        model.submitted_at = Set(Some(now));
+        model.updated_at = Set(now);
+        model.update(txn).await?;
+
+        if warnings.is_empty() {
+            return Ok(());
+        }
+        prescription_interaction_alerts::Entity::insert_many(warnings.into_iter().map(|warning| {
+            prescription_interaction_alerts::ActiveModel {
+                prescription_id: Set(prescription_id),
+                check_name: Set(warning.check.to_string()),
+                severity: Set(warning.severity.as_str().to_string()),
+                line_no: Set(warning.line_no),
+                drug_name: Set(warning.drug_name),
+                other_prescription_id: Set(warning.other_prescription_id),
+                other_line_no: Set(warning.other_line_no),
+                other_drug_name: Set(warning.other_drug_name),
+                description: Set(warning.description),
+                created_at: Set(now),
+                ..Default::default()
+            }
+        }))
+        .exec_without_returning(txn)
+        .await?;
+        Ok(())
+    }
+
+    async fn dispense(
+        txn: &DatabaseTransaction,
+        prescription: prescriptions::Model,
+        dispensed_by: i32,
+    ) -> Result<(), AppError> {
+        let now = Utc::now().naive_utc();
+        let mut model = prescription.into_active_model();
+        model.status = Set(PrescriptionStatus::Dispensed);
+        model.dispensed_at = Set(Some(now));
+        model.dispensed_by = Set(Some(dispensed_by));
+        model.updated_at = Set(now);
+        model.update(txn).await?;
+        Ok(())
+    }
+
+    async fn find_visit_prescriptions(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+    ) -> Result<Vec<PrescriptionRecord>, AppError> {
+        let prescriptions = prescriptions::Entity::find()
+            .filter(prescriptions::Column::VisitIntentId.eq(visit_intent_id))
+            .order_by_asc(prescriptions::Column::CreatedAt)
+            .order_by_asc(prescriptions::Column::Id)
+            .all(db)
+            .await?;
+
+        with_details(db, prescriptions).await
+    }
+
+    async fn find_patient_prescriptions(
+        db: &DatabaseConnection,
+        patient_id: i32,
+        submitted_since: NaiveDateTime,
+    ) -> Result<Vec<PrescriptionRecord>, AppError> {
+        let visit_ids = patients_visit_intent::Entity::find()
+            .select_only()
+            .column(patients_visit_intent::Column::Id)
+            .filter(patients_visit_intent::Column::PatientId.eq(patient_id))
+            .into_tuple::<i32>()
+            .all(db)
+            .await?;
+
+        let prescriptions = prescriptions::Entity::find()
+            .filter(prescriptions::Column::VisitIntentId.is_in(visit_ids))
+            .filter(
+                prescriptions::Column::Status
+                    .is_in([PrescriptionStatus::Submitted, PrescriptionStatus::Dispensed]),
+            )
+            .filter(prescriptions::Column::SubmittedAt.gte(submitted_since))
+            .order_by_asc(prescriptions::Column::SubmittedAt)
+            .order_by_asc(prescriptions::Column::Id)
+            .all(db)
+            .await?;
+
+        with_details(db, prescriptions).await
+    }
+
+    async fn find_queue(
+        db: &DatabaseConnection,
+        status: PrescriptionStatus,
+        since: NaiveDateTime,
+    ) -> Result<Vec<PrescriptionRecord>, AppError> {
+        let query = prescriptions::Entity::find().filter(prescriptions::Column::Status.eq(status));
+        let query = match status {
+            PrescriptionStatus::Dispensed => query
+                .filter(prescriptions::Column::DispensedAt.gte(since))
+                .order_by_desc(prescriptions::Column::DispensedAt),
+            _ => query.order_by_asc(prescriptions::Column::SubmittedAt),
+        };
+
+        let prescriptions = query
+            .order_by_asc(prescriptions::Column::Id)
+            .all(db)
+            .await?;
+        with_details(db, prescriptions).await
+    }
+}
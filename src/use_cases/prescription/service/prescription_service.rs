@@ -0,0 +1,762 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Local, NaiveDateTime, Utc};
+use entity::{
+    drugs, patient_allergies,
+    prescriptions::{self, PrescriptionStatus},
+    stock_movements::StockMovementType,
+};
+use sea_orm::{ActiveEnum, DatabaseConnection, TransactionTrait};
+
+pub use crate::use_cases::prescription::contracts::prescription_service_contract::PrescriptionServiceContracts;
+use crate::{
+    dtos::prescription::{
+        prescription_request::{
+            DispensePrescriptionRequest, PharmacyQueueQuery, PrescriptionRequest,
+            SubmitPrescriptionRequest,
+        },
+        response::{
+            AllergyCheckResponse, AllergyMatch, CompoundComponentResponse, DispenseResponse,
+            InteractionCheckResponse, InteractionWarningResponse, PharmacyQueueResponse,
+            PrescriptionDiscarded, PrescriptionItemResponse, PrescriptionResponse,
+            StockPickResponse, VisitPrescriptionsResponse,
+        },
+    },
+    error_handling::app_error::AppError,
+    format_created_at,
+    infra::{
+        api::ApiFieldError,
+        drug_interactions::{InteractionCheckers, InteractionWarning, Medication},
+    },
+    use_cases::{
+        allergy::{contracts::AllergyTraitRepo, repo::allergy_repo::AllergyRepo},
+        encounter::{contracts::EncounterTraitRepo, repo::encounter_repo::EncounterRepo},
+        patient::{contracts::PatientTraitRepo, repo::patient_repo::PatientRepo},
+        pharmacy::{
+            contracts::{PharmacyTraitRepo, pharmacy_repo_contract::NewStockMovement},
+            repo::pharmacy_repo::PharmacyRepo,
+            service::pharmacy_service::plan_fefo_picks,
+        },
+        prescription::{
+            contracts::{PrescriptionTraitRepo, prescription_repo_contract::PrescriptionRecord},
+            repo::prescription_repo::PrescriptionRepo,
+        },
+    },
+    utils::helpers::{hospital_day_start, hospital_now},
+};
+
+pub struct PrescriptionService;
+
+/// Entries of the free-text allergy note that record the absence of allergies.
+const NO_KNOWN_ALLERGY: [&str; 8] = [
+    "-",
+    "no",
+    "nil",
+    "nka",
+    "nkda",
+    "none",
+    "tidak",
+    "tidak ada",
+];
+
+fn prescription_response(record: PrescriptionRecord) -> PrescriptionResponse {
+    let PrescriptionRecord {
+        prescription,
+        patient_id,
+        doctor_name,
+        items,
+    } = record;
+
+    PrescriptionResponse {
+        id: prescription.id,
+        visit_intent_id: prescription.visit_intent_id,
+        patient_id,
+        doctor_id: prescription.doctor_id,
+        doctor_name,
+        status: prescription.status.to_value(),
+        notes: prescription.notes,
+        allergy_override_reason: prescription.allergy_override_reason,
+        interaction_override_reason: prescription.interaction_override_reason,
+        submitted_at: prescription
+            .submitted_at
+            .map(|submitted_at| format_created_at!(submitted_at)),
+        dispensed_at: prescription
+            .dispensed_at
+            .map(|dispensed_at| format_created_at!(dispensed_at)),
+        dispensed_by: prescription.dispensed_by,
+        created_at: format_created_at!(prescription.created_at),
+        updated_at: format_created_at!(prescription.updated_at),
+        items: items
+            .into_iter()
+            .map(|(item, components)| PrescriptionItemResponse {
+                id: item.id,
+                line_no: item.line_no,
+                drug_id: item.drug_id,
+                drug_name: item.drug_name,
+                dose: item.dose,
+                route: item.route.to_value(),
+                frequency: item.frequency,
+                duration_days: item.duration_days,
+                quantity: item.quantity,
+                unit: item.unit,
+                instructions: item.instructions,
+                is_compounded: item.is_compounded,
+                components: components
+                    .into_iter()
+                    .map(|component| CompoundComponentResponse {
+                        id: component.id,
+                        drug_name: component.drug_name,
+                        strength: component.strength,
+                    })
+                    .collect(),
+            })
+            .collect(),
+    }
+}
+
+/// Only the prescriber changes a draft, and a submitted prescription is changed by no one.
+fn ensure_editable(prescription: &prescriptions::Model, doctor_id: i32) -> Result<(), AppError> {
+    if prescription.status != PrescriptionStatus::Draft {
+        return Err(AppError::Conflict(format!(
+            "Prescription {} is already {} and can no longer be changed",
+            prescription.id,
+            prescription.status.to_value().to_lowercase()
+        )));
+    }
+    if prescription.doctor_id != doctor_id {
+        return Err(AppError::Forbidden(format!(
+            "Prescription {} is a draft of another doctor",
+            prescription.id
+        )));
+    }
+    Ok(())
+}
+
+/// The entries of the free-text allergy note, one per comma, semicolon, slash or line.
+fn legacy_substances(known_allergies: &str) -> Vec<String> {
+    known_allergies
+        .split([',', ';', '/', '\n'])
+        .map(|entry| entry.trim().to_lowercase())
+        .filter(|entry| entry.chars().count() >= 3 && !NO_KNOWN_ALLERGY.contains(&entry.as_str()))
+        .collect()
+}
+
+/// A drug name and an allergy substance match when either contains the other, ignoring
+/// case, so `Amoxicillin 500 mg` matches `amoxicillin` and `alergi amoxicillin` matches
+/// `Amoxicillin`. Names are compared as written; drug classes are not expanded.
+fn names_match(drug_name: &str, substance: &str) -> bool {
+    let drug_name = drug_name.trim().to_lowercase();
+    let substance = substance.trim().to_lowercase();
+    if drug_name.chars().count() < 3 || substance.chars().count() < 3 {
+        return false;
+    }
+    drug_name.contains(&substance) || substance.contains(&drug_name)
+}
+
+/// Every item, and every component of a racikan, checked against the recorded allergies
+/// and the free-text allergy note of the patient.
+fn allergy_matches(
+    record: &PrescriptionRecord,
+    allergies: &[patient_allergies::Model],
+    known_allergies: Option<&str>,
+) -> Vec<AllergyMatch> {
+    let legacy = known_allergies.map(legacy_substances).unwrap_or_default();
+    let mut matches = Vec::new();
+
+    for (item, components) in &record.items {
+        let names = std::iter::once(&item.drug_name)
+            .chain(components.iter().map(|component| &component.drug_name));
+        for drug_name in names {
+            for allergy in allergies {
+                if names_match(drug_name, &allergy.substance) {
+                    matches.push(AllergyMatch {
+                        line_no: item.line_no,
+                        drug_name: drug_name.clone(),
+                        substance: allergy.substance.clone(),
+                        severity: Some(allergy.severity.to_value()),
+                        reaction: allergy.reaction.clone(),
+                        source: "ALLERGY_LIST".to_string(),
+                    });
+                }
+            }
+            for substance in &legacy {
+                let recorded = allergies
+                    .iter()
+                    .any(|allergy| allergy.substance.eq_ignore_ascii_case(substance));
+                if !recorded && names_match(drug_name, substance) {
+                    matches.push(AllergyMatch {
+                        line_no: item.line_no,
+                        drug_name: drug_name.clone(),
+                        substance: substance.clone(),
+                        severity: None,
+                        reaction: None,
+                        source: "KNOWN_ALLERGIES".to_string(),
+                    });
+                }
+            }
+        }
+    }
+    matches
+}
+
+async fn find_allergy_matches(
+    db: &DatabaseConnection,
+    record: &PrescriptionRecord,
+) -> Result<Vec<AllergyMatch>, AppError> {
+    let patient =
+        <PatientRepo as PatientTraitRepo>::find_patient_by_id(db, record.patient_id).await?;
+    let allergies =
+        <AllergyRepo as AllergyTraitRepo>::find_patient_allergies(db, record.patient_id).await?;
+
+    Ok(allergy_matches(
+        record,
+        &allergies,
+        patient.known_allergies.as_deref(),
+    ))
+}
+
+/// Prescriptions are never taken for longer than an item may last, so older ones have
+/// nothing the patient is still taking.
+const ACTIVE_MEDICATION_LOOKBACK_DAYS: i64 = 365;
+
+/// The lines of a prescription as the interaction checks see them, with the generic name
+/// and therapeutic class of the formulary drugs they name.
+fn medications(record: &PrescriptionRecord, drugs: &HashMap<i32, drugs::Model>) -> Vec<Medication> {
+    record
+        .items
+        .iter()
+        .map(|(item, components)| {
+            let drug = item.drug_id.and_then(|drug_id| drugs.get(&drug_id));
+            let names = std::iter::once(&item.drug_name)
+                .chain(drug.map(|drug| &drug.generic_name))
+                .chain(components.iter().map(|component| &component.drug_name))
+                .map(|name| name.trim().to_lowercase())
+                .collect();
+
+            Medication {
+                prescription_id: record.prescription.id,
+                line_no: item.line_no,
+                drug_id: item.drug_id,
+                drug_name: item.drug_name.clone(),
+                names,
+                therapeutic_class: drug.and_then(|drug| drug.therapeutic_class.clone()),
+            }
+        })
+        .collect()
+}
+
+/// Interaction and duplicate therapy warnings between the lines of a prescription and
+/// against what the patient is still taking: lines of other submitted or dispensed
+/// prescriptions whose duration has not run out since they were dispensed, or submitted
+/// when not dispensed yet.
+async fn find_interaction_warnings(
+    db: &DatabaseConnection,
+    checks: &InteractionCheckers,
+    record: &PrescriptionRecord,
+) -> Result<Vec<InteractionWarning>, AppError> {
+    let now = Utc::now().naive_utc();
+    let mut others = <PrescriptionRepo as PrescriptionTraitRepo>::find_patient_prescriptions(
+        db,
+        record.patient_id,
+        now - Duration::days(ACTIVE_MEDICATION_LOOKBACK_DAYS),
+    )
+    .await?;
+    others.retain(|other| other.prescription.id != record.prescription.id);
+    for other in &mut others {
+        let started_at = other
+            .prescription
+            .dispensed_at
+            .or(other.prescription.submitted_at)
+            .unwrap_or(other.prescription.created_at);
+        other
+            .items
+            .retain(|(item, _)| started_at + Duration::days(item.duration_days.into()) > now);
+    }
+
+    let drug_ids = std::iter::once(record)
+        .chain(&others)
+        .flat_map(|record| record.items.iter().filter_map(|(item, _)| item.drug_id))
+        .collect::<Vec<_>>();
+    let drugs: HashMap<i32, drugs::Model> = if drug_ids.is_empty() {
+        HashMap::new()
+    } else {
+        <PharmacyRepo as PharmacyTraitRepo>::find_drugs(db, drug_ids)
+            .await?
+            .into_iter()
+            .map(|drug| (drug.id, drug))
+            .collect()
+    };
+
+    let active = others
+        .iter()
+        .flat_map(|other| medications(other, &drugs))
+        .collect::<Vec<_>>();
+    Ok(checks.check(&medications(record, &drugs), &active))
+}
+
+fn interaction_warning_response(warning: &InteractionWarning) -> InteractionWarningResponse {
+    InteractionWarningResponse {
+        check: warning.check.to_string(),
+        severity: warning.severity.as_str().to_string(),
+        line_no: warning.line_no,
+        drug_name: warning.drug_name.clone(),
+        other_prescription_id: warning.other_prescription_id,
+        other_line_no: warning.other_line_no,
+        other_drug_name: warning.other_drug_name.clone(),
+        description: warning.description.clone(),
+        needs_acknowledgement: warning.severity.needs_acknowledgement(),
+    }
+}
+
+/// Items naming a formulary drug must name one that is still on the formulary.
+async fn ensure_formulary_drugs(
+    db: &DatabaseConnection,
+    payload: &PrescriptionRequest,
+) -> Result<(), AppError> {
+    let drug_ids = payload
+        .items
+        .iter()
+        .filter_map(|item| item.drug_id)
+        .collect::<Vec<_>>();
+    if drug_ids.is_empty() {
+        return Ok(());
+    }
+    let drugs: HashMap<i32, drugs::Model> =
+        <PharmacyRepo as PharmacyTraitRepo>::find_drugs(db, drug_ids)
+            .await?
+            .into_iter()
+            .map(|drug| (drug.id, drug))
+            .collect();
+
+    let errors = payload
+        .items
+        .iter()
+        .enumerate()
+        .filter_map(|(index, item)| {
+            let drug_id = item.drug_id?;
+            let message = match drugs.get(&drug_id) {
+                None => format!("Drug with id {} is not found", drug_id),
+                Some(drug) if !drug.is_active => {
+                    format!("Drug {} is no longer on the formulary", drug.code)
+                }
+                Some(_) => return None,
+            };
+            Some(ApiFieldError {
+                field: format!("items[{}].drug_id", index),
+                message,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(AppError::ValidationError(errors))
+    }
+}
+
+#[async_trait]
+impl PrescriptionServiceContracts for PrescriptionService {
+    async fn create_prescription(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+        user_id: i32,
+        payload: PrescriptionRequest,
+    ) -> Result<PrescriptionResponse, AppError> {
+        let doctor =
+            <EncounterRepo as EncounterTraitRepo>::find_doctor_for_user(db, user_id).await?;
+        let visit =
+            <EncounterRepo as EncounterTraitRepo>::find_completed_visit(db, visit_intent_id)
+                .await?;
+        ensure_formulary_drugs(db, &payload).await?;
+
+        if let Some(draft) =
+            <PrescriptionRepo as PrescriptionTraitRepo>::find_draft(db, visit.id, doctor.id).await?
+        {
+            return Err(AppError::Conflict(format!(
+                "You already have draft prescription {} for visit {}",
+                draft.id, visit.id
+            )));
+        }
+
+        let txn = db.begin().await?;
+        let prescription = <PrescriptionRepo as PrescriptionTraitRepo>::create_prescription(
+            &txn, visit.id, doctor.id, payload,
+        )
+        .await?;
+        txn.commit().await?;
+
+        let record =
+            <PrescriptionRepo as PrescriptionTraitRepo>::find_prescription(db, prescription.id)
+                .await?;
+        Ok(prescription_response(record))
+    }
+
+    async fn get_prescription(
+        db: &DatabaseConnection,
+        prescription_id: i32,
+    ) -> Result<PrescriptionResponse, AppError> {
+        let record =
+            <PrescriptionRepo as PrescriptionTraitRepo>::find_prescription(db, prescription_id)
+                .await?;
+        Ok(prescription_response(record))
+    }
+
+    async fn update_prescription(
+        db: &DatabaseConnection,
+        prescription_id: i32,
+        user_id: i32,
+        payload: PrescriptionRequest,
+    ) -> Result<PrescriptionResponse, AppError> {
+        let doctor =
+            <EncounterRepo as EncounterTraitRepo>::find_doctor_for_user(db, user_id).await?;
+        ensure_formulary_drugs(db, &payload).await?;
+
+        let txn = db.begin().await?;
+        let prescription =
+            <PrescriptionRepo as PrescriptionTraitRepo>::find_prescription_for_update(
+                &txn,
+                prescription_id,
+            )
+            .await?;
+        ensure_editable(&prescription, doctor.id)?;
+
+        <PrescriptionRepo as PrescriptionTraitRepo>::replace_draft(&txn, prescription, payload)
+            .await?;
+        txn.commit().await?;
+
+        let record =
+            <PrescriptionRepo as PrescriptionTraitRepo>::find_prescription(db, prescription_id)
+                .await?;
+        Ok(prescription_response(record))
+    }
+
+    async fn discard_prescription(
+        db: &DatabaseConnection,
+        prescription_id: i32,
+        user_id: i32,
+    ) -> Result<PrescriptionDiscarded, AppError> {
+        let doctor =
+            <EncounterRepo as EncounterTraitRepo>::find_doctor_for_user(db, user_id).await?;
+
+        let txn = db.begin().await?;
+        let prescription =
+            <PrescriptionRepo as PrescriptionTraitRepo>::find_prescription_for_update(
+                &txn,
+                prescription_id,
+            )
+            .await?;
+        ensure_editable(&prescription, doctor.id)?;
+
+        let visit_intent_id = prescription.visit_intent_id;
+        <PrescriptionRepo as PrescriptionTraitRepo>::delete_draft(&txn, prescription).await?;
+        txn.commit().await?;
+
+        Ok(PrescriptionDiscarded {
+            id: prescription_id,
+            visit_intent_id,
+            discarded_at: format_created_at!(Utc::now().naive_utc()),
+        })
+    }
+
+    async fn check_allergies(
+        db: &DatabaseConnection,
+        prescription_id: i32,
+    ) -> Result<AllergyCheckResponse, AppError> {
+        let record =
+            <PrescriptionRepo as PrescriptionTraitRepo>::find_prescription(db, prescription_id)
+                .await?;
+        let matches = find_allergy_matches(db, &record).await?;
+
+        Ok(AllergyCheckResponse {
+            prescription_id,
+            patient_id: record.patient_id,
+            matches,
+        })
+    }
+
+    async fn check_interactions(
+        db: &DatabaseConnection,
+        checks: &InteractionCheckers,
+        prescription_id: i32,
+    ) -> Result<InteractionCheckResponse, AppError> {
+        let record =
+            <PrescriptionRepo as PrescriptionTraitRepo>::find_prescription(db, prescription_id)
+                .await?;
+        let warnings = find_interaction_warnings(db, checks, &record)
+            .await?
+            .iter()
+            .map(interaction_warning_response)
+            .collect::<Vec<_>>();
+
+        Ok(InteractionCheckResponse {
+            prescription_id,
+            patient_id: record.patient_id,
+            requires_acknowledgement: warnings.iter().any(|warning| warning.needs_acknowledgement),
+            warnings,
+        })
+    }
+
+    async fn submit_prescription(
+        db: &DatabaseConnection,
+        checks: &InteractionCheckers,
+        prescription_id: i32,
+        user_id: i32,
+        payload: SubmitPrescriptionRequest,
+    ) -> Result<PrescriptionResponse, AppError> {
+        let doctor =
+            <EncounterRepo as EncounterTraitRepo>::find_doctor_for_user(db, user_id).await?;
+
+        // The lock keeps the items checked below the ones that are submitted
+        let txn = db.begin().await?;
+        let prescription =
+            <PrescriptionRepo as PrescriptionTraitRepo>::find_prescription_for_update(
+                &txn,
+                prescription_id,
+            )
+            .await?;
+        ensure_editable(&prescription, doctor.id)?;
+
+        let record =
+            <PrescriptionRepo as PrescriptionTraitRepo>::find_prescription(db, prescription_id)
+                .await?;
+        let matches = find_allergy_matches(db, &record).await?;
+
+        let warnings = find_interaction_warnings(db, checks, &record).await?;
+
+        let mut conflicts = Vec::new();
+        let mut fields = Vec::new();
+        let allergy_override_reason = match (matches.is_empty(), payload.allergy_override_reason) {
+            (true, _) => None,
+            (false, Some(reason)) if !reason.trim().is_empty() => Some(reason),
+            (false, _) => {
+                conflicts.push("matches the patient's allergies");
+                fields.extend(matches.into_iter().map(|allergy| ApiFieldError {
+                    field: format!("items[{}].drug_name", allergy.line_no - 1),
+                    message: format!(
+                        "{} matches the recorded allergy to {}",
+                        allergy.drug_name, allergy.substance
+                    ),
+                }));
+                None
+            }
+        };
+
+        let acknowledged = warnings
+            .iter()
+            .filter(|warning| warning.severity.needs_acknowledgement())
+            .collect::<Vec<_>>();
+        let interaction_override_reason =
+            match (acknowledged.is_empty(), payload.interaction_override_reason) {
+                (true, _) => None,
+                (false, Some(reason)) if !reason.trim().is_empty() => Some(reason),
+                (false, _) => {
+                    conflicts.push("has drug interaction warnings");
+                    fields.extend(acknowledged.into_iter().map(|warning| ApiFieldError {
+                        field: format!("items[{}].drug_name", warning.line_no - 1),
+                        message: format!(
+                            "{} with {}: {}",
+                            warning.severity.as_str(),
+                            warning.other_drug_name,
+                            warning.description
+                        ),
+                    }));
+                    None
+                }
+            };
+
+        if !conflicts.is_empty() {
+            return Err(AppError::FieldConflict(
+                format!(
+                    "Prescription {}; change it or submit with an override reason",
+                    conflicts.join(" and ")
+                ),
+                fields,
+            ));
+        }
+
+        <PrescriptionRepo as PrescriptionTraitRepo>::submit(
+            &txn,
+            prescription,
+            allergy_override_reason,
+            interaction_override_reason,
+            warnings,
+        )
+        .await?;
+        txn.commit().await?;
+
+        let record =
+            <PrescriptionRepo as PrescriptionTraitRepo>::find_prescription(db, prescription_id)
+                .await?;
+        Ok(prescription_response(record))
+    }
+
+    async fn dispense_prescription(
+        db: &DatabaseConnection,
+        prescription_id: i32,
+        user_id: i32,
+        payload: DispensePrescriptionRequest,
+    ) -> Result<DispenseResponse, AppError> {
+        let location =
+            <PharmacyRepo as PharmacyTraitRepo>::find_location(db, payload.location_id).await?;
+        if !location.is_active {
+            return Err(AppError::Conflict(format!(
+                "Storage location {} is no longer in use",
+                location.code
+            )));
+        }
+
+        let txn = db.begin().await?;
+        let prescription =
+            <PrescriptionRepo as PrescriptionTraitRepo>::find_prescription_for_update(
+                &txn,
+                prescription_id,
+            )
+            .await?;
+        match prescription.status {
+            PrescriptionStatus::Submitted => {}
+            PrescriptionStatus::Draft => {
+                return Err(AppError::Conflict(format!(
+                    "Prescription {} has not been submitted",
+                    prescription_id
+                )));
+            }
+            PrescriptionStatus::Dispensed => {
+                return Err(AppError::Conflict(format!(
+                    "Prescription {} is already dispensed",
+                    prescription_id
+                )));
+            }
+        }
+
+        // Items of a submitted prescription no longer change, so they are read outside the lock
+        let record =
+            <PrescriptionRepo as PrescriptionTraitRepo>::find_prescription(db, prescription_id)
+                .await?;
+
+        // Formulary items are picked first expiry first from batches not yet expired;
+        // racikan and free-text items are prepared outside stock
+        let today = hospital_now().date_naive();
+        let mut picks = Vec::new();
+        let mut shortages = Vec::new();
+        for (index, (item, _)) in record.items.iter().enumerate() {
+            let Some(drug_id) = item.drug_id else {
+                continue;
+            };
+            let batches = <PharmacyRepo as PharmacyTraitRepo>::find_pickable_batches(
+                &txn,
+                drug_id,
+                location.id,
+                today,
+            )
+            .await?;
+            let (planned, missing) = plan_fefo_picks(batches, item.quantity);
+            if missing > 0 {
+                shortages.push(ApiFieldError {
+                    field: format!("items[{}].quantity", index),
+                    message: format!(
+                        "Only {} of {} {} of {} are in stock at {}",
+                        item.quantity - missing,
+                        item.quantity,
+                        item.unit,
+                        item.drug_name,
+                        location.code
+                    ),
+                });
+                continue;
+            }
+
+            for (batch, quantity) in planned {
+                let (batch, _) = <PharmacyRepo as PharmacyTraitRepo>::record_movement(
+                    &txn,
+                    batch,
+                    NewStockMovement {
+                        movement_type: StockMovementType::Dispense,
+                        quantity: -quantity,
+                        reason: None,
+                        notes: None,
+                        goods_receipt_id: None,
+                        prescription_item_id: Some(item.id),
+                        performed_by: user_id,
+                    },
+                )
+                .await?;
+                picks.push(StockPickResponse {
+                    line_no: item.line_no,
+                    drug_id,
+                    batch_id: batch.id,
+                    batch_no: batch.batch_no,
+                    expiry_date: batch.expiry_date,
+                    quantity,
+                });
+            }
+        }
+        if !shortages.is_empty() {
+            return Err(AppError::FieldConflict(
+                "Not enough usable stock to dispense the prescription".to_string(),
+                shortages,
+            ));
+        }
+
+        <PrescriptionRepo as PrescriptionTraitRepo>::dispense(&txn, prescription, user_id).await?;
+        txn.commit().await?;
+
+        let record =
+            <PrescriptionRepo as PrescriptionTraitRepo>::find_prescription(db, prescription_id)
+                .await?;
+        Ok(DispenseResponse {
+            prescription: prescription_response(record),
+            location_id: location.id,
+            picks,
+        })
+    }
+
+    async fn get_visit_prescriptions(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+    ) -> Result<VisitPrescriptionsResponse, AppError> {
+        let visit =
+            <PrescriptionRepo as PrescriptionTraitRepo>::find_visit(db, visit_intent_id).await?;
+
+        let prescriptions = <PrescriptionRepo as PrescriptionTraitRepo>::find_visit_prescriptions(
+            db,
+            visit_intent_id,
+        )
+        .await?;
+
+        Ok(VisitPrescriptionsResponse {
+            visit_intent_id,
+            patient_id: visit.patient_id,
+            data: prescriptions
+                .into_iter()
+                .map(prescription_response)
+                .collect(),
+        })
+    }
+
+    async fn get_pharmacy_queue(
+        db: &DatabaseConnection,
+        query: PharmacyQueueQuery,
+    ) -> Result<PharmacyQueueResponse, AppError> {
+        let status = query.status.unwrap_or(PrescriptionStatus::Submitted);
+        if status == PrescriptionStatus::Draft {
+            return Err(AppError::BadRequest(
+                "Drafts are not sent to the pharmacy".to_string(),
+            ));
+        }
+
+        // Dispensed prescriptions are listed for the current hospital day
+        let since = hospital_day_start(hospital_now().date_naive());
+        let prescriptions =
+            <PrescriptionRepo as PrescriptionTraitRepo>::find_queue(db, status, since).await?;
+
+        Ok(PharmacyQueueResponse {
+            status: status.to_value(),
+            data: prescriptions
+                .into_iter()
+                .map(prescription_response)
+                .collect(),
+        })
+    }
+}
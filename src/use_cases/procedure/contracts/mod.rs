@@ -0,0 +1,4 @@
+pub mod procedure_repo_contract;
+pub mod procedure_service_contract;
+pub use self::procedure_repo_contract::ProcedureTraitRepo;
+pub use self::procedure_service_contract::ProcedureServiceContracts;
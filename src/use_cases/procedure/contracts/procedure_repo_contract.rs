@@ -0,0 +1,78 @@
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use entity::{
+    doctor_schedules, doctors, icd9cm_procedures, patients_visit_intent, rooms, visit_procedures,
+};
+use sea_orm::DatabaseConnection;
+
+use crate::error_handling::app_error::AppError;
+
+/// A procedure with its catalog entry and the doctor who performed it.
+pub type ProcedureRecord = (
+    visit_procedures::Model,
+    icd9cm_procedures::Model,
+    doctors::Model,
+);
+
+/// A procedure checked against the catalog, the schedule and the rooms, ready to store.
+pub struct NewVisitProcedure {
+    pub visit_intent_id: i32,
+    pub procedure_code: String,
+    pub doctor_id: i32,
+    pub polyclinic_id: Option<i32>,
+    pub room_code: String,
+    pub performed_at: NaiveDateTime,
+    pub notes: Option<String>,
+    pub recorded_by: i32,
+}
+
+#[async_trait]
+pub trait ProcedureTraitRepo {
+    /// Active procedures whose code starts with the term, ignoring the dot, followed by
+    /// the ones whose English or Indonesian description contains it.
+    async fn search_codes(
+        db: &DatabaseConnection,
+        term: &str,
+        limit: u64,
+    ) -> Result<Vec<icd9cm_procedures::Model>, AppError>;
+    /// A catalog entry by its code, written with or without the dot.
+    async fn find_code(
+        db: &DatabaseConnection,
+        code: &str,
+    ) -> Result<icd9cm_procedures::Model, AppError>;
+    async fn find_visit(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+    ) -> Result<patients_visit_intent::Model, AppError>;
+    /// The polyclinic a visit is seen in: its encounter's, else its queue ticket's.
+    async fn find_visit_polyclinic(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+    ) -> Result<Option<i32>, AppError>;
+    /// The doctor's active schedule in the polyclinic covering a hospital wall-clock time.
+    async fn find_schedule_at(
+        db: &DatabaseConnection,
+        doctor_id: i32,
+        polyclinic_id: i32,
+        at: NaiveDateTime,
+    ) -> Result<Option<doctor_schedules::Model>, AppError>;
+    async fn find_room(db: &DatabaseConnection, room_code: &str) -> Result<rooms::Model, AppError>;
+    async fn create_procedure(
+        db: &DatabaseConnection,
+        procedure: NewVisitProcedure,
+    ) -> Result<visit_procedures::Model, AppError>;
+    async fn find_procedure(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+        procedure_id: i32,
+    ) -> Result<ProcedureRecord, AppError>;
+    /// Procedures of a visit in the order they were performed.
+    async fn find_visit_procedures(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+    ) -> Result<Vec<ProcedureRecord>, AppError>;
+    async fn delete_procedure(
+        db: &DatabaseConnection,
+        procedure: visit_procedures::Model,
+    ) -> Result<(), AppError>;
+}
@@ -0,0 +1,43 @@
+use async_trait::async_trait;
+use entity::user::Role;
+use sea_orm::DatabaseConnection;
+
+use crate::{
+    dtos::procedure::{
+        procedure_request::{ProcedureSearchQuery, RecordProcedureRequest},
+        response::{
+            ProcedureCodeResponse, ProcedureRemoved, ProcedureSearchResponse,
+            VisitProcedureResponse, VisitProceduresResponse,
+        },
+    },
+    error_handling::app_error::AppError,
+};
+
+#[async_trait]
+pub trait ProcedureServiceContracts {
+    async fn search_codes(
+        db: &DatabaseConnection,
+        query: ProcedureSearchQuery,
+    ) -> Result<ProcedureSearchResponse, AppError>;
+    async fn get_code(
+        db: &DatabaseConnection,
+        code: &str,
+    ) -> Result<ProcedureCodeResponse, AppError>;
+    async fn record_procedure(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+        user_id: i32,
+        role: Role,
+        payload: RecordProcedureRequest,
+    ) -> Result<VisitProcedureResponse, AppError>;
+    async fn remove_procedure(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+        procedure_id: i32,
+        user_id: i32,
+    ) -> Result<ProcedureRemoved, AppError>;
+    async fn get_visit_procedures(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+    ) -> Result<VisitProceduresResponse, AppError>;
+}
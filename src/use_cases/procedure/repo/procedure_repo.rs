@@ -0,0 +1,245 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use chrono::{NaiveDateTime, Utc};
+use entity::{
+    doctor_schedules, doctors, encounters, icd9cm_procedures, patients_visit_intent, queue_ticket,
+    rooms,
+    visit_procedures::{self, ActiveModel},
+};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, DatabaseConnection, EntityTrait, ModelTrait,
+    QueryFilter, QueryOrder, QuerySelect, sea_query::Expr,
+};
+
+use crate::{
+    error_handling::app_error::AppError,
+    use_cases::procedure::contracts::procedure_repo_contract::{
+        NewVisitProcedure, ProcedureRecord, ProcedureTraitRepo,
+    },
+};
+
+pub struct ProcedureRepo;
+
+/// Codes are compared without the dot, so `8952` finds `89.52`.
+fn normalize_code(code: &str) -> String {
+    code.trim().replace('.', "").to_uppercase()
+}
+
+async fn with_catalog(
+    db: &DatabaseConnection,
+    procedures: Vec<visit_procedures::Model>,
+) -> Result<Vec<ProcedureRecord>, AppError> {
+    let codes = procedures
+        .iter()
+        .map(|procedure| procedure.procedure_code.clone())
+        .collect::<Vec<_>>();
+    let doctor_ids = procedures
+        .iter()
+        .map(|procedure| procedure.doctor_id)
+        .collect::<Vec<_>>();
+
+    let catalog: HashMap<String, icd9cm_procedures::Model> = icd9cm_procedures::Entity::find()
+        .filter(icd9cm_procedures::Column::Code.is_in(codes))
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|code| (code.code.clone(), code))
+        .collect();
+    let doctors: HashMap<i32, doctors::Model> = doctors::Entity::find()
+        .filter(doctors::Column::Id.is_in(doctor_ids))
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|doctor| (doctor.id, doctor))
+        .collect();
+
+    Ok(procedures
+        .into_iter()
+        .filter_map(|procedure| {
+            let code = catalog.get(&procedure.procedure_code)?.clone();
+            let doctor = doctors.get(&procedure.doctor_id)?.clone();
+            Some((procedure, code, doctor))
+        })
+        .collect())
+}
+
+#[async_trait]
+impl ProcedureTraitRepo for ProcedureRepo {
+    async fn search_codes(
+        db: &DatabaseConnection,
+        term: &str,
+        limit: u64,
+    ) -> Result<Vec<icd9cm_procedures::Model>, AppError> {
+        let term = term.trim();
+        let code_prefix = normalize_code(term);
+
+        Ok(icd9cm_procedures::Entity::find()
+            .filter(icd9cm_procedures::Column::IsActive.eq(true))
+            .filter(Expr::cust_with_values(
+                "(starts_with(replace(code, '.', ''), $1) \
+                 OR strpos(lower(description_en), lower($2)) > 0 \
+                 OR strpos(lower(description_id), lower($3)) > 0)",
+                [code_prefix.as_str(), term, term],
+            ))
+            .order_by_asc(Expr::cust_with_values(
+                "CASE WHEN starts_with(replace(code, '.', ''), $1) THEN 0 ELSE 1 END",
+                [code_prefix.as_str()],
+            ))
+            .order_by_asc(icd9cm_procedures::Column::Code)
+            .limit(limit)
+            .all(db)
+            .await?)
+    }
+
+    async fn find_code(
+        db: &DatabaseConnection,
+        code: &str,
+    ) -> Result<icd9cm_procedures::Model, AppError> {
+        icd9cm_procedures::Entity::find()
+            .filter(Expr::cust_with_values(
+                "replace(code, '.', '') = $1",
+                [normalize_code(code)],
+            ))
+            .one(db)
+            .await?
+            .ok_or(AppError::NotFound(format!(
+                "ICD-9-CM procedure {} is not found",
+                code.trim()
+            )))
+    }
+
+    async fn find_visit(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+    ) -> Result<patients_visit_intent::Model, AppError> {
+        patients_visit_intent::Entity::find_by_id(visit_intent_id)
+            .one(db)
+            .await?
+            .ok_or(AppError::NotFound(format!(
+                "Visit with id {} is not found",
+                visit_intent_id
+            )))
+    }
+
+    async fn find_visit_polyclinic(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+    ) -> Result<Option<i32>, AppError> {
+        if let Some(encounter) = encounters::Entity::find()
+            .filter(encounters::Column::VisitIntentId.eq(visit_intent_id))
+            .one(db)
+            .await?
+        {
+            return Ok(Some(encounter.polyclinic_id));
+        }
+
+        Ok(queue_ticket::Entity::find()
+            .filter(queue_ticket::Column::VisitIntentId.eq(visit_intent_id))
+            .one(db)
+            .await?
+            .and_then(|ticket| ticket.polyclinic_id))
+    }
+
+    async fn find_schedule_at(
+        db: &DatabaseConnection,
+        doctor_id: i32,
+        polyclinic_id: i32,
+        at: NaiveDateTime,
+    ) -> Result<Option<doctor_schedules::Model>, AppError> {
+        let day_of_week = at.format("%A").to_string();
+        let time = at.time();
+
+        Ok(doctor_schedules::Entity::find()
+            .filter(doctor_schedules::Column::DoctorId.eq(doctor_id))
+            .filter(doctor_schedules::Column::PolyclinicId.eq(polyclinic_id))
+            .all(db)
+            .await?
+            .into_iter()
+            .find(|schedule| {
+                schedule.day_of_week.eq_ignore_ascii_case(&day_of_week)
+                    && schedule.status.eq_ignore_ascii_case("ACTIVE")
+                    && schedule.start_time <= time
+                    && time <= schedule.end_time
+            }))
+    }
+
+    async fn find_room(db: &DatabaseConnection, room_code: &str) -> Result<rooms::Model, AppError> {
+        rooms::Entity::find()
+            .filter(rooms::Column::Code.eq(room_code))
+            .one(db)
+            .await?
+            .ok_or(AppError::NotFound(format!(
+                "Room with code {} is not found",
+                room_code
+            )))
+    }
+
+    async fn create_procedure(
+        db: &DatabaseConnection,
+        procedure: NewVisitProcedure,
+    ) -> Result<visit_procedures::Model, AppError> {
+        let model = ActiveModel {
+            visit_intent_id: Set(procedure.visit_intent_id),
+            procedure_code: Set(procedure.procedure_code),
+            doctor_id: Set(procedure.doctor_id),
+            polyclinic_id: Set(procedure.polyclinic_id),
+            room_code: Set(procedure.room_code),
+            performed_at: Set(procedure.performed_at),
+            notes: Set(procedure
+                .notes
+                .map(|notes| notes.trim().to_string())
+                .filter(|notes| !notes.is_empty())),
+            recorded_by: Set(Some(procedure.recorded_by)),
+            created_at: Set(Utc::now().naive_utc()),
+            ..Default::default()
+        };
+
+        Ok(model.insert(db).await?)
+    }
+
+    async fn find_procedure(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+        procedure_id: i32,
+    ) -> Result<ProcedureRecord, AppError> {
+        let procedure = visit_procedures::Entity::find_by_id(procedure_id)
+            .filter(visit_procedures::Column::VisitIntentId.eq(visit_intent_id))
+            .one(db)
+            .await?
+            .ok_or(AppError::NotFound(format!(
+                "Procedure with id {} is not found for visit {}",
+                procedure_id, visit_intent_id
+            )))?;
+
+        with_catalog(db, vec![procedure])
+            .await?
+            .pop()
+            .ok_or(AppError::NotFound(format!(
+                "Procedure with id {} is not found for visit {}",
+                procedure_id, visit_intent_id
+            )))
+    }
+
+    async fn find_visit_procedures(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+    ) -> Result<Vec<ProcedureRecord>, AppError> {
+        let procedures = visit_procedures::Entity::find()
+            .filter(visit_procedures::Column::VisitIntentId.eq(visit_intent_id))
+            .order_by_asc(visit_procedures::Column::PerformedAt)
+            .order_by_asc(visit_procedures::Column::Id)
+            .all(db)
+            .await?;
+
+        with_catalog(db, procedures).await
+    }
+
+    async fn delete_procedure(
+        db: &DatabaseConnection,
+        procedure: visit_procedures::Model,
+    ) -> Result<(), AppError> {
+        procedure.delete(db).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,238 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Local, NaiveDateTime, Utc};
+use entity::{icd9cm_procedures, user::Role};
+use sea_orm::DatabaseConnection;
+
+pub use crate::use_cases::procedure::contracts::procedure_service_contract::ProcedureServiceContracts;
+use crate::{
+    dtos::procedure::{
+        procedure_request::{ProcedureSearchQuery, RecordProcedureRequest},
+        response::{
+            ProcedureCodeResponse, ProcedureRemoved, ProcedureSearchResponse,
+            VisitProcedureResponse, VisitProceduresResponse,
+        },
+    },
+    error_handling::app_error::AppError,
+    format_created_at,
+    use_cases::{
+        encounter::{contracts::EncounterTraitRepo, repo::encounter_repo::EncounterRepo},
+        procedure::{
+            contracts::{
+                ProcedureTraitRepo,
+                procedure_repo_contract::{NewVisitProcedure, ProcedureRecord},
+            },
+            repo::procedure_repo::ProcedureRepo,
+        },
+    },
+    utils::helpers::hospital_time,
+};
+
+pub struct ProcedureService;
+
+const DEFAULT_SEARCH_LIMIT: u64 = 20;
+
+/// How far ahead of the server clock a procedure may be stamped.
+const PERFORMED_AT_TOLERANCE_MINUTES: i64 = 5;
+
+fn code_response(code: icd9cm_procedures::Model) -> ProcedureCodeResponse {
+    ProcedureCodeResponse {
+        code: code.code,
+        description_en: code.description_en,
+        description_id: code.description_id,
+        is_active: code.is_active,
+    }
+}
+
+fn procedure_response((procedure, code, doctor): ProcedureRecord) -> VisitProcedureResponse {
+    VisitProcedureResponse {
+        id: procedure.id,
+        visit_intent_id: procedure.visit_intent_id,
+        code: code.code,
+        description_en: code.description_en,
+        description_id: code.description_id,
+        doctor_id: doctor.id,
+        doctor_name: doctor.name,
+        polyclinic_id: procedure.polyclinic_id,
+        room_code: procedure.room_code,
+        performed_at: format_created_at!(procedure.performed_at),
+        notes: procedure.notes,
+        recorded_by: procedure.recorded_by,
+        created_at: format_created_at!(procedure.created_at),
+    }
+}
+
+#[async_trait]
+impl ProcedureServiceContracts for ProcedureService {
+    async fn search_codes(
+        db: &DatabaseConnection,
+        query: ProcedureSearchQuery,
+    ) -> Result<ProcedureSearchResponse, AppError> {
+        let codes = <ProcedureRepo as ProcedureTraitRepo>::search_codes(
+            db,
+            &query.q,
+            query.limit.unwrap_or(DEFAULT_SEARCH_LIMIT),
+        )
+        .await?;
+
+        Ok(ProcedureSearchResponse {
+            query: query.q,
+            data: codes.into_iter().map(code_response).collect(),
+        })
+    }
+
+    async fn get_code(
+        db: &DatabaseConnection,
+        code: &str,
+    ) -> Result<ProcedureCodeResponse, AppError> {
+        let code = <ProcedureRepo as ProcedureTraitRepo>::find_code(db, code).await?;
+        Ok(code_response(code))
+    }
+
+    async fn record_procedure(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+        user_id: i32,
+        role: Role,
+        payload: RecordProcedureRequest,
+    ) -> Result<VisitProcedureResponse, AppError> {
+        let now = Utc::now().naive_utc();
+        let performed_at = payload
+            .performed_at
+            .map(|performed_at| performed_at.naive_utc())
+            .unwrap_or(now);
+        if performed_at > now + Duration::minutes(PERFORMED_AT_TOLERANCE_MINUTES) {
+            return Err(AppError::BadRequest(
+                "Procedure time cannot be in the future".to_string(),
+            ));
+        }
+
+        let visit =
+            <EncounterRepo as EncounterTraitRepo>::find_completed_visit(db, visit_intent_id)
+                .await?;
+        let code = <ProcedureRepo as ProcedureTraitRepo>::find_code(db, &payload.code).await?;
+        if !code.is_active {
+            return Err(AppError::BadRequest(format!(
+                "ICD-9-CM procedure {} is retired and cannot be recorded",
+                code.code
+            )));
+        }
+
+        let doctor = match payload.doctor_id {
+            Some(doctor_id) => {
+                <EncounterRepo as EncounterTraitRepo>::find_doctor_by_id(db, doctor_id).await?
+            }
+            None if role == Role::Doctor => {
+                <EncounterRepo as EncounterTraitRepo>::find_doctor_for_user(db, user_id).await?
+            }
+            None => {
+                return Err(AppError::BadRequest(
+                    "doctor_id of the performing doctor is required".to_string(),
+                ));
+            }
+        };
+
+        // In a polyclinic the performing doctor must be on shift there at that time;
+        // emergency visits have no polyclinic schedule to check against
+        let polyclinic_id =
+            <ProcedureRepo as ProcedureTraitRepo>::find_visit_polyclinic(db, visit.id).await?;
+        let scheduled_room = match polyclinic_id {
+            Some(polyclinic_id) => {
+                let local = hospital_time(performed_at).naive_local();
+                let schedule = <ProcedureRepo as ProcedureTraitRepo>::find_schedule_at(
+                    db,
+                    doctor.id,
+                    polyclinic_id,
+                    local,
+                )
+                .await?
+                .ok_or(AppError::BadRequest(format!(
+                    "Doctor {} is not scheduled in this polyclinic on {} at {}",
+                    doctor.name,
+                    local.format("%A"),
+                    local.format("%H:%M")
+                )))?;
+                Some(schedule.room_code)
+            }
+            None => None,
+        };
+
+        let room_code = match payload.room_code.or(scheduled_room) {
+            Some(room_code) => {
+                <ProcedureRepo as ProcedureTraitRepo>::find_room(db, room_code.trim())
+                    .await?
+                    .code
+            }
+            None => {
+                return Err(AppError::BadRequest(
+                    "room_code is required for a visit outside a polyclinic".to_string(),
+                ));
+            }
+        };
+
+        let procedure = <ProcedureRepo as ProcedureTraitRepo>::create_procedure(
+            db,
+            NewVisitProcedure {
+                visit_intent_id: visit.id,
+                procedure_code: code.code,
+                doctor_id: doctor.id,
+                polyclinic_id,
+                room_code,
+                performed_at,
+                notes: payload.notes,
+                recorded_by: user_id,
+            },
+        )
+        .await?;
+
+        let record =
+            <ProcedureRepo as ProcedureTraitRepo>::find_procedure(db, visit.id, procedure.id)
+                .await?;
+        Ok(procedure_response(record))
+    }
+
+    async fn remove_procedure(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+        procedure_id: i32,
+        user_id: i32,
+    ) -> Result<ProcedureRemoved, AppError> {
+        let (procedure, _, _) = <ProcedureRepo as ProcedureTraitRepo>::find_procedure(
+            db,
+            visit_intent_id,
+            procedure_id,
+        )
+        .await?;
+
+        // Only whoever entered a procedure takes it back
+        if procedure.recorded_by != Some(user_id) {
+            return Err(AppError::Forbidden(format!(
+                "Procedure {} was recorded by another user",
+                procedure_id
+            )));
+        }
+
+        <ProcedureRepo as ProcedureTraitRepo>::delete_procedure(db, procedure).await?;
+
+        Ok(ProcedureRemoved {
+            id: procedure_id,
+            visit_intent_id,
+            removed_at: format_created_at!(Utc::now().naive_utc()),
+        })
+    }
+
+    async fn get_visit_procedures(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+    ) -> Result<VisitProceduresResponse, AppError> {
+        let visit = <ProcedureRepo as ProcedureTraitRepo>::find_visit(db, visit_intent_id).await?;
+        let procedures =
+            <ProcedureRepo as ProcedureTraitRepo>::find_visit_procedures(db, visit_intent_id)
+                .await?;
+
+        Ok(VisitProceduresResponse {
+            visit_intent_id,
+            patient_id: visit.patient_id,
+            data: procedures.into_iter().map(procedure_response).collect(),
+        })
+    }
+}
@@ -1,66 +1,150 @@
 use async_trait::async_trait;
-use entity::{patients, patients_visit_intent, queue_ticket, referral_documents};
+use chrono::{NaiveDate, NaiveDateTime};
+use entity::{
+    doctors, patients,
+    patients_visit_intent::{self, PriorityCategory, VisitStatus},
+    polyclinic,
+    queue_ticket::{self, QueueStatus},
+    referral_documents,
+};
 use sea_orm::{DatabaseConnection, DatabaseTransaction};
 
 use crate::{
     dtos::triage::{
-        create_triage_request::{CreateTriageRequest, VisitType},
-        response::TriageQueueItem,
+        create_triage_request::{CreateTriageRequest, CreateUnidentifiedTriageRequest, VisitType},
+        queue_scope::QueueScope,
+        response::{QueueDailyStats, TriageQueueItem},
     },
     error_handling::app_error::AppError,
+    infra::config::MrnConfig,
+    use_cases::triage::repo::queue_transition::TicketTransition,
 };
 
 #[async_trait]
 pub trait TriageTraitRepo {
+    /// Registers a new patient, or resolves the stored one by national ID. Differing
+    /// demographics are handled per the request's `on_demographic_change`.
     async fn find_or_create_patient(
         txn: &DatabaseTransaction,
         payload: &CreateTriageRequest,
+        registered_by: i32,
+        mrn: &MrnConfig,
+        service_date: NaiveDate,
+    ) -> Result<patients::Model, AppError>;
+    /// Registers a temporary record for an emergency patient without known identity.
+    async fn create_unidentified_patient(
+        txn: &DatabaseTransaction,
+        payload: &CreateUnidentifiedTriageRequest,
+        mrn: &MrnConfig,
+        service_date: NaiveDate,
     ) -> Result<patients::Model, AppError>;
+    async fn find_polyclinic_by_code(
+        db: &DatabaseConnection,
+        code: &str,
+    ) -> Result<polyclinic::Model, AppError>;
+    async fn find_scheduled_doctor(
+        db: &DatabaseConnection,
+        doctor_id: i32,
+        polyclinic_id: i32,
+        day_of_week: &str,
+    ) -> Result<doctors::Model, AppError>;
     async fn create_visit_intent(
         txn: &DatabaseTransaction,
         patient_id: i32,
-        payload: &CreateTriageRequest,
+        visit_type: &VisitType,
+        priority: PriorityCategory,
     ) -> Result<patients_visit_intent::Model, AppError>;
+    async fn next_queue_number(
+        txn: &DatabaseTransaction,
+        service_date: NaiveDate,
+        queue_key: &str,
+    ) -> Result<i32, AppError>;
     async fn create_queue_ticket(
         txn: &DatabaseTransaction,
         intent_id: i32,
         visit_type: VisitType,
+        scope: &QueueScope,
+        service_date: NaiveDate,
+        priority: PriorityCategory,
+        doctor_id: Option<i32>,
     ) -> Result<queue_ticket::Model, AppError>;
     async fn get_queue(
         db: &DatabaseConnection,
-        visit_type: &VisitType,
+        scope: &QueueScope,
+        service_date: NaiveDate,
     ) -> Result<Vec<TriageQueueItem>, AppError>;
     async fn get_status_by_queue_number(
         db: &DatabaseConnection,
         queue_number: i32,
-        visit_type: VisitType,
+        scope: &QueueScope,
+        service_date: NaiveDate,
     ) -> Result<queue_ticket::Model, AppError>;
+    async fn count_waiting_ahead(
+        db: &DatabaseConnection,
+        ticket: &queue_ticket::Model,
+    ) -> Result<u64, AppError>;
+    async fn average_service_secs(
+        db: &DatabaseConnection,
+        queue_key: &str,
+        sample_size: u64,
+    ) -> Result<Option<f64>, AppError>;
+    async fn daily_queue_stats(
+        db: &DatabaseConnection,
+        queue_key: &str,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<QueueDailyStats>, AppError>;
     async fn update_visit_intent_status(
         txn: &DatabaseTransaction,
         visit_intent_id: i32,
-        status: &str,
+        status: VisitStatus,
     ) -> Result<(), AppError>;
-    async fn call_patient(
+    async fn transition_ticket(
         txn: &DatabaseTransaction,
         queue_number: i32,
-        visit_type: VisitType,
-    ) -> Result<queue_ticket::Model, AppError>;
-    async fn triage_queue_complete(
+        scope: &QueueScope,
+        service_date: NaiveDate,
+        to: QueueStatus,
+        allow_restricted: bool,
+    ) -> Result<TicketTransition, AppError>;
+    async fn transition_ticket_by_id(
+        txn: &DatabaseTransaction,
+        ticket_id: i32,
+        to: QueueStatus,
+    ) -> Result<TicketTransition, AppError>;
+    async fn recall_ticket(
         txn: &DatabaseTransaction,
         queue_number: i32,
-        visit_type: VisitType,
-    ) -> Result<queue_ticket::Model, AppError>;
-    async fn cancel_patient_queue(
+        scope: &QueueScope,
+        service_date: NaiveDate,
+        max_calls: i32,
+    ) -> Result<TicketTransition, AppError>;
+    async fn requeue_ticket(
         txn: &DatabaseTransaction,
         queue_number: i32,
-        visit_type: &VisitType,
-    ) -> Result<queue_ticket::Model, AppError>;
+        scope: &QueueScope,
+        service_date: NaiveDate,
+        position: usize,
+    ) -> Result<TicketTransition, AppError>;
+    async fn find_overdue_calls(
+        db: &DatabaseConnection,
+        service_date: NaiveDate,
+        called_before: NaiveDateTime,
+    ) -> Result<Vec<queue_ticket::Model>, AppError>;
+    async fn find_stale_tickets(
+        db: &DatabaseConnection,
+        before: NaiveDate,
+    ) -> Result<Vec<queue_ticket::Model>, AppError>;
+    async fn find_ticket_scope(
+        db: &DatabaseConnection,
+        ticket: &queue_ticket::Model,
+    ) -> Result<QueueScope, AppError>;
     async fn upload_referral_docs(
         txn: &DatabaseTransaction,
         filename: String,
         visit_id: i32,
         patient_id: i32,
-        file_bytes: &Vec<u8>,
+        file_bytes: &[u8],
         url: String,
     ) -> Result<referral_documents::Model, AppError>;
 }
@@ -2,55 +2,132 @@ use async_trait::async_trait;
 use aws_sdk_s3::Client;
 use bb8::Pool;
 use bb8_redis::RedisConnectionManager;
+use chrono::NaiveDate;
 use sea_orm::DatabaseConnection;
 
 use crate::{
     dtos::triage::{
-        create_triage_request::CreateTriageRequest,
+        create_triage_request::{CreateTriageRequest, CreateUnidentifiedTriageRequest},
+        queue_scope::QueueScope,
         referral_upload_metadata::ReferralUploadMetadata,
         response::{
             CreateTriageResponse, ReferralUploadResponse, TriagePatientCalled, TriagePatientCancel,
-            TriageQueueComplete, TriageQueueResponse, TriageQueueStatus,
+            TriageQueueComplete, TriageQueueResponse, TriageQueueStatsResponse, TriageQueueStatus,
+            TriageQueueTransition,
         },
     },
     error_handling::app_error::AppError,
+    infra::config::{MrnConfig, QueueConfig},
+    middleware::request_middleware::IdempotentRequest,
 };
 
 #[async_trait]
 pub trait TriageServiceContracts {
     async fn perform_triage(
         db: &DatabaseConnection,
+        redis: &Pool<RedisConnectionManager>,
+        mrn: &MrnConfig,
         payload: CreateTriageRequest,
+        registered_by: i32,
+        idempotency_key: Option<IdempotentRequest>,
+    ) -> Result<CreateTriageResponse, AppError>;
+    async fn perform_unidentified_triage(
+        db: &DatabaseConnection,
+        redis: &Pool<RedisConnectionManager>,
+        mrn: &MrnConfig,
+        payload: CreateUnidentifiedTriageRequest,
+        idempotency_key: Option<IdempotentRequest>,
     ) -> Result<CreateTriageResponse, AppError>;
     async fn get_triage_queue(
         db: &DatabaseConnection,
         redis: &Pool<RedisConnectionManager>,
-        visit_type: String,
+        scope: QueueScope,
+        service_date: NaiveDate,
+    ) -> Result<TriageQueueResponse, AppError>;
+    async fn get_triage_queue_snapshot(
+        db: &DatabaseConnection,
+        scope: &QueueScope,
+        service_date: NaiveDate,
     ) -> Result<TriageQueueResponse, AppError>;
     async fn get_triage_queue_status_by_id(
         db: &DatabaseConnection,
         redis: &Pool<RedisConnectionManager>,
-        visit_type: String,
+        config: &QueueConfig,
+        scope: QueueScope,
+        service_date: NaiveDate,
         queue_number: i32,
     ) -> Result<TriageQueueStatus, AppError>;
+    async fn get_triage_queue_stats(
+        db: &DatabaseConnection,
+        config: &QueueConfig,
+        scope: QueueScope,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<TriageQueueStatsResponse, AppError>;
     async fn call_patient(
         db: &DatabaseConnection,
         redis: &Pool<RedisConnectionManager>,
-        visit_type: String,
+        scope: QueueScope,
+        service_date: NaiveDate,
         queue_number: i32,
+        idempotency_key: Option<IdempotentRequest>,
     ) -> Result<TriagePatientCalled, AppError>;
     async fn triage_complete(
         db: &DatabaseConnection,
         redis: &Pool<RedisConnectionManager>,
-        visit_type: String,
+        scope: QueueScope,
+        service_date: NaiveDate,
         queue_number: i32,
+        idempotency_key: Option<IdempotentRequest>,
     ) -> Result<TriageQueueComplete, AppError>;
     async fn cancel_patient_queue(
         db: &DatabaseConnection,
         redis: &Pool<RedisConnectionManager>,
-        visit_type: String,
+        scope: QueueScope,
+        service_date: NaiveDate,
         queue_number: i32,
+        allow_finalized: bool,
+        idempotency_key: Option<IdempotentRequest>,
     ) -> Result<TriagePatientCancel, AppError>;
+    async fn recall_patient(
+        db: &DatabaseConnection,
+        redis: &Pool<RedisConnectionManager>,
+        scope: QueueScope,
+        service_date: NaiveDate,
+        queue_number: i32,
+        max_calls: i32,
+        idempotency_key: Option<IdempotentRequest>,
+    ) -> Result<TriageQueueTransition, AppError>;
+    async fn skip_patient(
+        db: &DatabaseConnection,
+        redis: &Pool<RedisConnectionManager>,
+        scope: QueueScope,
+        service_date: NaiveDate,
+        queue_number: i32,
+        idempotency_key: Option<IdempotentRequest>,
+    ) -> Result<TriageQueueTransition, AppError>;
+    async fn mark_no_show(
+        db: &DatabaseConnection,
+        redis: &Pool<RedisConnectionManager>,
+        scope: QueueScope,
+        service_date: NaiveDate,
+        queue_number: i32,
+        idempotency_key: Option<IdempotentRequest>,
+    ) -> Result<TriageQueueTransition, AppError>;
+    async fn requeue_patient(
+        db: &DatabaseConnection,
+        redis: &Pool<RedisConnectionManager>,
+        scope: QueueScope,
+        service_date: NaiveDate,
+        queue_number: i32,
+        position: usize,
+        idempotency_key: Option<IdempotentRequest>,
+    ) -> Result<TriageQueueTransition, AppError>;
+    async fn sweep_stale_tickets(
+        db: &DatabaseConnection,
+        redis: &Pool<RedisConnectionManager>,
+        config: &QueueConfig,
+    ) -> Result<usize, AppError>;
     async fn handle_referral_upload(
         db: &DatabaseConnection,
         s3: &Client,
@@ -1 +1,2 @@
+pub mod queue_transition;
 pub mod triage_repo;
@@ -0,0 +1,87 @@
+use entity::{patients_visit_intent::VisitStatus, queue_ticket};
+use queue_ticket::QueueStatus::{self, *};
+
+/// Every status change a ticket may go through. `restricted` transitions undo a visit that
+/// has already been completed and need `Permission::TriageCancelFinalized`.
+struct Transition {
+    from: QueueStatus,
+    to: QueueStatus,
+    restricted: bool,
+}
+
+const fn allow(from: QueueStatus, to: QueueStatus) -> Transition {
+    Transition {
+        from,
+        to,
+        restricted: false,
+    }
+}
+
+const fn restrict(from: QueueStatus, to: QueueStatus) -> Transition {
+    Transition {
+        from,
+        to,
+        restricted: true,
+    }
+}
+
+const TRANSITIONS: &[Transition] = &[
+    allow(Waiting, Called),
+    allow(Waiting, Skipped),
+    allow(Waiting, Canceled),
+    allow(Called, Recalled),
+    allow(Called, Done),
+    allow(Called, Skipped),
+    allow(Called, NoShow),
+    allow(Called, Canceled),
+    allow(Recalled, Recalled),
+    allow(Recalled, Done),
+    allow(Recalled, Skipped),
+    allow(Recalled, NoShow),
+    allow(Recalled, Canceled),
+    allow(Skipped, Called),
+    allow(Skipped, Waiting),
+    allow(Skipped, Canceled),
+    allow(NoShow, Waiting),
+    allow(NoShow, Canceled),
+    restrict(Done, Canceled),
+    // end-of-day sweep
+    allow(Waiting, Expired),
+    allow(Called, Expired),
+    allow(Recalled, Expired),
+    allow(Skipped, Expired),
+];
+
+pub enum TransitionCheck {
+    Allowed,
+    Restricted,
+    Denied,
+}
+
+pub fn check_transition(from: QueueStatus, to: QueueStatus) -> TransitionCheck {
+    match TRANSITIONS.iter().find(|t| t.from == from && t.to == to) {
+        Some(Transition {
+            restricted: true, ..
+        }) => TransitionCheck::Restricted,
+        Some(_) => TransitionCheck::Allowed,
+        None => TransitionCheck::Denied,
+    }
+}
+
+/// The visit intent mirrors its ticket; skipping or recalling does not change whether
+/// the patient is still waiting to be seen.
+pub fn visit_status_for(status: QueueStatus) -> VisitStatus {
+    match status {
+        Waiting | Skipped => VisitStatus::Waiting,
+        Called | Recalled => VisitStatus::Called,
+        NoShow => VisitStatus::NoShow,
+        Done => VisitStatus::Done,
+        Canceled => VisitStatus::Canceled,
+        Expired => VisitStatus::Expired,
+    }
+}
+
+pub struct TicketTransition {
+    pub previous: QueueStatus,
+    pub ticket: queue_ticket::Model,
+}
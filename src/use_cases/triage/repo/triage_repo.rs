@@ -1,68 +1,379 @@
+use std::collections::HashMap;
+
 use async_trait::async_trait;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Months, NaiveDate, NaiveDateTime, Utc};
 use entity::{
+    doctor_schedules, doctors,
+    patient_history::PatientChangeSource,
     patients::{self, ActiveModel},
-    patients_visit_intent, queue_ticket, referral_documents,
+    patients_visit_intent::{self, PriorityCategory, VisitStatus},
+    polyclinic,
+    queue_ticket::{self, QueueStatus},
+    referral_documents,
 };
 
 use sea_orm::{
-    ActiveModelTrait, ActiveValue::Set, ColumnTrait, DatabaseConnection, DatabaseTransaction,
-    EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
+    ActiveEnum, ActiveModelTrait, ActiveValue::Set, ColumnTrait, Condition, ConnectionTrait,
+    DatabaseConnection, DatabaseTransaction, DbBackend, EntityTrait, FromQueryResult,
+    PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Statement,
 };
 
 use crate::{
-    dtos::triage::{
-        create_triage_request::{CreateTriageRequest, VisitType},
-        response::TriageQueueItem,
+    dtos::{
+        patient::patient_request::UpdatePatientRequest,
+        triage::{
+            create_triage_request::{
+                CreateTriageRequest, CreateUnidentifiedTriageRequest, DemographicChangePolicy,
+                Gender, VisitType,
+            },
+            queue_scope::QueueScope,
+            response::{QueueDailyStats, TriageQueueItem},
+        },
     },
     error_handling::app_error::AppError,
-    use_cases::triage::contracts::triage_repo_contract::TriageTraitRepo,
+    infra::config::MrnConfig,
+    parse_visit_type,
+    use_cases::{
+        allergy::{contracts::AllergyTraitRepo, repo::allergy_repo::AllergyRepo},
+        patient::{
+            contracts::PatientTraitRepo,
+            repo::{
+                patient_changes::{conflict_fields, diff_patient},
+                patient_repo::PatientRepo,
+            },
+        },
+        triage::{
+            contracts::triage_repo_contract::TriageTraitRepo,
+            repo::queue_transition::{
+                TicketTransition, TransitionCheck, check_transition, visit_status_for,
+            },
+        },
+    },
 };
 
 pub struct TriageRepo;
 
+/// Counter key numbering the day's unidentified registrations, kept apart from the
+/// visit type and polyclinic queue keys.
+const UNIDENTIFIED_COUNTER_KEY: &str = "UNIDENTIFIED";
+
+#[derive(FromQueryResult)]
+struct DailyStatsRow {
+    service_date: NaiveDate,
+    total: i64,
+    completed: i64,
+    no_show: i64,
+    canceled: i64,
+    avg_wait_secs: Option<f64>,
+    avg_service_secs: Option<f64>,
+}
+
+fn scope_condition(scope: &QueueScope, service_date: NaiveDate) -> Condition {
+    let condition = match scope {
+        QueueScope::VisitType(visit_type) => Condition::all()
+            .add(queue_ticket::Column::QueueType.eq(visit_type.to_string()))
+            .add(queue_ticket::Column::PolyclinicId.is_null()),
+        QueueScope::Polyclinic(poly) => {
+            Condition::all().add(queue_ticket::Column::PolyclinicId.eq(poly.id))
+        }
+    };
+    condition.add(queue_ticket::Column::ServiceDate.eq(service_date))
+}
+
+/// Priority lanes are served by moving a ticket's place in line earlier by a fixed head
+/// start rather than by strict precedence. A regular patient can therefore only be
+/// overtaken by priority patients who arrived within that window after them, which
+/// bounds how long anyone waits no matter how many priority patients keep arriving.
+fn priority_head_start(priority: PriorityCategory) -> Duration {
+    match priority {
+        PriorityCategory::Regular => Duration::zero(),
+        PriorityCategory::Elderly => Duration::minutes(15),
+        PriorityCategory::Disabled | PriorityCategory::Pregnant => Duration::minutes(20),
+        PriorityCategory::Emergency => Duration::minutes(60),
+    }
+}
+
+async fn find_ticket_for_update(
+    txn: &DatabaseTransaction,
+    queue_number: i32,
+    scope: &QueueScope,
+    service_date: NaiveDate,
+) -> Result<queue_ticket::Model, AppError> {
+    queue_ticket::Entity::find()
+        .filter(queue_ticket::Column::QueueNumber.eq(queue_number))
+        .filter(scope_condition(scope, service_date))
+        .lock_exclusive()
+        .one(txn)
+        .await?
+        .ok_or(AppError::NotFound(format!(
+            "Queue {} with number {} is not found on {}",
+            scope, queue_number, service_date
+        )))
+}
+
+/// Moves a locked ticket along the transition table, stamping the time of the new
+/// status and keeping the visit intent in step.
+async fn apply_transition(
+    txn: &DatabaseTransaction,
+    ticket: queue_ticket::Model,
+    to: QueueStatus,
+    allow_restricted: bool,
+) -> Result<TicketTransition, AppError> {
+    let previous = ticket.status;
+    match check_transition(previous, to) {
+        TransitionCheck::Allowed => {}
+        TransitionCheck::Restricted if allow_restricted => {}
+        TransitionCheck::Restricted => {
+            return Err(AppError::Forbidden(format!(
+                "Queue {} is already {} and can only be {} by an administrator",
+                ticket.queue_code,
+                previous.to_value(),
+                to.to_value()
+            )));
+        }
+        TransitionCheck::Denied => {
+            return Err(AppError::Conflict(format!(
+                "Queue {} is {} and cannot be changed to {}",
+                ticket.queue_code,
+                previous.to_value(),
+                to.to_value()
+            )));
+        }
+    }
+
+    let now = Some(Utc::now().naive_utc());
+    let call_count = ticket.call_count;
+    let mut active: queue_ticket::ActiveModel = ticket.into();
+    active.status = Set(to);
+    match to {
+        QueueStatus::Called => {
+            active.called_at = Set(now);
+            active.call_count = Set(call_count + 1);
+        }
+        QueueStatus::Recalled => {
+            active.recalled_at = Set(now);
+            active.call_count = Set(call_count + 1);
+        }
+        QueueStatus::Waiting => {
+            active.requeued_at = Set(now);
+            active.call_count = Set(0);
+        }
+        QueueStatus::Skipped => active.skipped_at = Set(now),
+        QueueStatus::NoShow => active.no_show_at = Set(now),
+        QueueStatus::Done => active.done_at = Set(now),
+        QueueStatus::Canceled => active.canceled_at = Set(now),
+        QueueStatus::Expired => active.expired_at = Set(now),
+    }
+    let updated = active.update(txn).await?;
+
+    <TriageRepo as TriageTraitRepo>::update_visit_intent_status(
+        txn,
+        updated.visit_intent_id,
+        visit_status_for(to),
+    )
+    .await?;
+
+    Ok(TicketTransition {
+        previous,
+        ticket: updated,
+    })
+}
+
 #[async_trait]
 impl TriageTraitRepo for TriageRepo {
     async fn find_or_create_patient(
         txn: &DatabaseTransaction,
         payload: &CreateTriageRequest,
+        registered_by: i32,
+        mrn: &MrnConfig,
+        service_date: NaiveDate,
     ) -> Result<patients::Model, AppError> {
-        if let Some(existing) = patients::Entity::find()
+        let patient = match patients::Entity::find()
             .filter(patients::Column::NationalId.eq(&payload.national_id))
+            .lock_exclusive()
             .one(txn)
             .await?
         {
-            return Ok(existing);
-        }
+            Some(existing) => {
+                // A NIK registered on a duplicate that has been merged away registers the
+                // visit on the surviving record
+                let mut existing = existing;
+                while let Some(survivor_id) = existing.merged_into_id {
+                    existing = patients::Entity::find_by_id(survivor_id)
+                        .lock_exclusive()
+                        .one(txn)
+                        .await?
+                        .ok_or(AppError::NotFound(format!(
+                            "Patient with id {} is not found",
+                            survivor_id
+                        )))?;
+                }
+
+                let submitted = UpdatePatientRequest::from_triage(payload);
+                let changes = diff_patient(&existing, &submitted);
+                match payload.on_demographic_change {
+                    _ if changes.is_empty() => existing,
+                    DemographicChangePolicy::Keep => existing,
+                    DemographicChangePolicy::Reject => {
+                        return Err(AppError::FieldConflict(
+                            format!(
+                                "Patient {} is registered with different details",
+                                payload.national_id
+                            ),
+                            conflict_fields(&changes),
+                        ));
+                    }
+                    DemographicChangePolicy::Apply => {
+                        <PatientRepo as PatientTraitRepo>::apply_patient_changes(
+                            txn,
+                            existing,
+                            submitted,
+                            PatientChangeSource::Triage,
+                            Some(registered_by),
+                        )
+                        .await?
+                    }
+                }
+            }
+            None => {
+                let model = ActiveModel {
+                    name: Set(payload.name.clone()),
+                    date_of_birth: Set(payload.date_of_birth),
+                    national_id: Set(Some(payload.national_id.clone())),
+                    bpjs_number: Set(payload.bpjs_number.clone()),
+                    gender: Set(payload.gender.to_string()),
+                    emergency_contact_name: Set(Some(payload.emergency_contact_name.clone())),
+                    emergency_contact_phone: Set(Some(payload.emergency_contact_phone.clone())),
+                    emergency_contact_relationship: Set(Some(
+                        payload.emergency_contact_relationship.clone(),
+                    )),
+                    blood_type: Set(Some(payload.blood_type.to_string())),
+                    created_at: Set(Utc::now().naive_utc()),
+                    updated_at: Set(Utc::now().naive_utc()),
+                    ..Default::default()
+                };
+                model.insert(txn).await?
+            }
+        };
 
+        <AllergyRepo as AllergyTraitRepo>::record_missing_allergies(
+            txn,
+            patient.id,
+            payload.allergies.clone(),
+            registered_by,
+        )
+        .await?;
+
+        // New patients, and those registered before record numbers existed, get their
+        // number on this registration
+        <PatientRepo as PatientTraitRepo>::assign_medical_record_number(
+            txn,
+            patient,
+            mrn,
+            service_date,
+        )
+        .await
+    }
+
+    async fn create_unidentified_patient(
+        txn: &DatabaseTransaction,
+        payload: &CreateUnidentifiedTriageRequest,
+        mrn: &MrnConfig,
+        service_date: NaiveDate,
+    ) -> Result<patients::Model, AppError> {
+        let alias = match payload.gender {
+            Gender::Male => "Mr. X",
+            Gender::Female => "Mrs. X",
+        };
+        let number = Self::next_queue_number(txn, service_date, UNIDENTIFIED_COUNTER_KEY).await?;
+        let date_of_birth = service_date
+            .checked_sub_months(Months::new(payload.estimated_age * 12))
+            .unwrap_or(service_date);
+
+        let now = Utc::now().naive_utc();
         let model = ActiveModel {
-            name: Set(payload.name.clone()),
-            date_of_birth: Set(payload.date_of_birth),
-            national_id: Set(payload.national_id.clone()),
-            bpjs_number: Set(payload.bpjs_number.clone()),
+            name: Set(format!("{} {:03}", alias, number)),
+            date_of_birth: Set(date_of_birth),
             gender: Set(payload.gender.to_string()),
-            emergency_contact_name: Set(payload.emergency_contact_name.clone()),
-            emergency_contact_phone: Set(payload.emergency_contact_phone.clone()),
-            emergency_contact_relationship: Set(payload.emergency_contact_relationship.clone()),
-            blood_type: Set(payload.blood_type.to_string()),
-            known_allergies: Set(Some(payload.known_allergies.clone().unwrap_or_default())),
-            created_at: Set(Utc::now().naive_utc()),
-            updated_at: Set(Utc::now().naive_utc()),
+            is_unidentified: Set(true),
+            date_of_birth_estimated: Set(true),
+            identification_notes: Set(payload
+                .identification_notes
+                .clone()
+                .filter(|notes| !notes.trim().is_empty())),
+            created_at: Set(now),
+            updated_at: Set(now),
             ..Default::default()
         };
+        let patient = model.insert(txn).await?;
 
-        Ok(model.insert(txn).await?)
+        <PatientRepo as PatientTraitRepo>::assign_medical_record_number(
+            txn,
+            patient,
+            mrn,
+            service_date,
+        )
+        .await
+    }
+
+    async fn find_polyclinic_by_code(
+        db: &DatabaseConnection,
+        code: &str,
+    ) -> Result<polyclinic::Model, AppError> {
+        polyclinic::Entity::find()
+            .filter(polyclinic::Column::Code.eq(code.to_uppercase()))
+            .one(db)
+            .await?
+            .ok_or(AppError::NotFound(format!(
+                "Polyclinic with code {} is not found",
+                code
+            )))
+    }
+
+    async fn find_scheduled_doctor(
+        db: &DatabaseConnection,
+        doctor_id: i32,
+        polyclinic_id: i32,
+        day_of_week: &str,
+    ) -> Result<doctors::Model, AppError> {
+        let (doctor, schedules) = doctors::Entity::find_by_id(doctor_id)
+            .find_with_related(doctor_schedules::Entity)
+            .all(db)
+            .await?
+            .into_iter()
+            .next()
+            .ok_or(AppError::NotFound(format!(
+                "Doctor with id {} is not found",
+                doctor_id
+            )))?;
+
+        let on_schedule = schedules.iter().any(|schedule| {
+            schedule.polyclinic_id == polyclinic_id
+                && schedule.day_of_week.eq_ignore_ascii_case(day_of_week)
+                && schedule.status.eq_ignore_ascii_case("ACTIVE")
+        });
+
+        if !on_schedule {
+            return Err(AppError::BadRequest(format!(
+                "Doctor {} has no active schedule in this polyclinic on {}",
+                doctor.name, day_of_week
+            )));
+        }
+
+        Ok(doctor)
     }
 
     async fn create_visit_intent(
         txn: &DatabaseTransaction,
         patient_id: i32,
-        payload: &CreateTriageRequest,
+        visit_type: &VisitType,
+        priority: PriorityCategory,
     ) -> Result<entity::patients_visit_intent::Model, AppError> {
         let model = patients_visit_intent::ActiveModel {
             patient_id: Set(patient_id),
-            visit_type: Set(payload.visit_type.to_string()),
-            status: Set("WAITING".into()),
+            visit_type: Set(visit_type.to_string()),
+            status: Set(VisitStatus::Waiting),
+            priority: Set(priority),
             created_at: Set(Utc::now().naive_utc()),
             updated_at: Set(Utc::now().naive_utc()),
             ..Default::default()
@@ -70,22 +381,57 @@ impl TriageTraitRepo for TriageRepo {
         Ok(model.insert(txn).await?)
     }
 
+    async fn next_queue_number(
+        txn: &DatabaseTransaction,
+        service_date: NaiveDate,
+        queue_key: &str,
+    ) -> Result<i32, AppError> {
+        // The upsert takes a row lock on the counter, so concurrent registrations for the
+        // same queue and day wait for each other instead of reading the same count.
+        let row = txn
+            .query_one(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"INSERT INTO queue_counters (service_date, queue_key, last_number, updated_at)
+                VALUES ($1, $2, 1, CURRENT_TIMESTAMP)
+                ON CONFLICT (service_date, queue_key)
+                DO UPDATE SET last_number = queue_counters.last_number + 1,
+                    updated_at = CURRENT_TIMESTAMP
+                RETURNING last_number"#,
+                [service_date.into(), queue_key.into()],
+            ))
+            .await?
+            .ok_or(AppError::Internal(
+                "Failed to allocate a queue number".into(),
+            ))?;
+
+        Ok(row.try_get::<i32>("", "last_number")?)
+    }
+
     async fn create_queue_ticket(
         txn: &DatabaseTransaction,
         intent_id: i32,
         visit_type: VisitType,
+        scope: &QueueScope,
+        service_date: NaiveDate,
+        priority: PriorityCategory,
+        doctor_id: Option<i32>,
     ) -> Result<entity::queue_ticket::Model, AppError> {
-        let count = queue_ticket::Entity::find()
-            .filter(queue_ticket::Column::QueueType.eq(visit_type.to_string()))
-            .count(txn)
-            .await?;
+        let now = Utc::now().naive_utc();
+        let queue_key = scope.queue_key();
+        let queue_number = Self::next_queue_number(txn, service_date, &queue_key).await?;
 
         let model = queue_ticket::ActiveModel {
             visit_intent_id: Set(intent_id),
-            queue_number: Set(count as i32 + 1),
+            queue_number: Set(queue_number),
+            queue_code: Set(scope.format_code(queue_number)),
             queue_type: Set(visit_type.to_string()),
-            status: Set("WAITING".into()),
-            created_at: Set(Utc::now().naive_utc()),
+            polyclinic_id: Set(scope.polyclinic().map(|poly| poly.id)),
+            doctor_id: Set(doctor_id),
+            service_date: Set(service_date),
+            queue_key: Set(queue_key),
+            status: Set(QueueStatus::Waiting),
+            created_at: Set(now),
+            sort_at: Set(now - priority_head_start(priority)),
             ..Default::default()
         };
         Ok(model.insert(txn).await?)
@@ -93,23 +439,45 @@ impl TriageTraitRepo for TriageRepo {
 
     async fn get_queue(
         db: &DatabaseConnection,
-        visit_type: &VisitType,
+        scope: &QueueScope,
+        service_date: NaiveDate,
     ) -> Result<Vec<TriageQueueItem>, AppError> {
         let existing = queue_ticket::Entity::find()
-            .filter(queue_ticket::Column::QueueType.eq(visit_type.to_string()))
-            .filter(queue_ticket::Column::Status.eq("WAITING".to_string()))
-            .order_by_asc(queue_ticket::Column::CreatedAt)
+            .filter(scope_condition(scope, service_date))
+            .filter(queue_ticket::Column::Status.eq(QueueStatus::Waiting))
+            .order_by_asc(queue_ticket::Column::SortAt)
+            .order_by_asc(queue_ticket::Column::Id)
             .find_also_related(patients_visit_intent::Entity)
             .all(db)
             .await?;
 
+        let patient_ids = existing
+            .iter()
+            .filter_map(|(_, visit)| visit.as_ref().map(|v| v.patient_id))
+            .collect::<Vec<_>>();
+        let record_numbers: HashMap<i32, Option<String>> = patients::Entity::find()
+            .select_only()
+            .column(patients::Column::Id)
+            .column(patients::Column::MedicalRecordNumber)
+            .filter(patients::Column::Id.is_in(patient_ids))
+            .into_tuple()
+            .all(db)
+            .await?
+            .into_iter()
+            .collect();
+
         let result = existing
             .into_iter()
             .filter_map(|(ticket, visit)| {
                 visit.map(|v| TriageQueueItem {
                     queue_number: ticket.queue_number,
+                    queue_code: ticket.queue_code,
+                    queue_type: ticket.queue_type,
+                    doctor_id: ticket.doctor_id,
                     patient_id: v.patient_id,
-                    status: ticket.status,
+                    medical_record_number: record_numbers.get(&v.patient_id).cloned().flatten(),
+                    priority: v.priority.to_value(),
+                    status: ticket.status.to_value(),
                     created_at: DateTime::<Utc>::from_naive_utc_and_offset(ticket.created_at, Utc),
                 })
             })
@@ -120,11 +488,12 @@ impl TriageTraitRepo for TriageRepo {
     async fn get_status_by_queue_number(
         db: &DatabaseConnection,
         queue_number: i32,
-        visit_type: VisitType,
+        scope: &QueueScope,
+        service_date: NaiveDate,
     ) -> Result<queue_ticket::Model, AppError> {
         if let Some(existing) = queue_ticket::Entity::find()
             .filter(queue_ticket::Column::QueueNumber.eq(queue_number))
-            .filter(queue_ticket::Column::QueueType.eq(visit_type.to_string()))
+            .filter(scope_condition(scope, service_date))
             .one(db)
             .await?
         {
@@ -132,15 +501,113 @@ impl TriageTraitRepo for TriageRepo {
         }
 
         Err(AppError::NotFound(format!(
-            "Queue {} is not found with number {}",
-            visit_type, queue_number
+            "Queue {} is not found with number {} on {}",
+            scope, queue_number, service_date
         )))
     }
 
+    async fn count_waiting_ahead(
+        db: &DatabaseConnection,
+        ticket: &queue_ticket::Model,
+    ) -> Result<u64, AppError> {
+        Ok(queue_ticket::Entity::find()
+            .filter(queue_ticket::Column::ServiceDate.eq(ticket.service_date))
+            .filter(queue_ticket::Column::QueueKey.eq(ticket.queue_key.as_str()))
+            .filter(queue_ticket::Column::Status.eq(QueueStatus::Waiting))
+            .filter(
+                Condition::any()
+                    .add(queue_ticket::Column::SortAt.lt(ticket.sort_at))
+                    .add(
+                        Condition::all()
+                            .add(queue_ticket::Column::SortAt.eq(ticket.sort_at))
+                            .add(queue_ticket::Column::Id.lt(ticket.id)),
+                    ),
+            )
+            .count(db)
+            .await?)
+    }
+
+    async fn average_service_secs(
+        db: &DatabaseConnection,
+        queue_key: &str,
+        sample_size: u64,
+    ) -> Result<Option<f64>, AppError> {
+        let row = db
+            .query_one(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"SELECT avg(EXTRACT(EPOCH FROM done_at - called_at))::float8 AS avg_secs
+                FROM (
+                    SELECT called_at, done_at FROM queue_ticket
+                    WHERE queue_key = $1 AND status = $2 AND done_at > called_at
+                    ORDER BY done_at DESC
+                    LIMIT $3
+                ) AS recent"#,
+                [
+                    queue_key.into(),
+                    QueueStatus::Done.to_value().into(),
+                    (sample_size as i64).into(),
+                ],
+            ))
+            .await?;
+
+        match row {
+            Some(row) => Ok(row.try_get::<Option<f64>>("", "avg_secs")?),
+            None => Ok(None),
+        }
+    }
+
+    async fn daily_queue_stats(
+        db: &DatabaseConnection,
+        queue_key: &str,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<QueueDailyStats>, AppError> {
+        let rows = DailyStatsRow::find_by_statement(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            r#"SELECT
+                service_date,
+                count(*) AS total,
+                count(*) FILTER (WHERE status = $4) AS completed,
+                count(*) FILTER (WHERE status = $5) AS no_show,
+                count(*) FILTER (WHERE status = $6) AS canceled,
+                (avg(EXTRACT(EPOCH FROM called_at - created_at))
+                    FILTER (WHERE called_at IS NOT NULL))::float8 AS avg_wait_secs,
+                (avg(EXTRACT(EPOCH FROM done_at - called_at))
+                    FILTER (WHERE status = $4 AND done_at > called_at))::float8 AS avg_service_secs
+            FROM queue_ticket
+            WHERE queue_key = $1 AND service_date BETWEEN $2 AND $3
+            GROUP BY service_date
+            ORDER BY service_date"#,
+            [
+                queue_key.into(),
+                from.into(),
+                to.into(),
+                QueueStatus::Done.to_value().into(),
+                QueueStatus::NoShow.to_value().into(),
+                QueueStatus::Canceled.to_value().into(),
+            ],
+        ))
+        .all(db)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| QueueDailyStats {
+                service_date: row.service_date,
+                total: row.total,
+                completed: row.completed,
+                no_show: row.no_show,
+                canceled: row.canceled,
+                avg_wait_secs: row.avg_wait_secs,
+                avg_service_secs: row.avg_service_secs,
+            })
+            .collect())
+    }
+
     async fn update_visit_intent_status(
         txn: &DatabaseTransaction,
         visit_intent_id: i32,
-        status: &str,
+        status: VisitStatus,
     ) -> Result<(), AppError> {
         let intent = patients_visit_intent::Entity::find_by_id(visit_intent_id)
             .one(txn)
@@ -148,102 +615,146 @@ impl TriageTraitRepo for TriageRepo {
             .ok_or(AppError::NotFound("Visit intent not found".into()))?;
 
         let mut active = patients_visit_intent::ActiveModel::from(intent);
-        active.status = Set(status.into());
+        active.status = Set(status);
         active.updated_at = Set(Utc::now().naive_utc());
         active.update(txn).await?;
         Ok(())
     }
 
-    async fn call_patient(
+    async fn transition_ticket(
         txn: &DatabaseTransaction,
         queue_number: i32,
-        visit_type: VisitType,
-    ) -> Result<queue_ticket::Model, AppError> {
-        if let Some(existing) = queue_ticket::Entity::find()
-            .filter(queue_ticket::Column::QueueNumber.eq(queue_number))
-            .filter(queue_ticket::Column::QueueType.eq(visit_type.to_string()))
-            .filter(queue_ticket::Column::Status.eq("WAITING".to_string()))
+        scope: &QueueScope,
+        service_date: NaiveDate,
+        to: QueueStatus,
+        allow_restricted: bool,
+    ) -> Result<TicketTransition, AppError> {
+        let ticket = find_ticket_for_update(txn, queue_number, scope, service_date).await?;
+        apply_transition(txn, ticket, to, allow_restricted).await
+    }
+
+    async fn transition_ticket_by_id(
+        txn: &DatabaseTransaction,
+        ticket_id: i32,
+        to: QueueStatus,
+    ) -> Result<TicketTransition, AppError> {
+        let ticket = queue_ticket::Entity::find_by_id(ticket_id)
+            .lock_exclusive()
             .one(txn)
             .await?
-        {
-            let mut queue_ticket_active_model: queue_ticket::ActiveModel = existing.into();
-            queue_ticket_active_model.status = Set("CALLED".into());
-            queue_ticket_active_model.called_at = Set(Some(Utc::now().naive_utc()));
-            let updated = queue_ticket_active_model.update(txn).await?;
-            Self::update_visit_intent_status(txn, updated.visit_intent_id, "CALLED").await?;
-            return Ok(updated);
-        }
-
-        Err(AppError::NotFound(format!(
-            "Queue {} with number {} is not found or has been called",
-            visit_type, queue_number
-        )))
+            .ok_or(AppError::NotFound(format!(
+                "Queue ticket {} is not found",
+                ticket_id
+            )))?;
+        apply_transition(txn, ticket, to, false).await
     }
 
-    async fn triage_queue_complete(
+    async fn recall_ticket(
         txn: &DatabaseTransaction,
         queue_number: i32,
-        visit_type: VisitType,
-    ) -> Result<queue_ticket::Model, AppError> {
-        let ticket = queue_ticket::Entity::find()
-            .filter(queue_ticket::Column::QueueNumber.eq(queue_number))
-            .filter(queue_ticket::Column::QueueType.eq(visit_type.to_string()))
-            .one(txn)
-            .await?;
-
-        match ticket {
-            Some(t) => {
-                if t.status != "CALLED" {
-                    return Err(AppError::BadRequest(format!(
-                        "Queue {} with number {} is not currently being called",
-                        visit_type, queue_number
-                    )));
-                }
-                let mut active: queue_ticket::ActiveModel = t.into();
-                active.status = Set("DONE".into());
-                active.done_at = Set(Some(Utc::now().naive_utc()));
-                let updated = active.update(txn).await?;
-                Self::update_visit_intent_status(txn, updated.visit_intent_id, "DONE").await?;
-                return Ok(updated);
-            }
-            None => {
-                return Err(AppError::NotFound(format!(
-                    "Queue {} with number {} is not found",
-                    visit_type, queue_number
-                )));
-            }
-        }
+        scope: &QueueScope,
+        service_date: NaiveDate,
+        max_calls: i32,
+    ) -> Result<TicketTransition, AppError> {
+        let ticket = find_ticket_for_update(txn, queue_number, scope, service_date).await?;
+        let to = if ticket.call_count >= max_calls {
+            QueueStatus::NoShow
+        } else {
+            QueueStatus::Recalled
+        };
+        apply_transition(txn, ticket, to, false).await
     }
 
-    async fn cancel_patient_queue(
+    async fn requeue_ticket(
         txn: &DatabaseTransaction,
         queue_number: i32,
-        visit_type: &VisitType,
-    ) -> Result<queue_ticket::Model, AppError> {
-        let ticket = queue_ticket::Entity::find()
-            .filter(queue_ticket::Column::QueueNumber.eq(queue_number))
-            .filter(queue_ticket::Column::QueueType.eq(visit_type.to_string()))
-            .one(txn)
+        scope: &QueueScope,
+        service_date: NaiveDate,
+        position: usize,
+    ) -> Result<TicketTransition, AppError> {
+        let ticket = find_ticket_for_update(txn, queue_number, scope, service_date).await?;
+
+        let waiting = queue_ticket::Entity::find()
+            .filter(scope_condition(scope, service_date))
+            .filter(queue_ticket::Column::Status.eq(QueueStatus::Waiting))
+            .order_by_asc(queue_ticket::Column::SortAt)
+            .order_by_asc(queue_ticket::Column::Id)
+            .all(txn)
             .await?;
 
-        match ticket {
-            Some(t) => {
-                if t.status == "CALLED" || t.status == "DONE" {
-                    return Err(AppError::BadRequest(format!(
-                        "Queue {} with number {} is not currently being called",
-                        visit_type, queue_number
-                    )));
-                }
-                let mut active: queue_ticket::ActiveModel = t.into();
-                active.status = Set("CANCELED".into());
-                let updated = active.update(txn).await?;
-                return Ok(updated);
-            }
+        // slot the ticket between the neighbours it should end up between
+        let sort_at = match (
+            position.checked_sub(1).and_then(|idx| waiting.get(idx)),
+            waiting.get(position),
+        ) {
+            (_, None) => Utc::now().naive_utc(),
+            (None, Some(first)) => first.sort_at - Duration::milliseconds(1),
+            (Some(before), Some(after)) => before.sort_at + (after.sort_at - before.sort_at) / 2,
+        };
+
+        let mut transition = apply_transition(txn, ticket, QueueStatus::Waiting, false).await?;
+        let mut active: queue_ticket::ActiveModel = transition.ticket.into();
+        active.sort_at = Set(sort_at);
+        transition.ticket = active.update(txn).await?;
+        Ok(transition)
+    }
+
+    async fn find_overdue_calls(
+        db: &DatabaseConnection,
+        service_date: NaiveDate,
+        called_before: NaiveDateTime,
+    ) -> Result<Vec<queue_ticket::Model>, AppError> {
+        Ok(queue_ticket::Entity::find()
+            .filter(queue_ticket::Column::ServiceDate.eq(service_date))
+            .filter(
+                Condition::any()
+                    .add(
+                        Condition::all()
+                            .add(queue_ticket::Column::Status.eq(QueueStatus::Called))
+                            .add(queue_ticket::Column::CalledAt.lt(called_before)),
+                    )
+                    .add(
+                        Condition::all()
+                            .add(queue_ticket::Column::Status.eq(QueueStatus::Recalled))
+                            .add(queue_ticket::Column::RecalledAt.lt(called_before)),
+                    ),
+            )
+            .all(db)
+            .await?)
+    }
+
+    async fn find_stale_tickets(
+        db: &DatabaseConnection,
+        before: NaiveDate,
+    ) -> Result<Vec<queue_ticket::Model>, AppError> {
+        Ok(queue_ticket::Entity::find()
+            .filter(queue_ticket::Column::ServiceDate.lt(before))
+            .filter(queue_ticket::Column::Status.is_in([
+                QueueStatus::Waiting,
+                QueueStatus::Called,
+                QueueStatus::Recalled,
+                QueueStatus::Skipped,
+            ]))
+            .all(db)
+            .await?)
+    }
+
+    async fn find_ticket_scope(
+        db: &DatabaseConnection,
+        ticket: &queue_ticket::Model,
+    ) -> Result<QueueScope, AppError> {
+        match ticket.polyclinic_id {
+            Some(polyclinic_id) => polyclinic::Entity::find_by_id(polyclinic_id)
+                .one(db)
+                .await?
+                .map(QueueScope::Polyclinic)
+                .ok_or(AppError::NotFound(format!(
+                    "Polyclinic with id {} is not found",
+                    polyclinic_id
+                ))),
             None => {
-                return Err(AppError::NotFound(format!(
-                    "Queue {} with number {} is not found",
-                    visit_type, queue_number
-                )));
+                let visit_type: Result<VisitType, AppError> = parse_visit_type!(ticket.queue_type);
+                Ok(QueueScope::VisitType(visit_type?))
             }
         }
     }
@@ -253,7 +764,7 @@ impl TriageTraitRepo for TriageRepo {
         filename: String,
         visit_id: i32,
         patient_id: i32,
-        file_bytes: &Vec<u8>,
+        file_bytes: &[u8],
         url: String,
     ) -> Result<referral_documents::Model, AppError> {
         let model = referral_documents::ActiveModel {
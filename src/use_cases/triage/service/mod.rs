@@ -1 +1,2 @@
+pub mod queue_sweeper;
 pub mod triage_service;
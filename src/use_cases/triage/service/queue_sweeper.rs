@@ -0,0 +1,39 @@
+use std::time::Duration;
+
+use bb8::Pool;
+use bb8_redis::RedisConnectionManager;
+use log::{error, info};
+use sea_orm::DatabaseConnection;
+use tokio::time::MissedTickBehavior;
+
+use crate::{
+    infra::config::QueueConfig,
+    use_cases::triage::service::triage_service::{TriageService, TriageServiceContracts},
+};
+
+/// Periodically turns unanswered calls into no-shows and expires tickets left open
+/// from previous days. Safe to run on every instance: each ticket is moved under a row
+/// lock and a ticket another instance already moved is skipped.
+pub fn spawn_queue_sweeper(
+    db: DatabaseConnection,
+    redis: Pool<RedisConnectionManager>,
+    config: QueueConfig,
+) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(config.sweep_interval_secs));
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            interval.tick().await;
+            match <TriageService as TriageServiceContracts>::sweep_stale_tickets(
+                &db, &redis, &config,
+            )
+            .await
+            {
+                Ok(0) => {}
+                Ok(changed) => info!("Queue sweeper updated {} tickets", changed),
+                Err(err) => error!("Queue sweeper failed: {}", err),
+            }
+        }
+    });
+}
@@ -3,97 +3,419 @@ use aws_sdk_s3::{Client, primitives::ByteStream};
 use bb8::Pool;
 use bb8_redis::RedisConnectionManager;
 
-use chrono::{DateTime, Local, NaiveDateTime, Utc};
-use sea_orm::{DatabaseConnection, TransactionTrait};
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, Utc};
+use chrono_tz::Tz;
+use entity::{
+    doctors, patients, patients_visit_intent::PriorityCategory, queue_ticket::QueueStatus,
+};
+use sea_orm::{ActiveEnum, DatabaseConnection, DatabaseTransaction, TransactionTrait};
 use uuid::Uuid;
 
 pub use crate::{
     dtos::triage::{
-        create_triage_request::{CreateTriageRequest, VisitType},
+        create_triage_request::{CreateTriageRequest, CreateUnidentifiedTriageRequest, VisitType},
+        queue_scope::QueueScope,
         response::CreateTriageResponse,
     },
     use_cases::triage::contracts::triage_service_contract::TriageServiceContracts,
 };
 use crate::{
     dtos::triage::{
+        queue_event::{QueueEvent, QueueEventKind},
         referral_upload_metadata::ReferralUploadMetadata,
         response::{
             ReferralUploadResponse, TriagePatientCalled, TriagePatientCancel, TriageQueueComplete,
-            TriageQueueItem, TriageQueueResponse, TriageQueueStatus,
+            TriageQueueEta, TriageQueueItem, TriageQueueResponse, TriageQueueStatsResponse,
+            TriageQueueStatus, TriageQueueTransition,
         },
     },
     error_handling::app_error::AppError,
-    format_created_at, format_option_dt, parse_visit_type,
-    use_cases::triage::{
-        contracts::triage_repo_contract::TriageTraitRepo, repo::triage_repo::TriageRepo,
+    format_created_at, format_option_dt,
+    infra::{
+        cache::{QUEUE_CACHE_TTL_SECS, QueueCache, replay, run_idempotent},
+        config::{MrnConfig, QueueConfig},
+        queue_events::publish_queue_event,
+    },
+    middleware::request_middleware::IdempotentRequest,
+    use_cases::{
+        encounter::{contracts::EncounterTraitRepo, repo::encounter_repo::EncounterRepo},
+        triage::{
+            contracts::triage_repo_contract::TriageTraitRepo,
+            repo::{queue_transition::TicketTransition, triage_repo::TriageRepo},
+        },
     },
-    utils::helpers::{get_cache_data, set_cache_data},
+    utils::helpers::{get_cache_data, hospital_now, set_cache_data},
 };
 
 pub struct TriageService;
 
-#[async_trait]
-impl TriageServiceContracts for TriageService {
-    async fn perform_triage(
+const MAX_STATS_RANGE_DAYS: i64 = 366;
+
+/// Age from which patients are served in the elderly lane (lansia).
+const ELDERLY_AGE: u32 = 60;
+
+enum TicketAction {
+    Recall { max_calls: i32 },
+    Skip,
+    NoShow,
+    Requeue { position: usize },
+}
+
+impl TicketAction {
+    fn operation(&self) -> &'static str {
+        match self {
+            TicketAction::Recall { .. } => "recall",
+            TicketAction::Skip => "skip",
+            TicketAction::NoShow => "no-show",
+            TicketAction::Requeue { .. } => "requeue",
+        }
+    }
+}
+
+impl TriageService {
+    /// The queue a registration goes to, and the doctor chosen for it, who has to be on
+    /// the polyclinic's schedule today.
+    async fn resolve_destination(
         db: &DatabaseConnection,
-        payload: CreateTriageRequest,
-    ) -> Result<CreateTriageResponse, AppError> {
-        let txn = db.begin().await?;
+        polyclinic_code: Option<&str>,
+        visit_type: &VisitType,
+        doctor_id: Option<i32>,
+        now: DateTime<Tz>,
+    ) -> Result<(QueueScope, Option<doctors::Model>), AppError> {
+        let scope = match polyclinic_code {
+            Some(code) => QueueScope::Polyclinic(
+                <TriageRepo as TriageTraitRepo>::find_polyclinic_by_code(db, code).await?,
+            ),
+            None => QueueScope::VisitType(visit_type.clone()),
+        };
 
-        let patient =
-            <TriageRepo as TriageTraitRepo>::find_or_create_patient(&txn, &payload).await?;
+        let doctor = match (doctor_id, scope.polyclinic()) {
+            (Some(doctor_id), Some(poly)) => {
+                let today = now.format("%A").to_string();
+                Some(
+                    <TriageRepo as TriageTraitRepo>::find_scheduled_doctor(
+                        db, doctor_id, poly.id, &today,
+                    )
+                    .await?,
+                )
+            }
+            (Some(_), None) => {
+                return Err(AppError::BadRequest(
+                    "A doctor can only be chosen together with a polyclinic".into(),
+                ));
+            }
+            (None, _) => None,
+        };
 
-        let visit_intent =
-            <TriageRepo as TriageTraitRepo>::create_visit_intent(&txn, patient.id, &payload)
-                .await?;
+        Ok((scope, doctor))
+    }
+
+    /// Opens the visit and its queue ticket for a resolved patient, commits the
+    /// registration and announces the new ticket.
+    #[allow(clippy::too_many_arguments)]
+    async fn enqueue_visit(
+        redis: &Pool<RedisConnectionManager>,
+        txn: DatabaseTransaction,
+        patient: patients::Model,
+        visit_type: VisitType,
+        scope: QueueScope,
+        service_date: NaiveDate,
+        priority: PriorityCategory,
+        doctor_id: Option<i32>,
+    ) -> Result<CreateTriageResponse, AppError> {
+        let visit_intent = <TriageRepo as TriageTraitRepo>::create_visit_intent(
+            &txn,
+            patient.id,
+            &visit_type,
+            priority,
+        )
+        .await?;
 
         let queue_ticket = <TriageRepo as TriageTraitRepo>::create_queue_ticket(
             &txn,
             visit_intent.id,
-            payload.visit_type,
+            visit_type,
+            &scope,
+            service_date,
+            priority,
+            doctor_id,
         )
         .await?;
 
         txn.commit().await?;
 
+        QueueCache::invalidate(redis, &scope, service_date).await;
+        publish_queue_event(
+            redis,
+            &QueueEvent::from_ticket(QueueEventKind::Registered, &scope, &queue_ticket),
+        )
+        .await;
+
         Ok(CreateTriageResponse {
             patient_id: patient.id,
+            medical_record_number: patient.medical_record_number,
             visit_intent_id: visit_intent.id,
             queue_number: queue_ticket.queue_number,
+            queue_code: queue_ticket.queue_code,
             queue_type: queue_ticket.queue_type,
-            status: queue_ticket.status,
+            polyclinic_code: scope.polyclinic().map(|poly| poly.code.clone()),
+            doctor_id: queue_ticket.doctor_id,
+            service_date: queue_ticket.service_date,
+            priority: visit_intent.priority.to_value(),
+            status: queue_ticket.status.to_value(),
+        })
+    }
+
+    async fn change_ticket(
+        db: &DatabaseConnection,
+        redis: &Pool<RedisConnectionManager>,
+        scope: QueueScope,
+        service_date: NaiveDate,
+        queue_number: i32,
+        action: TicketAction,
+        idempotency_key: Option<IdempotentRequest>,
+    ) -> Result<TriageQueueTransition, AppError> {
+        let replay = idempotency_key.map(|key| {
+            replay(
+                action.operation(),
+                &format!("{}:{}:{}", scope.queue_key(), service_date, queue_number),
+                &key,
+            )
+        });
+
+        run_idempotent(redis, replay, || async move {
+            let txn = db.begin().await?;
+
+            let TicketTransition { previous, ticket } = match action {
+                TicketAction::Recall { max_calls } => {
+                    <TriageRepo as TriageTraitRepo>::recall_ticket(
+                        &txn,
+                        queue_number,
+                        &scope,
+                        service_date,
+                        max_calls,
+                    )
+                    .await?
+                }
+                TicketAction::Skip => {
+                    <TriageRepo as TriageTraitRepo>::transition_ticket(
+                        &txn,
+                        queue_number,
+                        &scope,
+                        service_date,
+                        QueueStatus::Skipped,
+                        false,
+                    )
+                    .await?
+                }
+                TicketAction::NoShow => {
+                    <TriageRepo as TriageTraitRepo>::transition_ticket(
+                        &txn,
+                        queue_number,
+                        &scope,
+                        service_date,
+                        QueueStatus::NoShow,
+                        false,
+                    )
+                    .await?
+                }
+                TicketAction::Requeue { position } => {
+                    <TriageRepo as TriageTraitRepo>::requeue_ticket(
+                        &txn,
+                        queue_number,
+                        &scope,
+                        service_date,
+                        position,
+                    )
+                    .await?
+                }
+            };
+
+            txn.commit().await?;
+
+            QueueCache::invalidate(redis, &scope, service_date).await;
+            publish_queue_event(
+                redis,
+                &QueueEvent::from_ticket(
+                    QueueEventKind::for_status(ticket.status),
+                    &scope,
+                    &ticket,
+                ),
+            )
+            .await;
+
+            Ok(TriageQueueTransition {
+                queue_number: ticket.queue_number,
+                queue_code: ticket.queue_code,
+                queue_type: ticket.queue_type,
+                previous_status: previous.to_value(),
+                status: ticket.status.to_value(),
+                call_count: ticket.call_count,
+            })
+        })
+        .await
+    }
+}
+
+#[async_trait]
+impl TriageServiceContracts for TriageService {
+    async fn perform_triage(
+        db: &DatabaseConnection,
+        redis: &Pool<RedisConnectionManager>,
+        mrn: &MrnConfig,
+        payload: CreateTriageRequest,
+        registered_by: i32,
+        idempotency_key: Option<IdempotentRequest>,
+    ) -> Result<CreateTriageResponse, AppError> {
+        let replay = idempotency_key.map(|key| replay("triage", "register", &key));
+
+        run_idempotent(redis, replay, || async move {
+            let now = hospital_now();
+            let service_date = now.date_naive();
+            let (scope, doctor) = Self::resolve_destination(
+                db,
+                payload.polyclinic_code.as_deref(),
+                &payload.visit_type,
+                payload.doctor_id,
+                now,
+            )
+            .await?;
+
+            let txn = db.begin().await?;
+
+            let patient = <TriageRepo as TriageTraitRepo>::find_or_create_patient(
+                &txn,
+                &payload,
+                registered_by,
+                mrn,
+                service_date,
+            )
+            .await?;
+
+            let priority = payload.priority.unwrap_or_else(|| {
+                match service_date.years_since(patient.date_of_birth) {
+                    Some(age) if age >= ELDERLY_AGE => PriorityCategory::Elderly,
+                    _ => PriorityCategory::Regular,
+                }
+            });
+
+            Self::enqueue_visit(
+                redis,
+                txn,
+                patient,
+                payload.visit_type,
+                scope,
+                service_date,
+                priority,
+                doctor.map(|doc| doc.id),
+            )
+            .await
+        })
+        .await
+    }
+
+    async fn perform_unidentified_triage(
+        db: &DatabaseConnection,
+        redis: &Pool<RedisConnectionManager>,
+        mrn: &MrnConfig,
+        payload: CreateUnidentifiedTriageRequest,
+        idempotency_key: Option<IdempotentRequest>,
+    ) -> Result<CreateTriageResponse, AppError> {
+        let replay = idempotency_key.map(|key| replay("triage", "unidentified", &key));
+
+        run_idempotent(redis, replay, || async move {
+            // Without an identity there is no insurance to bill, so the visit is general
+            let visit_type = VisitType::COMMON;
+            let now = hospital_now();
+            let service_date = now.date_naive();
+            let (scope, doctor) = Self::resolve_destination(
+                db,
+                payload.polyclinic_code.as_deref(),
+                &visit_type,
+                payload.doctor_id,
+                now,
+            )
+            .await?;
+
+            let txn = db.begin().await?;
+
+            let patient = <TriageRepo as TriageTraitRepo>::create_unidentified_patient(
+                &txn,
+                &payload,
+                mrn,
+                service_date,
+            )
+            .await?;
+
+            Self::enqueue_visit(
+                redis,
+                txn,
+                patient,
+                visit_type,
+                scope,
+                service_date,
+                payload.priority.unwrap_or(PriorityCategory::Emergency),
+                doctor.map(|doc| doc.id),
+            )
+            .await
         })
+        .await
     }
 
     async fn get_triage_queue(
         db: &DatabaseConnection,
         redis: &Pool<RedisConnectionManager>,
-        visit_type: String,
+        scope: QueueScope,
+        service_date: NaiveDate,
     ) -> Result<TriageQueueResponse, AppError> {
-        let normalize_type: Result<VisitType, AppError> = parse_visit_type!(visit_type);
-
-        let cache_key = format!("triage:queue:{}", normalize_type.as_ref()?);
+        let cache_key = QueueCache::resolve(redis, &scope, service_date)
+            .await?
+            .queue_key();
+        let (visit_type, polyclinic_code) = match &scope {
+            QueueScope::VisitType(visit_type) => (Some(visit_type.to_string()), None),
+            QueueScope::Polyclinic(poly) => (None, Some(poly.code.clone())),
+        };
 
-        if let Some(cached) = get_cache_data::<Vec<TriageQueueItem>>(&redis, &cache_key).await? {
+        if let Some(cached) = get_cache_data::<Vec<TriageQueueItem>>(redis, &cache_key).await? {
             let result = TriageQueueResponse {
-                visit_type: normalize_type?.to_string(),
+                visit_type,
+                polyclinic_code,
+                service_date,
                 data: cached,
             };
             return Ok(result);
         }
 
-        let response =
-            <TriageRepo as TriageTraitRepo>::get_queue(&db, normalize_type.as_ref()?).await?;
+        let response = <TriageRepo as TriageTraitRepo>::get_queue(db, &scope, service_date).await?;
+
+        set_cache_data(redis, &cache_key, &response, QUEUE_CACHE_TTL_SECS).await?;
 
         let result = TriageQueueResponse {
-            visit_type: normalize_type?.to_string(),
-            data: response.clone(),
+            visit_type,
+            polyclinic_code,
+            service_date,
+            data: response,
         };
 
-        set_cache_data(&redis, &cache_key, &result, 300).await?;
+        Ok(result)
+    }
+
+    async fn get_triage_queue_snapshot(
+        db: &DatabaseConnection,
+        scope: &QueueScope,
+        service_date: NaiveDate,
+    ) -> Result<TriageQueueResponse, AppError> {
+        let (visit_type, polyclinic_code) = match scope {
+            QueueScope::VisitType(visit_type) => (Some(visit_type.to_string()), None),
+            QueueScope::Polyclinic(poly) => (None, Some(poly.code.clone())),
+        };
+
+        let response = <TriageRepo as TriageTraitRepo>::get_queue(db, scope, service_date).await?;
 
         Ok(TriageQueueResponse {
             visit_type,
+            polyclinic_code,
+            service_date,
             data: response,
         })
     }
@@ -101,166 +423,428 @@ impl TriageServiceContracts for TriageService {
     async fn get_triage_queue_status_by_id(
         db: &DatabaseConnection,
         redis: &Pool<RedisConnectionManager>,
-        visit_type: String,
+        config: &QueueConfig,
+        scope: QueueScope,
+        service_date: NaiveDate,
         queue_number: i32,
     ) -> Result<TriageQueueStatus, AppError> {
-        let normalize_type: Result<VisitType, AppError> = parse_visit_type!(visit_type);
-        let cache_key = format!(
-            "triage:queue:{}:id:{}",
-            normalize_type.as_ref()?,
-            &queue_number
-        );
+        let cache_key = QueueCache::resolve(redis, &scope, service_date)
+            .await?
+            .ticket_key(queue_number);
 
-        if let Some(cached) = get_cache_data::<TriageQueueStatus>(&redis, &cache_key).await? {
+        if let Some(cached) = get_cache_data::<TriageQueueStatus>(redis, &cache_key).await? {
             return Ok(cached);
         }
 
         let response = <TriageRepo as TriageTraitRepo>::get_status_by_queue_number(
-            &db,
+            db,
             queue_number,
-            normalize_type?,
+            &scope,
+            service_date,
         )
         .await?;
 
+        let eta = if response.status == QueueStatus::Waiting {
+            let ahead = <TriageRepo as TriageTraitRepo>::count_waiting_ahead(db, &response).await?;
+            let avg_service_secs = <TriageRepo as TriageTraitRepo>::average_service_secs(
+                db,
+                &response.queue_key,
+                config.eta_sample_size,
+            )
+            .await?
+            .map(|secs| secs.round() as i64)
+            .unwrap_or(config.default_service_secs);
+            let estimated_wait_secs = ahead as i64 * avg_service_secs;
+
+            Some(TriageQueueEta {
+                position: ahead + 1,
+                avg_service_secs,
+                estimated_wait_secs,
+                estimated_call_at: Utc::now() + chrono::Duration::seconds(estimated_wait_secs),
+            })
+        } else {
+            None
+        };
+
         let formatted = format_created_at!(response.created_at);
 
         let result = TriageQueueStatus {
             queue_number: response.queue_number,
+            queue_code: response.queue_code,
             queue_type: response.queue_type,
-            status: response.status,
+            service_date: response.service_date,
+            status: response.status.to_value(),
             created_at: formatted,
+            eta,
         };
 
-        set_cache_data(&redis, &cache_key, &result, 300).await?;
+        set_cache_data(redis, &cache_key, &result, QUEUE_CACHE_TTL_SECS).await?;
 
         Ok(result)
     }
 
-    async fn call_patient(
+    async fn get_triage_queue_stats(
         db: &DatabaseConnection,
-        redis: &Pool<RedisConnectionManager>,
-        visit_type: String,
-        queue_number: i32,
-    ) -> Result<TriagePatientCalled, AppError> {
-        let normalize_type: Result<VisitType, AppError> = parse_visit_type!(visit_type);
-        let cache_key = format!(
-            "call:queue:{}:id:{}",
-            normalize_type.as_ref()?,
-            &queue_number
-        );
-
-        if let Some(cached) = get_cache_data::<TriagePatientCalled>(&redis, &cache_key).await? {
-            return Ok(cached);
+        config: &QueueConfig,
+        scope: QueueScope,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<TriageQueueStatsResponse, AppError> {
+        if from > to {
+            return Err(AppError::BadRequest(
+                "The start date must not be after the end date".into(),
+            ));
+        }
+        if (to - from).num_days() > MAX_STATS_RANGE_DAYS {
+            return Err(AppError::BadRequest(format!(
+                "Statistics can cover at most {} days",
+                MAX_STATS_RANGE_DAYS
+            )));
         }
 
-        let txn = db.begin().await?;
-
-        let response =
-            <TriageRepo as TriageTraitRepo>::call_patient(&txn, queue_number, normalize_type?)
-                .await?;
-
-        txn.commit().await?;
-
-        let formatted = format_option_dt!(response.called_at);
+        let queue_key = scope.queue_key();
+        let rolling_avg_service_secs = <TriageRepo as TriageTraitRepo>::average_service_secs(
+            db,
+            &queue_key,
+            config.eta_sample_size,
+        )
+        .await?;
+        let days =
+            <TriageRepo as TriageTraitRepo>::daily_queue_stats(db, &queue_key, from, to).await?;
 
-        let result = TriagePatientCalled {
-            queue_number: response.queue_number,
-            queue_type: response.queue_type,
-            called_at: formatted,
+        let (visit_type, polyclinic_code) = match &scope {
+            QueueScope::VisitType(visit_type) => (Some(visit_type.to_string()), None),
+            QueueScope::Polyclinic(poly) => (None, Some(poly.code.clone())),
         };
 
-        set_cache_data(&redis, &cache_key, &result, 300).await?;
+        Ok(TriageQueueStatsResponse {
+            visit_type,
+            polyclinic_code,
+            from,
+            to,
+            rolling_avg_service_secs,
+            rolling_sample_size: config.eta_sample_size,
+            days,
+        })
+    }
+
+    async fn call_patient(
+        db: &DatabaseConnection,
+        redis: &Pool<RedisConnectionManager>,
+        scope: QueueScope,
+        service_date: NaiveDate,
+        queue_number: i32,
+        idempotency_key: Option<IdempotentRequest>,
+    ) -> Result<TriagePatientCalled, AppError> {
+        let replay = idempotency_key.map(|key| {
+            replay(
+                "call",
+                &format!("{}:{}:{}", scope.queue_key(), service_date, queue_number),
+                &key,
+            )
+        });
+
+        run_idempotent(redis, replay, || async move {
+            let txn = db.begin().await?;
+
+            let response = <TriageRepo as TriageTraitRepo>::transition_ticket(
+                &txn,
+                queue_number,
+                &scope,
+                service_date,
+                QueueStatus::Called,
+                false,
+            )
+            .await?
+            .ticket;
+
+            txn.commit().await?;
+
+            QueueCache::invalidate(redis, &scope, service_date).await;
+            publish_queue_event(
+                redis,
+                &QueueEvent::from_ticket(QueueEventKind::Called, &scope, &response),
+            )
+            .await;
+
+            let formatted = format_option_dt!(response.called_at);
+
+            let result = TriagePatientCalled {
+                queue_number: response.queue_number,
+                queue_code: response.queue_code,
+                queue_type: response.queue_type,
+                called_at: formatted,
+            };
 
-        Ok(result)
+            Ok(result)
+        })
+        .await
     }
 
     async fn triage_complete(
         db: &DatabaseConnection,
         redis: &Pool<RedisConnectionManager>,
-        visit_type: String,
+        scope: QueueScope,
+        service_date: NaiveDate,
         queue_number: i32,
+        idempotency_key: Option<IdempotentRequest>,
     ) -> Result<TriageQueueComplete, AppError> {
-        let normalize_type: Result<VisitType, AppError> = parse_visit_type!(visit_type);
-        let cache_key = format!(
-            "complete:queue:{}:id:{}",
-            normalize_type.as_ref()?,
-            &queue_number
-        );
-
-        if let Some(cached) = get_cache_data::<TriageQueueComplete>(&redis, &cache_key).await? {
-            return Ok(cached);
-        }
-
-        let txn = db.begin().await?;
-
-        let response = <TriageRepo as TriageTraitRepo>::triage_queue_complete(
-            &txn,
-            queue_number,
-            normalize_type?,
-        )
-        .await?;
-
-        txn.commit().await?;
-
-        let formatted_called_at = format_option_dt!(response.called_at);
-        let formatted_done_at = format_option_dt!(response.done_at);
-
-        let result = TriageQueueComplete {
-            queue_number: response.queue_number,
-            queue_type: response.queue_type,
-            status: response.status,
-            called_at: formatted_called_at,
-            done_at: formatted_done_at,
-        };
+        let replay = idempotency_key.map(|key| {
+            replay(
+                "complete",
+                &format!("{}:{}:{}", scope.queue_key(), service_date, queue_number),
+                &key,
+            )
+        });
+
+        run_idempotent(redis, replay, || async move {
+            let txn = db.begin().await?;
+
+            let response = <TriageRepo as TriageTraitRepo>::transition_ticket(
+                &txn,
+                queue_number,
+                &scope,
+                service_date,
+                QueueStatus::Done,
+                false,
+            )
+            .await?
+            .ticket;
+
+            // A patient queued to a polyclinic goes on to its doctors; a visit-type queue
+            // is handed to a polyclinic separately
+            let encounter = match response.polyclinic_id {
+                Some(polyclinic_id) => Some(
+                    <EncounterRepo as EncounterTraitRepo>::open_encounter(
+                        &txn,
+                        response.visit_intent_id,
+                        polyclinic_id,
+                        response.doctor_id,
+                        service_date,
+                    )
+                    .await?,
+                ),
+                None => None,
+            };
 
-        set_cache_data(&redis, &cache_key, &result, 300).await?;
+            txn.commit().await?;
+
+            QueueCache::invalidate(redis, &scope, service_date).await;
+            publish_queue_event(
+                redis,
+                &QueueEvent::from_ticket(QueueEventKind::Completed, &scope, &response),
+            )
+            .await;
+
+            let formatted_called_at = format_option_dt!(response.called_at);
+            let formatted_done_at = format_option_dt!(response.done_at);
+
+            let result = TriageQueueComplete {
+                queue_number: response.queue_number,
+                queue_code: response.queue_code,
+                queue_type: response.queue_type,
+                status: response.status.to_value(),
+                called_at: formatted_called_at,
+                done_at: formatted_done_at,
+                encounter_id: encounter.map(|encounter| encounter.id),
+            };
 
-        Ok(result)
+            Ok(result)
+        })
+        .await
     }
 
     async fn cancel_patient_queue(
         db: &DatabaseConnection,
         redis: &Pool<RedisConnectionManager>,
-        visit_type: String,
+        scope: QueueScope,
+        service_date: NaiveDate,
         queue_number: i32,
+        allow_finalized: bool,
+        idempotency_key: Option<IdempotentRequest>,
     ) -> Result<TriagePatientCancel, AppError> {
-        let normalize_type: Result<VisitType, AppError> = parse_visit_type!(visit_type);
-        let cache_key = format!(
-            "cancel:queue:{}:id:{}",
-            normalize_type.as_ref()?,
-            &queue_number
-        );
-        if let Some(cached) = get_cache_data::<TriagePatientCancel>(&redis, &cache_key).await? {
-            return Ok(cached);
-        };
+        let replay = idempotency_key.map(|key| {
+            replay(
+                "cancel",
+                &format!("{}:{}:{}", scope.queue_key(), service_date, queue_number),
+                &key,
+            )
+        });
+
+        run_idempotent(redis, replay, || async move {
+            let txn = db.begin().await?;
+            let TicketTransition {
+                previous,
+                ticket: response,
+            } = <TriageRepo as TriageTraitRepo>::transition_ticket(
+                &txn,
+                queue_number,
+                &scope,
+                service_date,
+                QueueStatus::Canceled,
+                allow_finalized,
+            )
+            .await?;
+
+            txn.commit().await?;
+
+            QueueCache::invalidate(redis, &scope, service_date).await;
+            publish_queue_event(
+                redis,
+                &QueueEvent::from_ticket(QueueEventKind::Canceled, &scope, &response),
+            )
+            .await;
+
+            let result = TriagePatientCancel {
+                queue_type: response.queue_type,
+                queue_number: response.queue_number,
+                queue_code: response.queue_code,
+                previous_status: previous.to_value(),
+                new_status: response.status.to_value(),
+            };
 
-        let ticket = <TriageRepo as TriageTraitRepo>::get_status_by_queue_number(
-            &db,
-            queue_number.clone(),
-            normalize_type.clone()?,
+            Ok(result)
+        })
+        .await
+    }
+
+    async fn recall_patient(
+        db: &DatabaseConnection,
+        redis: &Pool<RedisConnectionManager>,
+        scope: QueueScope,
+        service_date: NaiveDate,
+        queue_number: i32,
+        max_calls: i32,
+        idempotency_key: Option<IdempotentRequest>,
+    ) -> Result<TriageQueueTransition, AppError> {
+        Self::change_ticket(
+            db,
+            redis,
+            scope,
+            service_date,
+            queue_number,
+            TicketAction::Recall { max_calls },
+            idempotency_key,
         )
-        .await?;
+        .await
+    }
 
-        let txn = db.begin().await?;
-        let response = <TriageRepo as TriageTraitRepo>::cancel_patient_queue(
-            &txn,
-            queue_number.clone(),
-            &normalize_type?,
+    async fn skip_patient(
+        db: &DatabaseConnection,
+        redis: &Pool<RedisConnectionManager>,
+        scope: QueueScope,
+        service_date: NaiveDate,
+        queue_number: i32,
+        idempotency_key: Option<IdempotentRequest>,
+    ) -> Result<TriageQueueTransition, AppError> {
+        Self::change_ticket(
+            db,
+            redis,
+            scope,
+            service_date,
+            queue_number,
+            TicketAction::Skip,
+            idempotency_key,
         )
-        .await?;
+        .await
+    }
 
-        txn.commit().await?;
+    async fn mark_no_show(
+        db: &DatabaseConnection,
+        redis: &Pool<RedisConnectionManager>,
+        scope: QueueScope,
+        service_date: NaiveDate,
+        queue_number: i32,
+        idempotency_key: Option<IdempotentRequest>,
+    ) -> Result<TriageQueueTransition, AppError> {
+        Self::change_ticket(
+            db,
+            redis,
+            scope,
+            service_date,
+            queue_number,
+            TicketAction::NoShow,
+            idempotency_key,
+        )
+        .await
+    }
 
-        let result = TriagePatientCancel {
-            queue_type: response.queue_type,
-            queue_number: response.queue_number,
-            previous_status: ticket.status,
-            new_status: response.status,
-        };
+    async fn requeue_patient(
+        db: &DatabaseConnection,
+        redis: &Pool<RedisConnectionManager>,
+        scope: QueueScope,
+        service_date: NaiveDate,
+        queue_number: i32,
+        position: usize,
+        idempotency_key: Option<IdempotentRequest>,
+    ) -> Result<TriageQueueTransition, AppError> {
+        Self::change_ticket(
+            db,
+            redis,
+            scope,
+            service_date,
+            queue_number,
+            TicketAction::Requeue { position },
+            idempotency_key,
+        )
+        .await
+    }
 
-        set_cache_data(&redis, &cache_key, &result, 300).await?;
+    async fn sweep_stale_tickets(
+        db: &DatabaseConnection,
+        redis: &Pool<RedisConnectionManager>,
+        config: &QueueConfig,
+    ) -> Result<usize, AppError> {
+        let today = hospital_now().date_naive();
+        let called_before =
+            Utc::now().naive_utc() - chrono::Duration::seconds(config.call_timeout_secs);
+
+        // yesterday's leftovers expire; today's unanswered calls become no-shows
+        let stale = <TriageRepo as TriageTraitRepo>::find_stale_tickets(db, today).await?;
+        let overdue =
+            <TriageRepo as TriageTraitRepo>::find_overdue_calls(db, today, called_before).await?;
+
+        let mut changed = 0;
+        for (ticket, to) in stale
+            .into_iter()
+            .map(|ticket| (ticket, QueueStatus::Expired))
+            .chain(
+                overdue
+                    .into_iter()
+                    .map(|ticket| (ticket, QueueStatus::NoShow)),
+            )
+        {
+            let txn = db.begin().await?;
+            let transition =
+                match <TriageRepo as TriageTraitRepo>::transition_ticket_by_id(&txn, ticket.id, to)
+                    .await
+                {
+                    Ok(transition) => transition,
+                    // moved on by a user or another instance since it was read
+                    Err(AppError::Conflict(_)) | Err(AppError::NotFound(_)) => {
+                        txn.rollback().await?;
+                        continue;
+                    }
+                    Err(err) => return Err(err),
+                };
+            txn.commit().await?;
+            changed += 1;
+
+            let scope =
+                <TriageRepo as TriageTraitRepo>::find_ticket_scope(db, &transition.ticket).await?;
+            QueueCache::invalidate(redis, &scope, transition.ticket.service_date).await;
+            publish_queue_event(
+                redis,
+                &QueueEvent::from_ticket(
+                    QueueEventKind::for_status(transition.ticket.status),
+                    &scope,
+                    &transition.ticket,
+                ),
+            )
+            .await;
+        }
 
-        Ok(result)
+        Ok(changed)
     }
 
     async fn handle_referral_upload(
@@ -0,0 +1,4 @@
+pub mod vitals_repo_contract;
+pub mod vitals_service_contract;
+pub use self::vitals_repo_contract::VitalsTraitRepo;
+pub use self::vitals_service_contract::VitalsServiceContracts;
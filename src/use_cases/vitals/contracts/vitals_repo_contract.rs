@@ -0,0 +1,56 @@
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use entity::{nurses, patients_visit_intent, vital_signs};
+use sea_orm::DatabaseConnection;
+
+use crate::{dtos::vitals::vitals_request::VitalMeasurements, error_handling::app_error::AppError};
+
+#[async_trait]
+pub trait VitalsTraitRepo {
+    async fn find_visit(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+    ) -> Result<patients_visit_intent::Model, AppError>;
+    /// The polyclinic a visit is seen in, from its encounter or else its queue ticket.
+    /// Visits of a visit-type queue not yet handed to a polyclinic have none.
+    async fn find_visit_polyclinic(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+    ) -> Result<Option<i32>, AppError>;
+    /// The nurse profile of a user account, matched through their employee record.
+    async fn find_nurse_for_user(
+        db: &DatabaseConnection,
+        user_id: i32,
+    ) -> Result<Option<nurses::Model>, AppError>;
+    /// Whether the nurse belongs to the polyclinic, as their home polyclinic or through
+    /// an assignment running at `at`.
+    async fn is_nurse_assigned(
+        db: &DatabaseConnection,
+        nurse: &nurses::Model,
+        polyclinic_id: i32,
+        at: NaiveDateTime,
+    ) -> Result<bool, AppError>;
+    /// Stores a set of measurements. The BMI is worked out from the weight and the
+    /// height taken with it, or the patient's last recorded height.
+    async fn create_vitals(
+        db: &DatabaseConnection,
+        visit: &patients_visit_intent::Model,
+        measurements: VitalMeasurements,
+        notes: Option<String>,
+        nurse_id: Option<i32>,
+        recorded_by: i32,
+        measured_at: NaiveDateTime,
+    ) -> Result<vital_signs::Model, AppError>;
+    async fn find_visit_vitals(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+    ) -> Result<Vec<vital_signs::Model>, AppError>;
+    /// Measurements of all of a patient's visits taken in `[from, to)`, newest first.
+    async fn find_patient_vitals(
+        db: &DatabaseConnection,
+        patient_id: i32,
+        from: Option<NaiveDateTime>,
+        to: Option<NaiveDateTime>,
+        limit: Option<u64>,
+    ) -> Result<Vec<vital_signs::Model>, AppError>;
+}
@@ -0,0 +1,39 @@
+use async_trait::async_trait;
+use entity::user::Role;
+use sea_orm::DatabaseConnection;
+
+use crate::{
+    dtos::vitals::{
+        response::{
+            PatientVitalsHistoryResponse, PatientVitalsTrendResponse, VisitVitalsResponse,
+            VitalSignsResponse,
+        },
+        vitals_request::{RecordVitalsRequest, VitalsHistoryQuery, VitalsTrendQuery},
+    },
+    error_handling::app_error::AppError,
+};
+
+#[async_trait]
+pub trait VitalsServiceContracts {
+    async fn record_vitals(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+        payload: RecordVitalsRequest,
+        recorded_by: i32,
+        role: Role,
+    ) -> Result<VitalSignsResponse, AppError>;
+    async fn get_visit_vitals(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+    ) -> Result<VisitVitalsResponse, AppError>;
+    async fn get_patient_vitals(
+        db: &DatabaseConnection,
+        patient_id: i32,
+        query: VitalsHistoryQuery,
+    ) -> Result<PatientVitalsHistoryResponse, AppError>;
+    async fn get_patient_vitals_trend(
+        db: &DatabaseConnection,
+        patient_id: i32,
+        query: VitalsTrendQuery,
+    ) -> Result<PatientVitalsTrendResponse, AppError>;
+}
@@ -0,0 +1 @@
+pub mod vitals_repo;
@@ -0,0 +1,191 @@
+use async_trait::async_trait;
+use chrono::{NaiveDateTime, Utc};
+use entity::{
+    encounters, nurses, nurses_polyclinic_assignments, patients_visit_intent, queue_ticket, user,
+    vital_signs::{self, ActiveModel},
+};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, Condition, DatabaseConnection, EntityTrait,
+    JoinType, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, RelationTrait,
+};
+
+use crate::{
+    dtos::vitals::vitals_request::VitalMeasurements, error_handling::app_error::AppError,
+    use_cases::vitals::contracts::vitals_repo_contract::VitalsTraitRepo,
+};
+
+pub struct VitalsRepo;
+
+fn body_mass_index(weight_kg: f64, height_cm: f64) -> f64 {
+    let height_m = height_cm / 100.0;
+    (weight_kg / (height_m * height_m) * 10.0).round() / 10.0
+}
+
+#[async_trait]
+impl VitalsTraitRepo for VitalsRepo {
+    async fn find_visit(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+    ) -> Result<patients_visit_intent::Model, AppError> {
+        patients_visit_intent::Entity::find_by_id(visit_intent_id)
+            .one(db)
+            .await?
+            .ok_or(AppError::NotFound(format!(
+                "Visit with id {} is not found",
+                visit_intent_id
+            )))
+    }
+
+    async fn find_visit_polyclinic(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+    ) -> Result<Option<i32>, AppError> {
+        if let Some(encounter) = encounters::Entity::find()
+            .filter(encounters::Column::VisitIntentId.eq(visit_intent_id))
+            .one(db)
+            .await?
+        {
+            return Ok(Some(encounter.polyclinic_id));
+        }
+
+        Ok(queue_ticket::Entity::find()
+            .filter(queue_ticket::Column::VisitIntentId.eq(visit_intent_id))
+            .one(db)
+            .await?
+            .and_then(|ticket| ticket.polyclinic_id))
+    }
+
+    async fn find_nurse_for_user(
+        db: &DatabaseConnection,
+        user_id: i32,
+    ) -> Result<Option<nurses::Model>, AppError> {
+        let Some(account) = user::Entity::find_by_id(user_id).one(db).await? else {
+            return Ok(None);
+        };
+
+        Ok(nurses::Entity::find()
+            .filter(nurses::Column::EmployeeId.eq(account.employee_id))
+            .one(db)
+            .await?)
+    }
+
+    async fn is_nurse_assigned(
+        db: &DatabaseConnection,
+        nurse: &nurses::Model,
+        polyclinic_id: i32,
+        at: NaiveDateTime,
+    ) -> Result<bool, AppError> {
+        if nurse.polyclinic_id == polyclinic_id {
+            return Ok(true);
+        }
+
+        let assignments = nurses_polyclinic_assignments::Entity::find()
+            .filter(nurses_polyclinic_assignments::Column::NurseId.eq(nurse.id))
+            .filter(nurses_polyclinic_assignments::Column::PolyclinicId.eq(polyclinic_id))
+            .filter(nurses_polyclinic_assignments::Column::AssignedSince.lte(at))
+            .filter(
+                Condition::any()
+                    .add(nurses_polyclinic_assignments::Column::AssignedUntil.is_null())
+                    .add(nurses_polyclinic_assignments::Column::AssignedUntil.gt(at)),
+            )
+            .count(db)
+            .await?;
+
+        Ok(assignments > 0)
+    }
+
+    async fn create_vitals(
+        db: &DatabaseConnection,
+        visit: &patients_visit_intent::Model,
+        measurements: VitalMeasurements,
+        notes: Option<String>,
+        nurse_id: Option<i32>,
+        recorded_by: i32,
+        measured_at: NaiveDateTime,
+    ) -> Result<vital_signs::Model, AppError> {
+        let bmi = match (measurements.weight_kg, measurements.height_cm) {
+            (Some(weight), Some(height)) => Some(body_mass_index(weight, height)),
+            (Some(weight), None) => vital_signs::Entity::find()
+                .join(
+                    JoinType::InnerJoin,
+                    vital_signs::Relation::PatientsVisitIntent.def(),
+                )
+                .filter(patients_visit_intent::Column::PatientId.eq(visit.patient_id))
+                .filter(vital_signs::Column::HeightCm.is_not_null())
+                .order_by_desc(vital_signs::Column::MeasuredAt)
+                .one(db)
+                .await?
+                .and_then(|last| last.height_cm)
+                .map(|height| body_mass_index(weight, height)),
+            _ => None,
+        };
+
+        let model = ActiveModel {
+            visit_intent_id: Set(visit.id),
+            systolic_bp: Set(measurements.systolic_bp),
+            diastolic_bp: Set(measurements.diastolic_bp),
+            pulse_rate: Set(measurements.pulse_rate),
+            respiratory_rate: Set(measurements.respiratory_rate),
+            temperature_celsius: Set(measurements
+                .temperature_celsius
+                .map(|celsius| (celsius * 10.0).round() / 10.0)),
+            spo2: Set(measurements.spo2),
+            weight_kg: Set(measurements
+                .weight_kg
+                .map(|weight| (weight * 100.0).round() / 100.0)),
+            height_cm: Set(measurements
+                .height_cm
+                .map(|height| (height * 10.0).round() / 10.0)),
+            bmi: Set(bmi),
+            pain_score: Set(measurements.pain_score),
+            notes: Set(notes.filter(|notes| !notes.trim().is_empty())),
+            nurse_id: Set(nurse_id),
+            recorded_by: Set(Some(recorded_by)),
+            measured_at: Set(measured_at),
+            created_at: Set(Utc::now().naive_utc()),
+            ..Default::default()
+        };
+
+        Ok(model.insert(db).await?)
+    }
+
+    async fn find_visit_vitals(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+    ) -> Result<Vec<vital_signs::Model>, AppError> {
+        Ok(vital_signs::Entity::find()
+            .filter(vital_signs::Column::VisitIntentId.eq(visit_intent_id))
+            .order_by_desc(vital_signs::Column::MeasuredAt)
+            .order_by_desc(vital_signs::Column::Id)
+            .all(db)
+            .await?)
+    }
+
+    async fn find_patient_vitals(
+        db: &DatabaseConnection,
+        patient_id: i32,
+        from: Option<NaiveDateTime>,
+        to: Option<NaiveDateTime>,
+        limit: Option<u64>,
+    ) -> Result<Vec<vital_signs::Model>, AppError> {
+        let mut query = vital_signs::Entity::find()
+            .join(
+                JoinType::InnerJoin,
+                vital_signs::Relation::PatientsVisitIntent.def(),
+            )
+            .filter(patients_visit_intent::Column::PatientId.eq(patient_id));
+        if let Some(from) = from {
+            query = query.filter(vital_signs::Column::MeasuredAt.gte(from));
+        }
+        if let Some(to) = to {
+            query = query.filter(vital_signs::Column::MeasuredAt.lt(to));
+        }
+
+        Ok(query
+            .order_by_desc(vital_signs::Column::MeasuredAt)
+            .order_by_desc(vital_signs::Column::Id)
+            .limit(limit)
+            .all(db)
+            .await?)
+    }
+}
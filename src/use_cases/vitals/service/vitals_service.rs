@@ -0,0 +1,245 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Days, Duration, Local, NaiveDate, NaiveDateTime, Utc};
+use entity::{patients_visit_intent::VisitStatus, user::Role, vital_signs};
+use sea_orm::DatabaseConnection;
+
+pub use crate::use_cases::vitals::contracts::vitals_service_contract::VitalsServiceContracts;
+use crate::{
+    dtos::vitals::{
+        response::{
+            PatientVitalsHistoryResponse, PatientVitalsTrendResponse, VisitVitalsResponse,
+            VitalSignsResponse, VitalTrend, VitalTrendPoint,
+        },
+        vitals_request::{RecordVitalsRequest, VitalMeasure, VitalsHistoryQuery, VitalsTrendQuery},
+    },
+    error_handling::app_error::AppError,
+    format_created_at,
+    use_cases::{
+        patient::{contracts::PatientTraitRepo, repo::patient_repo::PatientRepo},
+        vitals::{contracts::VitalsTraitRepo, repo::vitals_repo::VitalsRepo},
+    },
+    utils::helpers::hospital_day_start,
+};
+
+pub struct VitalsService;
+
+const DEFAULT_HISTORY_LIMIT: u64 = 100;
+
+/// How far ahead of the server clock a device may stamp its measurements.
+const MEASURED_AT_TOLERANCE_MINUTES: i64 = 5;
+
+fn vitals_response(vitals: vital_signs::Model) -> VitalSignsResponse {
+    VitalSignsResponse {
+        id: vitals.id,
+        visit_intent_id: vitals.visit_intent_id,
+        systolic_bp: vitals.systolic_bp,
+        diastolic_bp: vitals.diastolic_bp,
+        pulse_rate: vitals.pulse_rate,
+        respiratory_rate: vitals.respiratory_rate,
+        temperature_celsius: vitals.temperature_celsius,
+        spo2: vitals.spo2,
+        weight_kg: vitals.weight_kg,
+        height_cm: vitals.height_cm,
+        bmi: vitals.bmi,
+        pain_score: vitals.pain_score,
+        notes: vitals.notes,
+        nurse_id: vitals.nurse_id,
+        recorded_by: vitals.recorded_by,
+        measured_at: format_created_at!(vitals.measured_at),
+        created_at: format_created_at!(vitals.created_at),
+    }
+}
+
+/// Timestamps bounding whole hospital days, the end day included.
+fn day_range(
+    from: Option<NaiveDate>,
+    to: Option<NaiveDate>,
+) -> Result<(Option<NaiveDateTime>, Option<NaiveDateTime>), AppError> {
+    if let (Some(from), Some(to)) = (from, to)
+        && from > to
+    {
+        return Err(AppError::BadRequest(
+            "The range must not end before it starts".to_string(),
+        ));
+    }
+
+    Ok((
+        from.map(hospital_day_start),
+        to.and_then(|to| to.checked_add_days(Days::new(1)))
+            .map(hospital_day_start),
+    ))
+}
+
+fn vital_trend(measure: VitalMeasure, oldest_first: &[vital_signs::Model]) -> VitalTrend {
+    let points = oldest_first
+        .iter()
+        .filter_map(|vitals| {
+            measure.value(vitals).map(|value| VitalTrendPoint {
+                visit_intent_id: vitals.visit_intent_id,
+                measured_at: format_created_at!(vitals.measured_at),
+                value,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let values = points.iter().map(|point| point.value);
+    let min = values.clone().reduce(f64::min);
+    let max = values.reduce(f64::max);
+    let latest = points.last().map(|point| point.value);
+    let change = points
+        .first()
+        .zip(latest)
+        .map(|(earliest, latest)| latest - earliest.value);
+
+    VitalTrend {
+        measure: measure.name().to_string(),
+        unit: measure.unit().to_string(),
+        latest,
+        min,
+        max,
+        change,
+        points,
+    }
+}
+
+#[async_trait]
+impl VitalsServiceContracts for VitalsService {
+    async fn record_vitals(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+        payload: RecordVitalsRequest,
+        recorded_by: i32,
+        role: Role,
+    ) -> Result<VitalSignsResponse, AppError> {
+        let measurements = payload.measurements()?;
+
+        let now = Utc::now().naive_utc();
+        let measured_at = payload
+            .measured_at
+            .map(|measured_at| measured_at.naive_utc())
+            .unwrap_or(now);
+        if measured_at > now + Duration::minutes(MEASURED_AT_TOLERANCE_MINUTES) {
+            return Err(AppError::BadRequest(
+                "Measurement time cannot be in the future".to_string(),
+            ));
+        }
+
+        let visit = <VitalsRepo as VitalsTraitRepo>::find_visit(db, visit_intent_id).await?;
+        if matches!(
+            visit.status,
+            VisitStatus::Canceled | VisitStatus::Expired | VisitStatus::NoShow
+        ) {
+            return Err(AppError::Conflict(format!(
+                "Visit {} is {} and takes no measurements",
+                visit_intent_id, visit.status
+            )));
+        }
+
+        // Nurses record for the polyclinics they are assigned to
+        let nurse_id = if role == Role::Nurse {
+            let nurse = <VitalsRepo as VitalsTraitRepo>::find_nurse_for_user(db, recorded_by)
+                .await?
+                .ok_or(AppError::Forbidden(
+                    "Your account is not linked to a nurse".to_string(),
+                ))?;
+            if let Some(polyclinic_id) =
+                <VitalsRepo as VitalsTraitRepo>::find_visit_polyclinic(db, visit_intent_id).await?
+                && !<VitalsRepo as VitalsTraitRepo>::is_nurse_assigned(
+                    db,
+                    &nurse,
+                    polyclinic_id,
+                    now,
+                )
+                .await?
+            {
+                return Err(AppError::Forbidden(format!(
+                    "Nurse {} is not assigned to the polyclinic of visit {}",
+                    nurse.name, visit_intent_id
+                )));
+            }
+            Some(nurse.id)
+        } else {
+            None
+        };
+
+        let vitals = <VitalsRepo as VitalsTraitRepo>::create_vitals(
+            db,
+            &visit,
+            measurements,
+            payload.notes,
+            nurse_id,
+            recorded_by,
+            measured_at,
+        )
+        .await?;
+
+        Ok(vitals_response(vitals))
+    }
+
+    async fn get_visit_vitals(
+        db: &DatabaseConnection,
+        visit_intent_id: i32,
+    ) -> Result<VisitVitalsResponse, AppError> {
+        let visit = <VitalsRepo as VitalsTraitRepo>::find_visit(db, visit_intent_id).await?;
+        let vitals =
+            <VitalsRepo as VitalsTraitRepo>::find_visit_vitals(db, visit_intent_id).await?;
+
+        Ok(VisitVitalsResponse {
+            visit_intent_id,
+            patient_id: visit.patient_id,
+            data: vitals.into_iter().map(vitals_response).collect(),
+        })
+    }
+
+    async fn get_patient_vitals(
+        db: &DatabaseConnection,
+        patient_id: i32,
+        query: VitalsHistoryQuery,
+    ) -> Result<PatientVitalsHistoryResponse, AppError> {
+        <PatientRepo as PatientTraitRepo>::find_patient_by_id(db, patient_id).await?;
+        let (from, to) = day_range(query.from, query.to)?;
+
+        let vitals = <VitalsRepo as VitalsTraitRepo>::find_patient_vitals(
+            db,
+            patient_id,
+            from,
+            to,
+            Some(query.limit.unwrap_or(DEFAULT_HISTORY_LIMIT)),
+        )
+        .await?;
+
+        Ok(PatientVitalsHistoryResponse {
+            patient_id,
+            data: vitals.into_iter().map(vitals_response).collect(),
+        })
+    }
+
+    async fn get_patient_vitals_trend(
+        db: &DatabaseConnection,
+        patient_id: i32,
+        query: VitalsTrendQuery,
+    ) -> Result<PatientVitalsTrendResponse, AppError> {
+        <PatientRepo as PatientTraitRepo>::find_patient_by_id(db, patient_id).await?;
+        let (from, to) = day_range(query.from, query.to)?;
+
+        let mut vitals =
+            <VitalsRepo as VitalsTraitRepo>::find_patient_vitals(db, patient_id, from, to, None)
+                .await?;
+        vitals.reverse();
+
+        let measures = match query.measure {
+            Some(measure) => vec![measure],
+            None => VitalMeasure::ALL.to_vec(),
+        };
+
+        Ok(PatientVitalsTrendResponse {
+            patient_id,
+            from: query.from,
+            to: query.to,
+            data: measures
+                .into_iter()
+                .map(|measure| vital_trend(measure, &vitals))
+                .collect(),
+        })
+    }
+}
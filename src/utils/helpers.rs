@@ -1,24 +1,73 @@
 use axum::extract::multipart::Field;
 use bb8::Pool;
 use bb8_redis::RedisConnectionManager;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use chrono_tz::{Asia::Jakarta, Tz};
 use futures::{StreamExt, TryStreamExt};
 use image::ImageReader;
-use serde::de::DeserializeOwned;
+use sea_orm::ActiveEnum;
+use serde::{Deserialize, Deserializer, de::DeserializeOwned};
 
 use crate::error_handling::app_error::AppError;
 
 use redis::AsyncCommands;
 
+/// Current time at the hospital, used wherever a "today" is involved.
+pub fn hospital_now() -> DateTime<Tz> {
+    Utc::now().with_timezone(&Jakarta)
+}
+
+/// A stored UTC timestamp as the hospital's wall-clock time.
+pub fn hospital_time(at: NaiveDateTime) -> DateTime<Tz> {
+    Utc.from_utc_datetime(&at).with_timezone(&Jakarta)
+}
+
+/// Midnight opening a hospital day, as the UTC timestamp it is stored as.
+pub fn hospital_day_start(date: NaiveDate) -> NaiveDateTime {
+    let midnight = date.and_time(NaiveTime::MIN);
+    Jakarta
+        .from_local_datetime(&midnight)
+        .earliest()
+        .map(|start| start.naive_utc())
+        .unwrap_or(midnight)
+}
+
+/// Reads a database enum from its stored value, accepting any case and spaces or dashes
+/// for underscores, e.g. `life-threatening` for `LIFE_THREATENING`.
+pub fn deserialize_active_enum<'de, D, E>(d: D) -> Result<E, D::Error>
+where
+    D: Deserializer<'de>,
+    E: ActiveEnum<Value = String>,
+{
+    let s = String::deserialize(d)?;
+    let value = s.trim().to_uppercase().replace([' ', '-'], "_");
+    E::try_from_value(&value).map_err(|_| serde::de::Error::custom(format!("Unknown value: {}", s)))
+}
+
+pub fn deserialize_optional_active_enum<'de, D, E>(d: D) -> Result<Option<E>, D::Error>
+where
+    D: Deserializer<'de>,
+    E: ActiveEnum<Value = String>,
+{
+    let Some(s) = Option::<String>::deserialize(d)? else {
+        return Ok(None);
+    };
+    let value = s.trim().to_uppercase().replace([' ', '-'], "_");
+    E::try_from_value(&value)
+        .map(Some)
+        .map_err(|_| serde::de::Error::custom(format!("Unknown value: {}", s)))
+}
+
 pub async fn get_cache_data<T: DeserializeOwned>(
     redis: &Pool<RedisConnectionManager>,
     cache_key: &str,
 ) -> Result<Option<T>, AppError> {
     let mut redis_conn = redis.get().await?;
     let cache_data: Option<String> = redis_conn.get(cache_key).await?;
-    if let Some(cache_data) = cache_data {
-        if let Ok(parsed) = serde_json::from_str::<T>(&cache_data) {
-            return Ok(Some(parsed));
-        }
+    if let Some(cache_data) = cache_data
+        && let Ok(parsed) = serde_json::from_str::<T>(&cache_data)
+    {
+        return Ok(Some(parsed));
     }
     Ok(None)
 }
@@ -38,6 +87,29 @@ pub async fn set_cache_data<T: serde::Serialize>(
     Ok(())
 }
 
+pub async fn take_cache_data<T: DeserializeOwned>(
+    redis: &Pool<RedisConnectionManager>,
+    cache_key: &str,
+) -> Result<Option<T>, AppError> {
+    let mut redis_conn = redis.get().await?;
+    let cache_data: Option<String> = redis_conn.get_del(cache_key).await?;
+    if let Some(cache_data) = cache_data
+        && let Ok(parsed) = serde_json::from_str::<T>(&cache_data)
+    {
+        return Ok(Some(parsed));
+    }
+    Ok(None)
+}
+
+pub async fn delete_cache_data(
+    redis: &Pool<RedisConnectionManager>,
+    cache_key: &str,
+) -> Result<(), AppError> {
+    let mut redis_conn = redis.get().await?;
+    redis_conn.del::<_, ()>(cache_key).await?;
+    Ok(())
+}
+
 pub async fn resize_image_from_bytes(original: Vec<u8>) -> Result<Vec<u8>, AppError> {
     // Decode image from buffer
     let img = ImageReader::new(std::io::Cursor::new(original))
@@ -0,0 +1,87 @@
+use chrono::Utc;
+use entity::user;
+use jsonwebtoken::{DecodingKey, EncodingKey, Header, Validation, decode, encode};
+use sea_orm::ActiveEnum;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::error_handling::app_error::AppError;
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum TokenType {
+    Access,
+    Refresh,
+    /// Only opens queue display feeds, passed in the query string by browser boards
+    /// that cannot send an `Authorization` header.
+    Display,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Claims {
+    pub sub: String,
+    pub username: String,
+    pub role: String,
+    pub token_type: TokenType,
+    pub jti: String,
+    pub iat: i64,
+    pub exp: i64,
+}
+
+impl Claims {
+    pub fn user_id(&self) -> Result<i32, AppError> {
+        self.sub
+            .parse::<i32>()
+            .map_err(|_| AppError::Unauthorized("Invalid token subject".into()))
+    }
+
+    /// Seconds left before the token expires, used as TTL for redis entries.
+    pub fn remaining_secs(&self) -> u64 {
+        (self.exp - Utc::now().timestamp()).max(1) as u64
+    }
+}
+
+pub fn issue_token(
+    user: &user::Model,
+    token_type: TokenType,
+    secret: &str,
+    expire_secs: u64,
+) -> Result<(String, Claims), AppError> {
+    let now = Utc::now().timestamp();
+    let claims = Claims {
+        sub: user.id.to_string(),
+        username: user.username.clone(),
+        role: user.role.to_value(),
+        token_type,
+        jti: Uuid::new_v4().to_string(),
+        iat: now,
+        exp: now + expire_secs as i64,
+    };
+
+    let token = encode(
+        &Header::default(),
+        &claims,
+        &EncodingKey::from_secret(secret.as_bytes()),
+    )
+    .map_err(|e| AppError::Internal(format!("Failed to sign token: {e}")))?;
+
+    Ok((token, claims))
+}
+
+pub fn decode_token(
+    token: &str,
+    secret: &str,
+    expected_type: TokenType,
+) -> Result<Claims, AppError> {
+    let data = decode::<Claims>(
+        token,
+        &DecodingKey::from_secret(secret.as_bytes()),
+        &Validation::default(),
+    )?;
+
+    if data.claims.token_type != expected_type {
+        return Err(AppError::Unauthorized("Invalid token type".into()));
+    }
+
+    Ok(data.claims)
+}
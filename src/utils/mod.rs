@@ -1,2 +1,4 @@
 pub mod helpers;
+pub mod jwt;
 pub mod macros;
+pub mod nik;
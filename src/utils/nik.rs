@@ -0,0 +1,249 @@
+use chrono::{Datelike, NaiveDate};
+use validator::{ValidationError, ValidationErrors};
+
+/// Province codes (kode provinsi) in use on NIKs, including the codes of the Papua
+/// provinces split off in 2022 and those issued before the split.
+const PROVINCE_CODES: &[u32] = &[
+    11, 12, 13, 14, 15, 16, 17, 18, 19, // Sumatra
+    21, // Kepulauan Riau
+    31, 32, 33, 34, 35, 36, // Java
+    51, 52, 53, // Bali & Nusa Tenggara
+    61, 62, 63, 64, 65, // Kalimantan
+    71, 72, 73, 74, 75, 76, // Sulawesi
+    81, 82, // Maluku
+    91, 92, 93, 94, 95, 96, // Papua
+];
+
+/// Women have 40 added to the day of birth encoded in their NIK.
+const FEMALE_DAY_OFFSET: u32 = 40;
+
+/// An Indonesian NIK (Nomor Induk Kependudukan) split into its parts:
+/// `PPRRDD DDMMYY SSSS` - province, regency, district, birth date and serial.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Nik {
+    pub province: u32,
+    pub regency: u32,
+    pub district: u32,
+    pub birth_day: u32,
+    pub birth_month: u32,
+    pub birth_year: u32,
+    pub female: bool,
+    pub serial: u32,
+}
+
+impl Nik {
+    pub fn parse(nik: &str) -> Result<Nik, ValidationError> {
+        if nik.len() != 16 {
+            return Err(nik_error("nik_length", "National ID must be 16 digits"));
+        }
+        if !nik.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(nik_error(
+                "nik_digits",
+                "National ID must only contain digits",
+            ));
+        }
+
+        let part = |from: usize, to: usize| nik[from..to].parse::<u32>().unwrap_or_default();
+        let province = part(0, 2);
+        let regency = part(2, 4);
+        let district = part(4, 6);
+        let encoded_day = part(6, 8);
+        let birth_month = part(8, 10);
+        let birth_year = part(10, 12);
+        let serial = part(12, 16);
+
+        if !PROVINCE_CODES.contains(&province) {
+            return Err(nik_error(
+                "nik_province",
+                "National ID has an unknown province code",
+            ));
+        }
+        if regency == 0 || district == 0 {
+            return Err(nik_error(
+                "nik_region",
+                "National ID has an invalid regency or district code",
+            ));
+        }
+
+        let female = encoded_day > FEMALE_DAY_OFFSET;
+        let birth_day = if female {
+            encoded_day - FEMALE_DAY_OFFSET
+        } else {
+            encoded_day
+        };
+        // The century is not encoded; 2000 + YY is a leap year whenever either century
+        // could be, so 29 February is only accepted for years that can have one
+        if NaiveDate::from_ymd_opt(2000 + birth_year as i32, birth_month, birth_day).is_none() {
+            return Err(nik_error(
+                "nik_birth_date",
+                "National ID has an invalid encoded birth date",
+            ));
+        }
+        if serial == 0 {
+            return Err(nik_error(
+                "nik_serial",
+                "National ID has an invalid serial number",
+            ));
+        }
+
+        Ok(Nik {
+            province,
+            regency,
+            district,
+            birth_day,
+            birth_month,
+            birth_year,
+            female,
+            serial,
+        })
+    }
+
+    /// Whether the encoded birth date agrees with `date_of_birth`. The NIK only keeps the
+    /// last two digits of the year.
+    pub fn matches_date_of_birth(&self, date_of_birth: NaiveDate) -> bool {
+        self.birth_day == date_of_birth.day()
+            && self.birth_month == date_of_birth.month()
+            && self.birth_year == date_of_birth.year().rem_euclid(100) as u32
+    }
+}
+
+/// Cross-checks a well-formed NIK against the patient's details, adding an error on the
+/// field that disagrees. Details that are not known are not checked.
+pub fn check_nik_profile(
+    nik: &Nik,
+    date_of_birth: Option<NaiveDate>,
+    female: Option<bool>,
+    errors: &mut ValidationErrors,
+) {
+    if let Some(date_of_birth) = date_of_birth
+        && !nik.matches_date_of_birth(date_of_birth)
+    {
+        errors.add(
+            "date_of_birth",
+            nik_error(
+                "nik_birth_date_mismatch",
+                "Date of birth does not match the birth date encoded in the national ID",
+            ),
+        );
+    }
+    if let Some(female) = female
+        && female != nik.female
+    {
+        errors.add(
+            "gender",
+            nik_error(
+                "nik_gender_mismatch",
+                "Gender does not match the national ID",
+            ),
+        );
+    }
+}
+
+pub fn validate_nik(nik: &str) -> Result<(), ValidationError> {
+    Nik::parse(nik).map(|_| ())
+}
+
+fn nik_error(code: &'static str, message: &'static str) -> ValidationError {
+    ValidationError::new(code).with_message(message.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn error_code(nik: &str) -> String {
+        Nik::parse(nik).unwrap_err().code.to_string()
+    }
+
+    #[test]
+    fn parses_male_nik() {
+        let nik = Nik::parse("3201010508900001").unwrap();
+        assert_eq!(nik.province, 32);
+        assert_eq!(nik.regency, 1);
+        assert_eq!(nik.district, 1);
+        assert_eq!((nik.birth_day, nik.birth_month, nik.birth_year), (5, 8, 90));
+        assert!(!nik.female);
+        assert_eq!(nik.serial, 1);
+    }
+
+    #[test]
+    fn female_birth_day_has_forty_added() {
+        let nik = Nik::parse("3201014508900001").unwrap();
+        assert!(nik.female);
+        assert_eq!(nik.birth_day, 5);
+        assert!(nik.matches_date_of_birth(date(1990, 8, 5)));
+    }
+
+    #[test]
+    fn rejects_encoded_day_between_32_and_40() {
+        for day in 32..=40 {
+            let nik = format!("320101{day:02}08900001");
+            assert_eq!(error_code(&nik), "nik_birth_date", "day {day}");
+        }
+        assert_eq!(error_code("3201017208900001"), "nik_birth_date");
+    }
+
+    #[test]
+    fn rejects_malformed_input() {
+        assert_eq!(error_code("320101050890001"), "nik_length");
+        assert_eq!(error_code("32010105089000a1"), "nik_digits");
+    }
+
+    #[test]
+    fn rejects_unknown_province_code() {
+        assert_eq!(error_code("1001010508900001"), "nik_province");
+        assert_eq!(error_code("9901010508900001"), "nik_province");
+    }
+
+    #[test]
+    fn rejects_zero_regency_district_or_serial() {
+        assert_eq!(error_code("3200010508900001"), "nik_region");
+        assert_eq!(error_code("3201000508900001"), "nik_region");
+        assert_eq!(error_code("3201010508900000"), "nik_serial");
+    }
+
+    #[test]
+    fn accepts_29_february_only_in_leap_years() {
+        let leap = Nik::parse("3201012902000001").unwrap();
+        assert!(leap.matches_date_of_birth(date(2000, 2, 29)));
+        assert!(Nik::parse("3201016902960001").unwrap().female);
+
+        assert_eq!(error_code("3201012902010001"), "nik_birth_date");
+        assert_eq!(error_code("3201016902990001"), "nik_birth_date");
+    }
+
+    #[test]
+    fn matches_date_of_birth_on_the_last_two_year_digits() {
+        let nik = Nik::parse("3201010508900001").unwrap();
+        assert!(nik.matches_date_of_birth(date(1990, 8, 5)));
+        assert!(!nik.matches_date_of_birth(date(1991, 8, 5)));
+        assert!(!nik.matches_date_of_birth(date(1990, 8, 6)));
+    }
+
+    #[test]
+    fn profile_check_flags_mismatched_fields() {
+        let nik = Nik::parse("3201014508900001").unwrap();
+
+        let mut errors = ValidationErrors::new();
+        check_nik_profile(&nik, Some(date(1990, 8, 5)), Some(true), &mut errors);
+        assert!(errors.is_empty());
+
+        let mut errors = ValidationErrors::new();
+        check_nik_profile(&nik, Some(date(1990, 8, 6)), Some(false), &mut errors);
+        let fields = errors.field_errors();
+        assert_eq!(fields["date_of_birth"][0].code, "nik_birth_date_mismatch");
+        assert_eq!(fields["gender"][0].code, "nik_gender_mismatch");
+    }
+
+    #[test]
+    fn profile_check_skips_unknown_details() {
+        let nik = Nik::parse("3201014508900001").unwrap();
+        let mut errors = ValidationErrors::new();
+        check_nik_profile(&nik, None, None, &mut errors);
+        assert!(errors.is_empty());
+    }
+}